@@ -0,0 +1,269 @@
+// Copyright (c) 2021 The vulkano developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>,
+// at your option. All files in the project carrying such
+// notice may not be copied, modified, or distributed except
+// according to those terms.
+
+//! Generates the invocations of `device_extensions!` and `instance_extensions!`.
+
+use crate::registry::{parse_version, Depends, Extension, ExtensionType, Registry};
+use std::fmt::Write;
+
+/// Extensions that must be enabled whenever the device supports them.
+const REQUIRED_IF_SUPPORTED: &[(&str, &str)] = &[(
+    "VK_KHR_portability_subset",
+    "https://vulkan.lunarg.com/doc/view/1.2.162.1/mac/1.2-extensions/vkspec.html#VUID-VkDeviceCreateInfo-pProperties-04451",
+)];
+
+/// Extensions that were promoted to core, but whose functionality is only an optional feature of
+/// the core version. They are not treated as enabled by the core version.
+const OPTIONAL_IN_CORE: &[&str] = &[
+    "VK_KHR_sampler_mirror_clamp_to_edge",
+    "VK_KHR_draw_indirect_count",
+    "VK_EXT_sampler_filter_minmax",
+    "VK_EXT_shader_viewport_index_layer",
+];
+
+/// Field names that don't follow the usual naming and are kept for compatibility.
+const RENAMES: &[(&str, &str)] = &[(
+    "VK_EXT_external_memory_dma_buf",
+    "ext_external_memory_dmabuf",
+)];
+
+/// Generates the `device_extensions!` invocation.
+pub fn generate_device(registry: &Registry) -> String {
+    let mut out = String::new();
+    out.push_str(HEADER);
+    out.push_str("device_extensions! {\n    DeviceExtensions,\n    RawDeviceExtensions,\n");
+    out.push_str("    [ // required if supported extensions\n");
+    for &(name, reason) in REQUIRED_IF_SUPPORTED {
+        if registry.extension(name).is_some() {
+            writeln!(out, "        // {}", reason).unwrap();
+            writeln!(out, "        {},", field_name(name)).unwrap();
+        }
+    }
+    out.push_str("    ],\n");
+
+    for ext in registry
+        .extensions
+        .iter()
+        .filter(|e| e.ty == ExtensionType::Device)
+    {
+        let deps = dependencies(registry, ext);
+        write_entry_start(&mut out, ext);
+        if let Some((major, minor)) = deps.api_version.filter(|&v| v > (1, 0)) {
+            writeln!(out, "        api_version: Version::V{}_{},", major, minor).unwrap();
+        }
+        writeln!(out, "        requires: [{}],", deps.device.join(", ")).unwrap();
+        writeln!(
+            out,
+            "        requires_instance: [{}],",
+            deps.instance.join(", ")
+        )
+        .unwrap();
+        out.push_str("    },\n");
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+/// Generates the `instance_extensions!` invocation.
+pub fn generate_instance(registry: &Registry) -> String {
+    let mut out = String::new();
+    out.push_str(HEADER);
+    out.push_str("instance_extensions! {\n    InstanceExtensions,\n    RawInstanceExtensions,\n");
+
+    for ext in registry
+        .extensions
+        .iter()
+        .filter(|e| e.ty == ExtensionType::Instance)
+    {
+        let deps = dependencies(registry, ext);
+        write_entry_start(&mut out, ext);
+        writeln!(out, "        requires: [{}],", deps.instance.join(", ")).unwrap();
+        out.push_str("    },\n");
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+const HEADER: &str =
+    "// This file is generated by `autogen` from the Vulkan registry. Do not edit it by hand.\n\n";
+
+fn write_entry_start(out: &mut String, ext: &Extension) {
+    writeln!(out, "    {} => b\"{}\" {{", field_name(&ext.name), ext.name).unwrap();
+
+    let core = ext.promoted_to.as_deref().and_then(parse_version);
+    match core {
+        Some((major, minor)) if OPTIONAL_IN_CORE.contains(&ext.name.as_str()) => {
+            writeln!(
+                out,
+                "        // Promoted to Vulkan {}.{}, but only as an optional feature.",
+                major, minor
+            )
+            .unwrap();
+            out.push_str("        core: None,\n");
+        }
+        Some((major, minor)) => {
+            writeln!(out, "        core: Some(Version::V{}_{}),", major, minor).unwrap();
+        }
+        None => out.push_str("        core: None,\n"),
+    }
+}
+
+/// Turns `VK_KHR_swapchain` into `khr_swapchain`.
+pub fn field_name(name: &str) -> String {
+    match RENAMES.iter().find(|(n, _)| *n == name) {
+        Some((_, field)) => (*field).to_owned(),
+        None => name.trim_start_matches("VK_").to_ascii_lowercase(),
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Dependencies {
+    device: Vec<String>,
+    instance: Vec<String>,
+    api_version: Option<(u32, u32)>,
+}
+
+/// Returns the extensions that must be enabled alongside `ext`, and the minimum API version.
+///
+/// When the registry allows either an extension or a core version, as in
+/// `VK_KHR_get_physical_device_properties2,VK_VERSION_1_1`, the extension is listed. This
+/// matches what the extension tables expect: extensions that are promoted to the core version in
+/// use are treated as enabled, so the requirement is still met on that version.
+fn dependencies(registry: &Registry, ext: &Extension) -> Dependencies {
+    fn collect(depends: &Depends, names: &mut Vec<String>, version: &mut Option<(u32, u32)>) {
+        match depends {
+            Depends::Name(name) => match parse_version(name) {
+                Some(v) => *version = Some(version.map_or(v, |cur| cur.max(v))),
+                None => {
+                    if !names.contains(name) {
+                        names.push(name.clone());
+                    }
+                }
+            },
+            Depends::And(terms) => {
+                for term in terms {
+                    collect(term, names, version);
+                }
+            }
+            Depends::Or(alternatives) => {
+                // Prefer the first alternative that only needs extensions.
+                let chosen = alternatives
+                    .iter()
+                    .find(|alt| {
+                        let mut v = None;
+                        collect(alt, &mut Vec::new(), &mut v);
+                        v.is_none()
+                    })
+                    .unwrap_or(&alternatives[0]);
+                collect(chosen, names, version);
+            }
+        }
+    }
+
+    let mut names = Vec::new();
+    let mut api_version = None;
+    if let Some(depends) = &ext.depends {
+        collect(depends, &mut names, &mut api_version);
+    }
+
+    let mut deps = Dependencies {
+        api_version,
+        ..Dependencies::default()
+    };
+    for name in names {
+        match registry.extension(&name).map(|e| e.ty) {
+            Some(ExtensionType::Device) => deps.device.push(field_name(&name)),
+            Some(ExtensionType::Instance) => deps.instance.push(field_name(&name)),
+            None => (),
+        }
+    }
+    deps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{dependencies, generate_device, generate_instance, Dependencies};
+    use crate::registry::fixture;
+
+    #[test]
+    fn dependency_expressions() {
+        let registry = fixture();
+        let deps = |name| dependencies(&registry, registry.extension(name).unwrap());
+
+        // Old-style `requires`.
+        assert_eq!(
+            deps("VK_KHR_8bit_storage"),
+            Dependencies {
+                device: vec!["khr_storage_buffer_storage_class".to_owned()],
+                instance: vec!["khr_get_physical_device_properties2".to_owned()],
+                api_version: None,
+            }
+        );
+        // An extension or the core version that it was promoted to.
+        assert_eq!(
+            deps("VK_NV_mesh_shader"),
+            Dependencies {
+                device: vec![],
+                instance: vec!["khr_get_physical_device_properties2".to_owned()],
+                api_version: None,
+            }
+        );
+        // A minimum version and an extension.
+        assert_eq!(
+            deps("VK_KHR_spirv_1_4"),
+            Dependencies {
+                device: vec!["khr_shader_float_controls".to_owned()],
+                instance: vec![],
+                api_version: Some((1, 1)),
+            }
+        );
+    }
+
+    #[test]
+    fn device_table() {
+        let out = generate_device(&fixture());
+
+        assert!(out.starts_with(
+            "// This file is generated by `autogen` from the Vulkan registry. Do not edit it by \
+             hand.\n\ndevice_extensions! {\n    DeviceExtensions,\n    RawDeviceExtensions,\n    \
+             [ // required if supported extensions\n    ],\n"
+        ));
+        assert!(out.contains(
+            "    khr_8bit_storage => b\"VK_KHR_8bit_storage\" {\n        \
+             core: Some(Version::V1_2),\n        requires: [khr_storage_buffer_storage_class],\n        \
+             requires_instance: [khr_get_physical_device_properties2],\n    },\n"
+        ));
+        assert!(out.contains(
+            "    khr_spirv_1_4 => b\"VK_KHR_spirv_1_4\" {\n        core: Some(Version::V1_2),\n        \
+             api_version: Version::V1_1,\n        requires: [khr_shader_float_controls],\n        \
+             requires_instance: [],\n    },\n"
+        ));
+        assert!(out
+            .contains("    ext_external_memory_dmabuf => b\"VK_EXT_external_memory_dma_buf\" {\n"));
+        assert!(!out.contains("khr_surface =>"));
+        assert!(!out.contains("khr_disabled"));
+    }
+
+    #[test]
+    fn instance_table() {
+        let out = generate_instance(&fixture());
+
+        assert!(out.contains(
+            "    khr_xlib_surface => b\"VK_KHR_xlib_surface\" {\n        core: None,\n        \
+             requires: [khr_surface],\n    },\n"
+        ));
+        assert!(out.contains(
+            "    khr_get_physical_device_properties2 => b\"VK_KHR_get_physical_device_properties2\" \
+             {\n        core: Some(Version::V1_1),\n        requires: [],\n    },\n"
+        ));
+        assert!(!out.contains("khr_8bit_storage"));
+    }
+}
@@ -0,0 +1,302 @@
+// Copyright (c) 2021 The vulkano developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>,
+// at your option. All files in the project carrying such
+// notice may not be copied, modified, or distributed except
+// according to those terms.
+
+//! Generates the invocation of `features!`.
+//!
+//! The members of `VkPhysicalDeviceFeatures` go in the `core` list. Every other struct that can
+//! be chained to `VkPhysicalDeviceFeatures2` goes in the `extensions` list, which `features!`
+//! wires into the `pNext` chain.
+
+use crate::registry::{Registry, TypeKind};
+use std::collections::HashSet;
+use std::fmt::Write;
+
+/// Feature names that don't follow the usual naming and are kept for compatibility.
+const RENAMES: &[(&str, &str, &str)] = &[
+    (
+        "VkPhysicalDevice8BitStorageFeatures",
+        "storageBuffer8BitAccess",
+        "storage_buffer_8bit",
+    ),
+    (
+        "VkPhysicalDevice8BitStorageFeatures",
+        "uniformAndStorageBuffer8BitAccess",
+        "storage_uniform_8bit",
+    ),
+    (
+        "VkPhysicalDevice8BitStorageFeatures",
+        "storagePushConstant8",
+        "storage_push_constant_8bit",
+    ),
+    (
+        "VkPhysicalDevice16BitStorageFeatures",
+        "storageBuffer16BitAccess",
+        "storage_buffer_16bit",
+    ),
+    (
+        "VkPhysicalDevice16BitStorageFeatures",
+        "uniformAndStorageBuffer16BitAccess",
+        "storage_uniform_16bit",
+    ),
+    (
+        "VkPhysicalDevice16BitStorageFeatures",
+        "storagePushConstant16",
+        "storage_push_constant_16bit",
+    ),
+    (
+        "VkPhysicalDevice16BitStorageFeatures",
+        "storageInputOutput16",
+        "storage_input_output_16bit",
+    ),
+];
+
+/// Generates the `features!` invocation.
+pub fn generate(registry: &Registry) -> String {
+    let mut out = String::new();
+    out.push_str(
+        "// This file is generated by `autogen` from the Vulkan registry. Do not edit it by hand.\n\n",
+    );
+    out.push_str("features! {\n    core: [\n");
+
+    let mut taken = HashSet::new();
+
+    if let Some(TypeKind::Struct { members, .. }) =
+        registry.ty("VkPhysicalDeviceFeatures").map(|t| &t.kind)
+    {
+        for member in members {
+            let name = snake_case(&member.name);
+            writeln!(out, "        {} => {},", name, member.name).unwrap();
+            taken.insert(name);
+        }
+    }
+    out.push_str("    ],\n    extensions: [\n");
+
+    for struct_name in extension_structs(registry) {
+        let members = match &registry.ty(struct_name).unwrap().kind {
+            TypeKind::Struct { members, .. } => members,
+            _ => unreachable!(),
+        };
+        let vendor = vendor_suffix(struct_name).to_ascii_lowercase();
+
+        let mut stype = None;
+        let mut fields = Vec::new();
+        for member in members {
+            if member.name == "sType" {
+                stype = member.values.as_deref();
+                continue;
+            }
+            if member.ty.base != "VkBool32" || !member.ty.is_value() || !member.ty.arrays.is_empty()
+            {
+                continue;
+            }
+
+            let mut name = RENAMES
+                .iter()
+                .find(|(s, m, _)| *s == struct_name && *m == member.name)
+                .map(|(_, _, name)| (*name).to_owned())
+                .unwrap_or_else(|| snake_case(&member.name));
+            if taken.contains(&name) && !vendor.is_empty() {
+                name = format!("{}_{}", name, vendor);
+            }
+            if taken.contains(&name) {
+                name = format!("{}_{}", name, ffi_name(struct_name));
+            }
+            taken.insert(name.clone());
+            fields.push((name, &member.name));
+        }
+
+        let stype = match stype {
+            Some(stype) if !fields.is_empty() => stype,
+            _ => continue,
+        };
+
+        out.push_str("        {\n");
+        writeln!(
+            out,
+            "            ty: vk::{},",
+            struct_name.trim_start_matches("Vk")
+        )
+        .unwrap();
+        writeln!(out, "            ffi_name: {},", ffi_name(struct_name)).unwrap();
+        writeln!(
+            out,
+            "            sType: vk::{},",
+            stype.trim_start_matches("VK_")
+        )
+        .unwrap();
+        out.push_str("            fields: [\n");
+        for (name, vk_name) in fields {
+            writeln!(out, "                {} => {},", name, vk_name).unwrap();
+        }
+        out.push_str("            ],\n        },\n");
+    }
+
+    out.push_str("    ],\n}\n");
+    out
+}
+
+/// Returns the feature structs that can be chained to `VkPhysicalDeviceFeatures2`.
+///
+/// Structs without a vendor suffix come first, then `KHR` and `EXT` ones, so that when two
+/// structs have a feature with the same name, the more widely available struct gets the plain
+/// name. The `VkPhysicalDeviceVulkanXYFeatures` structs are skipped, since they only repeat the
+/// features of the other structs.
+fn extension_structs(registry: &Registry) -> Vec<&str> {
+    let required: HashSet<&str> = registry
+        .requires()
+        .flat_map(|r| r.types.iter())
+        .filter_map(|t| registry.resolve_type(t))
+        .map(|t| t.name.as_str())
+        .collect();
+
+    let mut structs: Vec<&str> = registry
+        .types
+        .iter()
+        .filter(|t| required.contains(t.name.as_str()))
+        .filter(|t| match &t.kind {
+            TypeKind::Struct { extends, .. } => {
+                extends.iter().any(|e| e == "VkPhysicalDeviceFeatures2")
+            }
+            _ => false,
+        })
+        .map(|t| t.name.as_str())
+        .filter(|name| !name.starts_with("VkPhysicalDeviceVulkan"))
+        .collect();
+
+    structs.sort_by_key(|name| match vendor_suffix(name) {
+        "" => 0,
+        "KHR" => 1,
+        "EXT" => 2,
+        _ => 3,
+    });
+    structs
+}
+
+/// Returns the vendor suffix of a struct name, such as `EXT` or `NV`.
+fn vendor_suffix(name: &str) -> &str {
+    let len = name
+        .bytes()
+        .rev()
+        .take_while(|b| b.is_ascii_uppercase())
+        .count();
+    // The last letter of a name without a suffix is part of a word, such as the `S` of
+    // `Features`, and is preceded by a lowercase letter.
+    if len <= 1 {
+        ""
+    } else {
+        &name[name.len() - len..]
+    }
+}
+
+/// Name of the field that holds the struct in the `pNext` chain.
+fn ffi_name(struct_name: &str) -> String {
+    let short = struct_name
+        .trim_start_matches("VkPhysicalDevice")
+        .replacen("Features", "", 1);
+    let name = snake_case(&short);
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("features_{}", name)
+    } else {
+        name
+    }
+}
+
+/// Turns `shaderStorageImageMultisample` into `shader_storage_image_multisample`.
+///
+/// Digits stay attached to the preceding word (`shaderInt64` becomes `shader_int64`), and
+/// acronyms are kept together (`textureCompressionETC2` becomes `texture_compression_etc2`).
+pub fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut out = String::with_capacity(name.len() + 4);
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_ascii_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_is_lower = matches!(chars.get(i + 1), Some(n) if n.is_ascii_lowercase());
+            if prev.is_ascii_lowercase()
+                || (prev.is_ascii_digit() && next_is_lower)
+                || (prev.is_ascii_uppercase() && next_is_lower)
+            {
+                out.push('_');
+            }
+        }
+        out.push(c.to_ascii_lowercase());
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate, snake_case};
+    use crate::registry::fixture;
+
+    #[test]
+    fn names() {
+        assert_eq!(snake_case("robustBufferAccess"), "robust_buffer_access");
+        assert_eq!(
+            snake_case("textureCompressionETC2"),
+            "texture_compression_etc2"
+        );
+        assert_eq!(
+            snake_case("textureCompressionASTC_LDR"),
+            "texture_compression_astc_ldr"
+        );
+        assert_eq!(
+            snake_case("sparseResidencyImage2D"),
+            "sparse_residency_image2d"
+        );
+        assert_eq!(
+            snake_case("sparseResidency2Samples"),
+            "sparse_residency2_samples"
+        );
+        assert_eq!(
+            snake_case("shaderBufferInt64Atomics"),
+            "shader_buffer_int64_atomics"
+        );
+        assert_eq!(snake_case("MeshShaderNV"), "mesh_shader_nv");
+    }
+
+    #[test]
+    fn table() {
+        let out = generate(&fixture());
+
+        let extensions = out.find("    extensions: [\n").unwrap();
+        let (core, extensions) = out.split_at(extensions);
+        assert!(core.starts_with(
+            "// This file is generated by `autogen` from the Vulkan registry. Do not edit it by \
+             hand.\n\nfeatures! {\n    core: [\n        robust_buffer_access => robustBufferAccess,\n"
+        ));
+        assert!(
+            core.contains("        texture_compression_astc_ldr => textureCompressionASTC_LDR,\n")
+        );
+        assert!(extensions.contains(
+            "        {\n            ty: vk::PhysicalDevice8BitStorageFeatures,\n            \
+             ffi_name: features_8_bit_storage,\n            \
+             sType: vk::STRUCTURE_TYPE_PHYSICAL_DEVICE_8BIT_STORAGE_FEATURES,\n            \
+             fields: [\n                \
+             storage_buffer_8bit => storageBuffer8BitAccess,\n                \
+             storage_uniform_8bit => uniformAndStorageBuffer8BitAccess,\n            ],\n        },\n"
+        ));
+        assert!(out.ends_with("    ],\n}\n"));
+
+        // The EXT struct gets the plain names, the NV one gets a suffix.
+        assert!(out.contains(
+            "            ty: vk::PhysicalDeviceMeshShaderFeaturesEXT,\n            \
+             ffi_name: mesh_shader_ext,\n"
+        ));
+        assert!(out.contains("                task_shader => taskShader,\n"));
+        assert!(out.contains("                task_shader_nv => taskShader,\n"));
+        assert!(out.contains("                mesh_shader_nv => meshShader,\n"));
+        assert!(out.find("FeaturesEXT").unwrap() < out.find("FeaturesNV").unwrap());
+
+        // `VkPhysicalDeviceFeatures2` itself isn't part of the chain.
+        assert!(!out.contains("vk::PhysicalDeviceFeatures2"));
+    }
+}
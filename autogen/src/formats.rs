@@ -0,0 +1,203 @@
+// Copyright (c) 2021 The vulkano developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>,
+// at your option. All files in the project carrying such
+// notice may not be copied, modified, or distributed except
+// according to those terms.
+
+//! Generates the invocation of `formats!` from the `<formats>` section of the registry.
+
+use crate::registry::{Format, Registry};
+use std::collections::HashSet;
+use std::fmt::Write;
+
+/// Numeric formats and other words that are written in camel case in variant names.
+const SUFFIXES: &[&str] = &[
+    "UNORM", "SNORM", "USCALED", "SSCALED", "UINT", "SINT", "UFLOAT", "SFLOAT", "SRGB", "BLOCK",
+];
+
+/// Generates the `formats!` invocation.
+pub fn generate(registry: &Registry) -> Result<String, String> {
+    if registry.formats.is_empty() {
+        return Err("the registry has no <formats> section, it is probably too old".to_owned());
+    }
+
+    // Only the formats that are part of a core version or of a supported extension.
+    let available: HashSet<&str> = registry
+        .enums
+        .iter()
+        .filter(|e| e.name == "VkFormat")
+        .flat_map(|e| e.values.iter())
+        .chain(registry.requires().flat_map(|r| r.enums.iter()))
+        .filter(|v| matches!(v.extends.as_deref(), None | Some("VkFormat")))
+        .map(|v| v.name.as_str())
+        .collect();
+
+    let mut out = String::new();
+    out.push_str(
+        "// This file is generated by `autogen` from the Vulkan registry. Do not edit it by hand.\n\n",
+    );
+    out.push_str("formats! {\n");
+
+    for format in registry
+        .formats
+        .iter()
+        .filter(|f| available.contains(f.name.as_str()))
+    {
+        let ty = format_ty(format);
+        let size = match ty {
+            "DepthStencil" => "None".to_owned(),
+            _ if format.planes > 1 => "None".to_owned(),
+            _ => format!("Some({})", format.block_size),
+        };
+        let (w, h) = format.block_extent.unwrap_or((1, 1));
+
+        write!(
+            out,
+            "    {} => {{vk: {}, bdim: ({}, {}), size: {}, ty: {}",
+            variant_name(format),
+            format.name.trim_start_matches("VK_"),
+            w,
+            h,
+            size,
+            ty
+        )
+        .unwrap();
+        if format.planes > 1 {
+            write!(out, ", planes: {}", format.planes).unwrap();
+        }
+        out.push_str("},\n");
+    }
+
+    out.push_str("}\n");
+    Ok(out)
+}
+
+/// Returns the `FormatTy` of a format.
+fn format_ty(format: &Format) -> &'static str {
+    if format.compressed.is_some() {
+        return "Compressed";
+    }
+    if format.chroma.is_some() || format.planes > 1 {
+        return "Ycbcr";
+    }
+
+    let depth = format.components.iter().any(|c| c.name == "D");
+    let stencil = format.components.iter().any(|c| c.name == "S");
+    match (depth, stencil) {
+        (true, true) => return "DepthStencil",
+        (true, false) => return "Depth",
+        (false, true) => return "Stencil",
+        (false, false) => (),
+    }
+
+    match format.components.first().map(|c| c.numeric_format.as_str()) {
+        Some("UINT") => "Uint",
+        Some("SINT") => "Sint",
+        _ => "Float",
+    }
+}
+
+/// Turns `VK_FORMAT_D24_UNORM_S8_UINT` into `D24Unorm_S8Uint`, following the existing names.
+///
+/// Components are joined together (`G8_B8_R8` becomes `G8B8R8`), numeric formats and packing
+/// are camel cased, and an underscore is kept after a compression scheme, before the plane count
+/// and between a numeric format or padding and the next component.
+fn variant_name(format: &Format) -> String {
+    #[derive(PartialEq)]
+    enum Kind {
+        Start,
+        Prefix,
+        Component,
+        Suffix,
+    }
+
+    let mut out = String::new();
+    let mut prev = Kind::Start;
+
+    for (i, part) in format
+        .name
+        .trim_start_matches("VK_FORMAT_")
+        .split('_')
+        .enumerate()
+    {
+        let is_vendor = prev == Kind::Suffix && part.chars().all(|c| c.is_ascii_uppercase());
+        if SUFFIXES.contains(&part) || part.contains("PACK") || is_vendor {
+            // `UNORM`, `PACK16`, `3PACK16`, and vendor suffixes such as `IMG`.
+            out.push_str(&camel_case(part));
+            prev = Kind::Suffix;
+        } else if i == 0 && format.compressed.is_some() {
+            // `BC1`, `ETC2`, `ASTC`, ...
+            out.push_str(part);
+            prev = Kind::Prefix;
+        } else if part.ends_with("PLANE") {
+            out.push('_');
+            out.push_str(part);
+            prev = Kind::Component;
+        } else {
+            let is_depth_stencil = part.len() > 1
+                && (part.starts_with('D') || part.starts_with('S'))
+                && part[1..].starts_with(|c: char| c.is_ascii_digit());
+            let separate = match prev {
+                Kind::Start => false,
+                Kind::Prefix | Kind::Suffix => true,
+                Kind::Component => is_depth_stencil,
+            };
+            if separate {
+                out.push('_');
+            }
+            out.push_str(part);
+            prev = Kind::Component;
+        }
+    }
+
+    out
+}
+
+/// `UNORM` becomes `Unorm`, `PACK16` becomes `Pack16`.
+fn camel_case(part: &str) -> String {
+    let mut chars = part.chars();
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::generate;
+    use crate::registry::fixture;
+
+    #[test]
+    fn table() {
+        let out = generate(&fixture()).unwrap();
+
+        assert!(out.contains(
+            "    R8G8B8A8Unorm => {vk: FORMAT_R8G8B8A8_UNORM, bdim: (1, 1), size: Some(4), ty: Float},\n"
+        ));
+        assert!(out.contains(
+            "    R32Sint => {vk: FORMAT_R32_SINT, bdim: (1, 1), size: Some(4), ty: Sint},\n"
+        ));
+        assert!(out.contains(
+            "    D24Unorm_S8Uint => {vk: FORMAT_D24_UNORM_S8_UINT, bdim: (1, 1), size: None, \
+             ty: DepthStencil},\n"
+        ));
+        assert!(out.contains(
+            "    BC1_RGBSrgbBlock => {vk: FORMAT_BC1_RGB_SRGB_BLOCK, bdim: (4, 4), size: Some(8), \
+             ty: Compressed},\n"
+        ));
+        assert!(out.contains(
+            "    ASTC_4x4UnormBlock => {vk: FORMAT_ASTC_4x4_UNORM_BLOCK, bdim: (4, 4), \
+             size: Some(16), ty: Compressed},\n"
+        ));
+        assert!(out.contains(
+            "    G8B8R8_3PLANE420Unorm => {vk: FORMAT_G8_B8_R8_3PLANE_420_UNORM, bdim: (1, 1), \
+             size: None, ty: Ycbcr, planes: 3},\n"
+        ));
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright (c) 2021 The vulkano developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>,
+// at your option. All files in the project carrying such
+// notice may not be copied, modified, or distributed except
+// according to those terms.
+
+//! Generates code from the Vulkan registry (`vk.xml`).
+//!
+//! ```text
+//! cargo run -p autogen [path/to/vk.xml]
+//! ```
+//!
+//! The registry defaults to `autogen/vk.xml`, which is vendored so that the generated files can
+//! be reproduced. To update to a newer version of Vulkan, replace it with the `registry/vk.xml`
+//! of the Vulkan-Headers repository and run the generator again. It writes:
+//!
+//! - `vk-sys/src/autogen.rs`: the types and function pointer tables of `vk-sys`.
+//! - `vulkano/src/autogen/device_extensions.rs` and `instance_extensions.rs`: the
+//!   `device_extensions!` and `instance_extensions!` invocations, with the dependencies of each
+//!   extension.
+//! - `vulkano/src/autogen/features.rs`: the `features!` invocation, including the structs of the
+//!   `pNext` chain.
+//! - `vulkano/src/autogen/formats.rs`: the `formats!` invocation, with the block size and type of
+//!   each format.
+//!
+//! The generated files are committed, so building vulkano doesn't need the registry.
+
+mod extensions;
+mod features;
+mod formats;
+mod registry;
+mod vk_sys;
+mod xml;
+
+use std::env;
+use std::fs;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::process::Command;
+
+use crate::registry::Registry;
+
+fn main() {
+    let repo = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let registry_path = env::args_os()
+        .nth(1)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| repo.join("autogen").join("vk.xml"));
+
+    if let Err(err) = run(&registry_path, repo) {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    }
+}
+
+fn run(registry_path: &Path, repo: &Path) -> Result<(), String> {
+    let file = File::open(registry_path)
+        .map_err(|e| format!("can't open `{}`: {}", registry_path.display(), e))?;
+    let root = xml::parse(BufReader::new(file))
+        .map_err(|e| format!("can't parse `{}`: {}", registry_path.display(), e))?;
+    let registry = Registry::parse(&root)?;
+
+    let outputs = [
+        ("vk-sys/src/autogen.rs", vk_sys::generate(&registry)),
+        (
+            "vulkano/src/autogen/device_extensions.rs",
+            extensions::generate_device(&registry),
+        ),
+        (
+            "vulkano/src/autogen/instance_extensions.rs",
+            extensions::generate_instance(&registry),
+        ),
+        (
+            "vulkano/src/autogen/features.rs",
+            features::generate(&registry),
+        ),
+        (
+            "vulkano/src/autogen/formats.rs",
+            formats::generate(&registry)?,
+        ),
+    ];
+
+    for (name, contents) in &outputs {
+        let path = repo.join(name);
+        let dir = path.parent().unwrap();
+        fs::create_dir_all(dir).map_err(|e| format!("can't create `{}`: {}", dir.display(), e))?;
+        fs::write(&path, contents)
+            .map_err(|e| format!("can't write `{}`: {}", path.display(), e))?;
+
+        // Formatting is only cosmetic, so a missing `rustfmt` isn't an error.
+        let formatted = Command::new("rustfmt")
+            .arg("--edition")
+            .arg("2018")
+            .arg(&path)
+            .status()
+            .map(|s| s.success())
+            .unwrap_or(false);
+        if !formatted {
+            eprintln!("warning: couldn't run rustfmt on `{}`", path.display());
+        }
+
+        println!("wrote {}", path.display());
+    }
+
+    Ok(())
+}
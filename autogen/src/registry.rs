@@ -0,0 +1,888 @@
+// Copyright (c) 2021 The vulkano developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>,
+// at your option. All files in the project carrying such
+// notice may not be copied, modified, or distributed except
+// according to those terms.
+
+//! The parts of the Vulkan registry (`vk.xml`) that the generators need.
+//!
+//! Everything that is specific to another API (such as Vulkan SC) is filtered out while parsing,
+//! so the generators only ever see the Vulkan view of the registry.
+
+use crate::xml::Element;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+/// The parsed registry.
+#[derive(Debug, Default)]
+pub struct Registry {
+    pub types: Vec<Type>,
+    pub enums: Vec<Enums>,
+    pub commands: Vec<Command>,
+    pub features: Vec<Feature>,
+    pub extensions: Vec<Extension>,
+    pub formats: Vec<Format>,
+    types_index: HashMap<String, usize>,
+    commands_index: HashMap<String, usize>,
+}
+
+/// An entry of `<types>`.
+#[derive(Debug, Clone)]
+pub struct Type {
+    pub name: String,
+    pub kind: TypeKind,
+}
+
+#[derive(Debug, Clone)]
+pub enum TypeKind {
+    /// Another name for the given type.
+    Alias(String),
+    /// A `typedef` of the given C type, or an opaque platform struct if `None`.
+    BaseType(Option<String>),
+    Handle {
+        dispatchable: bool,
+    },
+    /// An enumeration. Its values are in the `<enums>` block with the same name.
+    Enum,
+    /// A `Flags` type. `bits` is the name of the corresponding `FlagBits` enum, if any.
+    Bitmask {
+        base: String,
+        bits: Option<String>,
+    },
+    Struct {
+        members: Vec<Member>,
+        extends: Vec<String>,
+    },
+    Union {
+        members: Vec<Member>,
+    },
+    FuncPointer {
+        ret: CType,
+        params: Vec<Member>,
+    },
+    /// A type that comes from a platform header, such as `Display` or `HWND`.
+    External,
+}
+
+/// A struct member, a function parameter or a function return type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Member {
+    pub name: String,
+    pub ty: CType,
+    /// Width of a bitfield member.
+    pub bits: Option<u32>,
+    /// Fixed value of the member. Used for `sType`.
+    pub values: Option<String>,
+}
+
+/// A C type as written in a declaration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CType {
+    /// Name of the type without qualifiers, such as `uint32_t` or `VkExtent2D`.
+    pub base: String,
+    /// One entry per level of indirection, from the innermost to the outermost. The value is
+    /// true if the pointed-to value is `const`.
+    pub pointers: Vec<bool>,
+    /// Array dimensions, from the outermost to the innermost. Either a number or the name of an
+    /// API constant.
+    pub arrays: Vec<String>,
+}
+
+impl CType {
+    /// Returns true if the type is stored by value, in other words if it isn't a pointer.
+    #[inline]
+    pub fn is_value(&self) -> bool {
+        self.pointers.is_empty()
+    }
+}
+
+/// An `<enums>` block.
+#[derive(Debug, Clone)]
+pub struct Enums {
+    pub name: String,
+    pub kind: EnumsKind,
+    pub values: Vec<EnumValue>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum EnumsKind {
+    /// The `API Constants` block.
+    Constants,
+    Enum,
+    Bitmask {
+        bitwidth: u32,
+    },
+}
+
+/// A single `<enum>`, either in an `<enums>` block or in a `<require>` block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnumValue {
+    pub name: String,
+    pub value: Value,
+    /// The enum that this value is added to, for values defined by features and extensions.
+    pub extends: Option<String>,
+    /// The C type of an API constant.
+    pub ty: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Int(i64),
+    BitPos(u32),
+    Alias(String),
+    /// A value that isn't a plain integer, such as `(~0U)`, `1000.0F` or a string.
+    Literal(String),
+}
+
+/// An entry of `<commands>`.
+#[derive(Debug, Clone)]
+pub struct Command {
+    pub name: String,
+    pub kind: CommandKind,
+}
+
+#[derive(Debug, Clone)]
+pub enum CommandKind {
+    Alias(String),
+    Proto { ret: CType, params: Vec<Member> },
+}
+
+/// A `<feature>`, in other words a core version of the API. Only Vulkan features are kept.
+#[derive(Debug, Clone)]
+pub struct Feature {
+    pub require: Vec<Require>,
+}
+
+/// The contents of a `<require>` block.
+#[derive(Debug, Clone, Default)]
+pub struct Require {
+    pub types: Vec<String>,
+    pub commands: Vec<String>,
+    pub enums: Vec<EnumValue>,
+}
+
+/// An `<extension>` that is supported by Vulkan.
+#[derive(Debug, Clone)]
+pub struct Extension {
+    /// For example `VK_KHR_swapchain`.
+    pub name: String,
+    pub ty: ExtensionType,
+    pub depends: Option<Depends>,
+    /// For example `VK_VERSION_1_1` or `VK_KHR_maintenance4`.
+    pub promoted_to: Option<String>,
+    pub require: Vec<Require>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ExtensionType {
+    Instance,
+    Device,
+}
+
+/// A dependency expression, as found in the `depends` attribute of an extension.
+///
+/// Older registries use `requires` and `requiresCore` instead, which are converted to an
+/// expression while parsing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Depends {
+    /// The name of an extension or a version such as `VK_VERSION_1_1`.
+    Name(String),
+    And(Vec<Depends>),
+    Or(Vec<Depends>),
+}
+
+/// An entry of `<formats>`.
+#[derive(Debug, Clone)]
+pub struct Format {
+    /// For example `VK_FORMAT_R8G8B8A8_UNORM`.
+    pub name: String,
+    pub block_size: u32,
+    pub block_extent: Option<(u32, u32)>,
+    /// Name of the compression scheme, such as `BC` or `ASTC LDR`.
+    pub compressed: Option<String>,
+    /// Chroma subsampling, such as `420`.
+    pub chroma: Option<String>,
+    pub components: Vec<Component>,
+    pub planes: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct Component {
+    /// `R`, `G`, `B`, `A`, `D` or `S`.
+    pub name: String,
+    /// `UNORM`, `SINT`, `SFLOAT`, ...
+    pub numeric_format: String,
+}
+
+impl Registry {
+    /// Builds the registry from the root `<registry>` element.
+    pub fn parse(root: &Element) -> Result<Registry, String> {
+        if root.name != "registry" {
+            return Err(format!("expected <registry>, found <{}>", root.name));
+        }
+
+        let mut registry = Registry::default();
+
+        for element in root.elements() {
+            match element.name.as_str() {
+                "types" => {
+                    for ty in element.elements_named("type").filter(|e| api_matches(e)) {
+                        if let Some(ty) = parse_type(ty)? {
+                            registry.types.push(ty);
+                        }
+                    }
+                }
+                "enums" => registry.enums.push(parse_enums(element)?),
+                "commands" => {
+                    for cmd in element.elements_named("command").filter(|e| api_matches(e)) {
+                        registry.commands.push(parse_command(cmd)?);
+                    }
+                }
+                "feature" if api_matches(element) => {
+                    registry.features.push(parse_feature(element)?);
+                }
+                "extensions" => {
+                    for ext in element.elements_named("extension") {
+                        if let Some(ext) = parse_extension(ext)? {
+                            registry.extensions.push(ext);
+                        }
+                    }
+                }
+                "formats" => {
+                    for format in element.elements_named("format") {
+                        registry.formats.push(parse_format(format)?);
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        registry.types_index = registry
+            .types
+            .iter()
+            .enumerate()
+            .map(|(n, t)| (t.name.clone(), n))
+            .collect();
+        registry.commands_index = registry
+            .commands
+            .iter()
+            .enumerate()
+            .map(|(n, c)| (c.name.clone(), n))
+            .collect();
+
+        Ok(registry)
+    }
+
+    /// Returns the type with the given name.
+    #[inline]
+    pub fn ty(&self, name: &str) -> Option<&Type> {
+        self.types_index.get(name).map(|&n| &self.types[n])
+    }
+
+    /// Returns the command with the given name.
+    #[inline]
+    pub fn command(&self, name: &str) -> Option<&Command> {
+        self.commands_index.get(name).map(|&n| &self.commands[n])
+    }
+
+    /// Returns the extension with the given name.
+    #[inline]
+    pub fn extension(&self, name: &str) -> Option<&Extension> {
+        self.extensions.iter().find(|e| e.name == name)
+    }
+
+    /// Follows aliases until a type that isn't an alias is found.
+    pub fn resolve_type<'a>(&'a self, name: &'a str) -> Option<&'a Type> {
+        let mut ty = self.ty(name)?;
+        let mut seen = HashSet::new();
+        while let TypeKind::Alias(ref target) = ty.kind {
+            if !seen.insert(target.as_str()) {
+                return None;
+            }
+            ty = self.ty(target)?;
+        }
+        Some(ty)
+    }
+
+    /// Returns every `<require>` block of the core versions and supported extensions.
+    pub fn requires(&self) -> impl Iterator<Item = &Require> {
+        self.features
+            .iter()
+            .flat_map(|f| f.require.iter())
+            .chain(self.extensions.iter().flat_map(|e| e.require.iter()))
+    }
+}
+
+/// Returns false if the element has an `api` attribute that doesn't include Vulkan.
+fn api_matches(element: &Element) -> bool {
+    match element.attr("api") {
+        Some(api) => api.split(',').any(|a| a == "vulkan"),
+        None => true,
+    }
+}
+
+fn parse_type(element: &Element) -> Result<Option<Type>, String> {
+    let name = match element.attr("name") {
+        Some(name) => name.to_owned(),
+        None => match element.child_text("name") {
+            Some(name) => name,
+            None => return Ok(None),
+        },
+    };
+
+    if let Some(alias) = element.attr("alias") {
+        return Ok(Some(Type {
+            name,
+            kind: TypeKind::Alias(alias.to_owned()),
+        }));
+    }
+
+    let kind = match element.attr("category") {
+        Some("basetype") => {
+            if element.text().trim_start().starts_with("typedef") {
+                TypeKind::BaseType(element.child_text("type"))
+            } else {
+                TypeKind::BaseType(None)
+            }
+        }
+        Some("handle") => TypeKind::Handle {
+            dispatchable: element.child_text("type").as_deref() == Some("VK_DEFINE_HANDLE"),
+        },
+        Some("enum") => TypeKind::Enum,
+        Some("bitmask") => TypeKind::Bitmask {
+            base: element
+                .child_text("type")
+                .ok_or_else(|| format!("bitmask `{}` has no base type", name))?,
+            bits: element
+                .attr("requires")
+                .or_else(|| element.attr("bitvalues"))
+                .map(ToOwned::to_owned),
+        },
+        Some("struct") => TypeKind::Struct {
+            members: parse_members(element)?,
+            extends: element
+                .attr("structextends")
+                .map(|s| s.split(',').map(ToOwned::to_owned).collect())
+                .unwrap_or_default(),
+        },
+        Some("union") => TypeKind::Union {
+            members: parse_members(element)?,
+        },
+        Some("funcpointer") => parse_funcpointer(element, &name)?,
+        Some(_) => return Ok(None),
+        None => match element.attr("requires") {
+            Some("vk_platform") | None => return Ok(None),
+            Some(_) => TypeKind::External,
+        },
+    };
+
+    Ok(Some(Type { name, kind }))
+}
+
+fn parse_members(element: &Element) -> Result<Vec<Member>, String> {
+    element
+        .elements_named("member")
+        .filter(|e| api_matches(e))
+        .map(|member| {
+            let mut decl = parse_decl(&member.text())?;
+            decl.values = member.attr("values").map(ToOwned::to_owned);
+            Ok(decl)
+        })
+        .collect()
+}
+
+fn parse_funcpointer(element: &Element, name: &str) -> Result<TypeKind, String> {
+    // Newer registries describe function pointers the same way as commands.
+    if let Some(proto) = element.child("proto") {
+        let ret = parse_decl(&proto.text())?.ty;
+        let params = element
+            .elements_named("param")
+            .map(|p| parse_decl(&p.text()))
+            .collect::<Result<_, _>>()?;
+        return Ok(TypeKind::FuncPointer { ret, params });
+    }
+
+    // Otherwise it's a plain C declaration, for example:
+    // `typedef void* (VKAPI_PTR *PFN_vkAllocationFunction)(void* pUserData, size_t size);`
+    let text = element.text();
+    let err = || format!("unrecognized function pointer `{}`", name);
+    let ret_start = text.find("typedef").ok_or_else(err)? + "typedef".len();
+    let ret_end = text.find("(VKAPI_PTR").ok_or_else(err)?;
+    let ret = parse_decl(&text[ret_start..ret_end])?.ty;
+
+    let params_start = text.find(")(").ok_or_else(err)? + 2;
+    let params_end = text.rfind(')').ok_or_else(err)?;
+    let params = text[params_start..params_end]
+        .split(',')
+        .map(str::trim)
+        .filter(|p| !p.is_empty() && *p != "void")
+        .map(parse_decl)
+        .collect::<Result<_, _>>()?;
+
+    Ok(TypeKind::FuncPointer { ret, params })
+}
+
+/// Parses a C declaration such as `const char* const* ppNames` or `float color[4]`.
+///
+/// The name is empty if the declaration doesn't have one, which is the case for return types.
+pub fn parse_decl(text: &str) -> Result<Member, String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    for c in text.chars() {
+        if c.is_ascii_alphanumeric() || c == '_' {
+            current.push(c);
+            continue;
+        }
+        if !current.is_empty() {
+            tokens.push(std::mem::take(&mut current));
+        }
+        if c == '*' || c == '[' || c == ']' || c == ':' {
+            tokens.push(c.to_string());
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+
+    let mut base = None;
+    let mut name = None;
+    let mut pointers = Vec::new();
+    let mut arrays = Vec::new();
+    let mut bits = None;
+    let mut is_const = false;
+
+    let mut tokens = tokens.into_iter();
+    while let Some(token) = tokens.next() {
+        match token.as_str() {
+            "const" => is_const = true,
+            "struct" | "typedef" | "VKAPI_PTR" | "VKAPI_ATTR" | "VKAPI_CALL" => (),
+            "*" => pointers.push(std::mem::replace(&mut is_const, false)),
+            "[" => {
+                arrays.push(
+                    tokens
+                        .next()
+                        .ok_or_else(|| format!("bad array in `{}`", text))?,
+                );
+                tokens.next();
+            }
+            ":" => {
+                bits = tokens.next().and_then(|b| b.parse().ok());
+            }
+            _ if base.is_none() => base = Some(token),
+            _ if name.is_none() => name = Some(token),
+            _ => return Err(format!("unrecognized declaration `{}`", text.trim())),
+        }
+    }
+
+    Ok(Member {
+        name: name.unwrap_or_default(),
+        ty: CType {
+            base: base.ok_or_else(|| format!("declaration `{}` has no type", text.trim()))?,
+            pointers,
+            arrays,
+        },
+        bits,
+        values: None,
+    })
+}
+
+fn parse_enums(element: &Element) -> Result<Enums, String> {
+    let name = element.attr("name").unwrap_or_default().to_owned();
+    let kind = match element.attr("type") {
+        Some("enum") => EnumsKind::Enum,
+        Some("bitmask") => EnumsKind::Bitmask {
+            bitwidth: element
+                .attr("bitwidth")
+                .and_then(|b| b.parse().ok())
+                .unwrap_or(32),
+        },
+        _ => EnumsKind::Constants,
+    };
+
+    let values = element
+        .elements_named("enum")
+        .filter(|e| api_matches(e))
+        .map(|e| parse_enum_value(e, None))
+        .filter_map(Result::transpose)
+        .collect::<Result<_, _>>()?;
+
+    Ok(Enums { name, kind, values })
+}
+
+/// Parses an `<enum>`. `ext_number` is the number of the extension that contains it, if any.
+///
+/// Returns `None` for `<enum>`s that only reference a value defined elsewhere.
+fn parse_enum_value(
+    element: &Element,
+    ext_number: Option<i64>,
+) -> Result<Option<EnumValue>, String> {
+    let name = element
+        .attr("name")
+        .ok_or("<enum> without a name")?
+        .to_owned();
+
+    let value = if let Some(value) = element.attr("value") {
+        match parse_int(value) {
+            Some(v) => Value::Int(v),
+            None => Value::Literal(value.to_owned()),
+        }
+    } else if let Some(bitpos) = element.attr("bitpos") {
+        Value::BitPos(
+            bitpos
+                .parse()
+                .map_err(|_| format!("bad bitpos for `{}`", name))?,
+        )
+    } else if let Some(offset) = element.attr("offset") {
+        let offset: i64 = offset
+            .parse()
+            .map_err(|_| format!("bad offset for `{}`", name))?;
+        let ext_number = element
+            .attr("extnumber")
+            .and_then(parse_int)
+            .or(ext_number)
+            .ok_or_else(|| format!("no extension number for `{}`", name))?;
+        let value = 1_000_000_000 + (ext_number - 1) * 1000 + offset;
+        Value::Int(if element.attr("dir") == Some("-") {
+            -value
+        } else {
+            value
+        })
+    } else if let Some(alias) = element.attr("alias") {
+        Value::Alias(alias.to_owned())
+    } else {
+        return Ok(None);
+    };
+
+    Ok(Some(EnumValue {
+        name,
+        value,
+        extends: element.attr("extends").map(ToOwned::to_owned),
+        ty: element.attr("type").map(ToOwned::to_owned),
+    }))
+}
+
+/// Parses a decimal or hexadecimal integer, possibly negative.
+pub fn parse_int(s: &str) -> Option<i64> {
+    let s = s.trim();
+    let (negative, s) = match s.strip_prefix('-') {
+        Some(s) => (true, s),
+        None => (false, s),
+    };
+    let value = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(hex) => i64::from_str_radix(hex, 16).ok()?,
+        None => s.parse().ok()?,
+    };
+    Some(if negative { -value } else { value })
+}
+
+fn parse_requires(element: &Element, ext_number: Option<i64>) -> Result<Vec<Require>, String> {
+    element
+        .elements_named("require")
+        .filter(|e| api_matches(e))
+        .map(|require| {
+            let mut out = Require::default();
+            for item in require.elements() {
+                if !api_matches(item) {
+                    continue;
+                }
+                match item.name.as_str() {
+                    "type" => out.types.extend(item.attr("name").map(ToOwned::to_owned)),
+                    "command" => out
+                        .commands
+                        .extend(item.attr("name").map(ToOwned::to_owned)),
+                    "enum" => out.enums.extend(parse_enum_value(item, ext_number)?),
+                    _ => (),
+                }
+            }
+            Ok(out)
+        })
+        .collect()
+}
+
+fn parse_command(element: &Element) -> Result<Command, String> {
+    if let Some(alias) = element.attr("alias") {
+        return Ok(Command {
+            name: element
+                .attr("name")
+                .ok_or("command alias without a name")?
+                .to_owned(),
+            kind: CommandKind::Alias(alias.to_owned()),
+        });
+    }
+
+    let proto = parse_decl(
+        &element
+            .child("proto")
+            .ok_or("command without a <proto>")?
+            .text(),
+    )?;
+    let params = element
+        .elements_named("param")
+        .filter(|e| api_matches(e))
+        .map(|p| parse_decl(&p.text()))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Command {
+        name: proto.name,
+        kind: CommandKind::Proto {
+            ret: proto.ty,
+            params,
+        },
+    })
+}
+
+fn parse_feature(element: &Element) -> Result<Feature, String> {
+    Ok(Feature {
+        require: parse_requires(element, None)?,
+    })
+}
+
+/// Turns `VK_VERSION_1_2` into `(1, 2)`.
+pub fn parse_version(name: &str) -> Option<(u32, u32)> {
+    let mut parts = name.strip_prefix("VK_VERSION_")?.split('_');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    Some((major, minor))
+}
+
+/// Returns `None` for extensions that aren't supported by Vulkan, such as disabled ones.
+fn parse_extension(element: &Element) -> Result<Option<Extension>, String> {
+    let supported = element.attr("supported").unwrap_or_default();
+    if !supported.split(',').any(|s| s == "vulkan") {
+        return Ok(None);
+    }
+
+    let name = element.attr("name").ok_or("<extension> without a name")?;
+    let number = element
+        .attr("number")
+        .and_then(parse_int)
+        .ok_or_else(|| format!("extension `{}` has no number", name))?;
+    let ty = match element.attr("type") {
+        Some("instance") => ExtensionType::Instance,
+        Some("device") => ExtensionType::Device,
+        _ => return Err(format!("extension `{}` has no type", name)),
+    };
+
+    let depends = if let Some(depends) = element.attr("depends") {
+        Some(parse_depends(depends)?)
+    } else {
+        let mut terms: Vec<_> = element
+            .attr("requires")
+            .into_iter()
+            .flat_map(|r| r.split(','))
+            .map(|r| Depends::Name(r.to_owned()))
+            .collect();
+        if let Some(core) = element.attr("requiresCore") {
+            terms.push(Depends::Name(format!(
+                "VK_VERSION_{}",
+                core.replace('.', "_")
+            )));
+        }
+        match terms.len() {
+            0 => None,
+            1 => terms.pop(),
+            _ => Some(Depends::And(terms)),
+        }
+    };
+
+    Ok(Some(Extension {
+        name: name.to_owned(),
+        ty,
+        depends,
+        promoted_to: element.attr("promotedto").map(ToOwned::to_owned),
+        require: parse_requires(element, Some(number))?,
+    }))
+}
+
+/// Parses a dependency expression such as `VK_KHR_a+(VK_KHR_b,VK_VERSION_1_1)`.
+///
+/// `+` is a logical and, `,` is a logical or. The registry doesn't give one precedence over the
+/// other and uses parentheses whenever they are mixed, so operators are applied left to right.
+pub fn parse_depends(s: &str) -> Result<Depends, String> {
+    fn term(tokens: &[String], pos: &mut usize) -> Result<Depends, String> {
+        let token = tokens.get(*pos).ok_or("unexpected end of dependencies")?;
+        *pos += 1;
+        if token == "(" {
+            let inner = expr(tokens, pos)?;
+            if tokens.get(*pos).map(String::as_str) != Some(")") {
+                return Err("unbalanced parentheses in dependencies".to_owned());
+            }
+            *pos += 1;
+            Ok(inner)
+        } else if token == ")" || token == "+" || token == "," {
+            Err(format!("unexpected `{}` in dependencies", token))
+        } else {
+            Ok(Depends::Name(token.clone()))
+        }
+    }
+
+    fn expr(tokens: &[String], pos: &mut usize) -> Result<Depends, String> {
+        let mut lhs = term(tokens, pos)?;
+        while let Some(op) = tokens.get(*pos).filter(|t| *t == "+" || *t == ",") {
+            *pos += 1;
+            let rhs = term(tokens, pos)?;
+            lhs = match (op.as_str(), lhs) {
+                ("+", Depends::And(mut terms)) => {
+                    terms.push(rhs);
+                    Depends::And(terms)
+                }
+                (",", Depends::Or(mut terms)) => {
+                    terms.push(rhs);
+                    Depends::Or(terms)
+                }
+                ("+", lhs) => Depends::And(vec![lhs, rhs]),
+                (_, lhs) => Depends::Or(vec![lhs, rhs]),
+            };
+        }
+        Ok(lhs)
+    }
+
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    for c in s.chars() {
+        match c {
+            '(' | ')' | '+' | ',' => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+                tokens.push(c.to_string());
+            }
+            c if c.is_whitespace() => (),
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+
+    let mut pos = 0;
+    let result = expr(&tokens, &mut pos)?;
+    if pos != tokens.len() {
+        return Err(format!("trailing tokens in dependencies `{}`", s));
+    }
+    Ok(result)
+}
+
+fn parse_format(element: &Element) -> Result<Format, String> {
+    let name = element.attr("name").ok_or("<format> without a name")?;
+    let block_size = element
+        .attr("blockSize")
+        .and_then(parse_int)
+        .ok_or_else(|| format!("format `{}` has no block size", name))?;
+    let block_extent = match element.attr("blockExtent") {
+        Some(extent) => {
+            let mut dims = extent.split(',').map(|d| d.trim().parse::<u32>());
+            match (dims.next(), dims.next()) {
+                (Some(Ok(w)), Some(Ok(h))) => Some((w, h)),
+                _ => return Err(format!("bad block extent for `{}`", name)),
+            }
+        }
+        None => None,
+    };
+
+    Ok(Format {
+        name: name.to_owned(),
+        block_size: block_size as u32,
+        block_extent,
+        compressed: element.attr("compressed").map(ToOwned::to_owned),
+        chroma: element.attr("chroma").map(ToOwned::to_owned),
+        components: element
+            .elements_named("component")
+            .map(|c| Component {
+                name: c.attr("name").unwrap_or_default().to_owned(),
+                numeric_format: c.attr("numericFormat").unwrap_or_default().to_owned(),
+            })
+            .collect(),
+        planes: element.elements_named("plane").count() as u32,
+    })
+}
+
+/// Registry used by the tests of all the generators.
+#[cfg(test)]
+pub fn fixture() -> Registry {
+    let root = crate::xml::parse(&include_bytes!("fixture.xml")[..]).unwrap();
+    Registry::parse(&root).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decl() {
+        let m = parse_decl("const char* const* ppEnabledExtensionNames").unwrap();
+        assert_eq!(m.name, "ppEnabledExtensionNames");
+        assert_eq!(m.ty.base, "char");
+        assert_eq!(m.ty.pointers, vec![true, true]);
+
+        let m = parse_decl("void** ppData").unwrap();
+        assert_eq!(m.ty.pointers, vec![false, false]);
+
+        let m = parse_decl("float matrix[3][4]").unwrap();
+        assert_eq!(m.ty.arrays, vec!["3".to_owned(), "4".to_owned()]);
+
+        let m = parse_decl("uint32_t mask:8").unwrap();
+        assert_eq!(m.bits, Some(8));
+
+        let m = parse_decl("void* ").unwrap();
+        assert_eq!(m.name, "");
+        assert_eq!(m.ty.pointers, vec![false]);
+    }
+
+    #[test]
+    fn depends() {
+        assert_eq!(
+            parse_depends("VK_KHR_a+(VK_KHR_b,VK_VERSION_1_1)").unwrap(),
+            Depends::And(vec![
+                Depends::Name("VK_KHR_a".to_owned()),
+                Depends::Or(vec![
+                    Depends::Name("VK_KHR_b".to_owned()),
+                    Depends::Name("VK_VERSION_1_1".to_owned()),
+                ]),
+            ])
+        );
+        assert!(parse_depends("(VK_KHR_a").is_err());
+        assert!(parse_depends("VK_KHR_a+").is_err());
+    }
+
+    #[test]
+    fn fixture_contents() {
+        let registry = fixture();
+
+        // Disabled extensions and Vulkan SC items are dropped.
+        assert!(registry.extension("VK_KHR_disabled").is_none());
+        assert!(registry.command("vkGetFaultData").is_none());
+
+        // Extension enums get their final value.
+        let value = registry
+            .extension("VK_KHR_surface")
+            .unwrap()
+            .require
+            .iter()
+            .flat_map(|r| r.enums.iter())
+            .find(|e| e.name == "VK_ERROR_SURFACE_LOST_KHR")
+            .unwrap();
+        assert_eq!(value.value, Value::Int(-1_000_000_000));
+
+        // Old-style `requires` and `requiresCore` are turned into an expression.
+        assert_eq!(
+            registry.extension("VK_KHR_8bit_storage").unwrap().depends,
+            Some(Depends::And(vec![
+                Depends::Name("VK_KHR_get_physical_device_properties2".to_owned()),
+                Depends::Name("VK_KHR_storage_buffer_storage_class".to_owned()),
+            ]))
+        );
+
+        match registry
+            .resolve_type("VkPhysicalDeviceFeatures2KHR")
+            .unwrap()
+            .kind
+        {
+            TypeKind::Struct { ref members, .. } => assert_eq!(members.len(), 3),
+            _ => panic!(),
+        }
+    }
+}
@@ -0,0 +1,735 @@
+// Copyright (c) 2021 The vulkano developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>,
+// at your option. All files in the project carrying such
+// notice may not be copied, modified, or distributed except
+// according to those terms.
+
+//! Generates the raw types and function pointer tables of `vk-sys`.
+//!
+//! The naming follows the existing bindings: the `Vk`, `vk` and `VK_` prefixes are removed,
+//! enums are `u32` type aliases with one constant per value, dispatchable handles are `usize` and
+//! non-dispatchable handles are `u64`.
+
+use crate::registry::{
+    CType, CommandKind, EnumValue, EnumsKind, Member, Registry, TypeKind, Value,
+};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+/// Types from the platform headers that can appear by value in the registry, with the Rust type
+/// used for them. Platform types that are only ever used behind a pointer become `c_void`.
+const PLATFORM_TYPES: &[(&str, &str)] = &[
+    ("Display", "c_void"),
+    ("VisualID", "c_ulong"),
+    ("Window", "c_ulong"),
+    ("RROutput", "c_ulong"),
+    ("xcb_connection_t", "c_void"),
+    ("xcb_visualid_t", "u32"),
+    ("xcb_window_t", "u32"),
+    ("wl_display", "c_void"),
+    ("wl_surface", "c_void"),
+    ("HINSTANCE", "*mut c_void"),
+    ("HWND", "*mut c_void"),
+    ("HMONITOR", "*mut c_void"),
+    ("HANDLE", "*mut c_void"),
+    ("SECURITY_ATTRIBUTES", "c_void"),
+    ("DWORD", "u32"),
+    ("LPCWSTR", "*const u16"),
+    ("zx_handle_t", "u32"),
+    ("IDirectFB", "c_void"),
+    ("IDirectFBSurface", "c_void"),
+];
+
+/// Generates the contents of the `vk-sys` bindings.
+pub fn generate(registry: &Registry) -> String {
+    let gen = Generator::new(registry);
+    let mut out = String::new();
+
+    out.push_str(
+        "// This file is generated by `autogen` from the Vulkan registry. Do not edit it by hand.\n\
+         //\n\
+         // It is meant to be included at the root of vk-sys, after the `ptrs!` macro and the\n\
+         // `std::os::raw` imports.\n\n",
+    );
+
+    gen.base_types(&mut out);
+    gen.handles(&mut out);
+    gen.constants(&mut out);
+    gen.enums(&mut out);
+    gen.bitmasks_and_aliases(&mut out);
+    gen.func_pointers(&mut out);
+    gen.structs(&mut out);
+    gen.commands(&mut out);
+
+    out
+}
+
+struct Generator<'a> {
+    registry: &'a Registry,
+    /// Types that are required by a core version or a supported extension, and whose layout is
+    /// fully known.
+    types: HashSet<&'a str>,
+    /// Values added to enums by core versions and extensions, keyed by the enum they extend.
+    extension_values: HashMap<&'a str, Vec<&'a EnumValue>>,
+    /// Required commands, in registry order.
+    commands: Vec<&'a str>,
+}
+
+impl<'a> Generator<'a> {
+    fn new(registry: &'a Registry) -> Generator<'a> {
+        let mut types: HashSet<&str> = HashSet::new();
+        let mut commands: HashSet<&str> = HashSet::new();
+        let mut extension_values: HashMap<&str, Vec<&EnumValue>> = HashMap::new();
+        let mut seen_values = HashSet::new();
+
+        for require in registry.requires() {
+            types.extend(require.types.iter().map(String::as_str));
+            commands.extend(require.commands.iter().map(String::as_str));
+            for value in &require.enums {
+                if let Some(extends) = &value.extends {
+                    if seen_values.insert(value.name.as_str()) {
+                        extension_values.entry(extends).or_default().push(value);
+                    }
+                }
+            }
+        }
+
+        // Types that are only used by required commands or by other required types.
+        let mut pending: Vec<&str> = types.iter().copied().collect();
+        for &name in &commands {
+            if let Some(cmd) = registry.command(name) {
+                match &cmd.kind {
+                    CommandKind::Alias(target) => {
+                        if let Some(CommandKind::Proto { ret, params }) =
+                            registry.command(target).map(|c| &c.kind)
+                        {
+                            pending.push(&ret.base);
+                            pending.extend(params.iter().map(|p| p.ty.base.as_str()));
+                        }
+                    }
+                    CommandKind::Proto { ret, params } => {
+                        pending.push(&ret.base);
+                        pending.extend(params.iter().map(|p| p.ty.base.as_str()));
+                    }
+                }
+            }
+        }
+        while let Some(name) = pending.pop() {
+            let ty = match registry.ty(name) {
+                Some(ty) => ty,
+                None => continue,
+            };
+            types.insert(&ty.name);
+            let mut refs: Vec<&str> = Vec::new();
+            match &ty.kind {
+                TypeKind::Alias(target) => refs.push(target),
+                TypeKind::Bitmask { base, bits } => {
+                    refs.push(base);
+                    refs.extend(bits.as_deref());
+                }
+                TypeKind::BaseType(Some(base)) => refs.push(base),
+                TypeKind::Struct { members, .. } | TypeKind::Union { members } => {
+                    refs.extend(members.iter().map(|m| m.ty.base.as_str()));
+                }
+                TypeKind::FuncPointer { ret, params } => {
+                    refs.push(&ret.base);
+                    refs.extend(params.iter().map(|p| p.ty.base.as_str()));
+                }
+                _ => (),
+            }
+            for r in refs {
+                if registry.ty(r).is_some() && !types.contains(r) {
+                    pending.push(r);
+                }
+            }
+        }
+
+        let mut gen = Generator {
+            registry,
+            types,
+            extension_values,
+            commands: registry
+                .commands
+                .iter()
+                .map(|c| c.name.as_str())
+                .filter(|c| commands.contains(c))
+                .collect(),
+        };
+
+        // Remove the types that contain a value of an unknown type, such as the video types that
+        // are defined outside of vk.xml, until nothing changes.
+        loop {
+            let broken: Vec<&str> = gen
+                .types
+                .iter()
+                .copied()
+                .filter(|&name| !gen.is_complete(name))
+                .collect();
+            if broken.is_empty() {
+                break;
+            }
+            for name in broken {
+                gen.types.remove(name);
+            }
+        }
+
+        gen
+    }
+
+    fn is_complete(&self, name: &str) -> bool {
+        let ty = match self.registry.ty(name) {
+            Some(ty) => ty,
+            None => return false,
+        };
+        match &ty.kind {
+            TypeKind::Alias(target) => self.types.contains(target.as_str()),
+            TypeKind::BaseType(Some(base)) => self.rust_base(base).is_some(),
+            TypeKind::Bitmask { base, .. } => self.rust_base(base).is_some(),
+            TypeKind::Struct { members, .. } | TypeKind::Union { members } => {
+                members.iter().all(|m| self.rust_type(&m.ty).is_some())
+            }
+            TypeKind::FuncPointer { ret, params } => {
+                self.rust_type(ret).is_some()
+                    && params.iter().all(|p| self.rust_type(&p.ty).is_some())
+            }
+            _ => true,
+        }
+    }
+
+    /// Returns the Rust name of a C type name, or `None` if the type isn't known.
+    fn rust_base(&self, base: &str) -> Option<String> {
+        let primitive = match base {
+            "void" => "c_void",
+            "char" => "c_char",
+            "float" => "f32",
+            "double" => "f64",
+            "int8_t" => "i8",
+            "uint8_t" => "u8",
+            "int16_t" => "i16",
+            "uint16_t" => "u16",
+            "int" | "int32_t" => "i32",
+            "uint32_t" => "u32",
+            "int64_t" => "i64",
+            "uint64_t" => "u64",
+            "size_t" => "usize",
+            _ => "",
+        };
+        if !primitive.is_empty() {
+            return Some(primitive.to_owned());
+        }
+
+        match &self.registry.ty(base)?.kind {
+            TypeKind::External => PLATFORM_TYPES
+                .iter()
+                .find(|(name, _)| *name == base)
+                .map(|(_, rust)| (*rust).to_owned()),
+            TypeKind::BaseType(None) => Some("c_void".to_owned()),
+            _ if self.types.contains(base) => Some(strip_type(base).to_owned()),
+            _ => None,
+        }
+    }
+
+    /// Returns the Rust version of a C type, or `None` if it stores an unknown type by value.
+    fn rust_type(&self, ty: &CType) -> Option<String> {
+        let mut out = match self.rust_base(&ty.base) {
+            Some(base) => base,
+            None if !ty.is_value() => "c_void".to_owned(),
+            None => return None,
+        };
+        for &is_const in &ty.pointers {
+            out = format!("*{} {}", if is_const { "const" } else { "mut" }, out);
+        }
+        for len in ty.arrays.iter().rev() {
+            if len.bytes().all(|b| b.is_ascii_digit()) {
+                out = format!("[{}; {}]", out, len);
+            } else {
+                out = format!("[{}; {} as usize]", out, strip_const(len));
+            }
+        }
+        Some(out)
+    }
+
+    /// Iterates over the required types of the registry, in registry order.
+    fn required_types(&self) -> impl Iterator<Item = &'a crate::registry::Type> + '_ {
+        self.registry
+            .types
+            .iter()
+            .filter(move |t| self.types.contains(t.name.as_str()))
+    }
+
+    fn base_types(&self, out: &mut String) {
+        for ty in self.required_types() {
+            if let TypeKind::BaseType(Some(base)) = &ty.kind {
+                let rust = self.rust_base(base).unwrap();
+                writeln!(out, "pub type {} = {};", strip_type(&ty.name), rust).unwrap();
+            }
+        }
+        out.push('\n');
+    }
+
+    fn handles(&self, out: &mut String) {
+        for ty in self.required_types() {
+            if let TypeKind::Handle { dispatchable } = ty.kind {
+                let rust = if dispatchable { "usize" } else { "u64" };
+                writeln!(out, "pub type {} = {};", strip_type(&ty.name), rust).unwrap();
+            }
+        }
+        out.push('\n');
+    }
+
+    fn constants(&self, out: &mut String) {
+        let constants = self
+            .registry
+            .enums
+            .iter()
+            .filter(|e| e.kind == EnumsKind::Constants)
+            .flat_map(|e| e.values.iter());
+
+        for value in constants {
+            let (ty, rust) = match constant_value(value) {
+                Some(v) => v,
+                None => continue,
+            };
+            writeln!(
+                out,
+                "pub const {}: {} = {};",
+                strip_const(&value.name),
+                ty,
+                rust
+            )
+            .unwrap();
+        }
+        out.push('\n');
+    }
+
+    fn enums(&self, out: &mut String) {
+        for ty in self.required_types() {
+            if !matches!(ty.kind, TypeKind::Enum) {
+                continue;
+            }
+            let block = self.registry.enums.iter().find(|e| e.name == ty.name);
+            let kind = block.map_or(EnumsKind::Enum, |b| b.kind);
+            let rust = match kind {
+                EnumsKind::Bitmask { bitwidth: 64 } => "u64",
+                _ => "u32",
+            };
+
+            writeln!(out, "pub type {} = {};", strip_type(&ty.name), rust).unwrap();
+
+            let mut seen = HashSet::new();
+            let values = block
+                .into_iter()
+                .flat_map(|b| b.values.iter())
+                .chain(
+                    self.extension_values
+                        .get(ty.name.as_str())
+                        .into_iter()
+                        .flatten()
+                        .copied(),
+                )
+                .filter(|v| seen.insert(v.name.as_str()));
+
+            for value in values {
+                let rust_value = match (&value.value, kind) {
+                    (Value::Int(v), EnumsKind::Bitmask { .. }) if *v != 0 => format!("{:#010x}", v),
+                    (Value::Int(v), _) if *v < 0 => format!("{}i32 as u32", v),
+                    (Value::Int(v), _) => v.to_string(),
+                    (Value::BitPos(pos), _) => format!("{:#010x}", 1u64 << pos),
+                    (Value::Alias(alias), _) => strip_const(alias).to_owned(),
+                    (Value::Literal(_), _) => continue,
+                };
+                writeln!(
+                    out,
+                    "pub const {}: {} = {};",
+                    strip_const(&value.name),
+                    rust,
+                    rust_value
+                )
+                .unwrap();
+            }
+            out.push('\n');
+        }
+    }
+
+    fn bitmasks_and_aliases(&self, out: &mut String) {
+        for ty in self.required_types() {
+            match &ty.kind {
+                TypeKind::Bitmask { base, .. } => {
+                    writeln!(
+                        out,
+                        "pub type {} = {};",
+                        strip_type(&ty.name),
+                        strip_type(base)
+                    )
+                    .unwrap();
+                }
+                TypeKind::Alias(target) => {
+                    writeln!(
+                        out,
+                        "pub type {} = {};",
+                        strip_type(&ty.name),
+                        strip_type(target)
+                    )
+                    .unwrap();
+                }
+                _ => (),
+            }
+        }
+        out.push('\n');
+    }
+
+    fn func_pointers(&self, out: &mut String) {
+        for ty in self.required_types() {
+            if let TypeKind::FuncPointer { ret, params } = &ty.kind {
+                let params: Vec<_> = params
+                    .iter()
+                    .map(|p| self.rust_type(&p.ty).unwrap())
+                    .collect();
+                write!(
+                    out,
+                    "pub type {} = extern \"system\" fn({})",
+                    ty.name,
+                    params.join(", ")
+                )
+                .unwrap();
+                if !is_void(ret) {
+                    write!(out, " -> {}", self.rust_type(ret).unwrap()).unwrap();
+                }
+                out.push_str(";\n");
+            }
+        }
+        out.push('\n');
+    }
+
+    fn structs(&self, out: &mut String) {
+        // Union fields must be `Copy`, and so must everything they contain.
+        let mut copy = HashSet::new();
+        let mut pending: Vec<&str> = self
+            .required_types()
+            .filter(|t| matches!(t.kind, TypeKind::Union { .. }))
+            .map(|t| t.name.as_str())
+            .collect();
+        while let Some(name) = pending.pop() {
+            if !copy.insert(name) {
+                continue;
+            }
+            if let Some(ty) = self.registry.resolve_type(name) {
+                if let TypeKind::Struct { members, .. } | TypeKind::Union { members } = &ty.kind {
+                    pending.extend(
+                        members
+                            .iter()
+                            .filter(|m| m.ty.is_value())
+                            .map(|m| m.ty.base.as_str()),
+                    );
+                }
+            }
+        }
+
+        for ty in self.required_types() {
+            let (keyword, members) = match &ty.kind {
+                TypeKind::Struct { members, .. } => ("struct", members),
+                TypeKind::Union { members } => ("union", members),
+                _ => continue,
+            };
+
+            out.push_str("#[repr(C)]\n");
+            if copy.contains(ty.name.as_str()) {
+                out.push_str("#[derive(Copy, Clone)]\n");
+            }
+            writeln!(out, "pub {} {} {{", keyword, strip_type(&ty.name)).unwrap();
+            for (name, rust) in self.fields(members) {
+                writeln!(out, "    pub {}: {},", name, rust).unwrap();
+            }
+            out.push_str("}\n\n");
+        }
+    }
+
+    /// Returns the fields of a struct. Consecutive bitfields are merged into a single field that
+    /// has the size of their storage type.
+    fn fields(&self, members: &[Member]) -> Vec<(String, String)> {
+        let mut fields = Vec::new();
+        let mut members = members.iter().peekable();
+
+        while let Some(member) = members.next() {
+            let rust = self.rust_type(&member.ty).unwrap();
+            let bits = match member.bits {
+                Some(bits) => bits,
+                None => {
+                    fields.push((field_name(&member.name), rust));
+                    continue;
+                }
+            };
+
+            let storage = match member.ty.base.as_str() {
+                "uint8_t" | "int8_t" => 8,
+                "uint16_t" | "int16_t" => 16,
+                "uint64_t" | "int64_t" => 64,
+                _ => 32,
+            };
+            let mut names = vec![member.name.as_str()];
+            let mut used = bits;
+            while used < storage {
+                match members.peek() {
+                    Some(next) if next.bits.is_some() && next.ty.base == member.ty.base => {
+                        used += next.bits.unwrap();
+                        names.push(&next.name);
+                        members.next();
+                    }
+                    _ => break,
+                }
+            }
+            fields.push((names.join("_and_"), rust));
+        }
+
+        fields
+    }
+
+    fn commands(&self, out: &mut String) {
+        let mut tables: [(&str, Vec<String>); 4] = [
+            ("Static", Vec::new()),
+            ("EntryPoints", Vec::new()),
+            ("InstancePointers", Vec::new()),
+            ("DevicePointers", Vec::new()),
+        ];
+
+        for &name in &self.commands {
+            let (ret, params) = match self.registry.command(name).map(|c| &c.kind) {
+                Some(CommandKind::Proto { ret, params }) => (ret, params),
+                Some(CommandKind::Alias(target)) => {
+                    match self.registry.command(target).map(|c| &c.kind) {
+                        Some(CommandKind::Proto { ret, params }) => (ret, params),
+                        _ => continue,
+                    }
+                }
+                None => continue,
+            };
+
+            let rust_params: Option<Vec<String>> = params
+                .iter()
+                .map(|p| {
+                    // Array parameters decay to pointers in C. All of them are `const`.
+                    let rust = self.rust_type(&p.ty)?;
+                    let rust = if p.ty.arrays.is_empty() {
+                        rust
+                    } else {
+                        format!("&{}", rust)
+                    };
+                    Some(format!("{}: {}", field_name(&p.name), rust))
+                })
+                .collect();
+            let rust_ret = if is_void(ret) {
+                Some("()".to_owned())
+            } else {
+                self.rust_type(ret)
+            };
+            let (rust_params, rust_ret) = match (rust_params, rust_ret) {
+                (Some(p), Some(r)) => (p, r),
+                _ => continue,
+            };
+
+            let first_param = params
+                .first()
+                .filter(|p| p.ty.is_value())
+                .and_then(|p| self.registry.resolve_type(&p.ty.base))
+                .map(|t| t.name.as_str());
+            let table = match (name, first_param) {
+                ("vkGetInstanceProcAddr", _) => 0,
+                ("vkGetDeviceProcAddr", _) => 2,
+                (_, Some("VkInstance")) | (_, Some("VkPhysicalDevice")) => 2,
+                (_, Some("VkDevice")) | (_, Some("VkQueue")) | (_, Some("VkCommandBuffer")) => 3,
+                _ => 1,
+            };
+
+            tables[table].1.push(format!(
+                "    {} => ({}) -> {},",
+                name.trim_start_matches("vk"),
+                rust_params.join(", "),
+                rust_ret
+            ));
+        }
+
+        for (table, entries) in &tables {
+            writeln!(out, "ptrs!({}, {{", table).unwrap();
+            for entry in entries {
+                writeln!(out, "{}", entry).unwrap();
+            }
+            out.push_str("});\n\n");
+        }
+    }
+}
+
+/// Returns the type and the Rust expression of an API constant.
+fn constant_value(value: &EnumValue) -> Option<(&'static str, String)> {
+    let ty = match value.ty.as_deref() {
+        Some("uint64_t") => "u64",
+        Some("float") => "f32",
+        _ => "u32",
+    };
+    let literal = match &value.value {
+        Value::Int(v) => return Some((ty, v.to_string())),
+        Value::Alias(alias) => return Some((ty, strip_const(alias).to_owned())),
+        Value::BitPos(_) => return None,
+        Value::Literal(literal) => literal,
+    };
+
+    // `(~0U)`, `(~1U)`, `(~0ULL)`, ...
+    if let Some(inner) = literal.strip_prefix("(~").and_then(|l| l.strip_suffix(')')) {
+        if let Some(n) = inner
+            .strip_suffix("ULL")
+            .and_then(|n| n.parse::<u64>().ok())
+        {
+            return Some(("u64", format!("{:#x}", !n)));
+        }
+        if let Some(n) = inner.strip_suffix('U').and_then(|n| n.parse::<u32>().ok()) {
+            return Some(("u32", format!("{:#x}", !n)));
+        }
+    }
+
+    // `1000.0F`, `1000.0f`
+    let float = literal.trim_end_matches(['F', 'f']);
+    if float.contains('.') && float.parse::<f32>().is_ok() {
+        return Some(("f32", float.to_owned()));
+    }
+
+    None
+}
+
+fn is_void(ty: &CType) -> bool {
+    ty.base == "void" && ty.is_value() && ty.arrays.is_empty()
+}
+
+/// `VkExtent2D` becomes `Extent2D`. Function pointer types keep their name.
+fn strip_type(name: &str) -> &str {
+    name.strip_prefix("Vk").unwrap_or(name)
+}
+
+/// `VK_WHOLE_SIZE` becomes `WHOLE_SIZE`.
+fn strip_const(name: &str) -> &str {
+    name.strip_prefix("VK_").unwrap_or(name)
+}
+
+/// Turns a member or parameter name into a valid Rust identifier.
+fn field_name(name: &str) -> String {
+    match name {
+        "type" => "ty".to_owned(),
+        "ref" | "fn" | "in" | "match" | "mod" | "move" | "use" | "where" | "loop" | "impl" => {
+            format!("r#{}", name)
+        }
+        _ => name.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::generate;
+    use crate::registry::fixture;
+
+    #[test]
+    fn types() {
+        let out = generate(&fixture());
+
+        assert!(out.contains("pub type Bool32 = u32;\n"));
+        assert!(out.contains("pub type Instance = usize;\n"));
+        assert!(out.contains("pub type Fence = u64;\n"));
+        assert!(out.contains("pub type CullModeFlags = Flags;\n"));
+        assert!(out.contains("pub type PhysicalDeviceFeatures2KHR = PhysicalDeviceFeatures2;\n"));
+        assert!(out.contains(
+            "pub type PFN_vkAllocationFunction = extern \"system\" fn(*mut c_void, usize, usize) \
+             -> *mut c_void;\n"
+        ));
+        assert!(out.contains("pub type PFN_vkVoidFunction = extern \"system\" fn();\n"));
+
+        // Types of Vulkan SC and of disabled extensions aren't generated.
+        assert!(!out.contains("FaultLevel"));
+        assert!(!out.contains("DISABLED_KHR"));
+    }
+
+    #[test]
+    fn constants_and_enums() {
+        let out = generate(&fixture());
+
+        assert!(out.contains("pub const MAX_PHYSICAL_DEVICE_NAME_SIZE: u32 = 256;\n"));
+        assert!(out.contains("pub const LOD_CLAMP_NONE: f32 = 1000.0;\n"));
+        assert!(out.contains("pub const REMAINING_MIP_LEVELS: u32 = 0xffffffff;\n"));
+        assert!(out.contains("pub const QUEUE_FAMILY_EXTERNAL: u32 = 0xfffffffe;\n"));
+        assert!(out.contains("pub const WHOLE_SIZE: u64 = 0xffffffffffffffff;\n"));
+
+        assert!(out.contains("pub type Result = u32;\n"));
+        assert!(out.contains("pub const ERROR_OUT_OF_HOST_MEMORY: u32 = -1i32 as u32;\n"));
+        assert!(out.contains("pub const ERROR_SURFACE_LOST_KHR: u32 = -1000000000i32 as u32;\n"));
+        assert!(out.contains("pub const FORMAT_G8_B8_R8_3PLANE_420_UNORM: u32 = 1000156002;\n"));
+        assert!(out.contains(
+            "pub const STRUCTURE_TYPE_XLIB_SURFACE_CREATE_INFO_KHR: u32 = 1000004000;\n"
+        ));
+
+        assert!(out.contains("pub const CULL_MODE_NONE: u32 = 0;\n"));
+        assert!(out.contains("pub const CULL_MODE_BACK_BIT: u32 = 0x00000002;\n"));
+        assert!(out.contains("pub const CULL_MODE_FRONT_AND_BACK: u32 = 0x00000003;\n"));
+        assert!(out.contains("pub type PipelineStageFlagBits2 = u64;\n"));
+        assert!(out.contains("pub const PIPELINE_STAGE_2_COPY_BIT: u64 = 0x100000000;\n"));
+    }
+
+    #[test]
+    fn structs() {
+        let out = generate(&fixture());
+
+        assert!(out.contains(
+            "#[repr(C)]\npub struct InstanceCreateInfo {\n    pub sType: StructureType,\n    \
+             pub pNext: *const c_void,\n    pub enabledExtensionCount: u32,\n    \
+             pub ppEnabledExtensionNames: *const *const c_char,\n}\n"
+        ));
+        assert!(
+            out.contains("    pub deviceName: [c_char; MAX_PHYSICAL_DEVICE_NAME_SIZE as usize],\n")
+        );
+        assert!(out.contains("    pub pointSizeRange: [f32; 2],\n"));
+        assert!(out.contains("    pub dpy: *mut c_void,\n    pub window: c_ulong,\n"));
+        assert!(out.contains("    pub instanceCustomIndex_and_mask: u32,\n"));
+
+        // Unions and the structs they contain are `Copy`.
+        assert!(out.contains("#[repr(C)]\n#[derive(Copy, Clone)]\npub union ClearColorValue {"));
+        assert!(out.contains("#[repr(C)]\n#[derive(Copy, Clone)]\npub struct Extent2D {"));
+        assert!(out.contains("#[repr(C)]\npub struct PhysicalDeviceFeatures {"));
+
+        // Structs that contain types defined outside of vk.xml are skipped, and so are the
+        // commands that use them.
+        assert!(!out.contains("StdVideoUser"));
+        assert!(!out.contains("UseStdVideo"));
+    }
+
+    #[test]
+    fn commands() {
+        let out = generate(&fixture());
+
+        assert!(out.contains(
+            "ptrs!(Static, {\n    GetInstanceProcAddr => (instance: Instance, pName: *const c_char) \
+             -> PFN_vkVoidFunction,\n});\n"
+        ));
+        assert!(out.contains(
+            "ptrs!(EntryPoints, {\n    CreateInstance => (pCreateInfo: *const InstanceCreateInfo, \
+             pAllocator: *const AllocationCallbacks, pInstance: *mut Instance) -> Result,\n});\n"
+        ));
+        assert!(out.contains(
+            "    GetDeviceProcAddr => (device: Device, pName: *const c_char) -> PFN_vkVoidFunction,\n"
+        ));
+        assert!(out.contains(
+            "    GetPhysicalDeviceFeatures2KHR => (physicalDevice: PhysicalDevice, \
+             pFeatures: *mut PhysicalDeviceFeatures2) -> (),\n"
+        ));
+        assert!(out.contains(
+            "    GetPhysicalDeviceXlibPresentationSupportKHR => (physicalDevice: PhysicalDevice, \
+             queueFamilyIndex: u32, dpy: *mut c_void, visualID: c_ulong) -> Bool32,\n"
+        ));
+
+        let device = &out[out.find("ptrs!(DevicePointers").unwrap()..];
+        assert!(device.contains(
+            "    CmdSetCullMode => (commandBuffer: CommandBuffer, cullMode: CullModeFlags) -> (),\n"
+        ));
+        assert!(device.contains(
+            "    CmdSetBlendConstants => (commandBuffer: CommandBuffer, \
+             blendConstants: &[f32; 4]) -> (),\n"
+        ));
+    }
+}
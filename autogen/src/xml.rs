@@ -0,0 +1,158 @@
+// Copyright (c) 2021 The vulkano developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>,
+// at your option. All files in the project carrying such
+// notice may not be copied, modified, or distributed except
+// according to those terms.
+
+//! Minimal element tree on top of `xml-rs`.
+//!
+//! The registry is only a few megabytes, so it is simpler to load it whole and walk the tree
+//! than to drive a streaming parser from every generator.
+
+use std::io::Read;
+use xml::reader::{EventReader, ParserConfig, XmlEvent};
+
+/// An XML element with its attributes and children, in document order.
+#[derive(Debug, Clone, Default)]
+pub struct Element {
+    pub name: String,
+    pub attrs: Vec<(String, String)>,
+    pub children: Vec<Node>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Node {
+    Element(Element),
+    Text(String),
+}
+
+impl Element {
+    /// Returns the value of an attribute.
+    #[inline]
+    pub fn attr(&self, name: &str) -> Option<&str> {
+        self.attrs
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Iterates over the child elements, skipping text.
+    #[inline]
+    pub fn elements(&self) -> impl Iterator<Item = &Element> {
+        self.children.iter().filter_map(|node| match node {
+            Node::Element(e) => Some(e),
+            Node::Text(_) => None,
+        })
+    }
+
+    /// Iterates over the child elements with the given tag name.
+    #[inline]
+    pub fn elements_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Element> + 'a {
+        self.elements().filter(move |e| e.name == name)
+    }
+
+    /// Returns the first child element with the given tag name.
+    #[inline]
+    pub fn child(&self, name: &str) -> Option<&Element> {
+        self.elements().find(|e| e.name == name)
+    }
+
+    /// Returns the text of the first child element with the given tag name.
+    #[inline]
+    pub fn child_text(&self, name: &str) -> Option<String> {
+        self.child(name).map(|e| e.text())
+    }
+
+    /// Concatenates all the text inside this element, including the text of child elements.
+    ///
+    /// `<comment>` elements are skipped, since the registry uses them inside declarations.
+    pub fn text(&self) -> String {
+        let mut out = String::new();
+        self.push_text(&mut out);
+        out
+    }
+
+    fn push_text(&self, out: &mut String) {
+        for node in &self.children {
+            match node {
+                Node::Text(t) => out.push_str(t),
+                Node::Element(e) if e.name == "comment" => (),
+                Node::Element(e) => e.push_text(out),
+            }
+        }
+    }
+}
+
+/// Parses a whole document and returns its root element.
+pub fn parse<R: Read>(source: R) -> Result<Element, xml::reader::Error> {
+    let config = ParserConfig::new()
+        .trim_whitespace(false)
+        .whitespace_to_characters(true)
+        .cdata_to_characters(true)
+        .ignore_comments(true);
+    let reader = EventReader::new_with_config(source, config);
+
+    let mut stack: Vec<Element> = vec![Element::default()];
+
+    for event in reader {
+        match event? {
+            XmlEvent::StartElement {
+                name, attributes, ..
+            } => {
+                stack.push(Element {
+                    name: name.local_name,
+                    attrs: attributes
+                        .into_iter()
+                        .map(|a| (a.name.local_name, a.value))
+                        .collect(),
+                    children: Vec::new(),
+                });
+            }
+            XmlEvent::EndElement { .. } => {
+                let element = stack.pop().unwrap();
+                stack
+                    .last_mut()
+                    .unwrap()
+                    .children
+                    .push(Node::Element(element));
+            }
+            XmlEvent::Characters(text) => {
+                if let Some(Node::Text(last)) = stack.last_mut().unwrap().children.last_mut() {
+                    last.push_str(&text);
+                } else {
+                    stack.last_mut().unwrap().children.push(Node::Text(text));
+                }
+            }
+            _ => (),
+        }
+    }
+
+    let document = stack.pop().unwrap();
+    Ok(document
+        .children
+        .into_iter()
+        .find_map(|node| match node {
+            Node::Element(e) => Some(e),
+            Node::Text(_) => None,
+        })
+        .unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+
+    #[test]
+    fn text_skips_comments() {
+        let root = parse(
+            &b"<member>const <type>void</type>* <name>pNext</name><comment>x</comment></member>"[..],
+        )
+        .unwrap();
+        assert_eq!(root.name, "member");
+        assert_eq!(root.text(), "const void* pNext");
+        assert_eq!(root.child_text("name").unwrap(), "pNext");
+    }
+}
@@ -15,13 +15,14 @@ use std::{
 };
 
 use proc_macro2::{Span, TokenStream};
-use shaderc::{CompileOptions, Compiler, TargetEnv};
+use shaderc::{CompileOptions, Compiler, EnvVersion, OptimizationLevel, TargetEnv};
 use syn::Ident;
 
 pub use crate::parse::ParseError;
 pub use shaderc::{CompilationArtifact, IncludeType, ResolvedInclude, ShaderKind};
 
 use crate::enums::Capability;
+use crate::enums::ExecutionModel;
 use crate::enums::StorageClass;
 use crate::parse::Instruction;
 
@@ -150,6 +151,10 @@ fn include_callback(
     })
 }
 
+/// Version of Vulkan that GLSL shaders are compiled for. SPIR-V modules are validated and
+/// optimized for the same environment.
+pub const TARGET_ENV_VERSION: EnvVersion = EnvVersion::Vulkan1_1;
+
 pub fn compile(
     path: Option<String>,
     base_path: &impl AsRef<Path>,
@@ -157,12 +162,13 @@ pub fn compile(
     ty: ShaderKind,
     include_directories: &[impl AsRef<Path>],
     macro_defines: &[(impl AsRef<str>, impl AsRef<str>)],
+    optimization: OptimizationLevel,
 ) -> Result<(CompilationArtifact, Vec<String>), String> {
     let includes_tracker = RefCell::new(Vec::new());
     let mut compiler = Compiler::new().ok_or("failed to create GLSL compiler")?;
     let mut compile_options = CompileOptions::new().ok_or("failed to initialize compile option")?;
-    const ENV_VULKAN_VERSION: u32 = (1 << 22) | (1 << 12);
-    compile_options.set_target_env(TargetEnv::Vulkan, ENV_VULKAN_VERSION);
+    compile_options.set_target_env(TargetEnv::Vulkan, TARGET_ENV_VERSION as u32);
+    compile_options.set_optimization_level(optimization);
     let root_source_path = if let &Some(ref path) = &path {
         path
     } else {
@@ -199,6 +205,50 @@ pub fn compile(
     Ok((content, includes))
 }
 
+/// Checks that a SPIR-V module has at least one entry point of the given shader kind.
+///
+/// Modules compiled from GLSL by the macro always pass this check, but precompiled modules
+/// loaded with `bytes` may not match the `ty` given to the macro.
+pub fn check_shader_kind(spirv: &[u32], ty: ShaderKind) -> Result<(), String> {
+    let doc = parse::parse_spirv(spirv).map_err(|err| format!("{:?}", err))?;
+
+    // Task and mesh shaders can come from either the NV or the EXT extension.
+    let expected: &[ExecutionModel] = match ty {
+        ShaderKind::Vertex => &[ExecutionModel::ExecutionModelVertex],
+        ShaderKind::Fragment => &[ExecutionModel::ExecutionModelFragment],
+        ShaderKind::Geometry => &[ExecutionModel::ExecutionModelGeometry],
+        ShaderKind::TessControl => &[ExecutionModel::ExecutionModelTessellationControl],
+        ShaderKind::TessEvaluation => &[ExecutionModel::ExecutionModelTessellationEvaluation],
+        ShaderKind::Compute => &[ExecutionModel::ExecutionModelGLCompute],
+        ShaderKind::Task => &[
+            ExecutionModel::ExecutionModelTaskNV,
+            ExecutionModel::ExecutionModelTaskEXT,
+        ],
+        ShaderKind::Mesh => &[
+            ExecutionModel::ExecutionModelMeshNV,
+            ExecutionModel::ExecutionModelMeshEXT,
+        ],
+        _ => unreachable!(),
+    };
+
+    let found = doc
+        .instructions
+        .iter()
+        .any(|instruction| match instruction {
+            &Instruction::EntryPoint { ref execution, .. } => expected.contains(execution),
+            _ => false,
+        });
+
+    if found {
+        Ok(())
+    } else {
+        Err(format!(
+            "The SPIR-V module has no entry point with any of the execution models {:?}",
+            expected
+        ))
+    }
+}
+
 pub(super) fn reflect<'a, I>(
     name: &str,
     spirv: &[u32],
@@ -214,6 +264,8 @@ where
 
     // checking whether each required capability is enabled in the Vulkan device
     let mut cap_checks: Vec<TokenStream> = vec![];
+    let mut uses_subgroup_operations = false;
+    let mut uses_quad_operations = false;
     for i in doc.instructions.iter() {
         let dev_req = {
             match i {
@@ -255,6 +307,61 @@ where
                     });
                 }
             }
+            DeviceRequirement::SubgroupOperations(operations) => {
+                uses_subgroup_operations = true;
+                for operation in operations {
+                    if *operation == "quad" {
+                        uses_quad_operations = true;
+                    }
+                    let ident = Ident::new(operation, Span::call_site());
+                    cap_checks.push(quote! {
+                        if !device
+                            .physical_device()
+                            .extended_properties()
+                            .subgroup_supported_operations()
+                            .map_or(false, |operations| operations.#ident)
+                        {
+                            panic!("Subgroup operations {:?} not supported by the physical device", #operation);
+                        }
+                    });
+                }
+            }
+        }
+    }
+
+    // checking whether subgroup operations are supported in the stages of the entry points
+    if uses_subgroup_operations {
+        for i in doc.instructions.iter() {
+            if let Instruction::EntryPoint { execution, .. } = i {
+                let stage = match execution_model_stage(execution) {
+                    Some(stage) => stage,
+                    None => continue,
+                };
+                let ident = Ident::new(stage, Span::call_site());
+                cap_checks.push(quote! {
+                    if !device
+                        .physical_device()
+                        .extended_properties()
+                        .subgroup_supported_stages()
+                        .map_or(false, |stages| stages.#ident)
+                    {
+                        panic!("Subgroup operations not supported in the {:?} stage by the physical device", #stage);
+                    }
+                });
+
+                if uses_quad_operations && stage != "fragment" && stage != "compute" {
+                    cap_checks.push(quote! {
+                        if !device
+                            .physical_device()
+                            .extended_properties()
+                            .subgroup_quad_operations_in_all_stages()
+                            .unwrap_or(false)
+                        {
+                            panic!("Subgroup quad operations not supported in the {:?} stage by the physical device", #stage);
+                        }
+                    });
+                }
+            }
         }
     }
 
@@ -488,6 +595,28 @@ fn capability_requirement(cap: &Capability) -> DeviceRequirement {
             DeviceRequirement::Features(&["shader_storage_image_write_without_format"])
         }
         Capability::CapabilityMultiViewport => DeviceRequirement::Features(&["multi_viewport"]),
+        Capability::CapabilityGroupNonUniform => DeviceRequirement::SubgroupOperations(&["basic"]),
+        Capability::CapabilityGroupNonUniformVote => {
+            DeviceRequirement::SubgroupOperations(&["vote"])
+        }
+        Capability::CapabilityGroupNonUniformArithmetic => {
+            DeviceRequirement::SubgroupOperations(&["arithmetic"])
+        }
+        Capability::CapabilityGroupNonUniformBallot => {
+            DeviceRequirement::SubgroupOperations(&["ballot"])
+        }
+        Capability::CapabilityGroupNonUniformShuffle => {
+            DeviceRequirement::SubgroupOperations(&["shuffle"])
+        }
+        Capability::CapabilityGroupNonUniformShuffleRelative => {
+            DeviceRequirement::SubgroupOperations(&["shuffle_relative"])
+        }
+        Capability::CapabilityGroupNonUniformClustered => {
+            DeviceRequirement::SubgroupOperations(&["clustered"])
+        }
+        Capability::CapabilityGroupNonUniformQuad => {
+            DeviceRequirement::SubgroupOperations(&["quad"])
+        }
         Capability::CapabilityStorageUniformBufferBlock16 => {
             DeviceRequirement::Extensions(&["khr_16bit_storage"])
         }
@@ -506,6 +635,27 @@ fn capability_requirement(cap: &Capability) -> DeviceRequirement {
         Capability::CapabilityStoragePushConstant8 => {
             DeviceRequirement::Extensions(&["khr_8bit_storage"])
         }
+        Capability::CapabilityMeshShadingNV => DeviceRequirement::Extensions(&["nv_mesh_shader"]),
+        Capability::CapabilityMeshShadingEXT => DeviceRequirement::Extensions(&["ext_mesh_shader"]),
+    }
+}
+
+/// Returns the name of the `ShaderStages` field corresponding to a SPIR-V execution model.
+fn execution_model_stage(execution: &ExecutionModel) -> Option<&'static str> {
+    match *execution {
+        ExecutionModel::ExecutionModelVertex => Some("vertex"),
+        ExecutionModel::ExecutionModelTessellationControl => Some("tessellation_control"),
+        ExecutionModel::ExecutionModelTessellationEvaluation => Some("tessellation_evaluation"),
+        ExecutionModel::ExecutionModelGeometry => Some("geometry"),
+        ExecutionModel::ExecutionModelFragment => Some("fragment"),
+        ExecutionModel::ExecutionModelGLCompute => Some("compute"),
+        ExecutionModel::ExecutionModelKernel => None,
+        ExecutionModel::ExecutionModelTaskNV | ExecutionModel::ExecutionModelTaskEXT => {
+            Some("task")
+        }
+        ExecutionModel::ExecutionModelMeshNV | ExecutionModel::ExecutionModelMeshEXT => {
+            Some("mesh")
+        }
     }
 }
 
@@ -527,6 +677,9 @@ fn storage_class_requirement(storage_class: &StorageClass) -> DeviceRequirement
         StorageClass::StorageClassStorageBuffer => {
             DeviceRequirement::Extensions(&["khr_storage_buffer_storage_class"])
         }
+        StorageClass::StorageClassTaskPayloadWorkgroupEXT => {
+            DeviceRequirement::Extensions(&["ext_mesh_shader"])
+        }
     }
 }
 
@@ -534,6 +687,8 @@ enum DeviceRequirement {
     None,
     Features(&'static [&'static str]),
     Extensions(&'static [&'static str]),
+    /// Fields of `SubgroupFeatures` that must be supported by the physical device.
+    SubgroupOperations(&'static [&'static str]),
 }
 
 #[cfg(test)]
@@ -584,6 +739,7 @@ mod tests {
             ShaderKind::Vertex,
             &includes,
             &defines,
+            OptimizationLevel::Zero,
         )
         .unwrap();
         let doc = parse::parse_spirv(comp.as_binary()).unwrap();
@@ -610,6 +766,7 @@ mod tests {
             ShaderKind::Vertex,
             &includes,
             &defines,
+            OptimizationLevel::Zero,
         )
         .unwrap();
         let doc = parse::parse_spirv(comp.as_binary()).unwrap();
@@ -640,6 +797,7 @@ mod tests {
             ShaderKind::Vertex,
             &includes,
             &defines,
+            OptimizationLevel::Zero,
         )
         .unwrap();
         let doc = parse::parse_spirv(comp.as_binary()).unwrap();
@@ -663,6 +821,7 @@ mod tests {
             ShaderKind::Vertex,
             &empty_includes,
             &defines,
+            OptimizationLevel::Zero,
         )
         .expect("Cannot resolve include files");
 
@@ -681,6 +840,7 @@ mod tests {
                 root_path.join("tests").join("include_dir_b"),
             ],
             &defines,
+            OptimizationLevel::Zero,
         )
         .expect("Cannot resolve include files");
         assert_eq!(
@@ -706,6 +866,7 @@ mod tests {
             ShaderKind::Vertex,
             &[root_path.join("tests").join("include_dir_a")],
             &defines,
+            OptimizationLevel::Zero,
         )
         .expect("Cannot resolve include files");
         assert_eq!(
@@ -741,6 +902,7 @@ mod tests {
             ShaderKind::Vertex,
             &empty_includes,
             &defines,
+            OptimizationLevel::Zero,
         )
         .expect("Cannot resolve include files");
         assert_eq!(
@@ -765,6 +927,7 @@ mod tests {
                 root_path.join("tests").join("include_dir_c"),
             ],
             &defines,
+            OptimizationLevel::Zero,
         )
         .expect("Cannot resolve include files");
         assert_eq!(
@@ -799,6 +962,7 @@ mod tests {
             ShaderKind::Vertex,
             &empty_includes,
             &no_defines,
+            OptimizationLevel::Zero,
         );
         assert!(compile_no_defines.is_err());
 
@@ -809,7 +973,162 @@ mod tests {
             ShaderKind::Vertex,
             &empty_includes,
             &defines,
+            OptimizationLevel::Zero,
         );
         compile_defines.expect("Setting shader macros did not work");
     }
+
+    #[test]
+    #[cfg(feature = "spirv-tools")]
+    fn test_optimization_and_validation() {
+        let empty_includes: [PathBuf; 0] = [];
+        let no_defines: [(String, String); 0] = [];
+        let source = "
+        #version 450
+        layout(location = 0) out vec4 color;
+        void main() {
+            float unused = 5.0;
+            color = vec4(1.0);
+        }
+        ";
+        let (unoptimized, _) = compile(
+            None,
+            &Path::new(""),
+            source,
+            ShaderKind::Fragment,
+            &empty_includes,
+            &no_defines,
+            OptimizationLevel::Zero,
+        )
+        .unwrap();
+        let (optimized, _) = compile(
+            None,
+            &Path::new(""),
+            source,
+            ShaderKind::Fragment,
+            &empty_includes,
+            &no_defines,
+            OptimizationLevel::Performance,
+        )
+        .unwrap();
+        assert!(optimized.as_binary().len() < unoptimized.as_binary().len());
+        crate::spirv_tools::validate(unoptimized.as_binary()).unwrap();
+        crate::spirv_tools::validate(optimized.as_binary()).unwrap();
+
+        let reoptimized =
+            crate::spirv_tools::optimize(unoptimized.as_binary(), OptimizationLevel::Size).unwrap();
+        crate::spirv_tools::validate(&reoptimized).unwrap();
+
+        // Truncating a module right after its header makes it invalid.
+        let truncated = &unoptimized.as_binary()[..6];
+        assert!(crate::spirv_tools::validate(truncated).is_err());
+    }
+
+    #[test]
+    fn test_check_shader_kind() {
+        let empty_includes: [PathBuf; 0] = [];
+        let no_defines: [(String, String); 0] = [];
+        let (comp, _) = compile(
+            None,
+            &Path::new(""),
+            "
+        #version 450
+        void main() {}
+        ",
+            ShaderKind::Vertex,
+            &empty_includes,
+            &no_defines,
+            OptimizationLevel::Zero,
+        )
+        .unwrap();
+        check_shader_kind(comp.as_binary(), ShaderKind::Vertex).unwrap();
+        assert!(check_shader_kind(comp.as_binary(), ShaderKind::Fragment).is_err());
+    }
+
+    #[test]
+    fn test_subgroup_operations() {
+        let empty_includes: [PathBuf; 0] = [];
+        let no_defines: [(String, String); 0] = [];
+        let (comp, _) = compile(
+            None,
+            &Path::new(""),
+            "
+        #version 450
+        #extension GL_KHR_shader_subgroup_arithmetic : require
+        layout(local_size_x = 64) in;
+        layout(set = 0, binding = 0) buffer Data { uint data[]; };
+        void main() {
+            data[gl_GlobalInvocationID.x] = subgroupAdd(data[gl_GlobalInvocationID.x]);
+        }
+        ",
+            ShaderKind::Compute,
+            &empty_includes,
+            &no_defines,
+            OptimizationLevel::Zero,
+        )
+        .unwrap();
+
+        let doc = parse::parse_spirv(comp.as_binary()).unwrap();
+        let mut operations: Vec<&str> = doc
+            .instructions
+            .iter()
+            .filter_map(|i| match i {
+                Instruction::Capability(cap) => match capability_requirement(cap) {
+                    DeviceRequirement::SubgroupOperations(ops) => Some(ops[0]),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect();
+        operations.sort();
+        assert_eq!(operations, ["arithmetic", "basic"]);
+    }
+
+    #[test]
+    fn test_mesh_shader() {
+        let empty_includes: [PathBuf; 0] = [];
+        let no_defines: [(String, String); 0] = [];
+        let (comp, _) = compile(
+            None,
+            &Path::new(""),
+            "
+        #version 450
+        #extension GL_NV_mesh_shader : require
+        layout(local_size_x = 1) in;
+        layout(triangles, max_vertices = 3, max_primitives = 1) out;
+        layout(location = 0) out vec4 color[];
+        void main() {
+            gl_MeshVerticesNV[0].gl_Position = vec4(0.0);
+            gl_MeshVerticesNV[1].gl_Position = vec4(1.0);
+            gl_MeshVerticesNV[2].gl_Position = vec4(2.0);
+            color[0] = vec4(1.0);
+            color[1] = vec4(1.0);
+            color[2] = vec4(1.0);
+            gl_PrimitiveIndicesNV[0] = 0;
+            gl_PrimitiveIndicesNV[1] = 1;
+            gl_PrimitiveIndicesNV[2] = 2;
+            gl_PrimitiveCountNV = 1;
+        }
+        ",
+            ShaderKind::Mesh,
+            &empty_includes,
+            &no_defines,
+            OptimizationLevel::Zero,
+        )
+        .unwrap();
+        check_shader_kind(comp.as_binary(), ShaderKind::Mesh).unwrap();
+        assert!(check_shader_kind(comp.as_binary(), ShaderKind::Vertex).is_err());
+
+        let doc = parse::parse_spirv(comp.as_binary()).unwrap();
+        let capabilities = doc.instructions.iter().filter_map(|i| match i {
+            Instruction::Capability(cap) => Some(cap),
+            _ => None,
+        });
+        assert!(capabilities
+            .map(capability_requirement)
+            .any(|req| match req {
+                DeviceRequirement::Extensions(&["nv_mesh_shader"]) => true,
+                _ => false,
+            }));
+    }
 }
@@ -567,7 +567,7 @@ mod tests {
     use super::*;
     use crate::codegen::compile;
     use crate::parse;
-    use shaderc::ShaderKind;
+    use shaderc::{OptimizationLevel, ShaderKind};
     use std::path::{Path, PathBuf};
 
     /// `entrypoint1.frag.glsl`:
@@ -715,6 +715,7 @@ mod tests {
             ShaderKind::Vertex,
             &includes,
             &defines,
+            OptimizationLevel::Zero,
         )
         .unwrap();
         let doc = parse::parse_spirv(comp.as_binary()).unwrap();
@@ -46,8 +46,21 @@ pub(super) fn write_entry_point(
     };
     let ignore_first_array_out = match *execution {
         ExecutionModel::ExecutionModelTessellationControl => true,
+        ExecutionModel::ExecutionModelMeshNV => true,
+        ExecutionModel::ExecutionModelMeshEXT => true,
         _ => false,
     };
+    // Apart from built-ins, the only inputs of mesh shaders and outputs of task shaders are the
+    // task payload, which isn't part of the pipeline interface.
+    let (skip_inputs, skip_outputs) = match *execution {
+        ExecutionModel::ExecutionModelMeshNV | ExecutionModel::ExecutionModelMeshEXT => {
+            (true, false)
+        }
+        ExecutionModel::ExecutionModelTaskNV | ExecutionModel::ExecutionModelTaskEXT => {
+            (false, true)
+        }
+        _ => (false, false),
+    };
 
     let interface_structs = write_interface_structs(
         doc,
@@ -55,6 +68,8 @@ pub(super) fn write_entry_point(
         interface,
         ignore_first_array_in,
         ignore_first_array_out,
+        skip_inputs,
+        skip_outputs,
     );
 
     let descriptor_sets_layout_name = Ident::new(
@@ -143,6 +158,14 @@ pub(super) fn write_entry_point(
                     quote! { ::vulkano::pipeline::shader::GraphicsShaderType::Fragment }
                 }
 
+                ExecutionModel::ExecutionModelTaskNV | ExecutionModel::ExecutionModelTaskEXT => {
+                    quote! { ::vulkano::pipeline::shader::GraphicsShaderType::Task }
+                }
+
+                ExecutionModel::ExecutionModelMeshNV | ExecutionModel::ExecutionModelMeshEXT => {
+                    quote! { ::vulkano::pipeline::shader::GraphicsShaderType::Mesh }
+                }
+
                 ExecutionModel::ExecutionModelGLCompute => unreachable!(),
 
                 ExecutionModel::ExecutionModelKernel => panic!("Kernels are not supported"),
@@ -169,6 +192,14 @@ pub(super) fn write_entry_point(
                     quote! { ShaderStages { fragment: true, .. ShaderStages::none() } }
                 }
 
+                ExecutionModel::ExecutionModelTaskNV | ExecutionModel::ExecutionModelTaskEXT => {
+                    quote! { ShaderStages { task: true, .. ShaderStages::none() } }
+                }
+
+                ExecutionModel::ExecutionModelMeshNV | ExecutionModel::ExecutionModelMeshEXT => {
+                    quote! { ShaderStages { mesh: true, .. ShaderStages::none() } }
+                }
+
                 ExecutionModel::ExecutionModelGLCompute => unreachable!(),
                 ExecutionModel::ExecutionModelKernel => unreachable!(),
             };
@@ -244,6 +275,8 @@ fn write_interface_structs(
     interface: &[u32],
     ignore_first_array_in: bool,
     ignore_first_array_out: bool,
+    skip_inputs: bool,
+    skip_outputs: bool,
 ) -> TokenStream {
     let mut input_elements = vec![];
     let mut output_elements = vec![];
@@ -263,10 +296,10 @@ fn write_interface_structs(
                     }
 
                     let (to_write, ignore_first_array) = match storage_class {
-                        &StorageClass::StorageClassInput => {
+                        &StorageClass::StorageClassInput if !skip_inputs => {
                             (&mut input_elements, ignore_first_array_in)
                         }
-                        &StorageClass::StorageClassOutput => {
+                        &StorageClass::StorageClassOutput if !skip_outputs => {
                             (&mut output_elements, ignore_first_array_out)
                         }
                         _ => continue,
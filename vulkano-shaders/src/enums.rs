@@ -53,6 +53,10 @@ enumeration! {
         ExecutionModelFragment = 4,
         ExecutionModelGLCompute = 5,
         ExecutionModelKernel = 6,
+        ExecutionModelTaskNV = 5267,
+        ExecutionModelMeshNV = 5268,
+        ExecutionModelTaskEXT = 5364,
+        ExecutionModelMeshEXT = 5365,
     } ExecutionModel;
 
     typedef enum AddressingModel_ {
@@ -99,6 +103,9 @@ enumeration! {
         ExecutionModeOutputTriangleStrip = 29,
         ExecutionModeVecTypeHint = 30,
         ExecutionModeContractionOff = 31,
+        ExecutionModeOutputLinesNV = 5269,
+        ExecutionModeOutputPrimitivesNV = 5270,
+        ExecutionModeOutputTrianglesNV = 5298,
     } ExecutionMode;
 
     typedef enum StorageClass_ {
@@ -115,6 +122,7 @@ enumeration! {
         StorageClassAtomicCounter = 10,
         StorageClassImage = 11,
         StorageClassStorageBuffer = 12,
+        StorageClassTaskPayloadWorkgroupEXT = 5402,
     } StorageClass;
 
     typedef enum Dim_ {
@@ -338,6 +346,9 @@ enumeration! {
         DecorationNoContraction = 42,
         DecorationInputAttachmentIndex = 43,
         DecorationAlignment = 44,
+        DecorationPerPrimitiveNV = 5271,
+        DecorationPerViewNV = 5272,
+        DecorationPerTaskNV = 5273,
     } Decoration;
 
     typedef enum BuiltIn_ {
@@ -382,6 +393,18 @@ enumeration! {
         BuiltInSubgroupLocalInvocationId = 41,
         BuiltInVertexIndex = 42,
         BuiltInInstanceIndex = 43,
+        BuiltInTaskCountNV = 5274,
+        BuiltInPrimitiveCountNV = 5275,
+        BuiltInPrimitiveIndicesNV = 5276,
+        BuiltInClipDistancePerViewNV = 5277,
+        BuiltInCullDistancePerViewNV = 5278,
+        BuiltInLayerPerViewNV = 5279,
+        BuiltInMeshViewCountNV = 5280,
+        BuiltInMeshViewIndicesNV = 5281,
+        BuiltInPrimitivePointIndicesEXT = 5294,
+        BuiltInPrimitiveLineIndicesEXT = 5295,
+        BuiltInPrimitiveTriangleIndicesEXT = 5296,
+        BuiltInCullPrimitiveEXT = 5299,
     } BuiltIn;
 
     typedef enum SelectionControlShift_ {
@@ -547,11 +570,21 @@ enumeration! {
         CapabilityStorageImageReadWithoutFormat = 55,
         CapabilityStorageImageWriteWithoutFormat = 56,
         CapabilityMultiViewport = 57,
+        CapabilityGroupNonUniform = 61,
+        CapabilityGroupNonUniformVote = 62,
+        CapabilityGroupNonUniformArithmetic = 63,
+        CapabilityGroupNonUniformBallot = 64,
+        CapabilityGroupNonUniformShuffle = 65,
+        CapabilityGroupNonUniformShuffleRelative = 66,
+        CapabilityGroupNonUniformClustered = 67,
+        CapabilityGroupNonUniformQuad = 68,
         CapabilityStorageUniformBufferBlock16 = 4433,
         CapabilityStorageUniform16 = 4434,
         CapabilityStoragePushConstant16 = 4435,
         CapabilityStorageInputOutput16 = 4436,
         CapabilityStorageInputOutput8 = 4448,
         CapabilityStoragePushConstant8 = 4450,
+        CapabilityMeshShadingNV = 5266,
+        CapabilityMeshShadingEXT = 5283,
     } Capability;
 }
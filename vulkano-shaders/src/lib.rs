@@ -116,6 +116,8 @@
 //! * `tess_ctrl`
 //! * `tess_eval`
 //! * `compute`
+//! * `task` (requires the `nv_mesh_shader` or `ext_mesh_shader` device extension)
+//! * `mesh` (requires the `nv_mesh_shader` or `ext_mesh_shader` device extension)
 //!
 //! For details on what these shader types mean, [see Vulkano's documentation][pipeline].
 //!
@@ -172,6 +174,25 @@
 //! final output of generated code the user can also use `dump` macro
 //! option(see below).
 //!
+//! ## `validate: true`
+//!
+//! Runs the SPIR-V validator of SPIRV-Tools (bundled with shaderc) on the final module, whether
+//! it was compiled from GLSL or loaded with `bytes`. Validation errors are reported as compile
+//! errors on the `shader!` invocation. Defaults to `false`.
+//!
+//! This requires the `spirv-tools` feature, which in turn requires shaderc to be linked
+//! statically with the `shaderc_combined` library.
+//!
+//! ## `optimize: "..."`
+//!
+//! Runs the SPIR-V optimizer on the module. The value can be `zero`, `size` or `performance`.
+//! For GLSL sources the optimization is done by shaderc while compiling, and for `bytes` the
+//! optimizer of SPIRV-Tools is run on the loaded module, which requires the `spirv-tools`
+//! feature. The optimizer refuses invalid modules. Defaults to no optimization.
+//!
+//! Regardless of these options, a module loaded with `bytes` is checked for a valid header and
+//! for an entry point matching `ty`, like a module compiled from GLSL.
+//!
 //! ## `dump: true`
 //!
 //! The crate fails to compile but prints the generated rust code to stdout.
@@ -195,11 +216,14 @@ extern crate quote;
 extern crate syn;
 extern crate proc_macro;
 
+use std::env;
 use std::fs;
 use std::fs::File;
 use std::io::{Read, Result as IoResult};
 use std::path::Path;
-use std::{env, iter::empty};
+
+use proc_macro2::{Span, TokenStream};
+use shaderc::OptimizationLevel;
 
 use syn::parse::{Parse, ParseStream, Result};
 use syn::{
@@ -213,10 +237,10 @@ mod enums;
 mod parse;
 mod spec_consts;
 mod spirv_search;
+mod spirv_tools;
 mod structs;
 
 use crate::codegen::ShaderKind;
-use std::slice::from_raw_parts;
 
 enum SourceKind {
     Src(String),
@@ -276,6 +300,8 @@ struct MacroInput {
     include_directories: Vec<String>,
     macro_defines: Vec<(String, String)>,
     types_meta: TypesMeta,
+    validate: bool,
+    optimization: Option<OptimizationLevel>,
     dump: bool,
 }
 
@@ -287,6 +313,8 @@ impl Parse for MacroInput {
         let mut include_directories = Vec::new();
         let mut macro_defines = Vec::new();
         let mut types_meta = None;
+        let mut validate = None;
+        let mut optimization = None;
 
         while !input.is_empty() {
             let name: Ident = input.parse()?;
@@ -306,7 +334,9 @@ impl Parse for MacroInput {
                         "tess_ctrl" => ShaderKind::TessControl,
                         "tess_eval" => ShaderKind::TessEvaluation,
                         "compute" => ShaderKind::Compute,
-                        _ => panic!("Unexpected shader type, valid values: vertex, fragment, geometry, tess_ctrl, tess_eval, compute")
+                        "task" => ShaderKind::Task,
+                        "mesh" => ShaderKind::Mesh,
+                        _ => panic!("Unexpected shader type, valid values: vertex, fragment, geometry, tess_ctrl, tess_eval, compute, task, mesh")
                     };
                     shader_kind = Some(ty);
                 }
@@ -504,6 +534,30 @@ impl Parse for MacroInput {
 
                     types_meta = Some(meta);
                 }
+                "validate" => {
+                    if validate.is_some() {
+                        panic!("Only one `validate` can be defined")
+                    }
+                    let validate_lit: LitBool = input.parse()?;
+                    validate = Some(validate_lit.value);
+                }
+                "optimize" => {
+                    if optimization.is_some() {
+                        panic!("Only one `optimize` can be defined")
+                    }
+
+                    let level: LitStr = input.parse()?;
+                    let level = match level.value().as_ref() {
+                        "zero" => OptimizationLevel::Zero,
+                        "size" => OptimizationLevel::Size,
+                        "performance" => OptimizationLevel::Performance,
+                        _ => return Err(syn::Error::new(
+                            level.span(),
+                            "Unexpected optimization level, valid values: zero, size, performance",
+                        )),
+                    };
+                    optimization = Some(level);
+                }
                 "dump" => {
                     if dump.is_some() {
                         panic!("Only one `dump` can be defined")
@@ -537,6 +591,8 @@ impl Parse for MacroInput {
             include_directories,
             dump,
             macro_defines,
+            validate: validate.unwrap_or(false),
+            optimization,
             types_meta: types_meta.unwrap_or_else(|| TypesMeta::default()),
         })
     }
@@ -548,49 +604,78 @@ pub(self) fn read_file_to_string(full_path: &Path) -> IoResult<String> {
     Ok(buf)
 }
 
+/// Converts the content of a `.spv` file to words, accepting both byte orders.
+fn spirv_words_from_bytes(bytes: &[u8]) -> std::result::Result<Vec<u32>, String> {
+    // The SPIR-V specification essentially guarantees that
+    // a shader will always be an integer number of words
+    if bytes.len() % 4 != 0 {
+        return Err(format!(
+            "The SPIR-V file has a size of {} bytes, which is not a multiple of 4",
+            bytes.len()
+        ));
+    }
+
+    let words = bytes
+        .chunks(4)
+        .map(|chunk| u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]));
+
+    match words.clone().next() {
+        Some(0x07230203) => Ok(words.collect()),
+        Some(0x03022307) => Ok(words.map(u32::swap_bytes).collect()),
+        _ => Err("The file does not start with the SPIR-V magic number".to_owned()),
+    }
+}
+
 #[proc_macro]
 pub fn shader(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as MacroInput);
 
+    match shader_impl(input) {
+        Ok(tokens) => tokens.into(),
+        // Pointing at the call site makes the error show up on the `shader!` invocation.
+        Err(message) => syn::Error::new(Span::call_site(), message)
+            .to_compile_error()
+            .into(),
+    }
+}
+
+fn shader_impl(input: MacroInput) -> std::result::Result<TokenStream, String> {
     let root = env::var("CARGO_MANIFEST_DIR").unwrap_or(".".into());
     let root_path = Path::new(&root);
 
-    if let SourceKind::Bytes(path) = input.source_kind {
+    let (words, input_paths) = if let SourceKind::Bytes(path) = input.source_kind {
         let full_path = root_path.join(&path);
 
         let bytes = if full_path.is_file() {
-            fs::read(full_path).expect(&format!("Error reading source from {:?}", path))
+            fs::read(&full_path)
+                .map_err(|err| format!("Error reading source from {:?}: {}", path, err))?
         } else {
-            panic!(
+            return Err(format!(
                 "File {:?} was not found ; note that the path must be relative to your Cargo.toml",
                 path
-            );
+            ));
         };
 
-        // The SPIR-V specification essentially guarantees that
-        // a shader will always be an integer number of words
-        assert_eq!(0, bytes.len() % 4);
-        codegen::reflect(
-            "Shader",
-            unsafe { from_raw_parts(bytes.as_slice().as_ptr() as *const u32, bytes.len() / 4) },
-            input.types_meta,
-            empty(),
-            input.dump,
-        )
-        .unwrap()
-        .into()
+        let words = spirv_words_from_bytes(&bytes)
+            .map_err(|err| format!("Invalid SPIR-V file {:?}: {}", path, err))?;
+        let words = match input.optimization {
+            Some(level) => spirv_tools::optimize(&words, level)?,
+            None => words,
+        };
+
+        (words, vec![codegen::path_to_str(&full_path).to_owned()])
     } else {
         let (path, full_path, source_code) = match input.source_kind {
             SourceKind::Src(source) => (None, None, source),
             SourceKind::Path(path) => {
                 let full_path = root_path.join(&path);
                 let source_code = read_file_to_string(&full_path)
-                    .expect(&format!("Error reading source from {:?}", path));
+                    .map_err(|err| format!("Error reading source from {:?}: {}", path, err))?;
 
                 if full_path.is_file() {
                     (Some(path.clone()), Some(full_path), source_code)
                 } else {
-                    panic!("File {:?} was not found ; note that the path must be relative to your Cargo.toml", path);
+                    return Err(format!("File {:?} was not found ; note that the path must be relative to your Cargo.toml", path));
                 }
             }
             SourceKind::Bytes(_) => unreachable!(),
@@ -607,33 +692,41 @@ pub fn shader(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
             })
             .collect::<Vec<_>>();
 
-        let (content, includes) = match codegen::compile(
+        let (content, includes) = codegen::compile(
             path,
             &root_path,
             &source_code,
             input.shader_kind,
             &include_paths,
             &input.macro_defines,
-        ) {
-            Ok(ok) => ok,
-            Err(e) => panic!("{}", e.replace("(s): ", "(s):\n")),
-        };
-
-        let input_paths = includes.iter().map(|s| s.as_ref()).chain(
-            full_path
-                .as_ref()
-                .map(|p| p.as_path())
-                .map(codegen::path_to_str),
-        );
-
-        codegen::reflect(
-            "Shader",
-            content.as_binary(),
-            input.types_meta,
-            input_paths,
-            input.dump,
+            input.optimization.unwrap_or(OptimizationLevel::Zero),
         )
-        .unwrap()
-        .into()
+        .map_err(|e| e.replace("(s): ", "(s):\n"))?;
+
+        let input_paths = includes
+            .into_iter()
+            .chain(
+                full_path
+                    .as_ref()
+                    .map(|p| codegen::path_to_str(p).to_owned()),
+            )
+            .collect();
+
+        (content.as_binary().to_owned(), input_paths)
+    };
+
+    if input.validate {
+        spirv_tools::validate(&words)?;
     }
+
+    codegen::check_shader_kind(&words, input.shader_kind)?;
+
+    codegen::reflect(
+        "Shader",
+        &words,
+        input.types_meta,
+        input_paths.iter().map(|s| s.as_str()),
+        input.dump,
+    )
+    .map_err(|err| format!("Failed to reflect the SPIR-V module: {:?}", err))
 }
@@ -0,0 +1,190 @@
+// Copyright (c) 2021 The vulkano developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>,
+// at your option. All files in the project carrying such
+// notice may not be copied, modified, or distributed except
+// according to those terms.
+
+//! Bindings to the validator and the optimizer of SPIRV-Tools.
+//!
+//! Only the small subset of the C API (`libspirv.h`) that the macro needs is declared here. The
+//! symbols are taken from the static `shaderc_combined` library, which bundles SPIRV-Tools. They
+//! are missing when shaderc is linked dynamically, which is why the bindings are only compiled
+//! with the `spirv-tools` feature. Without it, `validate` and `optimize` return an error.
+
+#[cfg(feature = "spirv-tools")]
+pub use self::ffi::{optimize, validate};
+
+#[cfg(not(feature = "spirv-tools"))]
+pub fn validate(_spirv: &[u32]) -> Result<(), String> {
+    Err("`validate` requires the `spirv-tools` feature of vulkano-shaders".to_owned())
+}
+
+#[cfg(not(feature = "spirv-tools"))]
+pub fn optimize(_spirv: &[u32], _level: shaderc::OptimizationLevel) -> Result<Vec<u32>, String> {
+    Err("optimizing `bytes` requires the `spirv-tools` feature of vulkano-shaders".to_owned())
+}
+
+#[cfg(feature = "spirv-tools")]
+mod ffi {
+    use std::ffi::CStr;
+    use std::os::raw::{c_char, c_int};
+    use std::ptr;
+    use std::slice;
+
+    use shaderc::{EnvVersion, OptimizationLevel};
+
+    use crate::codegen::TARGET_ENV_VERSION;
+
+    // Values of `spv_target_env` and `spv_result_t`.
+    const SPV_ENV_VULKAN_1_0: c_int = 1;
+    const SPV_ENV_OPENGL_4_5: c_int = 9;
+    const SPV_ENV_VULKAN_1_1: c_int = 18;
+    const SPV_ENV_WEBGPU_0: c_int = 19;
+    const SPV_ENV_VULKAN_1_2: c_int = 23;
+    const SPV_SUCCESS: c_int = 0;
+
+    #[repr(C)]
+    struct SpvPosition {
+        line: usize,
+        column: usize,
+        index: usize,
+    }
+
+    #[repr(C)]
+    struct SpvDiagnostic {
+        position: SpvPosition,
+        error: *mut c_char,
+        is_text_source: bool,
+    }
+
+    #[repr(C)]
+    struct SpvBinary {
+        code: *mut u32,
+        word_count: usize,
+    }
+
+    enum SpvContext {}
+    enum SpvOptimizer {}
+    enum SpvOptimizerOptions {}
+
+    extern "C" {
+        fn spvContextCreate(env: c_int) -> *mut SpvContext;
+        fn spvContextDestroy(context: *mut SpvContext);
+        fn spvValidateBinary(
+            context: *const SpvContext,
+            words: *const u32,
+            num_words: usize,
+            diagnostic: *mut *mut SpvDiagnostic,
+        ) -> c_int;
+        fn spvDiagnosticDestroy(diagnostic: *mut SpvDiagnostic);
+
+        fn spvOptimizerCreate(env: c_int) -> *mut SpvOptimizer;
+        fn spvOptimizerDestroy(optimizer: *mut SpvOptimizer);
+        fn spvOptimizerRegisterPerformancePasses(optimizer: *mut SpvOptimizer);
+        fn spvOptimizerRegisterSizePasses(optimizer: *mut SpvOptimizer);
+        fn spvOptimizerRun(
+            optimizer: *mut SpvOptimizer,
+            binary: *const u32,
+            word_count: usize,
+            optimized_binary: *mut *mut SpvBinary,
+            options: *const SpvOptimizerOptions,
+        ) -> c_int;
+        fn spvOptimizerOptionsCreate() -> *mut SpvOptimizerOptions;
+        fn spvOptimizerOptionsDestroy(options: *mut SpvOptimizerOptions);
+        fn spvBinaryDestroy(binary: *mut SpvBinary);
+    }
+
+    // Returns the `spv_target_env` matching the environment that shaderc compiles GLSL for.
+    fn target_env() -> c_int {
+        match TARGET_ENV_VERSION {
+            EnvVersion::Vulkan1_0 => SPV_ENV_VULKAN_1_0,
+            EnvVersion::Vulkan1_1 => SPV_ENV_VULKAN_1_1,
+            EnvVersion::Vulkan1_2 => SPV_ENV_VULKAN_1_2,
+            EnvVersion::OpenGL4_5 => SPV_ENV_OPENGL_4_5,
+            EnvVersion::WebGPU => SPV_ENV_WEBGPU_0,
+        }
+    }
+
+    /// Runs the SPIR-V validator on a module.
+    ///
+    /// On failure, returns the message of the validator, including the index of the offending
+    /// instruction when known.
+    pub fn validate(spirv: &[u32]) -> Result<(), String> {
+        unsafe {
+            let context = spvContextCreate(target_env());
+            if context.is_null() {
+                return Err("failed to create a SPIRV-Tools context".to_owned());
+            }
+
+            let mut diagnostic = ptr::null_mut();
+            let result = spvValidateBinary(context, spirv.as_ptr(), spirv.len(), &mut diagnostic);
+
+            let outcome = if result == SPV_SUCCESS {
+                Ok(())
+            } else if diagnostic.is_null() {
+                Err(format!("SPIR-V validation failed with code {}", result))
+            } else {
+                let message = CStr::from_ptr((*diagnostic).error).to_string_lossy();
+                Err(format!(
+                    "SPIR-V validation failed at word {}: {}",
+                    (*diagnostic).position.index,
+                    message
+                ))
+            };
+
+            if !diagnostic.is_null() {
+                spvDiagnosticDestroy(diagnostic);
+            }
+            spvContextDestroy(context);
+            outcome
+        }
+    }
+
+    /// Runs the SPIR-V optimizer on a module with the passes corresponding to `level`.
+    ///
+    /// The optimizer validates its input before running, so an invalid module results in an error.
+    /// `OptimizationLevel::Zero` returns the module unchanged.
+    pub fn optimize(spirv: &[u32], level: OptimizationLevel) -> Result<Vec<u32>, String> {
+        if level == OptimizationLevel::Zero {
+            return Ok(spirv.to_owned());
+        }
+
+        unsafe {
+            let optimizer = spvOptimizerCreate(target_env());
+            if optimizer.is_null() {
+                return Err("failed to create the SPIR-V optimizer".to_owned());
+            }
+
+            match level {
+                OptimizationLevel::Zero => unreachable!(),
+                OptimizationLevel::Size => spvOptimizerRegisterSizePasses(optimizer),
+                OptimizationLevel::Performance => spvOptimizerRegisterPerformancePasses(optimizer),
+            }
+
+            let options = spvOptimizerOptionsCreate();
+            let mut binary = ptr::null_mut();
+            let result =
+                spvOptimizerRun(optimizer, spirv.as_ptr(), spirv.len(), &mut binary, options);
+
+            let outcome = if result == SPV_SUCCESS && !binary.is_null() {
+                Ok(slice::from_raw_parts((*binary).code, (*binary).word_count).to_owned())
+            } else {
+                Err(format!(
+                    "SPIR-V optimization failed with code {} ; the module is probably invalid, \
+                 enable `validate: true` for details",
+                    result
+                ))
+            };
+
+            if !binary.is_null() {
+                spvBinaryDestroy(binary);
+            }
+            spvOptimizerOptionsDestroy(options);
+            spvOptimizerDestroy(optimizer);
+            outcome
+        }
+    }
+}
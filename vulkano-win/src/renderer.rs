@@ -0,0 +1,569 @@
+use std::error;
+use std::fmt;
+use std::sync::Arc;
+
+use vulkano::device::Device;
+use vulkano::device::DeviceOwned;
+use vulkano::device::Queue;
+use vulkano::format::Format;
+use vulkano::image::view::ImageView;
+use vulkano::image::view::ImageViewCreationError;
+use vulkano::image::ImageUsage;
+use vulkano::image::SwapchainImage;
+use vulkano::swapchain;
+use vulkano::swapchain::AcquireError;
+use vulkano::swapchain::CapabilitiesError;
+use vulkano::swapchain::ColorSpace;
+use vulkano::swapchain::FullscreenExclusive;
+use vulkano::swapchain::PresentMode;
+use vulkano::swapchain::Surface;
+use vulkano::swapchain::Swapchain;
+use vulkano::swapchain::SwapchainCreationError;
+use vulkano::sync;
+use vulkano::sync::FenceSignalFuture;
+use vulkano::sync::FlushError;
+use vulkano::sync::GpuFuture;
+use winit::window::Window;
+
+/// An image view of one of the images of the swapchain of a `WindowRenderer`.
+pub type SwapchainImageView = Arc<ImageView<Arc<SwapchainImage<Window>>>>;
+
+/// Owns the surface and the swapchain of a window, and takes care of acquiring and presenting
+/// its images.
+///
+/// The swapchain is recreated automatically when the window is resized or when the swapchain
+/// becomes suboptimal or out of date, and frames are skipped while the window is minimized.
+/// Up to `frames_in_flight` frames can be processed by the GPU at the same time.
+///
+/// # Example
+///
+/// ```no_run
+/// # use std::sync::Arc;
+/// # use vulkano::sync::GpuFuture;
+/// # use vulkano_win::WindowRenderer;
+/// # let surface: Arc<vulkano::swapchain::Surface<winit::window::Window>> = return;
+/// # let queue: Arc<vulkano::device::Queue> = return;
+/// let mut renderer = WindowRenderer::start(surface, queue)
+///     .frames_in_flight(2)
+///     .build()
+///     .unwrap();
+///
+/// // For each `RedrawEventsCleared` event:
+/// let mut frame = match renderer.acquire().unwrap() {
+///     Some(frame) => frame,
+///     None => return, // The window is minimized.
+/// };
+///
+/// if frame.swapchain_recreated() {
+///     // Rebuild the framebuffers from `frame.image_views()`.
+/// }
+///
+/// let future = frame.take_future();
+/// // let future = future.then_execute(queue.clone(), command_buffer).unwrap();
+/// frame.present(future).unwrap();
+/// ```
+pub struct WindowRenderer {
+    surface: Arc<Surface<Window>>,
+    queue: Arc<Queue>,
+    swapchain: Arc<Swapchain<Window>>,
+    image_views: Vec<SwapchainImageView>,
+    // One slot per frame in flight, containing the future of the latest frame submitted in that
+    // slot.
+    frames: Vec<Option<Arc<FenceSignalFuture<Box<dyn GpuFuture>>>>>,
+    // Slot of the next frame.
+    current_frame: usize,
+    // True if the swapchain must be recreated before acquiring the next image.
+    recreate_swapchain: bool,
+    // True if the swapchain has been recreated since the last frame was acquired.
+    swapchain_recreated: bool,
+}
+
+impl WindowRenderer {
+    /// Begins building a `WindowRenderer` that presents to `surface` on `queue`.
+    #[inline]
+    pub fn start(surface: Arc<Surface<Window>>, queue: Arc<Queue>) -> WindowRendererBuilder {
+        WindowRendererBuilder {
+            surface,
+            queue,
+            frames_in_flight: 2,
+            present_mode: PresentMode::Fifo,
+            format: None,
+            usage: ImageUsage::color_attachment(),
+        }
+    }
+
+    /// Returns the surface of the window.
+    #[inline]
+    pub fn surface(&self) -> &Arc<Surface<Window>> {
+        &self.surface
+    }
+
+    /// Returns the window.
+    #[inline]
+    pub fn window(&self) -> &Window {
+        self.surface.window()
+    }
+
+    /// Returns the queue that the images are presented on.
+    #[inline]
+    pub fn queue(&self) -> &Arc<Queue> {
+        &self.queue
+    }
+
+    /// Returns the current swapchain. It changes each time the swapchain is recreated.
+    #[inline]
+    pub fn swapchain(&self) -> &Arc<Swapchain<Window>> {
+        &self.swapchain
+    }
+
+    /// Returns the image views of the images of the current swapchain.
+    #[inline]
+    pub fn image_views(&self) -> &[SwapchainImageView] {
+        &self.image_views
+    }
+
+    /// Returns the format of the images of the swapchain.
+    #[inline]
+    pub fn format(&self) -> Format {
+        self.swapchain.format()
+    }
+
+    /// Returns the dimensions of the images of the current swapchain.
+    #[inline]
+    pub fn dimensions(&self) -> [u32; 2] {
+        self.swapchain.dimensions()
+    }
+
+    /// Returns the maximum number of frames that can be processed by the GPU at the same time.
+    #[inline]
+    pub fn frames_in_flight(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Requests the swapchain to be recreated before the next frame. Call this when the window
+    /// is resized.
+    #[inline]
+    pub fn resize(&mut self) {
+        self.recreate_swapchain = true;
+    }
+
+    /// Acquires the next image of the swapchain, recreating the swapchain first if needed.
+    ///
+    /// Returns `None` if no image can be drawn on for now, for example because the window is
+    /// minimized. The frame should then be skipped.
+    ///
+    /// If more than `frames_in_flight` frames are being processed by the GPU, this blocks until
+    /// the oldest one is finished.
+    pub fn acquire(&mut self) -> Result<Option<FrameContext<'_>>, WindowRendererError> {
+        if let Some(frame) = self.frames[self.current_frame].take() {
+            frame.wait(None)?;
+        }
+        for frame in self.frames.iter_mut().flatten() {
+            frame.cleanup_finished();
+        }
+
+        // A minimized window has no area to draw on.
+        let dimensions: [u32; 2] = self.window().inner_size().into();
+        if dimensions[0] == 0 || dimensions[1] == 0 {
+            return Ok(None);
+        }
+
+        // If the swapchain is out of date when acquiring, it is recreated and acquiring is tried
+        // once more. If that fails too, the frame is skipped.
+        for _ in 0..2 {
+            if self.recreate_swapchain {
+                match self.recreate(dimensions) {
+                    Ok(()) => (),
+                    Err(WindowRendererError::SwapchainCreationError(
+                        SwapchainCreationError::UnsupportedDimensions,
+                    )) => return Ok(None),
+                    Err(err) => return Err(err),
+                }
+            }
+
+            let (image_num, suboptimal, acquire_future) =
+                match swapchain::acquire_next_image(self.swapchain.clone(), None) {
+                    Ok(r) => r,
+                    Err(AcquireError::OutOfDate) => {
+                        self.recreate_swapchain = true;
+                        continue;
+                    }
+                    Err(err) => return Err(err.into()),
+                };
+
+            if suboptimal {
+                self.recreate_swapchain = true;
+            }
+
+            let previous_frame = self.frames.len() + self.current_frame - 1;
+            let future = match self.frames[previous_frame % self.frames.len()] {
+                Some(ref frame) => frame.clone().join(acquire_future).boxed(),
+                None => sync::now(self.device().clone())
+                    .join(acquire_future)
+                    .boxed(),
+            };
+
+            let swapchain_recreated = self.swapchain_recreated;
+            self.swapchain_recreated = false;
+
+            return Ok(Some(FrameContext {
+                renderer: self,
+                image_num,
+                future: Some(future),
+                swapchain_recreated,
+            }));
+        }
+
+        Ok(None)
+    }
+
+    fn recreate(&mut self, dimensions: [u32; 2]) -> Result<(), WindowRendererError> {
+        let (swapchain, images) = self.swapchain.recreate_with_dimensions(dimensions)?;
+        self.image_views = create_image_views(images)?;
+        self.swapchain = swapchain;
+        self.recreate_swapchain = false;
+        self.swapchain_recreated = true;
+        Ok(())
+    }
+}
+
+unsafe impl DeviceOwned for WindowRenderer {
+    #[inline]
+    fn device(&self) -> &Arc<Device> {
+        self.queue.device()
+    }
+}
+
+impl fmt::Debug for WindowRenderer {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        fmt.debug_struct("WindowRenderer")
+            .field("swapchain", &self.swapchain)
+            .field("frames_in_flight", &self.frames.len())
+            .field("current_frame", &self.current_frame)
+            .finish()
+    }
+}
+
+/// Prototype of a `WindowRenderer`. Created with `WindowRenderer::start`.
+#[derive(Debug)]
+pub struct WindowRendererBuilder {
+    surface: Arc<Surface<Window>>,
+    queue: Arc<Queue>,
+    frames_in_flight: usize,
+    present_mode: PresentMode,
+    format: Option<(Format, ColorSpace)>,
+    usage: ImageUsage,
+}
+
+impl WindowRendererBuilder {
+    /// Sets the maximum number of frames that can be processed by the GPU at the same time.
+    /// The default value is 2.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if `frames_in_flight` is 0.
+    ///
+    #[inline]
+    pub fn frames_in_flight(mut self, frames_in_flight: usize) -> Self {
+        assert_ne!(frames_in_flight, 0);
+        self.frames_in_flight = frames_in_flight;
+        self
+    }
+
+    /// Sets the present mode of the swapchain. The default value is `PresentMode::Fifo`.
+    #[inline]
+    pub fn present_mode(mut self, present_mode: PresentMode) -> Self {
+        self.present_mode = present_mode;
+        self
+    }
+
+    /// Sets the format and color space of the swapchain images. By default, the first supported
+    /// sRGB format with the `SrgbNonLinear` color space is used.
+    #[inline]
+    pub fn format(mut self, format: Format, color_space: ColorSpace) -> Self {
+        self.format = Some((format, color_space));
+        self
+    }
+
+    /// Sets how the swapchain images are going to be used. The default value is
+    /// `ImageUsage::color_attachment()`.
+    #[inline]
+    pub fn usage(mut self, usage: ImageUsage) -> Self {
+        self.usage = usage;
+        self
+    }
+
+    /// Creates the swapchain and builds the `WindowRenderer`.
+    pub fn build(self) -> Result<WindowRenderer, WindowRendererError> {
+        let device = self.queue.device().clone();
+        let caps = self.surface.capabilities(device.physical_device())?;
+
+        let (format, color_space) = match self.format {
+            Some(format) => format,
+            None => caps
+                .supported_formats
+                .iter()
+                .cloned()
+                .find(|&(format, color_space)| {
+                    color_space == ColorSpace::SrgbNonLinear
+                        && (format == Format::B8G8R8A8Srgb || format == Format::R8G8B8A8Srgb)
+                })
+                .or_else(|| caps.supported_formats.first().cloned())
+                .ok_or(WindowRendererError::NoSupportedFormat)?,
+        };
+
+        let alpha = caps
+            .supported_composite_alpha
+            .iter()
+            .next()
+            .ok_or(WindowRendererError::NoSupportedCompositeAlpha)?;
+
+        // One image more than the number of frames in flight, so that acquiring the image of a
+        // new frame doesn't have to wait for the presentation of the previous ones.
+        let mut num_images = caps.min_image_count.max(self.frames_in_flight as u32 + 1);
+        if let Some(max) = caps.max_image_count {
+            num_images = num_images.min(max);
+        }
+
+        let dimensions = caps
+            .current_extent
+            .unwrap_or_else(|| self.surface.window().inner_size().into());
+
+        let (swapchain, images) = Swapchain::new(
+            device,
+            self.surface.clone(),
+            num_images,
+            format,
+            dimensions,
+            1,
+            self.usage,
+            &self.queue,
+            caps.current_transform,
+            alpha,
+            self.present_mode,
+            FullscreenExclusive::Default,
+            true,
+            color_space,
+        )?;
+
+        Ok(WindowRenderer {
+            surface: self.surface,
+            queue: self.queue,
+            swapchain,
+            image_views: create_image_views(images)?,
+            frames: (0..self.frames_in_flight).map(|_| None).collect(),
+            current_frame: 0,
+            recreate_swapchain: false,
+            swapchain_recreated: true,
+        })
+    }
+}
+
+fn create_image_views(
+    images: Vec<Arc<SwapchainImage<Window>>>,
+) -> Result<Vec<SwapchainImageView>, ImageViewCreationError> {
+    images.into_iter().map(ImageView::new).collect()
+}
+
+/// A frame being drawn with a `WindowRenderer`, returned by `WindowRenderer::acquire`.
+///
+/// The image of the frame must be presented by calling `present`.
+pub struct FrameContext<'r> {
+    renderer: &'r mut WindowRenderer,
+    image_num: usize,
+    future: Option<Box<dyn GpuFuture>>,
+    swapchain_recreated: bool,
+}
+
+impl<'r> FrameContext<'r> {
+    /// Returns the index of the acquired image in the list of images of the swapchain.
+    #[inline]
+    pub fn image_num(&self) -> usize {
+        self.image_num
+    }
+
+    /// Returns the image view of the acquired image.
+    #[inline]
+    pub fn image_view(&self) -> &SwapchainImageView {
+        &self.renderer.image_views[self.image_num]
+    }
+
+    /// Returns the image views of all the images of the swapchain.
+    #[inline]
+    pub fn image_views(&self) -> &[SwapchainImageView] {
+        &self.renderer.image_views
+    }
+
+    /// Returns the dimensions of the acquired image.
+    #[inline]
+    pub fn dimensions(&self) -> [u32; 2] {
+        self.renderer.dimensions()
+    }
+
+    /// Returns true if the swapchain has been recreated since the previous frame. Everything
+    /// that depends on the swapchain images, such as framebuffers, must then be rebuilt.
+    #[inline]
+    pub fn swapchain_recreated(&self) -> bool {
+        self.swapchain_recreated
+    }
+
+    /// Returns the renderer of the frame.
+    #[inline]
+    pub fn renderer(&self) -> &WindowRenderer {
+        self.renderer
+    }
+
+    /// Takes the future that represents the moment when the acquired image is available and the
+    /// previous frame has been submitted. The work of the frame must be chained onto it.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if the future has already been taken.
+    ///
+    #[inline]
+    pub fn take_future(&mut self) -> Box<dyn GpuFuture> {
+        self.future
+            .take()
+            .expect("the future of the frame was already taken")
+    }
+
+    /// Presents the acquired image after `future`, which must have been built on the future
+    /// returned by `take_future`, and flushes it.
+    ///
+    /// If the swapchain turns out to be out of date, it is recreated before the next frame.
+    pub fn present<F>(self, future: F) -> Result<(), WindowRendererError>
+    where
+        F: GpuFuture + 'static,
+    {
+        let renderer = self.renderer;
+        let current_frame = renderer.current_frame;
+        renderer.current_frame = (current_frame + 1) % renderer.frames.len();
+
+        let result = future
+            .then_swapchain_present(
+                renderer.queue.clone(),
+                renderer.swapchain.clone(),
+                self.image_num,
+            )
+            .boxed()
+            .then_signal_fence_and_flush();
+
+        match result {
+            Ok(future) => {
+                renderer.frames[current_frame] = Some(Arc::new(future));
+                Ok(())
+            }
+            Err(FlushError::OutOfDate) => {
+                renderer.recreate_swapchain = true;
+                Ok(())
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+impl<'r> fmt::Debug for FrameContext<'r> {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        fmt.debug_struct("FrameContext")
+            .field("image_num", &self.image_num)
+            .field("swapchain_recreated", &self.swapchain_recreated)
+            .finish()
+    }
+}
+
+/// Error that can happen when creating or using a `WindowRenderer`.
+#[derive(Debug)]
+pub enum WindowRendererError {
+    /// Error when querying the capabilities of the surface.
+    CapabilitiesError(CapabilitiesError),
+    /// Error when creating or recreating the swapchain.
+    SwapchainCreationError(SwapchainCreationError),
+    /// Error when creating the image views of the swapchain images.
+    ImageViewCreationError(ImageViewCreationError),
+    /// Error when acquiring an image.
+    AcquireError(AcquireError),
+    /// Error when submitting or waiting for a frame.
+    FlushError(FlushError),
+    /// The surface doesn't support any format.
+    NoSupportedFormat,
+    /// The surface doesn't support any composite alpha mode.
+    NoSupportedCompositeAlpha,
+}
+
+impl error::Error for WindowRendererError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            WindowRendererError::CapabilitiesError(ref err) => Some(err),
+            WindowRendererError::SwapchainCreationError(ref err) => Some(err),
+            WindowRendererError::ImageViewCreationError(ref err) => Some(err),
+            WindowRendererError::AcquireError(ref err) => Some(err),
+            WindowRendererError::FlushError(ref err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for WindowRendererError {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(
+            fmt,
+            "{}",
+            match *self {
+                WindowRendererError::CapabilitiesError(_) => {
+                    "error while querying the capabilities of the surface"
+                }
+                WindowRendererError::SwapchainCreationError(_) => {
+                    "error while creating the swapchain"
+                }
+                WindowRendererError::ImageViewCreationError(_) => {
+                    "error while creating the image views of the swapchain"
+                }
+                WindowRendererError::AcquireError(_) => "error while acquiring an image",
+                WindowRendererError::FlushError(_) => "error while submitting a frame",
+                WindowRendererError::NoSupportedFormat => "the surface doesn't support any format",
+                WindowRendererError::NoSupportedCompositeAlpha => {
+                    "the surface doesn't support any composite alpha mode"
+                }
+            }
+        )
+    }
+}
+
+impl From<CapabilitiesError> for WindowRendererError {
+    #[inline]
+    fn from(err: CapabilitiesError) -> WindowRendererError {
+        WindowRendererError::CapabilitiesError(err)
+    }
+}
+
+impl From<SwapchainCreationError> for WindowRendererError {
+    #[inline]
+    fn from(err: SwapchainCreationError) -> WindowRendererError {
+        WindowRendererError::SwapchainCreationError(err)
+    }
+}
+
+impl From<ImageViewCreationError> for WindowRendererError {
+    #[inline]
+    fn from(err: ImageViewCreationError) -> WindowRendererError {
+        WindowRendererError::ImageViewCreationError(err)
+    }
+}
+
+impl From<AcquireError> for WindowRendererError {
+    #[inline]
+    fn from(err: AcquireError) -> WindowRendererError {
+        WindowRendererError::AcquireError(err)
+    }
+}
+
+impl From<FlushError> for WindowRendererError {
+    #[inline]
+    fn from(err: FlushError) -> WindowRendererError {
+        WindowRendererError::FlushError(err)
+    }
+}
@@ -0,0 +1,792 @@
+// This file is generated by `autogen` from the Vulkan registry. Do not edit it by hand.
+
+device_extensions! {
+    DeviceExtensions,
+    RawDeviceExtensions,
+    [ // required if supported extensions
+        // https://vulkan.lunarg.com/doc/view/1.2.162.1/mac/1.2-extensions/vkspec.html#VUID-VkDeviceCreateInfo-pProperties-04451
+        khr_portability_subset,
+    ],
+    khr_swapchain => b"VK_KHR_swapchain" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_surface],
+    },
+    khr_display_swapchain => b"VK_KHR_display_swapchain" {
+        core: None,
+        requires: [khr_swapchain],
+        requires_instance: [khr_display],
+    },
+    nv_glsl_shader => b"VK_NV_glsl_shader" {
+        core: None,
+        requires: [],
+        requires_instance: [],
+    },
+    khr_sampler_mirror_clamp_to_edge => b"VK_KHR_sampler_mirror_clamp_to_edge" {
+        // Promoted to Vulkan 1.2, but only as an optional feature.
+        core: None,
+        requires: [],
+        requires_instance: [],
+    },
+    img_filter_cubic => b"VK_IMG_filter_cubic" {
+        core: None,
+        requires: [],
+        requires_instance: [],
+    },
+    amd_rasterization_order => b"VK_AMD_rasterization_order" {
+        core: None,
+        requires: [],
+        requires_instance: [],
+    },
+    ext_debug_marker => b"VK_EXT_debug_marker" {
+        core: None,
+        requires: [],
+        requires_instance: [ext_debug_report],
+    },
+    nv_dedicated_allocation => b"VK_NV_dedicated_allocation" {
+        core: None,
+        requires: [],
+        requires_instance: [],
+    },
+    ext_transform_feedback => b"VK_EXT_transform_feedback" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    nvx_image_view_handle => b"VK_NVX_image_view_handle" {
+        core: None,
+        requires: [],
+        requires_instance: [],
+    },
+    amd_texture_gather_bias_lod => b"VK_AMD_texture_gather_bias_lod" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    nv_corner_sampled_image => b"VK_NV_corner_sampled_image" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    khr_multiview => b"VK_KHR_multiview" {
+        core: Some(Version::V1_1),
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    img_format_pvrtc => b"VK_IMG_format_pvrtc" {
+        core: None,
+        requires: [],
+        requires_instance: [],
+    },
+    nv_external_memory => b"VK_NV_external_memory" {
+        core: None,
+        requires: [],
+        requires_instance: [nv_external_memory_capabilities],
+    },
+    nv_external_memory_win32 => b"VK_NV_external_memory_win32" {
+        core: None,
+        requires: [nv_external_memory],
+        requires_instance: [],
+    },
+    nv_win32_keyed_mutex => b"VK_NV_win32_keyed_mutex" {
+        core: None,
+        requires: [nv_external_memory_win32],
+        requires_instance: [],
+    },
+    khr_device_group => b"VK_KHR_device_group" {
+        core: Some(Version::V1_1),
+        requires: [],
+        requires_instance: [khr_device_group_creation],
+    },
+    ext_texture_compression_astc_hdr => b"VK_EXT_texture_compression_astc_hdr" {
+        core: Some(Version::V1_3),
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    ext_astc_decode_mode => b"VK_EXT_astc_decode_mode" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    khr_maintenance1 => b"VK_KHR_maintenance1" {
+        core: Some(Version::V1_1),
+        requires: [],
+        requires_instance: [],
+    },
+    khr_external_memory => b"VK_KHR_external_memory" {
+        core: Some(Version::V1_1),
+        requires: [],
+        requires_instance: [khr_external_memory_capabilities],
+    },
+    khr_external_memory_win32 => b"VK_KHR_external_memory_win32" {
+        core: None,
+        requires: [khr_external_memory],
+        requires_instance: [],
+    },
+    khr_external_memory_fd => b"VK_KHR_external_memory_fd" {
+        core: None,
+        requires: [khr_external_memory],
+        requires_instance: [],
+    },
+    khr_win32_keyed_mutex => b"VK_KHR_win32_keyed_mutex" {
+        core: None,
+        requires: [khr_external_memory_win32],
+        requires_instance: [],
+    },
+    khr_external_semaphore => b"VK_KHR_external_semaphore" {
+        core: Some(Version::V1_1),
+        requires: [],
+        requires_instance: [khr_external_semaphore_capabilities],
+    },
+    khr_external_semaphore_win32 => b"VK_KHR_external_semaphore_win32" {
+        core: None,
+        requires: [khr_external_semaphore],
+        requires_instance: [],
+    },
+    khr_external_semaphore_fd => b"VK_KHR_external_semaphore_fd" {
+        core: None,
+        requires: [khr_external_semaphore],
+        requires_instance: [],
+    },
+    khr_push_descriptor => b"VK_KHR_push_descriptor" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    ext_conditional_rendering => b"VK_EXT_conditional_rendering" {
+        core: None,
+        requires: [],
+        requires_instance: [],
+    },
+    khr_shader_float16_int8 => b"VK_KHR_shader_float16_int8" {
+        core: Some(Version::V1_2),
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    khr_16bit_storage => b"VK_KHR_16bit_storage" {
+        core: Some(Version::V1_1),
+        requires: [khr_storage_buffer_storage_class],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    khr_incremental_present => b"VK_KHR_incremental_present" {
+        core: None,
+        requires: [khr_swapchain],
+        requires_instance: [],
+    },
+    khr_descriptor_update_template => b"VK_KHR_descriptor_update_template" {
+        core: Some(Version::V1_1),
+        requires: [],
+        requires_instance: [],
+    },
+    nv_clip_space_w_scaling => b"VK_NV_clip_space_w_scaling" {
+        core: None,
+        requires: [],
+        requires_instance: [],
+    },
+    ext_display_control => b"VK_EXT_display_control" {
+        core: None,
+        requires: [khr_swapchain],
+        requires_instance: [ext_display_surface_counter],
+    },
+    google_display_timing => b"VK_GOOGLE_display_timing" {
+        core: None,
+        requires: [khr_swapchain],
+        requires_instance: [],
+    },
+    nvx_multiview_per_view_attributes => b"VK_NVX_multiview_per_view_attributes" {
+        core: None,
+        requires: [khr_multiview],
+        requires_instance: [],
+    },
+    nv_viewport_swizzle => b"VK_NV_viewport_swizzle" {
+        core: None,
+        requires: [],
+        requires_instance: [],
+    },
+    ext_discard_rectangles => b"VK_EXT_discard_rectangles" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    ext_conservative_rasterization => b"VK_EXT_conservative_rasterization" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    ext_depth_clip_enable => b"VK_EXT_depth_clip_enable" {
+        core: None,
+        requires: [],
+        requires_instance: [],
+    },
+    ext_hdr_metadata => b"VK_EXT_hdr_metadata" {
+        core: None,
+        requires: [khr_swapchain],
+        requires_instance: [],
+    },
+    khr_imageless_framebuffer => b"VK_KHR_imageless_framebuffer" {
+        core: Some(Version::V1_2),
+        requires: [khr_maintenance2, khr_image_format_list],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    khr_create_renderpass2 => b"VK_KHR_create_renderpass2" {
+        core: Some(Version::V1_2),
+        requires: [khr_multiview, khr_maintenance2],
+        requires_instance: [],
+    },
+    khr_shared_presentable_image => b"VK_KHR_shared_presentable_image" {
+        core: None,
+        requires: [khr_swapchain],
+        requires_instance: [khr_get_physical_device_properties2, khr_get_surface_capabilities2],
+    },
+    khr_external_fence => b"VK_KHR_external_fence" {
+        core: Some(Version::V1_1),
+        requires: [],
+        requires_instance: [khr_external_fence_capabilities],
+    },
+    khr_external_fence_win32 => b"VK_KHR_external_fence_win32" {
+        core: None,
+        requires: [khr_external_fence],
+        requires_instance: [],
+    },
+    khr_external_fence_fd => b"VK_KHR_external_fence_fd" {
+        core: None,
+        requires: [khr_external_fence],
+        requires_instance: [],
+    },
+    khr_performance_query => b"VK_KHR_performance_query" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    khr_maintenance2 => b"VK_KHR_maintenance2" {
+        core: Some(Version::V1_1),
+        requires: [],
+        requires_instance: [],
+    },
+    khr_variable_pointers => b"VK_KHR_variable_pointers" {
+        core: Some(Version::V1_1),
+        requires: [khr_storage_buffer_storage_class],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    ext_external_memory_dmabuf => b"VK_EXT_external_memory_dma_buf" {
+        core: None,
+        requires: [khr_external_memory_fd],
+        requires_instance: [],
+    },
+    ext_queue_family_foreign => b"VK_EXT_queue_family_foreign" {
+        core: None,
+        requires: [khr_external_memory],
+        requires_instance: [],
+    },
+    khr_dedicated_allocation => b"VK_KHR_dedicated_allocation" {
+        core: Some(Version::V1_1),
+        requires: [khr_get_memory_requirements2],
+        requires_instance: [],
+    },
+    android_external_memory_android_hardware_buffer => b"VK_ANDROID_external_memory_android_hardware_buffer" {
+        core: None,
+        requires: [khr_sampler_ycbcr_conversion, khr_external_memory, ext_queue_family_foreign, khr_dedicated_allocation],
+        requires_instance: [],
+    },
+    ext_sampler_filter_minmax => b"VK_EXT_sampler_filter_minmax" {
+        // Promoted to Vulkan 1.2, but only as an optional feature.
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    khr_storage_buffer_storage_class => b"VK_KHR_storage_buffer_storage_class" {
+        core: Some(Version::V1_1),
+        requires: [],
+        requires_instance: [],
+    },
+    ext_inline_uniform_block => b"VK_EXT_inline_uniform_block" {
+        core: Some(Version::V1_3),
+        requires: [khr_maintenance1],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    ext_sample_locations => b"VK_EXT_sample_locations" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    khr_get_memory_requirements2 => b"VK_KHR_get_memory_requirements2" {
+        core: Some(Version::V1_1),
+        requires: [],
+        requires_instance: [],
+    },
+    khr_image_format_list => b"VK_KHR_image_format_list" {
+        core: Some(Version::V1_2),
+        requires: [],
+        requires_instance: [],
+    },
+    ext_blend_operation_advanced => b"VK_EXT_blend_operation_advanced" {
+        core: None,
+        requires: [],
+        requires_instance: [],
+    },
+    nv_fragment_coverage_to_color => b"VK_NV_fragment_coverage_to_color" {
+        core: None,
+        requires: [],
+        requires_instance: [],
+    },
+    khr_acceleration_structure => b"VK_KHR_acceleration_structure" {
+        core: None,
+        api_version: Version::V1_1,
+        requires: [ext_descriptor_indexing, khr_buffer_device_address, khr_deferred_host_operations],
+        requires_instance: [],
+    },
+    nv_framebuffer_mixed_samples => b"VK_NV_framebuffer_mixed_samples" {
+        core: None,
+        requires: [],
+        requires_instance: [],
+    },
+    nv_shader_sm_builtins => b"VK_NV_shader_sm_builtins" {
+        core: None,
+        requires: [],
+        requires_instance: [],
+    },
+    khr_sampler_ycbcr_conversion => b"VK_KHR_sampler_ycbcr_conversion" {
+        core: Some(Version::V1_1),
+        requires: [khr_maintenance1, khr_bind_memory2, khr_get_memory_requirements2],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    khr_bind_memory2 => b"VK_KHR_bind_memory2" {
+        core: Some(Version::V1_1),
+        requires: [],
+        requires_instance: [],
+    },
+    ext_image_drm_format_modifier => b"VK_EXT_image_drm_format_modifier" {
+        core: None,
+        requires: [khr_bind_memory2, khr_image_format_list, khr_sampler_ycbcr_conversion],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    ext_validation_cache => b"VK_EXT_validation_cache" {
+        core: None,
+        requires: [],
+        requires_instance: [],
+    },
+    ext_descriptor_indexing => b"VK_EXT_descriptor_indexing" {
+        core: Some(Version::V1_2),
+        requires: [khr_maintenance3],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    khr_portability_subset => b"VK_KHR_portability_subset" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    nv_shading_rate_image => b"VK_NV_shading_rate_image" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    nv_ray_tracing => b"VK_NV_ray_tracing" {
+        core: None,
+        requires: [khr_get_memory_requirements2],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    nv_representative_fragment_test => b"VK_NV_representative_fragment_test" {
+        core: None,
+        requires: [],
+        requires_instance: [],
+    },
+    khr_maintenance3 => b"VK_KHR_maintenance3" {
+        core: Some(Version::V1_1),
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    ext_filter_cubic => b"VK_EXT_filter_cubic" {
+        core: None,
+        requires: [],
+        requires_instance: [],
+    },
+    ext_global_priority => b"VK_EXT_global_priority" {
+        core: None,
+        requires: [],
+        requires_instance: [],
+    },
+    khr_shader_subgroup_extended_types => b"VK_KHR_shader_subgroup_extended_types" {
+        core: Some(Version::V1_2),
+        api_version: Version::V1_1,
+        requires: [],
+        requires_instance: [],
+    },
+    khr_8bit_storage => b"VK_KHR_8bit_storage" {
+        core: Some(Version::V1_2),
+        requires: [khr_storage_buffer_storage_class],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    ext_external_memory_host => b"VK_EXT_external_memory_host" {
+        core: None,
+        requires: [khr_external_memory],
+        requires_instance: [],
+    },
+    khr_shader_atomic_int64 => b"VK_KHR_shader_atomic_int64" {
+        core: Some(Version::V1_2),
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    khr_shader_clock => b"VK_KHR_shader_clock" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    amd_pipeline_compiler_control => b"VK_AMD_pipeline_compiler_control" {
+        core: None,
+        requires: [],
+        requires_instance: [],
+    },
+    ext_calibrated_timestamps => b"VK_EXT_calibrated_timestamps" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    amd_shader_core_properties => b"VK_AMD_shader_core_properties" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    amd_memory_overallocation_behavior => b"VK_AMD_memory_overallocation_behavior" {
+        core: None,
+        requires: [],
+        requires_instance: [],
+    },
+    ext_vertex_attribute_divisor => b"VK_EXT_vertex_attribute_divisor" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    ggp_frame_token => b"VK_GGP_frame_token" {
+        core: None,
+        requires: [khr_swapchain],
+        requires_instance: [ggp_stream_descriptor_surface],
+    },
+    ext_pipeline_creation_feedback => b"VK_EXT_pipeline_creation_feedback" {
+        core: Some(Version::V1_3),
+        requires: [],
+        requires_instance: [],
+    },
+    khr_driver_properties => b"VK_KHR_driver_properties" {
+        core: Some(Version::V1_2),
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    khr_shader_float_controls => b"VK_KHR_shader_float_controls" {
+        core: Some(Version::V1_2),
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    khr_depth_stencil_resolve => b"VK_KHR_depth_stencil_resolve" {
+        core: Some(Version::V1_2),
+        requires: [khr_create_renderpass2],
+        requires_instance: [],
+    },
+    nv_compute_shader_derivatives => b"VK_NV_compute_shader_derivatives" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    nv_mesh_shader => b"VK_NV_mesh_shader" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    nv_fragment_shader_barycentric => b"VK_NV_fragment_shader_barycentric" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    nv_shader_image_footprint => b"VK_NV_shader_image_footprint" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    nv_scissor_exclusive => b"VK_NV_scissor_exclusive" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    nv_device_diagnostic_checkpoints => b"VK_NV_device_diagnostic_checkpoints" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    khr_timeline_semaphore => b"VK_KHR_timeline_semaphore" {
+        core: Some(Version::V1_2),
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    intel_shader_integer_functions2 => b"VK_INTEL_shader_integer_functions2" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    intel_performance_query => b"VK_INTEL_performance_query" {
+        core: None,
+        requires: [],
+        requires_instance: [],
+    },
+    khr_vulkan_memory_model => b"VK_KHR_vulkan_memory_model" {
+        core: Some(Version::V1_2),
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    ext_pci_bus_info => b"VK_EXT_pci_bus_info" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    amd_display_native_hdr => b"VK_AMD_display_native_hdr" {
+        core: None,
+        requires: [khr_swapchain],
+        requires_instance: [khr_get_physical_device_properties2, khr_get_surface_capabilities2],
+    },
+    khr_shader_terminate_invocation => b"VK_KHR_shader_terminate_invocation" {
+        core: Some(Version::V1_3),
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    ext_fragment_density_map => b"VK_EXT_fragment_density_map" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    ext_scalar_block_layout => b"VK_EXT_scalar_block_layout" {
+        core: Some(Version::V1_2),
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    ext_subgroup_size_control => b"VK_EXT_subgroup_size_control" {
+        core: Some(Version::V1_3),
+        api_version: Version::V1_1,
+        requires: [],
+        requires_instance: [],
+    },
+    khr_fragment_shading_rate => b"VK_KHR_fragment_shading_rate" {
+        core: None,
+        requires: [khr_create_renderpass2],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    amd_shader_core_properties2 => b"VK_AMD_shader_core_properties2" {
+        core: None,
+        requires: [amd_shader_core_properties],
+        requires_instance: [],
+    },
+    amd_device_coherent_memory => b"VK_AMD_device_coherent_memory" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    ext_shader_image_atomic_int64 => b"VK_EXT_shader_image_atomic_int64" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    khr_spirv_1_4 => b"VK_KHR_spirv_1_4" {
+        core: Some(Version::V1_2),
+        api_version: Version::V1_1,
+        requires: [khr_shader_float_controls],
+        requires_instance: [],
+    },
+    ext_memory_budget => b"VK_EXT_memory_budget" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    ext_memory_priority => b"VK_EXT_memory_priority" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    nv_dedicated_allocation_image_aliasing => b"VK_NV_dedicated_allocation_image_aliasing" {
+        core: None,
+        requires: [khr_dedicated_allocation],
+        requires_instance: [],
+    },
+    khr_separate_depth_stencil_layouts => b"VK_KHR_separate_depth_stencil_layouts" {
+        core: Some(Version::V1_2),
+        requires: [khr_create_renderpass2],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    ext_buffer_device_address => b"VK_EXT_buffer_device_address" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    ext_tooling_info => b"VK_EXT_tooling_info" {
+        core: Some(Version::V1_3),
+        requires: [],
+        requires_instance: [],
+    },
+    ext_separate_stencil_usage => b"VK_EXT_separate_stencil_usage" {
+        core: Some(Version::V1_2),
+        requires: [],
+        requires_instance: [],
+    },
+    khr_present_wait => b"VK_KHR_present_wait" {
+        core: None,
+        requires: [khr_swapchain, khr_present_id],
+        requires_instance: [],
+    },
+    nv_cooperative_matrix => b"VK_NV_cooperative_matrix" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    nv_coverage_reduction_mode => b"VK_NV_coverage_reduction_mode" {
+        core: None,
+        requires: [nv_framebuffer_mixed_samples],
+        requires_instance: [],
+    },
+    ext_fragment_shader_interlock => b"VK_EXT_fragment_shader_interlock" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    ext_ycbcr_image_arrays => b"VK_EXT_ycbcr_image_arrays" {
+        core: None,
+        requires: [khr_sampler_ycbcr_conversion],
+        requires_instance: [],
+    },
+    khr_uniform_buffer_standard_layout => b"VK_KHR_uniform_buffer_standard_layout" {
+        core: Some(Version::V1_2),
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    ext_full_screen_exclusive => b"VK_EXT_full_screen_exclusive" {
+        core: None,
+        requires: [khr_swapchain],
+        requires_instance: [khr_get_physical_device_properties2, khr_surface, khr_get_surface_capabilities2],
+    },
+    khr_buffer_device_address => b"VK_KHR_buffer_device_address" {
+        core: Some(Version::V1_2),
+        requires: [khr_device_group],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    ext_line_rasterization => b"VK_EXT_line_rasterization" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    ext_shader_atomic_float => b"VK_EXT_shader_atomic_float" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    ext_host_query_reset => b"VK_EXT_host_query_reset" {
+        core: Some(Version::V1_2),
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    ext_index_type_uint8 => b"VK_EXT_index_type_uint8" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    ext_extended_dynamic_state => b"VK_EXT_extended_dynamic_state" {
+        core: Some(Version::V1_3),
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    khr_deferred_host_operations => b"VK_KHR_deferred_host_operations" {
+        core: None,
+        requires: [],
+        requires_instance: [],
+    },
+    khr_pipeline_executable_properties => b"VK_KHR_pipeline_executable_properties" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    ext_shader_demote_to_helper_invocation => b"VK_EXT_shader_demote_to_helper_invocation" {
+        core: Some(Version::V1_3),
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    nv_device_generated_commands => b"VK_NV_device_generated_commands" {
+        core: None,
+        api_version: Version::V1_1,
+        requires: [khr_buffer_device_address],
+        requires_instance: [],
+    },
+    ext_texel_buffer_alignment => b"VK_EXT_texel_buffer_alignment" {
+        core: Some(Version::V1_3),
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    qcom_render_pass_transform => b"VK_QCOM_render_pass_transform" {
+        core: None,
+        requires: [khr_swapchain],
+        requires_instance: [khr_surface],
+    },
+    ext_device_memory_report => b"VK_EXT_device_memory_report" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    ext_robustness2 => b"VK_EXT_robustness2" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    ext_custom_border_color => b"VK_EXT_custom_border_color" {
+        core: None,
+        requires: [],
+        requires_instance: [],
+    },
+    khr_pipeline_library => b"VK_KHR_pipeline_library" {
+        core: None,
+        requires: [],
+        requires_instance: [],
+    },
+    khr_present_id => b"VK_KHR_present_id" {
+        core: None,
+        requires: [khr_swapchain],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    ext_private_data => b"VK_EXT_private_data" {
+        core: Some(Version::V1_3),
+        requires: [],
+        requires_instance: [],
+    },
+    ext_pipeline_creation_cache_control => b"VK_EXT_pipeline_creation_cache_control" {
+        core: Some(Version::V1_3),
+        requires: [],
+        requires_instance: [],
+    },
+    nv_device_diagnostics_config => b"VK_NV_device_diagnostics_config" {
+        core: None,
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    khr_synchronization2 => b"VK_KHR_synchronization2" {
+        core: Some(Version::V1_3),
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    ext_mesh_shader => b"VK_EXT_mesh_shader" {
+        core: None,
+        requires: [khr_spirv_1_4],
+        requires_instance: [],
+    },
+    ext_fragment_density_map2 => b"VK_EXT_fragment_density_map2" {
+        core: None,
+        requires: [ext_fragment_density_map],
+        requires_instance: [],
+    },
+    ext_image_robustness => b"VK_EXT_image_robustness" {
+        core: Some(Version::V1_3),
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+    khr_copy_commands2 => b"VK_KHR_copy_commands2" {
+        core: Some(Version::V1_3),
+        requires: [],
+        requires_instance: [],
+    },
+    ext_4444_formats => b"VK_EXT_4444_formats" {
+        core: Some(Version::V1_3),
+        requires: [],
+        requires_instance: [khr_get_physical_device_properties2],
+    },
+}
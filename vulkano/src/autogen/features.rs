@@ -0,0 +1,175 @@
+// This file is generated by `autogen` from the Vulkan registry. Do not edit it by hand.
+
+features! {
+    core: [
+        robust_buffer_access => robustBufferAccess,
+        full_draw_index_uint32 => fullDrawIndexUint32,
+        image_cube_array => imageCubeArray,
+        independent_blend => independentBlend,
+        geometry_shader => geometryShader,
+        tessellation_shader => tessellationShader,
+        sample_rate_shading => sampleRateShading,
+        dual_src_blend => dualSrcBlend,
+        logic_op => logicOp,
+        multi_draw_indirect => multiDrawIndirect,
+        draw_indirect_first_instance => drawIndirectFirstInstance,
+        depth_clamp => depthClamp,
+        depth_bias_clamp => depthBiasClamp,
+        fill_mode_non_solid => fillModeNonSolid,
+        depth_bounds => depthBounds,
+        wide_lines => wideLines,
+        large_points => largePoints,
+        alpha_to_one => alphaToOne,
+        multi_viewport => multiViewport,
+        sampler_anisotropy => samplerAnisotropy,
+        texture_compression_etc2 => textureCompressionETC2,
+        texture_compression_astc_ldr => textureCompressionASTC_LDR,
+        texture_compression_bc => textureCompressionBC,
+        occlusion_query_precise => occlusionQueryPrecise,
+        pipeline_statistics_query => pipelineStatisticsQuery,
+        vertex_pipeline_stores_and_atomics => vertexPipelineStoresAndAtomics,
+        fragment_stores_and_atomics => fragmentStoresAndAtomics,
+        shader_tessellation_and_geometry_point_size => shaderTessellationAndGeometryPointSize,
+        shader_image_gather_extended => shaderImageGatherExtended,
+        shader_storage_image_extended_formats => shaderStorageImageExtendedFormats,
+        shader_storage_image_multisample => shaderStorageImageMultisample,
+        shader_storage_image_read_without_format => shaderStorageImageReadWithoutFormat,
+        shader_storage_image_write_without_format => shaderStorageImageWriteWithoutFormat,
+        shader_uniform_buffer_array_dynamic_indexing => shaderUniformBufferArrayDynamicIndexing,
+        shader_sampled_image_array_dynamic_indexing => shaderSampledImageArrayDynamicIndexing,
+        shader_storage_buffer_array_dynamic_indexing => shaderStorageBufferArrayDynamicIndexing,
+        shader_storage_image_array_dynamic_indexing => shaderStorageImageArrayDynamicIndexing,
+        shader_clip_distance => shaderClipDistance,
+        shader_cull_distance => shaderCullDistance,
+        shader_float64 => shaderFloat64,
+        shader_int64 => shaderInt64,
+        shader_int16 => shaderInt16,
+        shader_resource_residency => shaderResourceResidency,
+        shader_resource_min_lod => shaderResourceMinLod,
+        sparse_binding => sparseBinding,
+        sparse_residency_buffer => sparseResidencyBuffer,
+        sparse_residency_image2d => sparseResidencyImage2D,
+        sparse_residency_image3d => sparseResidencyImage3D,
+        sparse_residency2_samples => sparseResidency2Samples,
+        sparse_residency4_samples => sparseResidency4Samples,
+        sparse_residency8_samples => sparseResidency8Samples,
+        sparse_residency16_samples => sparseResidency16Samples,
+        sparse_residency_aliased => sparseResidencyAliased,
+        variable_multisample_rate => variableMultisampleRate,
+        inherited_queries => inheritedQueries,
+    ],
+    extensions: [
+        {
+            ty: vk::PhysicalDeviceSynchronization2Features,
+            ffi_name: synchronization2,
+            sType: vk::STRUCTURE_TYPE_PHYSICAL_DEVICE_SYNCHRONIZATION_2_FEATURES,
+            fields: [
+                synchronization2 => synchronization2,
+            ],
+        },
+        {
+            ty: vk::PhysicalDeviceSubgroupSizeControlFeatures,
+            ffi_name: subgroup_size_control,
+            sType: vk::STRUCTURE_TYPE_PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_FEATURES,
+            fields: [
+                subgroup_size_control => subgroupSizeControl,
+                compute_full_subgroups => computeFullSubgroups,
+            ],
+        },
+        {
+            ty: vk::PhysicalDeviceVariablePointersFeatures,
+            ffi_name: variable_pointers,
+            sType: vk::STRUCTURE_TYPE_PHYSICAL_DEVICE_VARIABLE_POINTERS_FEATURES,
+            fields: [
+                variable_pointers_storage_buffer => variablePointersStorageBuffer,
+                variable_pointers => variablePointers,
+            ],
+        },
+        {
+            ty: vk::PhysicalDeviceShaderAtomicInt64Features,
+            ffi_name: shader_atomic_int64,
+            sType: vk::STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_ATOMIC_INT64_FEATURES,
+            fields: [
+                shader_buffer_int64_atomics => shaderBufferInt64Atomics,
+                shader_shared_int64_atomics => shaderSharedInt64Atomics,
+            ],
+        },
+        {
+            ty: vk::PhysicalDevice8BitStorageFeatures,
+            ffi_name: features_8_bit_storage,
+            sType: vk::STRUCTURE_TYPE_PHYSICAL_DEVICE_8BIT_STORAGE_FEATURES,
+            fields: [
+                storage_buffer_8bit => storageBuffer8BitAccess,
+                storage_uniform_8bit => uniformAndStorageBuffer8BitAccess,
+                storage_push_constant_8bit => storagePushConstant8,
+            ],
+        },
+        {
+            ty: vk::PhysicalDevice16BitStorageFeatures,
+            ffi_name: features_16_bit_storage,
+            sType: vk::STRUCTURE_TYPE_PHYSICAL_DEVICE_16BIT_STORAGE_FEATURES,
+            fields: [
+                storage_buffer_16bit => storageBuffer16BitAccess,
+                storage_uniform_16bit => uniformAndStorageBuffer16BitAccess,
+                storage_push_constant_16bit => storagePushConstant16,
+                storage_input_output_16bit => storageInputOutput16,
+            ],
+        },
+        {
+            ty: vk::PhysicalDeviceShaderFloat16Int8Features,
+            ffi_name: shader_float16_int8,
+            sType: vk::STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_FLOAT16_INT8_FEATURES,
+            fields: [
+                shader_float16 => shaderFloat16,
+                shader_int8 => shaderInt8,
+            ],
+        },
+        {
+            ty: vk::PhysicalDevicePresentIdFeaturesKHR,
+            ffi_name: present_id_khr,
+            sType: vk::STRUCTURE_TYPE_PHYSICAL_DEVICE_PRESENT_ID_FEATURES_KHR,
+            fields: [
+                present_id => presentId,
+            ],
+        },
+        {
+            ty: vk::PhysicalDevicePresentWaitFeaturesKHR,
+            ffi_name: present_wait_khr,
+            sType: vk::STRUCTURE_TYPE_PHYSICAL_DEVICE_PRESENT_WAIT_FEATURES_KHR,
+            fields: [
+                present_wait => presentWait,
+            ],
+        },
+        {
+            ty: vk::PhysicalDeviceBufferDeviceAddressFeaturesEXT,
+            ffi_name: buffer_device_address_ext,
+            sType: vk::STRUCTURE_TYPE_PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES_EXT,
+            fields: [
+                buffer_device_address => bufferDeviceAddress,
+                buffer_device_address_capture_replay => bufferDeviceAddressCaptureReplay,
+                buffer_device_address_multi_device => bufferDeviceAddressMultiDevice,
+            ],
+        },
+        {
+            ty: vk::PhysicalDeviceMeshShaderFeaturesEXT,
+            ffi_name: mesh_shader_ext,
+            sType: vk::STRUCTURE_TYPE_PHYSICAL_DEVICE_MESH_SHADER_FEATURES_EXT,
+            fields: [
+                task_shader => taskShader,
+                mesh_shader => meshShader,
+                multiview_mesh_shader => multiviewMeshShader,
+                primitive_fragment_shading_rate_mesh_shader => primitiveFragmentShadingRateMeshShader,
+                mesh_shader_queries => meshShaderQueries,
+            ],
+        },
+        {
+            ty: vk::PhysicalDeviceMeshShaderFeaturesNV,
+            ffi_name: mesh_shader_nv,
+            sType: vk::STRUCTURE_TYPE_PHYSICAL_DEVICE_MESH_SHADER_FEATURES_NV,
+            fields: [
+                task_shader_nv => taskShader,
+                mesh_shader_nv => meshShader,
+            ],
+        },
+    ],
+}
@@ -0,0 +1,246 @@
+// This file is generated by `autogen` from the Vulkan registry. Do not edit it by hand.
+
+formats! {
+    R4G4UnormPack8 => {vk: FORMAT_R4G4_UNORM_PACK8, bdim: (1, 1), size: Some(1), ty: Float},
+    R4G4B4A4UnormPack16 => {vk: FORMAT_R4G4B4A4_UNORM_PACK16, bdim: (1, 1), size: Some(2), ty: Float},
+    B4G4R4A4UnormPack16 => {vk: FORMAT_B4G4R4A4_UNORM_PACK16, bdim: (1, 1), size: Some(2), ty: Float},
+    R5G6B5UnormPack16 => {vk: FORMAT_R5G6B5_UNORM_PACK16, bdim: (1, 1), size: Some(2), ty: Float},
+    B5G6R5UnormPack16 => {vk: FORMAT_B5G6R5_UNORM_PACK16, bdim: (1, 1), size: Some(2), ty: Float},
+    R5G5B5A1UnormPack16 => {vk: FORMAT_R5G5B5A1_UNORM_PACK16, bdim: (1, 1), size: Some(2), ty: Float},
+    B5G5R5A1UnormPack16 => {vk: FORMAT_B5G5R5A1_UNORM_PACK16, bdim: (1, 1), size: Some(2), ty: Float},
+    A1R5G5B5UnormPack16 => {vk: FORMAT_A1R5G5B5_UNORM_PACK16, bdim: (1, 1), size: Some(2), ty: Float},
+    R8Unorm => {vk: FORMAT_R8_UNORM, bdim: (1, 1), size: Some(1), ty: Float},
+    R8Snorm => {vk: FORMAT_R8_SNORM, bdim: (1, 1), size: Some(1), ty: Float},
+    R8Uscaled => {vk: FORMAT_R8_USCALED, bdim: (1, 1), size: Some(1), ty: Float},
+    R8Sscaled => {vk: FORMAT_R8_SSCALED, bdim: (1, 1), size: Some(1), ty: Float},
+    R8Uint => {vk: FORMAT_R8_UINT, bdim: (1, 1), size: Some(1), ty: Uint},
+    R8Sint => {vk: FORMAT_R8_SINT, bdim: (1, 1), size: Some(1), ty: Sint},
+    R8Srgb => {vk: FORMAT_R8_SRGB, bdim: (1, 1), size: Some(1), ty: Float},
+    R8G8Unorm => {vk: FORMAT_R8G8_UNORM, bdim: (1, 1), size: Some(2), ty: Float},
+    R8G8Snorm => {vk: FORMAT_R8G8_SNORM, bdim: (1, 1), size: Some(2), ty: Float},
+    R8G8Uscaled => {vk: FORMAT_R8G8_USCALED, bdim: (1, 1), size: Some(2), ty: Float},
+    R8G8Sscaled => {vk: FORMAT_R8G8_SSCALED, bdim: (1, 1), size: Some(2), ty: Float},
+    R8G8Uint => {vk: FORMAT_R8G8_UINT, bdim: (1, 1), size: Some(2), ty: Uint},
+    R8G8Sint => {vk: FORMAT_R8G8_SINT, bdim: (1, 1), size: Some(2), ty: Sint},
+    R8G8Srgb => {vk: FORMAT_R8G8_SRGB, bdim: (1, 1), size: Some(2), ty: Float},
+    R8G8B8Unorm => {vk: FORMAT_R8G8B8_UNORM, bdim: (1, 1), size: Some(3), ty: Float},
+    R8G8B8Snorm => {vk: FORMAT_R8G8B8_SNORM, bdim: (1, 1), size: Some(3), ty: Float},
+    R8G8B8Uscaled => {vk: FORMAT_R8G8B8_USCALED, bdim: (1, 1), size: Some(3), ty: Float},
+    R8G8B8Sscaled => {vk: FORMAT_R8G8B8_SSCALED, bdim: (1, 1), size: Some(3), ty: Float},
+    R8G8B8Uint => {vk: FORMAT_R8G8B8_UINT, bdim: (1, 1), size: Some(3), ty: Uint},
+    R8G8B8Sint => {vk: FORMAT_R8G8B8_SINT, bdim: (1, 1), size: Some(3), ty: Sint},
+    R8G8B8Srgb => {vk: FORMAT_R8G8B8_SRGB, bdim: (1, 1), size: Some(3), ty: Float},
+    B8G8R8Unorm => {vk: FORMAT_B8G8R8_UNORM, bdim: (1, 1), size: Some(3), ty: Float},
+    B8G8R8Snorm => {vk: FORMAT_B8G8R8_SNORM, bdim: (1, 1), size: Some(3), ty: Float},
+    B8G8R8Uscaled => {vk: FORMAT_B8G8R8_USCALED, bdim: (1, 1), size: Some(3), ty: Float},
+    B8G8R8Sscaled => {vk: FORMAT_B8G8R8_SSCALED, bdim: (1, 1), size: Some(3), ty: Float},
+    B8G8R8Uint => {vk: FORMAT_B8G8R8_UINT, bdim: (1, 1), size: Some(3), ty: Uint},
+    B8G8R8Sint => {vk: FORMAT_B8G8R8_SINT, bdim: (1, 1), size: Some(3), ty: Sint},
+    B8G8R8Srgb => {vk: FORMAT_B8G8R8_SRGB, bdim: (1, 1), size: Some(3), ty: Float},
+    R8G8B8A8Unorm => {vk: FORMAT_R8G8B8A8_UNORM, bdim: (1, 1), size: Some(4), ty: Float},
+    R8G8B8A8Snorm => {vk: FORMAT_R8G8B8A8_SNORM, bdim: (1, 1), size: Some(4), ty: Float},
+    R8G8B8A8Uscaled => {vk: FORMAT_R8G8B8A8_USCALED, bdim: (1, 1), size: Some(4), ty: Float},
+    R8G8B8A8Sscaled => {vk: FORMAT_R8G8B8A8_SSCALED, bdim: (1, 1), size: Some(4), ty: Float},
+    R8G8B8A8Uint => {vk: FORMAT_R8G8B8A8_UINT, bdim: (1, 1), size: Some(4), ty: Uint},
+    R8G8B8A8Sint => {vk: FORMAT_R8G8B8A8_SINT, bdim: (1, 1), size: Some(4), ty: Sint},
+    R8G8B8A8Srgb => {vk: FORMAT_R8G8B8A8_SRGB, bdim: (1, 1), size: Some(4), ty: Float},
+    B8G8R8A8Unorm => {vk: FORMAT_B8G8R8A8_UNORM, bdim: (1, 1), size: Some(4), ty: Float},
+    B8G8R8A8Snorm => {vk: FORMAT_B8G8R8A8_SNORM, bdim: (1, 1), size: Some(4), ty: Float},
+    B8G8R8A8Uscaled => {vk: FORMAT_B8G8R8A8_USCALED, bdim: (1, 1), size: Some(4), ty: Float},
+    B8G8R8A8Sscaled => {vk: FORMAT_B8G8R8A8_SSCALED, bdim: (1, 1), size: Some(4), ty: Float},
+    B8G8R8A8Uint => {vk: FORMAT_B8G8R8A8_UINT, bdim: (1, 1), size: Some(4), ty: Uint},
+    B8G8R8A8Sint => {vk: FORMAT_B8G8R8A8_SINT, bdim: (1, 1), size: Some(4), ty: Sint},
+    B8G8R8A8Srgb => {vk: FORMAT_B8G8R8A8_SRGB, bdim: (1, 1), size: Some(4), ty: Float},
+    A8B8G8R8UnormPack32 => {vk: FORMAT_A8B8G8R8_UNORM_PACK32, bdim: (1, 1), size: Some(4), ty: Float},
+    A8B8G8R8SnormPack32 => {vk: FORMAT_A8B8G8R8_SNORM_PACK32, bdim: (1, 1), size: Some(4), ty: Float},
+    A8B8G8R8UscaledPack32 => {vk: FORMAT_A8B8G8R8_USCALED_PACK32, bdim: (1, 1), size: Some(4), ty: Float},
+    A8B8G8R8SscaledPack32 => {vk: FORMAT_A8B8G8R8_SSCALED_PACK32, bdim: (1, 1), size: Some(4), ty: Float},
+    A8B8G8R8UintPack32 => {vk: FORMAT_A8B8G8R8_UINT_PACK32, bdim: (1, 1), size: Some(4), ty: Uint},
+    A8B8G8R8SintPack32 => {vk: FORMAT_A8B8G8R8_SINT_PACK32, bdim: (1, 1), size: Some(4), ty: Sint},
+    A8B8G8R8SrgbPack32 => {vk: FORMAT_A8B8G8R8_SRGB_PACK32, bdim: (1, 1), size: Some(4), ty: Float},
+    A2R10G10B10UnormPack32 => {vk: FORMAT_A2R10G10B10_UNORM_PACK32, bdim: (1, 1), size: Some(4), ty: Float},
+    A2R10G10B10SnormPack32 => {vk: FORMAT_A2R10G10B10_SNORM_PACK32, bdim: (1, 1), size: Some(4), ty: Float},
+    A2R10G10B10UscaledPack32 => {vk: FORMAT_A2R10G10B10_USCALED_PACK32, bdim: (1, 1), size: Some(4), ty: Float},
+    A2R10G10B10SscaledPack32 => {vk: FORMAT_A2R10G10B10_SSCALED_PACK32, bdim: (1, 1), size: Some(4), ty: Float},
+    A2R10G10B10UintPack32 => {vk: FORMAT_A2R10G10B10_UINT_PACK32, bdim: (1, 1), size: Some(4), ty: Uint},
+    A2R10G10B10SintPack32 => {vk: FORMAT_A2R10G10B10_SINT_PACK32, bdim: (1, 1), size: Some(4), ty: Sint},
+    A2B10G10R10UnormPack32 => {vk: FORMAT_A2B10G10R10_UNORM_PACK32, bdim: (1, 1), size: Some(4), ty: Float},
+    A2B10G10R10SnormPack32 => {vk: FORMAT_A2B10G10R10_SNORM_PACK32, bdim: (1, 1), size: Some(4), ty: Float},
+    A2B10G10R10UscaledPack32 => {vk: FORMAT_A2B10G10R10_USCALED_PACK32, bdim: (1, 1), size: Some(4), ty: Float},
+    A2B10G10R10SscaledPack32 => {vk: FORMAT_A2B10G10R10_SSCALED_PACK32, bdim: (1, 1), size: Some(4), ty: Float},
+    A2B10G10R10UintPack32 => {vk: FORMAT_A2B10G10R10_UINT_PACK32, bdim: (1, 1), size: Some(4), ty: Uint},
+    A2B10G10R10SintPack32 => {vk: FORMAT_A2B10G10R10_SINT_PACK32, bdim: (1, 1), size: Some(4), ty: Sint},
+    R16Unorm => {vk: FORMAT_R16_UNORM, bdim: (1, 1), size: Some(2), ty: Float},
+    R16Snorm => {vk: FORMAT_R16_SNORM, bdim: (1, 1), size: Some(2), ty: Float},
+    R16Uscaled => {vk: FORMAT_R16_USCALED, bdim: (1, 1), size: Some(2), ty: Float},
+    R16Sscaled => {vk: FORMAT_R16_SSCALED, bdim: (1, 1), size: Some(2), ty: Float},
+    R16Uint => {vk: FORMAT_R16_UINT, bdim: (1, 1), size: Some(2), ty: Uint},
+    R16Sint => {vk: FORMAT_R16_SINT, bdim: (1, 1), size: Some(2), ty: Sint},
+    R16Sfloat => {vk: FORMAT_R16_SFLOAT, bdim: (1, 1), size: Some(2), ty: Float},
+    R16G16Unorm => {vk: FORMAT_R16G16_UNORM, bdim: (1, 1), size: Some(4), ty: Float},
+    R16G16Snorm => {vk: FORMAT_R16G16_SNORM, bdim: (1, 1), size: Some(4), ty: Float},
+    R16G16Uscaled => {vk: FORMAT_R16G16_USCALED, bdim: (1, 1), size: Some(4), ty: Float},
+    R16G16Sscaled => {vk: FORMAT_R16G16_SSCALED, bdim: (1, 1), size: Some(4), ty: Float},
+    R16G16Uint => {vk: FORMAT_R16G16_UINT, bdim: (1, 1), size: Some(4), ty: Uint},
+    R16G16Sint => {vk: FORMAT_R16G16_SINT, bdim: (1, 1), size: Some(4), ty: Sint},
+    R16G16Sfloat => {vk: FORMAT_R16G16_SFLOAT, bdim: (1, 1), size: Some(4), ty: Float},
+    R16G16B16Unorm => {vk: FORMAT_R16G16B16_UNORM, bdim: (1, 1), size: Some(6), ty: Float},
+    R16G16B16Snorm => {vk: FORMAT_R16G16B16_SNORM, bdim: (1, 1), size: Some(6), ty: Float},
+    R16G16B16Uscaled => {vk: FORMAT_R16G16B16_USCALED, bdim: (1, 1), size: Some(6), ty: Float},
+    R16G16B16Sscaled => {vk: FORMAT_R16G16B16_SSCALED, bdim: (1, 1), size: Some(6), ty: Float},
+    R16G16B16Uint => {vk: FORMAT_R16G16B16_UINT, bdim: (1, 1), size: Some(6), ty: Uint},
+    R16G16B16Sint => {vk: FORMAT_R16G16B16_SINT, bdim: (1, 1), size: Some(6), ty: Sint},
+    R16G16B16Sfloat => {vk: FORMAT_R16G16B16_SFLOAT, bdim: (1, 1), size: Some(6), ty: Float},
+    R16G16B16A16Unorm => {vk: FORMAT_R16G16B16A16_UNORM, bdim: (1, 1), size: Some(8), ty: Float},
+    R16G16B16A16Snorm => {vk: FORMAT_R16G16B16A16_SNORM, bdim: (1, 1), size: Some(8), ty: Float},
+    R16G16B16A16Uscaled => {vk: FORMAT_R16G16B16A16_USCALED, bdim: (1, 1), size: Some(8), ty: Float},
+    R16G16B16A16Sscaled => {vk: FORMAT_R16G16B16A16_SSCALED, bdim: (1, 1), size: Some(8), ty: Float},
+    R16G16B16A16Uint => {vk: FORMAT_R16G16B16A16_UINT, bdim: (1, 1), size: Some(8), ty: Uint},
+    R16G16B16A16Sint => {vk: FORMAT_R16G16B16A16_SINT, bdim: (1, 1), size: Some(8), ty: Sint},
+    R16G16B16A16Sfloat => {vk: FORMAT_R16G16B16A16_SFLOAT, bdim: (1, 1), size: Some(8), ty: Float},
+    R32Uint => {vk: FORMAT_R32_UINT, bdim: (1, 1), size: Some(4), ty: Uint},
+    R32Sint => {vk: FORMAT_R32_SINT, bdim: (1, 1), size: Some(4), ty: Sint},
+    R32Sfloat => {vk: FORMAT_R32_SFLOAT, bdim: (1, 1), size: Some(4), ty: Float},
+    R32G32Uint => {vk: FORMAT_R32G32_UINT, bdim: (1, 1), size: Some(8), ty: Uint},
+    R32G32Sint => {vk: FORMAT_R32G32_SINT, bdim: (1, 1), size: Some(8), ty: Sint},
+    R32G32Sfloat => {vk: FORMAT_R32G32_SFLOAT, bdim: (1, 1), size: Some(8), ty: Float},
+    R32G32B32Uint => {vk: FORMAT_R32G32B32_UINT, bdim: (1, 1), size: Some(12), ty: Uint},
+    R32G32B32Sint => {vk: FORMAT_R32G32B32_SINT, bdim: (1, 1), size: Some(12), ty: Sint},
+    R32G32B32Sfloat => {vk: FORMAT_R32G32B32_SFLOAT, bdim: (1, 1), size: Some(12), ty: Float},
+    R32G32B32A32Uint => {vk: FORMAT_R32G32B32A32_UINT, bdim: (1, 1), size: Some(16), ty: Uint},
+    R32G32B32A32Sint => {vk: FORMAT_R32G32B32A32_SINT, bdim: (1, 1), size: Some(16), ty: Sint},
+    R32G32B32A32Sfloat => {vk: FORMAT_R32G32B32A32_SFLOAT, bdim: (1, 1), size: Some(16), ty: Float},
+    R64Uint => {vk: FORMAT_R64_UINT, bdim: (1, 1), size: Some(8), ty: Uint},
+    R64Sint => {vk: FORMAT_R64_SINT, bdim: (1, 1), size: Some(8), ty: Sint},
+    R64Sfloat => {vk: FORMAT_R64_SFLOAT, bdim: (1, 1), size: Some(8), ty: Float},
+    R64G64Uint => {vk: FORMAT_R64G64_UINT, bdim: (1, 1), size: Some(16), ty: Uint},
+    R64G64Sint => {vk: FORMAT_R64G64_SINT, bdim: (1, 1), size: Some(16), ty: Sint},
+    R64G64Sfloat => {vk: FORMAT_R64G64_SFLOAT, bdim: (1, 1), size: Some(16), ty: Float},
+    R64G64B64Uint => {vk: FORMAT_R64G64B64_UINT, bdim: (1, 1), size: Some(24), ty: Uint},
+    R64G64B64Sint => {vk: FORMAT_R64G64B64_SINT, bdim: (1, 1), size: Some(24), ty: Sint},
+    R64G64B64Sfloat => {vk: FORMAT_R64G64B64_SFLOAT, bdim: (1, 1), size: Some(24), ty: Float},
+    R64G64B64A64Uint => {vk: FORMAT_R64G64B64A64_UINT, bdim: (1, 1), size: Some(32), ty: Uint},
+    R64G64B64A64Sint => {vk: FORMAT_R64G64B64A64_SINT, bdim: (1, 1), size: Some(32), ty: Sint},
+    R64G64B64A64Sfloat => {vk: FORMAT_R64G64B64A64_SFLOAT, bdim: (1, 1), size: Some(32), ty: Float},
+    B10G11R11UfloatPack32 => {vk: FORMAT_B10G11R11_UFLOAT_PACK32, bdim: (1, 1), size: Some(4), ty: Float},
+    E5B9G9R9UfloatPack32 => {vk: FORMAT_E5B9G9R9_UFLOAT_PACK32, bdim: (1, 1), size: Some(4), ty: Float},
+    D16Unorm => {vk: FORMAT_D16_UNORM, bdim: (1, 1), size: Some(2), ty: Depth},
+    X8_D24UnormPack32 => {vk: FORMAT_X8_D24_UNORM_PACK32, bdim: (1, 1), size: Some(4), ty: Depth},
+    D32Sfloat => {vk: FORMAT_D32_SFLOAT, bdim: (1, 1), size: Some(4), ty: Depth},
+    S8Uint => {vk: FORMAT_S8_UINT, bdim: (1, 1), size: Some(1), ty: Stencil},
+    D16Unorm_S8Uint => {vk: FORMAT_D16_UNORM_S8_UINT, bdim: (1, 1), size: None, ty: DepthStencil},
+    D24Unorm_S8Uint => {vk: FORMAT_D24_UNORM_S8_UINT, bdim: (1, 1), size: None, ty: DepthStencil},
+    D32Sfloat_S8Uint => {vk: FORMAT_D32_SFLOAT_S8_UINT, bdim: (1, 1), size: None, ty: DepthStencil},
+    BC1_RGBUnormBlock => {vk: FORMAT_BC1_RGB_UNORM_BLOCK, bdim: (4, 4), size: Some(8), ty: Compressed},
+    BC1_RGBSrgbBlock => {vk: FORMAT_BC1_RGB_SRGB_BLOCK, bdim: (4, 4), size: Some(8), ty: Compressed},
+    BC1_RGBAUnormBlock => {vk: FORMAT_BC1_RGBA_UNORM_BLOCK, bdim: (4, 4), size: Some(8), ty: Compressed},
+    BC1_RGBASrgbBlock => {vk: FORMAT_BC1_RGBA_SRGB_BLOCK, bdim: (4, 4), size: Some(8), ty: Compressed},
+    BC2UnormBlock => {vk: FORMAT_BC2_UNORM_BLOCK, bdim: (4, 4), size: Some(16), ty: Compressed},
+    BC2SrgbBlock => {vk: FORMAT_BC2_SRGB_BLOCK, bdim: (4, 4), size: Some(16), ty: Compressed},
+    BC3UnormBlock => {vk: FORMAT_BC3_UNORM_BLOCK, bdim: (4, 4), size: Some(16), ty: Compressed},
+    BC3SrgbBlock => {vk: FORMAT_BC3_SRGB_BLOCK, bdim: (4, 4), size: Some(16), ty: Compressed},
+    BC4UnormBlock => {vk: FORMAT_BC4_UNORM_BLOCK, bdim: (4, 4), size: Some(8), ty: Compressed},
+    BC4SnormBlock => {vk: FORMAT_BC4_SNORM_BLOCK, bdim: (4, 4), size: Some(8), ty: Compressed},
+    BC5UnormBlock => {vk: FORMAT_BC5_UNORM_BLOCK, bdim: (4, 4), size: Some(16), ty: Compressed},
+    BC5SnormBlock => {vk: FORMAT_BC5_SNORM_BLOCK, bdim: (4, 4), size: Some(16), ty: Compressed},
+    BC6HUfloatBlock => {vk: FORMAT_BC6H_UFLOAT_BLOCK, bdim: (4, 4), size: Some(16), ty: Compressed},
+    BC6HSfloatBlock => {vk: FORMAT_BC6H_SFLOAT_BLOCK, bdim: (4, 4), size: Some(16), ty: Compressed},
+    BC7UnormBlock => {vk: FORMAT_BC7_UNORM_BLOCK, bdim: (4, 4), size: Some(16), ty: Compressed},
+    BC7SrgbBlock => {vk: FORMAT_BC7_SRGB_BLOCK, bdim: (4, 4), size: Some(16), ty: Compressed},
+    ETC2_R8G8B8UnormBlock => {vk: FORMAT_ETC2_R8G8B8_UNORM_BLOCK, bdim: (4, 4), size: Some(8), ty: Compressed},
+    ETC2_R8G8B8SrgbBlock => {vk: FORMAT_ETC2_R8G8B8_SRGB_BLOCK, bdim: (4, 4), size: Some(8), ty: Compressed},
+    ETC2_R8G8B8A1UnormBlock => {vk: FORMAT_ETC2_R8G8B8A1_UNORM_BLOCK, bdim: (4, 4), size: Some(8), ty: Compressed},
+    ETC2_R8G8B8A1SrgbBlock => {vk: FORMAT_ETC2_R8G8B8A1_SRGB_BLOCK, bdim: (4, 4), size: Some(8), ty: Compressed},
+    ETC2_R8G8B8A8UnormBlock => {vk: FORMAT_ETC2_R8G8B8A8_UNORM_BLOCK, bdim: (4, 4), size: Some(16), ty: Compressed},
+    ETC2_R8G8B8A8SrgbBlock => {vk: FORMAT_ETC2_R8G8B8A8_SRGB_BLOCK, bdim: (4, 4), size: Some(16), ty: Compressed},
+    EAC_R11UnormBlock => {vk: FORMAT_EAC_R11_UNORM_BLOCK, bdim: (4, 4), size: Some(8), ty: Compressed},
+    EAC_R11SnormBlock => {vk: FORMAT_EAC_R11_SNORM_BLOCK, bdim: (4, 4), size: Some(8), ty: Compressed},
+    EAC_R11G11UnormBlock => {vk: FORMAT_EAC_R11G11_UNORM_BLOCK, bdim: (4, 4), size: Some(16), ty: Compressed},
+    EAC_R11G11SnormBlock => {vk: FORMAT_EAC_R11G11_SNORM_BLOCK, bdim: (4, 4), size: Some(16), ty: Compressed},
+    ASTC_4x4UnormBlock => {vk: FORMAT_ASTC_4x4_UNORM_BLOCK, bdim: (4, 4), size: Some(16), ty: Compressed},
+    ASTC_4x4SrgbBlock => {vk: FORMAT_ASTC_4x4_SRGB_BLOCK, bdim: (4, 4), size: Some(16), ty: Compressed},
+    ASTC_5x4UnormBlock => {vk: FORMAT_ASTC_5x4_UNORM_BLOCK, bdim: (5, 4), size: Some(16), ty: Compressed},
+    ASTC_5x4SrgbBlock => {vk: FORMAT_ASTC_5x4_SRGB_BLOCK, bdim: (5, 4), size: Some(16), ty: Compressed},
+    ASTC_5x5UnormBlock => {vk: FORMAT_ASTC_5x5_UNORM_BLOCK, bdim: (5, 5), size: Some(16), ty: Compressed},
+    ASTC_5x5SrgbBlock => {vk: FORMAT_ASTC_5x5_SRGB_BLOCK, bdim: (5, 5), size: Some(16), ty: Compressed},
+    ASTC_6x5UnormBlock => {vk: FORMAT_ASTC_6x5_UNORM_BLOCK, bdim: (6, 5), size: Some(16), ty: Compressed},
+    ASTC_6x5SrgbBlock => {vk: FORMAT_ASTC_6x5_SRGB_BLOCK, bdim: (6, 5), size: Some(16), ty: Compressed},
+    ASTC_6x6UnormBlock => {vk: FORMAT_ASTC_6x6_UNORM_BLOCK, bdim: (6, 6), size: Some(16), ty: Compressed},
+    ASTC_6x6SrgbBlock => {vk: FORMAT_ASTC_6x6_SRGB_BLOCK, bdim: (6, 6), size: Some(16), ty: Compressed},
+    ASTC_8x5UnormBlock => {vk: FORMAT_ASTC_8x5_UNORM_BLOCK, bdim: (8, 5), size: Some(16), ty: Compressed},
+    ASTC_8x5SrgbBlock => {vk: FORMAT_ASTC_8x5_SRGB_BLOCK, bdim: (8, 5), size: Some(16), ty: Compressed},
+    ASTC_8x6UnormBlock => {vk: FORMAT_ASTC_8x6_UNORM_BLOCK, bdim: (8, 6), size: Some(16), ty: Compressed},
+    ASTC_8x6SrgbBlock => {vk: FORMAT_ASTC_8x6_SRGB_BLOCK, bdim: (8, 6), size: Some(16), ty: Compressed},
+    ASTC_8x8UnormBlock => {vk: FORMAT_ASTC_8x8_UNORM_BLOCK, bdim: (8, 8), size: Some(16), ty: Compressed},
+    ASTC_8x8SrgbBlock => {vk: FORMAT_ASTC_8x8_SRGB_BLOCK, bdim: (8, 8), size: Some(16), ty: Compressed},
+    ASTC_10x5UnormBlock => {vk: FORMAT_ASTC_10x5_UNORM_BLOCK, bdim: (10, 5), size: Some(16), ty: Compressed},
+    ASTC_10x5SrgbBlock => {vk: FORMAT_ASTC_10x5_SRGB_BLOCK, bdim: (10, 5), size: Some(16), ty: Compressed},
+    ASTC_10x6UnormBlock => {vk: FORMAT_ASTC_10x6_UNORM_BLOCK, bdim: (10, 6), size: Some(16), ty: Compressed},
+    ASTC_10x6SrgbBlock => {vk: FORMAT_ASTC_10x6_SRGB_BLOCK, bdim: (10, 6), size: Some(16), ty: Compressed},
+    ASTC_10x8UnormBlock => {vk: FORMAT_ASTC_10x8_UNORM_BLOCK, bdim: (10, 8), size: Some(16), ty: Compressed},
+    ASTC_10x8SrgbBlock => {vk: FORMAT_ASTC_10x8_SRGB_BLOCK, bdim: (10, 8), size: Some(16), ty: Compressed},
+    ASTC_10x10UnormBlock => {vk: FORMAT_ASTC_10x10_UNORM_BLOCK, bdim: (10, 10), size: Some(16), ty: Compressed},
+    ASTC_10x10SrgbBlock => {vk: FORMAT_ASTC_10x10_SRGB_BLOCK, bdim: (10, 10), size: Some(16), ty: Compressed},
+    ASTC_12x10UnormBlock => {vk: FORMAT_ASTC_12x10_UNORM_BLOCK, bdim: (12, 10), size: Some(16), ty: Compressed},
+    ASTC_12x10SrgbBlock => {vk: FORMAT_ASTC_12x10_SRGB_BLOCK, bdim: (12, 10), size: Some(16), ty: Compressed},
+    ASTC_12x12UnormBlock => {vk: FORMAT_ASTC_12x12_UNORM_BLOCK, bdim: (12, 12), size: Some(16), ty: Compressed},
+    ASTC_12x12SrgbBlock => {vk: FORMAT_ASTC_12x12_SRGB_BLOCK, bdim: (12, 12), size: Some(16), ty: Compressed},
+    G8B8G8R8422Unorm => {vk: FORMAT_G8B8G8R8_422_UNORM, bdim: (2, 1), size: Some(4), ty: Ycbcr},
+    B8G8R8G8422Unorm => {vk: FORMAT_B8G8R8G8_422_UNORM, bdim: (2, 1), size: Some(4), ty: Ycbcr},
+    G8B8R8_3PLANE420Unorm => {vk: FORMAT_G8_B8_R8_3PLANE_420_UNORM, bdim: (1, 1), size: None, ty: Ycbcr, planes: 3},
+    G8B8R8_2PLANE420Unorm => {vk: FORMAT_G8_B8R8_2PLANE_420_UNORM, bdim: (1, 1), size: None, ty: Ycbcr, planes: 2},
+    G8B8R8_3PLANE422Unorm => {vk: FORMAT_G8_B8_R8_3PLANE_422_UNORM, bdim: (1, 1), size: None, ty: Ycbcr, planes: 3},
+    G8B8R8_2PLANE422Unorm => {vk: FORMAT_G8_B8R8_2PLANE_422_UNORM, bdim: (1, 1), size: None, ty: Ycbcr, planes: 2},
+    G8B8R8_3PLANE444Unorm => {vk: FORMAT_G8_B8_R8_3PLANE_444_UNORM, bdim: (1, 1), size: None, ty: Ycbcr, planes: 3},
+    R10X6UnormPack16 => {vk: FORMAT_R10X6_UNORM_PACK16, bdim: (1, 1), size: Some(2), ty: Float},
+    R10X6G10X6Unorm2pack16 => {vk: FORMAT_R10X6G10X6_UNORM_2PACK16, bdim: (1, 1), size: Some(4), ty: Float},
+    R10X6G10X6B10X6A10X6Unorm4pack16 => {vk: FORMAT_R10X6G10X6B10X6A10X6_UNORM_4PACK16, bdim: (1, 1), size: Some(8), ty: Float},
+    G10X6B10X6G10X6R10X6422Unorm4pack16 => {vk: FORMAT_G10X6B10X6G10X6R10X6_422_UNORM_4PACK16, bdim: (2, 1), size: Some(8), ty: Ycbcr},
+    B10X6G10X6R10X6G10X6422Unorm4pack16 => {vk: FORMAT_B10X6G10X6R10X6G10X6_422_UNORM_4PACK16, bdim: (2, 1), size: Some(8), ty: Ycbcr},
+    G10X6B10X6R10X6_3PLANE420Unorm3pack16 => {vk: FORMAT_G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16, bdim: (1, 1), size: None, ty: Ycbcr, planes: 3},
+    G10X6B10X6R10X6_2PLANE420Unorm3pack16 => {vk: FORMAT_G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16, bdim: (1, 1), size: None, ty: Ycbcr, planes: 2},
+    G10X6B10X6R10X6_3PLANE422Unorm3pack16 => {vk: FORMAT_G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16, bdim: (1, 1), size: None, ty: Ycbcr, planes: 3},
+    G10X6B10X6R10X6_2PLANE422Unorm3pack16 => {vk: FORMAT_G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16, bdim: (1, 1), size: None, ty: Ycbcr, planes: 2},
+    G10X6B10X6R10X6_3PLANE444Unorm3pack16 => {vk: FORMAT_G10X6_B10X6_R10X6_3PLANE_444_UNORM_3PACK16, bdim: (1, 1), size: None, ty: Ycbcr, planes: 3},
+    R12X4UnormPack16 => {vk: FORMAT_R12X4_UNORM_PACK16, bdim: (1, 1), size: Some(2), ty: Float},
+    R12X4G12X4Unorm2pack16 => {vk: FORMAT_R12X4G12X4_UNORM_2PACK16, bdim: (1, 1), size: Some(4), ty: Float},
+    R12X4G12X4B12X4A12X4Unorm4pack16 => {vk: FORMAT_R12X4G12X4B12X4A12X4_UNORM_4PACK16, bdim: (1, 1), size: Some(8), ty: Float},
+    G12X4B12X4G12X4R12X4422Unorm4pack16 => {vk: FORMAT_G12X4B12X4G12X4R12X4_422_UNORM_4PACK16, bdim: (2, 1), size: Some(8), ty: Ycbcr},
+    B12X4G12X4R12X4G12X4422Unorm4pack16 => {vk: FORMAT_B12X4G12X4R12X4G12X4_422_UNORM_4PACK16, bdim: (2, 1), size: Some(8), ty: Ycbcr},
+    G12X4B12X4R12X4_3PLANE420Unorm3pack16 => {vk: FORMAT_G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16, bdim: (1, 1), size: None, ty: Ycbcr, planes: 3},
+    G12X4B12X4R12X4_2PLANE420Unorm3pack16 => {vk: FORMAT_G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16, bdim: (1, 1), size: None, ty: Ycbcr, planes: 2},
+    G12X4B12X4R12X4_3PLANE422Unorm3pack16 => {vk: FORMAT_G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16, bdim: (1, 1), size: None, ty: Ycbcr, planes: 3},
+    G12X4B12X4R12X4_2PLANE422Unorm3pack16 => {vk: FORMAT_G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16, bdim: (1, 1), size: None, ty: Ycbcr, planes: 2},
+    G12X4B12X4R12X4_3PLANE444Unorm3pack16 => {vk: FORMAT_G12X4_B12X4_R12X4_3PLANE_444_UNORM_3PACK16, bdim: (1, 1), size: None, ty: Ycbcr, planes: 3},
+    G16B16G16R16422Unorm => {vk: FORMAT_G16B16G16R16_422_UNORM, bdim: (2, 1), size: Some(8), ty: Ycbcr},
+    B16G16R16G16422Unorm => {vk: FORMAT_B16G16R16G16_422_UNORM, bdim: (2, 1), size: Some(8), ty: Ycbcr},
+    G16B16R16_3PLANE420Unorm => {vk: FORMAT_G16_B16_R16_3PLANE_420_UNORM, bdim: (1, 1), size: None, ty: Ycbcr, planes: 3},
+    G16B16R16_2PLANE420Unorm => {vk: FORMAT_G16_B16R16_2PLANE_420_UNORM, bdim: (1, 1), size: None, ty: Ycbcr, planes: 2},
+    G16B16R16_3PLANE422Unorm => {vk: FORMAT_G16_B16_R16_3PLANE_422_UNORM, bdim: (1, 1), size: None, ty: Ycbcr, planes: 3},
+    G16B16R16_2PLANE422Unorm => {vk: FORMAT_G16_B16R16_2PLANE_422_UNORM, bdim: (1, 1), size: None, ty: Ycbcr, planes: 2},
+    G16B16R16_3PLANE444Unorm => {vk: FORMAT_G16_B16_R16_3PLANE_444_UNORM, bdim: (1, 1), size: None, ty: Ycbcr, planes: 3},
+    PVRTC1_2BPPUnormBlockImg => {vk: FORMAT_PVRTC1_2BPP_UNORM_BLOCK_IMG, bdim: (8, 4), size: Some(8), ty: Compressed},
+    PVRTC1_4BPPUnormBlockImg => {vk: FORMAT_PVRTC1_4BPP_UNORM_BLOCK_IMG, bdim: (4, 4), size: Some(8), ty: Compressed},
+    PVRTC2_2BPPUnormBlockImg => {vk: FORMAT_PVRTC2_2BPP_UNORM_BLOCK_IMG, bdim: (8, 4), size: Some(8), ty: Compressed},
+    PVRTC2_4BPPUnormBlockImg => {vk: FORMAT_PVRTC2_4BPP_UNORM_BLOCK_IMG, bdim: (4, 4), size: Some(8), ty: Compressed},
+    PVRTC1_2BPPSrgbBlockImg => {vk: FORMAT_PVRTC1_2BPP_SRGB_BLOCK_IMG, bdim: (8, 4), size: Some(8), ty: Compressed},
+    PVRTC1_4BPPSrgbBlockImg => {vk: FORMAT_PVRTC1_4BPP_SRGB_BLOCK_IMG, bdim: (4, 4), size: Some(8), ty: Compressed},
+    PVRTC2_2BPPSrgbBlockImg => {vk: FORMAT_PVRTC2_2BPP_SRGB_BLOCK_IMG, bdim: (8, 4), size: Some(8), ty: Compressed},
+    PVRTC2_4BPPSrgbBlockImg => {vk: FORMAT_PVRTC2_4BPP_SRGB_BLOCK_IMG, bdim: (4, 4), size: Some(8), ty: Compressed},
+    ASTC_4x4SfloatBlock => {vk: FORMAT_ASTC_4x4_SFLOAT_BLOCK, bdim: (4, 4), size: Some(16), ty: Compressed},
+    ASTC_5x4SfloatBlock => {vk: FORMAT_ASTC_5x4_SFLOAT_BLOCK, bdim: (5, 4), size: Some(16), ty: Compressed},
+    ASTC_5x5SfloatBlock => {vk: FORMAT_ASTC_5x5_SFLOAT_BLOCK, bdim: (5, 5), size: Some(16), ty: Compressed},
+    ASTC_6x5SfloatBlock => {vk: FORMAT_ASTC_6x5_SFLOAT_BLOCK, bdim: (6, 5), size: Some(16), ty: Compressed},
+    ASTC_6x6SfloatBlock => {vk: FORMAT_ASTC_6x6_SFLOAT_BLOCK, bdim: (6, 6), size: Some(16), ty: Compressed},
+    ASTC_8x5SfloatBlock => {vk: FORMAT_ASTC_8x5_SFLOAT_BLOCK, bdim: (8, 5), size: Some(16), ty: Compressed},
+    ASTC_8x6SfloatBlock => {vk: FORMAT_ASTC_8x6_SFLOAT_BLOCK, bdim: (8, 6), size: Some(16), ty: Compressed},
+    ASTC_8x8SfloatBlock => {vk: FORMAT_ASTC_8x8_SFLOAT_BLOCK, bdim: (8, 8), size: Some(16), ty: Compressed},
+    ASTC_10x5SfloatBlock => {vk: FORMAT_ASTC_10x5_SFLOAT_BLOCK, bdim: (10, 5), size: Some(16), ty: Compressed},
+    ASTC_10x6SfloatBlock => {vk: FORMAT_ASTC_10x6_SFLOAT_BLOCK, bdim: (10, 6), size: Some(16), ty: Compressed},
+    ASTC_10x8SfloatBlock => {vk: FORMAT_ASTC_10x8_SFLOAT_BLOCK, bdim: (10, 8), size: Some(16), ty: Compressed},
+    ASTC_10x10SfloatBlock => {vk: FORMAT_ASTC_10x10_SFLOAT_BLOCK, bdim: (10, 10), size: Some(16), ty: Compressed},
+    ASTC_12x10SfloatBlock => {vk: FORMAT_ASTC_12x10_SFLOAT_BLOCK, bdim: (12, 10), size: Some(16), ty: Compressed},
+    ASTC_12x12SfloatBlock => {vk: FORMAT_ASTC_12x12_SFLOAT_BLOCK, bdim: (12, 12), size: Some(16), ty: Compressed},
+    A4R4G4B4UnormPack16 => {vk: FORMAT_A4R4G4B4_UNORM_PACK16, bdim: (1, 1), size: Some(2), ty: Float},
+    A4B4G4R4UnormPack16 => {vk: FORMAT_A4B4G4R4_UNORM_PACK16, bdim: (1, 1), size: Some(2), ty: Float},
+}
@@ -0,0 +1,126 @@
+// This file is generated by `autogen` from the Vulkan registry. Do not edit it by hand.
+
+instance_extensions! {
+    InstanceExtensions,
+    RawInstanceExtensions,
+    khr_surface => b"VK_KHR_surface" {
+        core: None,
+        requires: [],
+    },
+    khr_display => b"VK_KHR_display" {
+        core: None,
+        requires: [khr_surface],
+    },
+    khr_xlib_surface => b"VK_KHR_xlib_surface" {
+        core: None,
+        requires: [khr_surface],
+    },
+    khr_xcb_surface => b"VK_KHR_xcb_surface" {
+        core: None,
+        requires: [khr_surface],
+    },
+    khr_wayland_surface => b"VK_KHR_wayland_surface" {
+        core: None,
+        requires: [khr_surface],
+    },
+    khr_android_surface => b"VK_KHR_android_surface" {
+        core: None,
+        requires: [khr_surface],
+    },
+    khr_win32_surface => b"VK_KHR_win32_surface" {
+        core: None,
+        requires: [khr_surface],
+    },
+    ext_debug_report => b"VK_EXT_debug_report" {
+        core: None,
+        requires: [],
+    },
+    ggp_stream_descriptor_surface => b"VK_GGP_stream_descriptor_surface" {
+        core: None,
+        requires: [khr_surface],
+    },
+    nv_external_memory_capabilities => b"VK_NV_external_memory_capabilities" {
+        core: None,
+        requires: [],
+    },
+    khr_get_physical_device_properties2 => b"VK_KHR_get_physical_device_properties2" {
+        core: Some(Version::V1_1),
+        requires: [],
+    },
+    ext_validation_flags => b"VK_EXT_validation_flags" {
+        core: None,
+        requires: [],
+    },
+    nn_vi_surface => b"VK_NN_vi_surface" {
+        core: None,
+        requires: [khr_surface],
+    },
+    khr_device_group_creation => b"VK_KHR_device_group_creation" {
+        core: Some(Version::V1_1),
+        requires: [],
+    },
+    khr_external_memory_capabilities => b"VK_KHR_external_memory_capabilities" {
+        core: Some(Version::V1_1),
+        requires: [khr_get_physical_device_properties2],
+    },
+    khr_external_semaphore_capabilities => b"VK_KHR_external_semaphore_capabilities" {
+        core: Some(Version::V1_1),
+        requires: [khr_get_physical_device_properties2],
+    },
+    ext_display_surface_counter => b"VK_EXT_display_surface_counter" {
+        core: None,
+        requires: [khr_display],
+    },
+    ext_swapchain_colorspace => b"VK_EXT_swapchain_colorspace" {
+        core: None,
+        requires: [khr_surface],
+    },
+    khr_external_fence_capabilities => b"VK_KHR_external_fence_capabilities" {
+        core: Some(Version::V1_1),
+        requires: [khr_get_physical_device_properties2],
+    },
+    khr_get_surface_capabilities2 => b"VK_KHR_get_surface_capabilities2" {
+        core: None,
+        requires: [khr_surface],
+    },
+    khr_get_display_properties2 => b"VK_KHR_get_display_properties2" {
+        core: None,
+        requires: [khr_display],
+    },
+    mvk_ios_surface => b"VK_MVK_ios_surface" {
+        core: None,
+        requires: [khr_surface],
+    },
+    mvk_macos_surface => b"VK_MVK_macos_surface" {
+        core: None,
+        requires: [khr_surface],
+    },
+    ext_debug_utils => b"VK_EXT_debug_utils" {
+        core: None,
+        requires: [],
+    },
+    fuchsia_imagepipe_surface => b"VK_FUCHSIA_imagepipe_surface" {
+        core: None,
+        requires: [khr_surface],
+    },
+    ext_metal_surface => b"VK_EXT_metal_surface" {
+        core: None,
+        requires: [khr_surface],
+    },
+    khr_surface_protected_capabilities => b"VK_KHR_surface_protected_capabilities" {
+        core: None,
+        requires: [khr_get_surface_capabilities2],
+    },
+    ext_validation_features => b"VK_EXT_validation_features" {
+        core: None,
+        requires: [],
+    },
+    ext_headless_surface => b"VK_EXT_headless_surface" {
+        core: None,
+        requires: [khr_surface],
+    },
+    ext_directfb_surface => b"VK_EXT_directfb_surface" {
+        core: None,
+        requires: [khr_surface],
+    },
+}
@@ -82,6 +82,8 @@ pub use self::cpu_pool::CpuBufferPool;
 pub use self::device_local::DeviceLocalBuffer;
 pub use self::immutable::ImmutableBuffer;
 pub use self::slice::BufferSlice;
+pub use self::sparse::SparseBuffer;
+pub use self::staging::StagingBelt;
 pub use self::sys::BufferCreationError;
 pub use self::traits::BufferAccess;
 pub use self::traits::BufferInner;
@@ -94,6 +96,8 @@ pub mod cpu_access;
 pub mod cpu_pool;
 pub mod device_local;
 pub mod immutable;
+pub mod sparse;
+pub mod staging;
 pub mod sys;
 pub mod view;
 
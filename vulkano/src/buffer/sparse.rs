@@ -0,0 +1,560 @@
+// Copyright (c) 2021 The vulkano developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>,
+// at your option. All files in the project carrying such
+// notice may not be copied, modified, or distributed except
+// according to those terms.
+
+//! Buffer whose memory is only partially resident.
+//!
+//! A `SparseBuffer` is divided in pages whose size is chosen by the Vulkan implementation. Each
+//! page can be made resident or non-resident individually, which allocates or frees memory from
+//! a memory pool. Changing the residency of pages returns a `SparseBinds` object which must be
+//! submitted to a queue that supports sparse binding with `GpuFuture::then_bind_sparse`.
+//!
+//! Reading from a page that isn't resident returns undefined values, and writing to it has no
+//! effect.
+//!
+//! ```no_run
+//! # use std::sync::Arc;
+//! # use vulkano::device::Queue;
+//! use vulkano::buffer::BufferUsage;
+//! use vulkano::buffer::SparseBuffer;
+//! use vulkano::sync::GpuFuture;
+//!
+//! # let queue: Arc<Queue> = return;
+//! let buffer = SparseBuffer::<[u32]>::array(
+//!     queue.device().clone(),
+//!     1 << 24,
+//!     BufferUsage::all(),
+//!     Some(queue.family()),
+//! )
+//! .unwrap();
+//!
+//! let binds = SparseBuffer::bind_pages(&buffer, 0..4).unwrap();
+//! let future = vulkano::sync::now(queue.device().clone())
+//!     .then_bind_sparse(queue.clone(), binds)
+//!     .then_signal_semaphore_and_flush()
+//!     .unwrap();
+//! ```
+
+use smallvec::SmallVec;
+use std::cmp;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::marker::PhantomData;
+use std::mem;
+use std::ops::Range;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use crate::buffer::sys::BufferCreationError;
+use crate::buffer::sys::SparseLevel;
+use crate::buffer::sys::UnsafeBuffer;
+use crate::buffer::traits::BufferAccess;
+use crate::buffer::traits::BufferInner;
+use crate::buffer::traits::TypedBufferAccess;
+use crate::buffer::BufferUsage;
+use crate::device::Device;
+use crate::device::DeviceOwned;
+use crate::device::Queue;
+use crate::image::ImageAccess;
+use crate::instance::QueueFamily;
+use crate::memory::pool::choose_allocation_memory_type;
+use crate::memory::pool::AllocFromRequirementsFilter;
+use crate::memory::pool::AllocLayout;
+use crate::memory::pool::MappingRequirement;
+use crate::memory::pool::MemoryPool;
+use crate::memory::pool::MemoryPoolAlloc;
+use crate::memory::pool::StdMemoryPool;
+use crate::memory::DeviceMemoryAllocError;
+use crate::memory::SparseBinds;
+use crate::sync::AccessError;
+use crate::sync::Sharing;
+
+/// Buffer whose memory is made resident page by page.
+///
+/// See [the documentation of the module](index.html) for more info.
+pub struct SparseBuffer<T: ?Sized, P = Arc<StdMemoryPool>>
+where
+    P: MemoryPool,
+{
+    // Inner content.
+    inner: UnsafeBuffer,
+
+    // Pool the pages are allocated from.
+    pool: P,
+
+    // Memory type the pages are allocated from.
+    memory_type: u32,
+
+    // Size in bytes of a page, and total size of the memory range of the buffer.
+    page_size: usize,
+    memory_size: usize,
+
+    // Memory bound to each page, or `None` if the page isn't resident.
+    pages: Mutex<Vec<Option<Arc<P::Alloc>>>>,
+
+    // Queue families allowed to access this buffer.
+    queue_families: SmallVec<[u32; 4]>,
+
+    // Number of times this buffer is locked on the GPU side.
+    gpu_lock: Mutex<GpuAccess>,
+
+    // Necessary to make it compile.
+    marker: PhantomData<Box<T>>,
+}
+
+#[derive(Debug, Copy, Clone)]
+enum GpuAccess {
+    None,
+    NonExclusive { num: u32 },
+    Exclusive { num: u32 },
+}
+
+impl<T> SparseBuffer<[T]> {
+    /// Builds a new sparse buffer whose pages are allocated from the standard memory pool of the
+    /// device. All the pages are initially non-resident.
+    #[inline]
+    pub fn array<'a, I>(
+        device: Arc<Device>,
+        len: usize,
+        usage: BufferUsage,
+        queue_families: I,
+    ) -> Result<Arc<SparseBuffer<[T]>>, BufferCreationError>
+    where
+        I: IntoIterator<Item = QueueFamily<'a>>,
+    {
+        let pool = Device::standard_pool(&device);
+        SparseBuffer::array_with_pool(pool, len, usage, queue_families)
+    }
+}
+
+impl<T, P> SparseBuffer<[T], P>
+where
+    P: MemoryPool,
+{
+    /// Same as `array`, but allocates the pages from `pool`.
+    #[inline]
+    pub fn array_with_pool<'a, I>(
+        pool: P,
+        len: usize,
+        usage: BufferUsage,
+        queue_families: I,
+    ) -> Result<Arc<SparseBuffer<[T], P>>, BufferCreationError>
+    where
+        I: IntoIterator<Item = QueueFamily<'a>>,
+    {
+        unsafe { SparseBuffer::raw(pool, len * mem::size_of::<T>(), usage, queue_families) }
+    }
+}
+
+impl<T: ?Sized, P> SparseBuffer<T, P>
+where
+    P: MemoryPool,
+{
+    /// Builds a new sparse buffer without checking the size.
+    ///
+    /// # Safety
+    ///
+    /// You must ensure that the size that you pass is correct for `T`.
+    ///
+    pub unsafe fn raw<'a, I>(
+        pool: P,
+        size: usize,
+        usage: BufferUsage,
+        queue_families: I,
+    ) -> Result<Arc<SparseBuffer<T, P>>, BufferCreationError>
+    where
+        I: IntoIterator<Item = QueueFamily<'a>>,
+    {
+        let device = pool.device().clone();
+
+        let queue_families = queue_families
+            .into_iter()
+            .map(|f| f.id())
+            .collect::<SmallVec<[u32; 4]>>();
+
+        let (buffer, mem_reqs) = {
+            let sharing = if queue_families.len() >= 2 {
+                Sharing::Concurrent(queue_families.iter().cloned())
+            } else {
+                Sharing::Exclusive
+            };
+
+            let sparse = SparseLevel {
+                sparse: true,
+                sparse_residency: true,
+                sparse_aliased: false,
+            };
+
+            UnsafeBuffer::new(device.clone(), size, usage, sharing, sparse)?
+        };
+
+        let memory_type = choose_allocation_memory_type(
+            &device,
+            &mem_reqs,
+            |t| {
+                if t.is_device_local() {
+                    AllocFromRequirementsFilter::Preferred
+                } else {
+                    AllocFromRequirementsFilter::Allowed
+                }
+            },
+            MappingRequirement::DoNotMap,
+        )
+        .id();
+
+        // For sparse resources, the alignment is the size of a sparse block.
+        let page_size = mem_reqs.alignment;
+        let num_pages = (mem_reqs.size + page_size - 1) / page_size;
+
+        Ok(Arc::new(SparseBuffer {
+            inner: buffer,
+            pool,
+            memory_type,
+            page_size,
+            memory_size: mem_reqs.size,
+            pages: Mutex::new((0..num_pages).map(|_| None).collect()),
+            queue_families,
+            gpu_lock: Mutex::new(GpuAccess::None),
+            marker: PhantomData,
+        }))
+    }
+
+    /// Returns the size in bytes of a page of the buffer.
+    #[inline]
+    pub fn page_size(&self) -> usize {
+        self.page_size
+    }
+
+    /// Returns the number of pages of the buffer.
+    #[inline]
+    pub fn num_pages(&self) -> usize {
+        self.pages.lock().unwrap().len()
+    }
+
+    /// Returns the range of pages that contains the given range of bytes of the buffer.
+    #[inline]
+    pub fn pages_of_range(&self, range: Range<usize>) -> Range<usize> {
+        let start = range.start / self.page_size;
+        let end = (range.end + self.page_size - 1) / self.page_size;
+        start..cmp::max(start, end)
+    }
+
+    /// Returns true if the page is resident.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if `page` is out of range.
+    ///
+    #[inline]
+    pub fn is_page_resident(&self, page: usize) -> bool {
+        self.pages.lock().unwrap()[page].is_some()
+    }
+
+    /// Returns the number of resident pages.
+    #[inline]
+    pub fn num_resident_pages(&self) -> usize {
+        self.pages
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|p| p.is_some())
+            .count()
+    }
+
+    /// Returns the queue families this buffer can be used on.
+    // TODO: use a custom iterator
+    #[inline]
+    pub fn queue_families(&self) -> Vec<QueueFamily<'_>> {
+        self.queue_families
+            .iter()
+            .map(|&num| {
+                self.device()
+                    .physical_device()
+                    .queue_family_by_id(num)
+                    .unwrap()
+            })
+            .collect()
+    }
+}
+
+impl<T: ?Sized, P> SparseBuffer<T, P>
+where
+    T: Send + Sync + 'static,
+    P: MemoryPool + Send + Sync + 'static,
+    P::Alloc: Send + Sync + 'static,
+{
+    /// Allocates memory for the pages of the range that aren't resident yet, and returns the
+    /// operations that bind this memory to the buffer.
+    ///
+    /// The pages are considered resident as soon as this function returns. The returned binds
+    /// must be submitted with `GpuFuture::then_bind_sparse` before the GPU accesses these pages.
+    ///
+    /// If the allocation fails, no page is made resident.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if the range is out of the pages of the buffer.
+    ///
+    pub fn bind_pages(
+        me: &Arc<Self>,
+        pages: Range<usize>,
+    ) -> Result<SparseBinds, DeviceMemoryAllocError> {
+        let mut table = me.pages.lock().unwrap();
+        assert!(pages.start <= pages.end && pages.end <= table.len());
+
+        let mem_ty = me
+            .device()
+            .physical_device()
+            .memory_type_by_id(me.memory_type)
+            .unwrap();
+
+        let mut allocs = Vec::new();
+        for page in pages.filter(|&page| table[page].is_none()) {
+            let alloc = me.pool.alloc_generic(
+                mem_ty,
+                me.page_size,
+                me.page_size,
+                AllocLayout::Linear,
+                MappingRequirement::DoNotMap,
+            )?;
+            debug_assert!((alloc.offset() % me.page_size) == 0);
+            allocs.push((page, Arc::new(alloc)));
+        }
+
+        let mut binds = SparseBinds::new(me.device().clone());
+        for (page, alloc) in allocs {
+            let (offset, size) = me.page_range(page);
+            binds.add_buffer_bind(
+                &me.inner,
+                offset,
+                size,
+                Some((alloc.memory(), alloc.offset())),
+            );
+            table[page] = Some(alloc);
+        }
+        binds.keep_alive(me.clone());
+
+        Ok(binds)
+    }
+
+    /// Returns the operations that unbind the memory of the pages of the range that are
+    /// resident. The memory is freed once the operations have been executed.
+    ///
+    /// The pages are considered non-resident as soon as this function returns.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if the range is out of the pages of the buffer.
+    ///
+    /// # Safety
+    ///
+    /// - The returned binds must be submitted with `GpuFuture::then_bind_sparse`. If they are
+    ///   dropped instead, the memory is freed while it is still bound to the buffer.
+    /// - The GPU operations that access the pages must be finished or ordered before the
+    ///   submission of the binds.
+    ///
+    pub unsafe fn unbind_pages(me: &Arc<Self>, pages: Range<usize>) -> SparseBinds {
+        let mut table = me.pages.lock().unwrap();
+        assert!(pages.start <= pages.end && pages.end <= table.len());
+
+        let mut binds = SparseBinds::new(me.device().clone());
+        for page in pages {
+            if let Some(alloc) = table[page].take() {
+                let (offset, size) = me.page_range(page);
+                binds.add_buffer_bind(&me.inner, offset, size, None);
+                binds.keep_alive(alloc);
+            }
+        }
+        binds.keep_alive(me.clone());
+
+        binds
+    }
+
+    // Returns the offset and size of a page within the memory range of the buffer.
+    #[inline]
+    fn page_range(&self, page: usize) -> (usize, usize) {
+        let offset = page * self.page_size;
+        (offset, cmp::min(self.page_size, self.memory_size - offset))
+    }
+}
+
+unsafe impl<T: ?Sized, P> DeviceOwned for SparseBuffer<T, P>
+where
+    P: MemoryPool,
+{
+    #[inline]
+    fn device(&self) -> &Arc<Device> {
+        self.inner.device()
+    }
+}
+
+unsafe impl<T: ?Sized, P> BufferAccess for SparseBuffer<T, P>
+where
+    T: 'static + Send + Sync,
+    P: MemoryPool,
+{
+    #[inline]
+    fn inner(&self) -> BufferInner<'_> {
+        BufferInner {
+            buffer: &self.inner,
+            offset: 0,
+        }
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        self.inner.size()
+    }
+
+    #[inline]
+    fn conflicts_buffer(&self, other: &dyn BufferAccess) -> bool {
+        self.conflict_key() == other.conflict_key() // TODO:
+    }
+
+    #[inline]
+    fn conflicts_image(&self, other: &dyn ImageAccess) -> bool {
+        false
+    }
+
+    #[inline]
+    fn conflict_key(&self) -> (u64, usize) {
+        (self.inner.key(), 0)
+    }
+
+    #[inline]
+    fn try_gpu_lock(&self, exclusive: bool, _: &Queue) -> Result<(), AccessError> {
+        let mut lock = self.gpu_lock.lock().unwrap();
+        match &mut *lock {
+            a @ &mut GpuAccess::None => {
+                if exclusive {
+                    *a = GpuAccess::Exclusive { num: 1 };
+                } else {
+                    *a = GpuAccess::NonExclusive { num: 1 };
+                }
+
+                Ok(())
+            }
+            &mut GpuAccess::NonExclusive { ref mut num } => {
+                if exclusive {
+                    Err(AccessError::AlreadyInUse)
+                } else {
+                    *num += 1;
+                    Ok(())
+                }
+            }
+            &mut GpuAccess::Exclusive { .. } => Err(AccessError::AlreadyInUse),
+        }
+    }
+
+    #[inline]
+    unsafe fn increase_gpu_lock(&self) {
+        let mut lock = self.gpu_lock.lock().unwrap();
+        match *lock {
+            GpuAccess::None => panic!(),
+            GpuAccess::NonExclusive { ref mut num } => {
+                debug_assert!(*num >= 1);
+                *num += 1;
+            }
+            GpuAccess::Exclusive { ref mut num } => {
+                debug_assert!(*num >= 1);
+                *num += 1;
+            }
+        }
+    }
+
+    #[inline]
+    unsafe fn unlock(&self) {
+        let mut lock = self.gpu_lock.lock().unwrap();
+
+        match *lock {
+            GpuAccess::None => panic!("Tried to unlock a buffer that isn't locked"),
+            GpuAccess::NonExclusive { ref mut num } => {
+                assert!(*num >= 1);
+                *num -= 1;
+                if *num >= 1 {
+                    return;
+                }
+            }
+            GpuAccess::Exclusive { ref mut num } => {
+                assert!(*num >= 1);
+                *num -= 1;
+                if *num >= 1 {
+                    return;
+                }
+            }
+        };
+
+        *lock = GpuAccess::None;
+    }
+}
+
+unsafe impl<T: ?Sized, P> TypedBufferAccess for SparseBuffer<T, P>
+where
+    T: 'static + Send + Sync,
+    P: MemoryPool,
+{
+    type Content = T;
+}
+
+impl<T: ?Sized, P> PartialEq for SparseBuffer<T, P>
+where
+    T: 'static + Send + Sync,
+    P: MemoryPool,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.inner() == other.inner() && self.size() == other.size()
+    }
+}
+
+impl<T: ?Sized, P> Eq for SparseBuffer<T, P>
+where
+    T: 'static + Send + Sync,
+    P: MemoryPool,
+{
+}
+
+impl<T: ?Sized, P> Hash for SparseBuffer<T, P>
+where
+    T: 'static + Send + Sync,
+    P: MemoryPool,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inner().hash(state);
+        self.size().hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::buffer::sparse::SparseBuffer;
+    use crate::buffer::BufferUsage;
+
+    #[test]
+    fn bind_and_unbind_pages() {
+        let (device, queue) = gfx_dev_and_queue!(sparse_binding, sparse_residency_buffer);
+
+        let buffer =
+            SparseBuffer::<[u32]>::array(device, 1 << 20, BufferUsage::all(), Some(queue.family()))
+                .unwrap();
+        assert!(buffer.num_pages() >= 1);
+        assert_eq!(buffer.num_resident_pages(), 0);
+
+        let binds = SparseBuffer::bind_pages(&buffer, 0..1).unwrap();
+        assert!(!binds.is_empty());
+        assert!(buffer.is_page_resident(0));
+
+        // Binding a page that is already resident doesn't do anything.
+        assert!(SparseBuffer::bind_pages(&buffer, 0..1).unwrap().is_empty());
+
+        let binds = unsafe { SparseBuffer::unbind_pages(&buffer, 0..buffer.num_pages()) };
+        assert!(!binds.is_empty());
+        assert_eq!(buffer.num_resident_pages(), 0);
+    }
+}
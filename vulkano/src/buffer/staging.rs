@@ -0,0 +1,611 @@
+// Copyright (c) 2021 The vulkano developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>,
+// at your option. All files in the project carrying such
+// notice may not be copied, modified, or distributed except
+// according to those terms.
+
+//! Ring of host-visible memory used as the source of transfer operations.
+//!
+//! A `StagingBelt` is similar to a `CpuBufferPool`, except that it isn't typed: each chunk can
+//! hold a different type of data and can have its own alignment. This makes it possible to stage
+//! the data of many different buffers and images in the same memory-mapped buffer.
+
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::iter;
+use std::marker::PhantomData;
+use std::mem;
+use std::ops::Range;
+use std::ptr;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+
+use crate::buffer::sys::BufferCreationError;
+use crate::buffer::sys::SparseLevel;
+use crate::buffer::sys::UnsafeBuffer;
+use crate::buffer::traits::BufferAccess;
+use crate::buffer::traits::BufferInner;
+use crate::buffer::traits::TypedBufferAccess;
+use crate::buffer::BufferUsage;
+use crate::device::Device;
+use crate::device::DeviceOwned;
+use crate::device::Queue;
+use crate::image::ImageAccess;
+use crate::memory::pool::AllocFromRequirementsFilter;
+use crate::memory::pool::AllocLayout;
+use crate::memory::pool::MappingRequirement;
+use crate::memory::pool::MemoryPool;
+use crate::memory::pool::MemoryPoolAlloc;
+use crate::memory::pool::PotentialDedicatedAllocation;
+use crate::memory::pool::StdMemoryPool;
+use crate::memory::DedicatedAlloc;
+use crate::memory::DeviceMemoryAllocError;
+use crate::sync::AccessError;
+use crate::sync::Sharing;
+
+use crate::OomError;
+
+/// Ring buffer of host-visible memory that data can be staged into before being transferred to
+/// device-local resources.
+///
+/// Each call to `chunk` or `chunk_iter` writes data to a free region of the current buffer and
+/// returns a `StagingBeltChunk` that can be used as the source of a copy command. The region is
+/// reclaimed when the chunk is destroyed, which normally happens when the command buffer that
+/// uses it has finished executing.
+///
+/// If the current buffer is full, a new buffer twice as large is allocated. The old buffer is
+/// destroyed once all of its chunks have been destroyed.
+pub struct StagingBelt<A = Arc<StdMemoryPool>>
+where
+    A: MemoryPool,
+{
+    // The device of the belt.
+    device: Arc<Device>,
+
+    // The memory pool to use for allocations.
+    pool: A,
+
+    // Current buffer from which chunks are allocated.
+    current_buffer: Mutex<Option<Arc<BeltBuffer<A>>>>,
+}
+
+struct BeltBuffer<A>
+where
+    A: MemoryPool,
+{
+    // Inner content.
+    inner: UnsafeBuffer,
+
+    // The memory held by the buffer.
+    memory: PotentialDedicatedAllocation<A::Alloc>,
+
+    // Byte ranges that are reserved, and the offset where the next chunk should be allocated.
+    state: Mutex<BeltBufferState>,
+
+    // Size of the buffer in bytes.
+    capacity: usize,
+}
+
+struct BeltBufferState {
+    chunks_in_use: Vec<Range<usize>>,
+    next_offset: usize,
+}
+
+/// A region of a `StagingBelt` that holds some data.
+///
+/// When this object is destroyed, the region is automatically reclaimed by the belt.
+pub struct StagingBeltChunk<T: ?Sized, A = Arc<StdMemoryPool>>
+where
+    A: MemoryPool,
+{
+    buffer: Arc<BeltBuffer<A>>,
+
+    // Offset of the chunk within `buffer`, in bytes.
+    offset: usize,
+
+    // Size of the chunk in bytes. If this is 0, then no entry was added to `chunks_in_use`.
+    size: usize,
+
+    // Necessary to make it compile.
+    marker: PhantomData<Box<T>>,
+}
+
+impl StagingBelt {
+    /// Builds a `StagingBelt`. No memory is allocated until the first chunk is requested.
+    #[inline]
+    pub fn new(device: Arc<Device>) -> StagingBelt {
+        let pool = Device::standard_pool(&device);
+
+        StagingBelt {
+            device,
+            pool,
+            current_buffer: Mutex::new(None),
+        }
+    }
+}
+
+impl<A> StagingBelt<A>
+where
+    A: MemoryPool,
+{
+    /// Returns the current capacity of the belt in bytes.
+    pub fn capacity(&self) -> usize {
+        match *self.current_buffer.lock().unwrap() {
+            None => 0,
+            Some(ref buf) => buf.capacity,
+        }
+    }
+
+    /// Makes sure that the capacity is at least `capacity` bytes. Allocates a new buffer if that
+    /// isn't the case.
+    pub fn reserve(&self, capacity: usize) -> Result<(), DeviceMemoryAllocError> {
+        let mut cur_buf = self.current_buffer.lock().unwrap();
+
+        // Check current capacity.
+        match *cur_buf {
+            Some(ref buf) if buf.capacity >= capacity => {
+                return Ok(());
+            }
+            _ => (),
+        };
+
+        self.reset_buf(&mut cur_buf, capacity)
+    }
+
+    /// Writes `data` to a new chunk of the belt.
+    ///
+    /// If no region is available, a new buffer will automatically be allocated.
+    #[inline]
+    pub fn chunk<T>(&self, data: T) -> Result<StagingBeltChunk<T, A>, DeviceMemoryAllocError>
+    where
+        T: Send + Sync + 'static,
+    {
+        let chunk = self.alloc(mem::size_of::<T>(), mem::align_of::<T>())?;
+
+        if chunk.size != 0 {
+            unsafe {
+                let mem_off = chunk.buffer.memory.offset();
+                let range = chunk.offset + mem_off..chunk.offset + chunk.size + mem_off;
+                let mut mapping = chunk
+                    .buffer
+                    .memory
+                    .mapped_memory()
+                    .unwrap()
+                    .read_write::<T>(range);
+                ptr::write(&mut *mapping, data);
+            }
+        }
+
+        Ok(chunk)
+    }
+
+    /// Writes the elements of `data` to a new chunk of the belt.
+    ///
+    /// If no region is available, a new buffer will automatically be allocated.
+    ///
+    /// # Panic
+    ///
+    /// Panics if the length of the iterator didn't match the actual number of elements.
+    ///
+    #[inline]
+    pub fn chunk_iter<T, I>(
+        &self,
+        data: I,
+    ) -> Result<StagingBeltChunk<[T], A>, DeviceMemoryAllocError>
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+        T: Send + Sync + 'static,
+    {
+        self.chunk_aligned(data, 1)
+    }
+
+    /// Same as `chunk_iter`, but the offset of the chunk within its buffer is a multiple of
+    /// `alignment` in addition to the alignment of `T`.
+    ///
+    /// This is necessary when the chunk is used as the source of a copy to an image, in which
+    /// case the offset must be a multiple of 4 and of the size of a texel block of the format.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if `alignment` is 0.
+    /// - Panics if the length of the iterator didn't match the actual number of elements.
+    ///
+    pub fn chunk_aligned<T, I>(
+        &self,
+        data: I,
+        alignment: usize,
+    ) -> Result<StagingBeltChunk<[T], A>, DeviceMemoryAllocError>
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+        T: Send + Sync + 'static,
+    {
+        assert_ne!(alignment, 0);
+
+        let data = data.into_iter();
+        let alignment = lcm(alignment, mem::align_of::<T>());
+        let size = match data.len().checked_mul(mem::size_of::<T>()) {
+            Some(s) => s,
+            None => {
+                return Err(DeviceMemoryAllocError::OomError(
+                    OomError::OutOfDeviceMemory,
+                ))
+            }
+        };
+
+        let chunk = self.alloc(size, alignment)?;
+
+        // Write `data` in the memory.
+        if size != 0 {
+            unsafe {
+                let mem_off = chunk.buffer.memory.offset();
+                let range = chunk.offset + mem_off..chunk.offset + size + mem_off;
+                let mut mapping = chunk
+                    .buffer
+                    .memory
+                    .mapped_memory()
+                    .unwrap()
+                    .read_write::<[T]>(range);
+
+                let mut written = 0;
+                let expected = mapping.len();
+                for (o, i) in mapping.iter_mut().zip(data) {
+                    ptr::write(o, i);
+                    written += 1;
+                }
+                assert_eq!(
+                    written, expected,
+                    "Iterator passed to StagingBelt::chunk_iter has a mismatch between reported \
+                     length and actual number of elements"
+                );
+            }
+        }
+
+        Ok(chunk)
+    }
+
+    // Reserves a region of `size` bytes whose offset is a multiple of `alignment`, allocating a
+    // new buffer if necessary.
+    fn alloc<T: ?Sized>(
+        &self,
+        size: usize,
+        alignment: usize,
+    ) -> Result<StagingBeltChunk<T, A>, DeviceMemoryAllocError> {
+        let mut mutex = self.current_buffer.lock().unwrap();
+
+        let offset = match self.try_alloc(&mut mutex, size, alignment) {
+            Some(offset) => offset,
+            None => {
+                let next_capacity = match *mutex {
+                    Some(ref b) if size + alignment < b.capacity => 2 * b.capacity,
+                    _ => 2 * (size + alignment),
+                };
+
+                self.reset_buf(&mut mutex, next_capacity)?;
+
+                match self.try_alloc(&mut mutex, size, alignment) {
+                    Some(offset) => offset,
+                    None => unreachable!(),
+                }
+            }
+        };
+
+        Ok(StagingBeltChunk {
+            buffer: mutex.as_ref().unwrap().clone(),
+            offset,
+            size,
+            marker: PhantomData,
+        })
+    }
+
+    // Creates a new buffer and sets it as current. The capacity is in bytes.
+    //
+    // `cur_buf_mutex` must be an active lock of `self.current_buffer`.
+    fn reset_buf(
+        &self,
+        cur_buf_mutex: &mut MutexGuard<Option<Arc<BeltBuffer<A>>>>,
+        capacity: usize,
+    ) -> Result<(), DeviceMemoryAllocError> {
+        unsafe {
+            let (buffer, mem_reqs) = match UnsafeBuffer::new(
+                self.device.clone(),
+                capacity,
+                BufferUsage::transfer_source(),
+                Sharing::Exclusive::<iter::Empty<_>>,
+                SparseLevel::none(),
+            ) {
+                Ok(b) => b,
+                Err(BufferCreationError::AllocError(err)) => return Err(err),
+                Err(_) => unreachable!(), // We don't use sparse binding, therefore the other
+                                          // errors can't happen
+            };
+
+            let mem = MemoryPool::alloc_from_requirements(
+                &self.pool,
+                &mem_reqs,
+                AllocLayout::Linear,
+                MappingRequirement::Map,
+                DedicatedAlloc::Buffer(&buffer),
+                |_| AllocFromRequirementsFilter::Allowed,
+            )?;
+            debug_assert!((mem.offset() % mem_reqs.alignment) == 0);
+            debug_assert!(mem.mapped_memory().is_some());
+            buffer.bind_memory(mem.memory(), mem.offset())?;
+
+            **cur_buf_mutex = Some(Arc::new(BeltBuffer {
+                inner: buffer,
+                memory: mem,
+                state: Mutex::new(BeltBufferState {
+                    chunks_in_use: vec![],
+                    next_offset: 0,
+                }),
+                capacity,
+            }));
+
+            Ok(())
+        }
+    }
+
+    // Tries to reserve `size` bytes in the current buffer, and returns the offset of the region.
+    //
+    // `cur_buf_mutex` must be an active lock of `self.current_buffer`.
+    //
+    // Returns `None` if there is no region available in the current buffer.
+    fn try_alloc(
+        &self,
+        cur_buf_mutex: &mut MutexGuard<Option<Arc<BeltBuffer<A>>>>,
+        size: usize,
+        alignment: usize,
+    ) -> Option<usize> {
+        // Grab the current buffer. Return `None` if the belt wasn't "initialized" yet.
+        let current_buffer = match **cur_buf_mutex {
+            Some(ref b) => b,
+            None => return None,
+        };
+
+        // We special case when 0 bytes are requested. Polluting the list of allocated chunks
+        // with chunks of length 0 means that we will have troubles deallocating.
+        if size == 0 {
+            return Some(0);
+        }
+
+        let mut state = current_buffer.state.lock().unwrap();
+
+        let is_free = |state: &BeltBufferState, start: usize| {
+            start + size <= current_buffer.capacity
+                && !state
+                    .chunks_in_use
+                    .iter()
+                    .any(|c| c.start < start + size && start < c.end)
+        };
+
+        // Try after the last allocated chunk first, then wrap around to the start.
+        let tentative = align_up(state.next_offset, alignment);
+        let offset = if is_free(&state, tentative) {
+            tentative
+        } else if is_free(&state, 0) {
+            0
+        } else {
+            return None;
+        };
+
+        state.chunks_in_use.push(offset..offset + size);
+        state.next_offset = offset + size;
+        Some(offset)
+    }
+}
+
+#[inline]
+fn align_up(value: usize, alignment: usize) -> usize {
+    (value + alignment - 1) / alignment * alignment
+}
+
+// Least common multiple, used to combine two alignment requirements.
+#[inline]
+pub(crate) fn lcm(a: usize, b: usize) -> usize {
+    let (mut x, mut y) = (a, b);
+    while y != 0 {
+        let t = x % y;
+        x = y;
+        y = t;
+    }
+    a / x * b
+}
+
+unsafe impl<A> DeviceOwned for StagingBelt<A>
+where
+    A: MemoryPool,
+{
+    #[inline]
+    fn device(&self) -> &Arc<Device> {
+        &self.device
+    }
+}
+
+unsafe impl<T: ?Sized, A> BufferAccess for StagingBeltChunk<T, A>
+where
+    A: MemoryPool,
+{
+    #[inline]
+    fn inner(&self) -> BufferInner<'_> {
+        BufferInner {
+            buffer: &self.buffer.inner,
+            offset: self.offset,
+        }
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    #[inline]
+    fn conflicts_buffer(&self, other: &dyn BufferAccess) -> bool {
+        self.conflict_key() == other.conflict_key()
+    }
+
+    #[inline]
+    fn conflicts_image(&self, _other: &dyn ImageAccess) -> bool {
+        false
+    }
+
+    #[inline]
+    fn conflict_key(&self) -> (u64, usize) {
+        (
+            self.buffer.inner.key(),
+            // ensure the special cased empty chunks don't collide with a regular chunk at 0
+            if self.size == 0 {
+                usize::MAX
+            } else {
+                self.offset
+            },
+        )
+    }
+
+    #[inline]
+    fn try_gpu_lock(&self, exclusive_access: bool, _: &Queue) -> Result<(), AccessError> {
+        // The content is written once on creation and is never modified afterwards, so any
+        // number of reads can happen at the same time.
+        if exclusive_access {
+            return Err(AccessError::ExclusiveDenied);
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    unsafe fn increase_gpu_lock(&self) {}
+
+    #[inline]
+    unsafe fn unlock(&self) {}
+}
+
+unsafe impl<T: ?Sized, A> TypedBufferAccess for StagingBeltChunk<T, A>
+where
+    A: MemoryPool,
+{
+    type Content = T;
+}
+
+unsafe impl<T: ?Sized, A> DeviceOwned for StagingBeltChunk<T, A>
+where
+    A: MemoryPool,
+{
+    #[inline]
+    fn device(&self) -> &Arc<Device> {
+        self.buffer.inner.device()
+    }
+}
+
+impl<T: ?Sized, A> Drop for StagingBeltChunk<T, A>
+where
+    A: MemoryPool,
+{
+    fn drop(&mut self) {
+        // If `size` is 0, then no entry was added in the chunks.
+        if self.size == 0 {
+            return;
+        }
+
+        let mut state = self.buffer.state.lock().unwrap();
+        let chunk_num = state
+            .chunks_in_use
+            .iter()
+            .position(|c| c.start == self.offset)
+            .unwrap();
+        state.chunks_in_use.remove(chunk_num);
+    }
+}
+
+impl<T: ?Sized, A> PartialEq for StagingBeltChunk<T, A>
+where
+    A: MemoryPool,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.inner() == other.inner() && self.size() == other.size()
+    }
+}
+
+impl<T: ?Sized, A> Eq for StagingBeltChunk<T, A> where A: MemoryPool {}
+
+impl<T: ?Sized, A> Hash for StagingBeltChunk<T, A>
+where
+    A: MemoryPool,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inner().hash(state);
+        self.size().hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::buffer::BufferAccess;
+    use crate::buffer::StagingBelt;
+
+    #[test]
+    fn basic_create() {
+        let (device, _) = gfx_dev_and_queue!();
+        let belt = StagingBelt::new(device);
+        assert_eq!(belt.capacity(), 0);
+    }
+
+    #[test]
+    fn chunks_dont_overlap() {
+        let (device, _) = gfx_dev_and_queue!();
+        let belt = StagingBelt::new(device);
+
+        let a = belt.chunk_iter(0..16u8).unwrap();
+        let b = belt.chunk(12u32).unwrap();
+        let c = belt.chunk_aligned(0..3u16, 16).unwrap();
+
+        assert_eq!(a.size(), 16);
+        assert_eq!(b.size(), 4);
+        assert_eq!(c.size(), 6);
+        assert_eq!(b.inner().offset % 4, 0);
+        assert_eq!(c.inner().offset % 16, 0);
+
+        let mut ranges = [&a as &dyn BufferAccess, &b, &c]
+            .iter()
+            .filter(|c| c.inner().buffer.key() == a.inner().buffer.key())
+            .map(|c| c.inner().offset..c.inner().offset + c.size())
+            .collect::<Vec<_>>();
+        ranges.sort_by_key(|r| r.start);
+        for pair in ranges.windows(2) {
+            assert!(pair[0].end <= pair[1].start);
+        }
+    }
+
+    #[test]
+    fn reuses_freed_regions() {
+        let (device, _) = gfx_dev_and_queue!();
+        let belt = StagingBelt::new(device);
+        belt.reserve(64).unwrap();
+
+        let a = belt.chunk_iter(0..64u8).unwrap();
+        let offset = a.inner().offset;
+        drop(a);
+
+        let b = belt.chunk_iter(0..64u8).unwrap();
+        assert_eq!(b.inner().offset, offset);
+        assert_eq!(belt.capacity(), 64);
+    }
+
+    #[test]
+    fn grows_when_full() {
+        let (device, _) = gfx_dev_and_queue!();
+        let belt = StagingBelt::new(device);
+        belt.reserve(64).unwrap();
+
+        let _a = belt.chunk_iter(0..64u8).unwrap();
+        let _b = belt.chunk_iter(0..64u8).unwrap();
+        assert!(belt.capacity() >= 128);
+    }
+}
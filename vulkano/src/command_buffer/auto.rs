@@ -12,6 +12,7 @@ use crate::buffer::TypedBufferAccess;
 use crate::command_buffer::pool::standard::StandardCommandPoolAlloc;
 use crate::command_buffer::pool::standard::StandardCommandPoolBuilder;
 use crate::command_buffer::pool::CommandPool;
+use crate::command_buffer::pool::CommandPoolAlloc;
 use crate::command_buffer::pool::CommandPoolBuilderAlloc;
 use crate::command_buffer::synced::SyncCommandBuffer;
 use crate::command_buffer::synced::SyncCommandBufferBuilder;
@@ -30,6 +31,7 @@ use crate::command_buffer::CommandBufferLevel;
 use crate::command_buffer::DispatchIndirectCommand;
 use crate::command_buffer::DrawIndexedIndirectCommand;
 use crate::command_buffer::DrawIndirectCommand;
+use crate::command_buffer::DrawMeshTasksIndirectCommand;
 use crate::command_buffer::DynamicState;
 use crate::command_buffer::PrimaryCommandBuffer;
 use crate::command_buffer::SecondaryCommandBuffer;
@@ -68,6 +70,7 @@ use crate::render_pass::Subpass;
 use crate::sampler::Filter;
 use crate::sync::AccessCheckError;
 use crate::sync::AccessFlagBits;
+use crate::sync::Event;
 use crate::sync::GpuFuture;
 use crate::sync::PipelineMemoryAccess;
 use crate::sync::PipelineStage;
@@ -112,6 +115,9 @@ pub struct AutoCommandBufferBuilder<L, P = StandardCommandPoolBuilder> {
     // If any queries are active, this hashmap contains their state.
     query_state: FnvHashMap<vk::QueryType, QueryState>,
 
+    // Functions to call each time an execution of the command buffer has finished.
+    on_finished: Vec<Box<dyn Fn() + Send + Sync>>,
+
     _data: PhantomData<L>,
 }
 
@@ -552,6 +558,35 @@ impl<L> AutoCommandBufferBuilder<L, StandardCommandPoolBuilder> {
         level: CommandBufferLevel<F>,
         flags: Flags,
     ) -> Result<AutoCommandBufferBuilder<L, StandardCommandPoolBuilder>, OomError>
+    where
+        F: FramebufferAbstract + Clone + Send + Sync + 'static,
+    {
+        let pool = Device::standard_command_pool(&device, queue_family);
+        let pool_builder_alloc = pool
+            .alloc(!matches!(level, CommandBufferLevel::Primary), 1)?
+            .next()
+            .expect("Requested one command buffer from the command pool, but got zero.");
+
+        unsafe {
+            AutoCommandBufferBuilder::with_pool_builder_alloc(
+                pool_builder_alloc,
+                queue_family,
+                level,
+                flags,
+            )
+        }
+    }
+
+    // Starts recording into an existing allocation. Private.
+    //
+    // Safety: the command buffer of `pool_builder_alloc` must be in the initial state or
+    // resettable, and must not be pending execution.
+    unsafe fn with_pool_builder_alloc<F>(
+        pool_builder_alloc: StandardCommandPoolBuilder,
+        queue_family: QueueFamily,
+        level: CommandBufferLevel<F>,
+        flags: Flags,
+    ) -> Result<AutoCommandBufferBuilder<L, StandardCommandPoolBuilder>, OomError>
     where
         F: FramebufferAbstract + Clone + Send + Sync + 'static,
     {
@@ -591,26 +626,20 @@ impl<L> AutoCommandBufferBuilder<L, StandardCommandPoolBuilder> {
             }
         };
 
-        unsafe {
-            let pool = Device::standard_command_pool(&device, queue_family);
-            let pool_builder_alloc = pool
-                .alloc(!matches!(level, CommandBufferLevel::Primary), 1)?
-                .next()
-                .expect("Requested one command buffer from the command pool, but got zero.");
-            let inner = SyncCommandBufferBuilder::new(pool_builder_alloc.inner(), level, flags)?;
-
-            Ok(AutoCommandBufferBuilder {
-                inner,
-                pool_builder_alloc,
-                state_cacher: StateCacher::new(),
-                queue_family_id: queue_family.id(),
-                render_pass_state,
-                query_state: FnvHashMap::default(),
-                inheritance,
-                flags,
-                _data: PhantomData,
-            })
-        }
+        let inner = SyncCommandBufferBuilder::new(pool_builder_alloc.inner(), level, flags)?;
+
+        Ok(AutoCommandBufferBuilder {
+            inner,
+            pool_builder_alloc,
+            state_cacher: StateCacher::new(),
+            queue_family_id: queue_family.id(),
+            render_pass_state,
+            query_state: FnvHashMap::default(),
+            on_finished: Vec::new(),
+            inheritance,
+            flags,
+            _data: PhantomData,
+        })
     }
 }
 
@@ -691,8 +720,22 @@ where
             inner: self.inner.build()?,
             pool_alloc: self.pool_builder_alloc.into_alloc(),
             submit_state,
+            on_finished: self.on_finished,
         })
     }
+
+    /// Registers a function to call each time an execution of the command buffer has finished
+    /// on the GPU.
+    ///
+    /// The function is called when the command buffer is unlocked by the future of a successful
+    /// submission, and never if locking the resources of a submission fails.
+    #[inline]
+    pub(crate) fn on_finished<F>(&mut self, f: F)
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.on_finished.push(Box::new(f));
+    }
 }
 
 impl<P> AutoCommandBufferBuilder<SecondaryAutoCommandBuffer<P::Alloc>, P>
@@ -1456,6 +1499,7 @@ impl<L, P> AutoCommandBufferBuilder<L, P> {
             // TODO: must check that pipeline is compatible with render pass
 
             self.ensure_inside_render_pass_inline(&pipeline)?;
+            check_vertex_input_pipeline(&pipeline)?;
             check_dynamic_state_validity(&pipeline, dynamic)?;
             check_push_constants_validity(&pipeline, &constants)?;
             check_descriptor_sets_validity(&pipeline, &sets)?;
@@ -1531,6 +1575,7 @@ impl<L, P> AutoCommandBufferBuilder<L, P> {
             // TODO: must check that pipeline is compatible with render pass
 
             self.ensure_inside_render_pass_inline(&pipeline)?;
+            check_vertex_input_pipeline(&pipeline)?;
             check_indirect_buffer(self.device(), &indirect_buffer)?;
             check_dynamic_state_validity(&pipeline, dynamic)?;
             check_push_constants_validity(&pipeline, &constants)?;
@@ -1605,6 +1650,7 @@ impl<L, P> AutoCommandBufferBuilder<L, P> {
             // TODO: must check that pipeline is compatible with render pass
 
             self.ensure_inside_render_pass_inline(&pipeline)?;
+            check_vertex_input_pipeline(&pipeline)?;
             let ib_infos = check_index_buffer(self.device(), &index_buffer)?;
             check_dynamic_state_validity(&pipeline, dynamic)?;
             check_push_constants_validity(&pipeline, &constants)?;
@@ -1694,6 +1740,7 @@ impl<L, P> AutoCommandBufferBuilder<L, P> {
             // TODO: must check that pipeline is compatible with render pass
 
             self.ensure_inside_render_pass_inline(&pipeline)?;
+            check_vertex_input_pipeline(&pipeline)?;
             let ib_infos = check_index_buffer(self.device(), &index_buffer)?;
             check_indirect_buffer(self.device(), &indirect_buffer)?;
             check_dynamic_state_validity(&pipeline, dynamic)?;
@@ -1744,6 +1791,130 @@ impl<L, P> AutoCommandBufferBuilder<L, P> {
         }
     }
 
+    /// Perform a single draw operation using a graphics pipeline that has a mesh shader.
+    ///
+    /// `group_counts` is the number of task shader workgroups to dispatch, or the number of mesh
+    /// shader workgroups if the pipeline has no task shader. If only the `nv_mesh_shader`
+    /// extension is enabled, the Y and Z group counts must be 1.
+    #[inline]
+    pub fn draw_mesh_tasks<Gp, S, Pc, Do, Doi>(
+        &mut self,
+        pipeline: Gp,
+        dynamic: &DynamicState,
+        group_counts: [u32; 3],
+        sets: S,
+        constants: Pc,
+        dynamic_offsets: Do,
+    ) -> Result<&mut Self, DrawMeshTasksError>
+    where
+        Gp: GraphicsPipelineAbstract + Send + Sync + 'static + Clone, // TODO: meh for Clone
+        S: DescriptorSetsCollection,
+        Do: IntoIterator<Item = u32, IntoIter = Doi>,
+        Doi: Iterator<Item = u32> + Send + Sync + 'static,
+    {
+        unsafe {
+            // TODO: must check that pipeline is compatible with render pass
+
+            self.ensure_inside_render_pass_inline(&pipeline)?;
+            check_draw_mesh_tasks(self.device(), &pipeline, Some(group_counts))?;
+            check_dynamic_state_validity(&pipeline, dynamic)?;
+            check_push_constants_validity(&pipeline, &constants)?;
+            check_descriptor_sets_validity(&pipeline, &sets)?;
+
+            if let StateCacherOutcome::NeedChange =
+                self.state_cacher.bind_graphics_pipeline(&pipeline)
+            {
+                self.inner.bind_pipeline_graphics(pipeline.clone());
+            }
+
+            let dynamic = self.state_cacher.dynamic_state(dynamic);
+
+            push_constants(&mut self.inner, pipeline.clone(), constants);
+            set_state(&mut self.inner, &dynamic);
+            descriptor_sets(
+                &mut self.inner,
+                &mut self.state_cacher,
+                true,
+                pipeline.clone(),
+                sets,
+                dynamic_offsets,
+            )?;
+
+            debug_assert!(self.queue_family().supports_graphics());
+
+            self.inner.draw_mesh_tasks(group_counts);
+            Ok(self)
+        }
+    }
+
+    /// Perform multiple draw operations using a graphics pipeline that has a mesh shader. One
+    /// draw is performed for each `vulkano::command_buffer::DrawMeshTasksIndirectCommand` struct
+    /// in `indirect_buffer`.
+    ///
+    /// This requires the `ext_mesh_shader` extension.
+    #[inline]
+    pub fn draw_mesh_tasks_indirect<Gp, S, Pc, Inb, Do, Doi>(
+        &mut self,
+        pipeline: Gp,
+        dynamic: &DynamicState,
+        indirect_buffer: Inb,
+        sets: S,
+        constants: Pc,
+        dynamic_offsets: Do,
+    ) -> Result<&mut Self, DrawMeshTasksIndirectError>
+    where
+        Gp: GraphicsPipelineAbstract + Send + Sync + 'static + Clone, // TODO: meh for Clone
+        S: DescriptorSetsCollection,
+        Inb: BufferAccess
+            + TypedBufferAccess<Content = [DrawMeshTasksIndirectCommand]>
+            + Send
+            + Sync
+            + 'static,
+        Do: IntoIterator<Item = u32, IntoIter = Doi>,
+        Doi: Iterator<Item = u32> + Send + Sync + 'static,
+    {
+        unsafe {
+            // TODO: must check that pipeline is compatible with render pass
+
+            self.ensure_inside_render_pass_inline(&pipeline)?;
+            check_draw_mesh_tasks(self.device(), &pipeline, None)?;
+            check_indirect_buffer(self.device(), &indirect_buffer)?;
+            check_dynamic_state_validity(&pipeline, dynamic)?;
+            check_push_constants_validity(&pipeline, &constants)?;
+            check_descriptor_sets_validity(&pipeline, &sets)?;
+
+            let draw_count = indirect_buffer.len() as u32;
+
+            if let StateCacherOutcome::NeedChange =
+                self.state_cacher.bind_graphics_pipeline(&pipeline)
+            {
+                self.inner.bind_pipeline_graphics(pipeline.clone());
+            }
+
+            let dynamic = self.state_cacher.dynamic_state(dynamic);
+
+            push_constants(&mut self.inner, pipeline.clone(), constants);
+            set_state(&mut self.inner, &dynamic);
+            descriptor_sets(
+                &mut self.inner,
+                &mut self.state_cacher,
+                true,
+                pipeline.clone(),
+                sets,
+                dynamic_offsets,
+            )?;
+
+            debug_assert!(self.queue_family().supports_graphics());
+
+            self.inner.draw_mesh_tasks_indirect(
+                indirect_buffer,
+                draw_count,
+                mem::size_of::<DrawMeshTasksIndirectCommand>() as u32,
+            )?;
+            Ok(self)
+        }
+    }
+
     /// Adds a command that writes the content of a buffer.
     ///
     /// This function is similar to the `memset` function in C. The `data` parameter is a number
@@ -1798,6 +1969,23 @@ impl<L, P> AutoCommandBufferBuilder<L, P> {
         }
     }
 
+    /// Adds a command that sets which physical devices of the device group execute the commands
+    /// recorded afterwards.
+    ///
+    /// Bit `n` of `device_mask` designates the physical device with device index `n`, as
+    /// returned by `Device::physical_devices`. By default, commands are executed by all the
+    /// physical devices that the command buffer is submitted to.
+    #[inline]
+    pub fn set_device_mask(&mut self, device_mask: u32) -> Result<&mut Self, CheckDeviceMaskError> {
+        check_device_mask(self.device(), device_mask)?;
+
+        unsafe {
+            self.inner.set_device_mask(device_mask);
+        }
+
+        Ok(self)
+    }
+
     /// Adds a command that begins a query.
     ///
     /// The query will be active until [`end_query`](Self::end_query) is called for the same query.
@@ -1975,6 +2163,148 @@ impl<L, P> AutoCommandBufferBuilder<L, P> {
 
         Ok(self)
     }
+
+    /// Enables or disables split barriers.
+    ///
+    /// When enabled, a pipeline barrier between two commands that have independent commands
+    /// between them is replaced with an event that is set after the first command and waited upon
+    /// before the second one, so that the independent commands don't need to finish before the
+    /// second command can start.
+    ///
+    /// Split barriers are disabled by default. Enabling them has no effect on command buffers
+    /// that can be executed multiple times simultaneously, as the events would be shared between
+    /// the executions.
+    pub fn split_barriers(
+        &mut self,
+        enabled: bool,
+    ) -> Result<&mut Self, AutoCommandBufferBuilderContextError> {
+        if enabled
+            && !(self.queue_family().supports_graphics() || self.queue_family().supports_compute())
+        {
+            return Err(AutoCommandBufferBuilderContextError::NotSupportedByQueueFamily);
+        }
+
+        unsafe {
+            self.inner
+                .set_split_barriers(enabled && self.flags != Flags::SimultaneousUse);
+        }
+
+        Ok(self)
+    }
+
+    /// Adds a command that sets an event once the given stages of the previous commands have
+    /// finished.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if `stages` is empty or contains the host stage.
+    ///
+    /// # Safety
+    ///
+    /// - The event must not be waited upon by a command that is being executed.
+    pub unsafe fn set_event(
+        &mut self,
+        event: Arc<Event>,
+        stages: PipelineStages,
+    ) -> Result<&mut Self, AutoCommandBufferBuilderContextError> {
+        assert_eq!(
+            event.device().internal_object(),
+            self.device().internal_object()
+        );
+        assert!(stages != PipelineStages::none() && !stages.host);
+        self.ensure_outside_render_pass()?;
+
+        if !(self.queue_family().supports_graphics() || self.queue_family().supports_compute()) {
+            return Err(AutoCommandBufferBuilderContextError::NotSupportedByQueueFamily);
+        }
+
+        self.inner.set_event(event, stages);
+        Ok(self)
+    }
+
+    /// Adds a command that resets an event once the given stages of the previous commands have
+    /// finished.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if `stages` is empty or contains the host stage.
+    ///
+    /// # Safety
+    ///
+    /// - The event must not be waited upon by a command that is being executed.
+    pub unsafe fn reset_event(
+        &mut self,
+        event: Arc<Event>,
+        stages: PipelineStages,
+    ) -> Result<&mut Self, AutoCommandBufferBuilderContextError> {
+        assert_eq!(
+            event.device().internal_object(),
+            self.device().internal_object()
+        );
+        assert!(stages != PipelineStages::none() && !stages.host);
+        self.ensure_outside_render_pass()?;
+
+        if !(self.queue_family().supports_graphics() || self.queue_family().supports_compute()) {
+            return Err(AutoCommandBufferBuilderContextError::NotSupportedByQueueFamily);
+        }
+
+        self.inner.reset_event(event, stages);
+        Ok(self)
+    }
+
+    /// Adds a command that waits for events to be set, and makes the memory accesses
+    /// `source_access` of the stages `source_stages` available to the memory accesses
+    /// `destination_access` of the stages `destination_stages` of the following commands.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if `source_stages` or `destination_stages` is empty.
+    /// - Panics if the accesses are not compatible with the stages.
+    ///
+    /// # Safety
+    ///
+    /// - `source_stages` must be the union of the stages passed to the commands that set the
+    ///   events, plus the host stage if an event is set from the host.
+    /// - The events must be set before the command executes, either by a previous command of the
+    ///   same queue or from the host.
+    pub unsafe fn wait_events<I>(
+        &mut self,
+        events: I,
+        source_stages: PipelineStages,
+        source_access: AccessFlagBits,
+        destination_stages: PipelineStages,
+        destination_access: AccessFlagBits,
+    ) -> Result<&mut Self, AutoCommandBufferBuilderContextError>
+    where
+        I: IntoIterator<Item = Arc<Event>>,
+    {
+        assert!(source_stages != PipelineStages::none());
+        assert!(destination_stages != PipelineStages::none());
+        assert!(source_access.is_compatible_with(&source_stages));
+        assert!(destination_access.is_compatible_with(&destination_stages));
+        self.ensure_outside_render_pass()?;
+
+        if !(self.queue_family().supports_graphics() || self.queue_family().supports_compute()) {
+            return Err(AutoCommandBufferBuilderContextError::NotSupportedByQueueFamily);
+        }
+
+        let events: SmallVec<[Arc<Event>; 4]> = events.into_iter().collect();
+        for event in &events {
+            assert_eq!(
+                event.device().internal_object(),
+                self.device().internal_object()
+            );
+        }
+
+        self.inner.wait_events(
+            events,
+            source_stages,
+            source_access,
+            destination_stages,
+            destination_access,
+        );
+        Ok(self)
+    }
 }
 
 /// Commands that can only be executed on primary command buffers
@@ -2265,6 +2595,47 @@ where
         Ok(())
     }
 
+    // Returns the inheritance that a secondary command buffer needs in order to be executed at
+    // the current point of this command buffer, inside the current subpass.
+    #[inline]
+    pub(super) fn secondary_inheritance(
+        &self,
+    ) -> Result<CommandBufferInheritance<Arc<Framebuffer<()>>>, AutoCommandBufferBuilderContextError>
+    {
+        let render_pass_state = self
+            .render_pass_state
+            .as_ref()
+            .ok_or(AutoCommandBufferBuilderContextError::ForbiddenOutsideRenderPass)?;
+
+        if render_pass_state.contents != SubpassContents::SecondaryCommandBuffers {
+            return Err(AutoCommandBufferBuilderContextError::WrongSubpassType);
+        }
+
+        let mut occlusion_query = None;
+        let mut query_statistics_flags = QueryPipelineStatisticFlags::none();
+
+        for state in self.query_state.values() {
+            match state.ty {
+                QueryType::Occlusion => occlusion_query = Some(state.flags),
+                QueryType::PipelineStatistics(flags) => query_statistics_flags = flags,
+                _ => (),
+            }
+        }
+
+        Ok(CommandBufferInheritance {
+            render_pass: Some(CommandBufferInheritanceRenderPass {
+                subpass: Subpass::from(
+                    render_pass_state.subpass.0.clone(),
+                    render_pass_state.subpass.1,
+                )
+                .unwrap(),
+                framebuffer: None,
+            }),
+            occlusion_query,
+            query_statistics_flags,
+        })
+    }
+
     /// Adds a command that jumps to the next subpass of the current render pass.
     #[inline]
     pub fn next_subpass(
@@ -2504,6 +2875,105 @@ pub struct PrimaryAutoCommandBuffer<P = StandardCommandPoolAlloc> {
 
     // Tracks usage of the command buffer on the GPU.
     submit_state: SubmitState,
+
+    // Functions to call each time an execution of the command buffer has finished.
+    on_finished: Vec<Box<dyn Fn() + Send + Sync>>,
+}
+
+impl PrimaryAutoCommandBuffer<StandardCommandPoolAlloc> {
+    /// Resets the command buffer and starts recording into it again.
+    ///
+    /// The returned builder reuses the same Vulkan command buffer and has the same usage flags as
+    /// the original builder. Since the command buffer is taken by value, it can no longer be in
+    /// use by a `GpuFuture`.
+    ///
+    /// Returns an error if called from a different thread than the one the command buffer was
+    /// recorded on, as the command pool it was allocated from can only be used by that thread.
+    pub fn reset(
+        self,
+    ) -> Result<
+        AutoCommandBufferBuilder<PrimaryAutoCommandBuffer, StandardCommandPoolBuilder>,
+        CommandBufferResetError,
+    > {
+        let PrimaryAutoCommandBuffer {
+            inner,
+            pool_alloc,
+            submit_state,
+            on_finished: _,
+        } = self;
+
+        // The synced command buffer must be dropped before the allocation, as per the safety
+        // requirements of `PrimaryAutoCommandBuffer`.
+        drop(inner);
+
+        let flags = match submit_state {
+            SubmitState::Concurrent => Flags::SimultaneousUse,
+            SubmitState::ExclusiveUse { .. } => Flags::None,
+            SubmitState::OneTime { .. } => Flags::OneTimeSubmit,
+        };
+
+        let device = pool_alloc.device().clone();
+        let queue_family = device
+            .physical_device()
+            .queue_family_by_id(pool_alloc.queue_family().id())
+            .unwrap();
+        let pool_builder_alloc = pool_alloc
+            .into_builder()
+            .map_err(|_| CommandBufferResetError::WrongThread)?;
+
+        unsafe {
+            pool_builder_alloc.inner().reset(false)?;
+            Ok(AutoCommandBufferBuilder::with_pool_builder_alloc(
+                pool_builder_alloc,
+                queue_family,
+                CommandBufferLevel::primary(),
+                flags,
+            )?)
+        }
+    }
+}
+
+/// Error that can happen when resetting a `PrimaryAutoCommandBuffer`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CommandBufferResetError {
+    /// The command buffer was reset from a different thread than the one it was allocated from.
+    WrongThread,
+    /// Not enough memory.
+    OomError(OomError),
+}
+
+impl error::Error for CommandBufferResetError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            Self::OomError(ref err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for CommandBufferResetError {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(
+            fmt,
+            "{}",
+            match *self {
+                Self::WrongThread => {
+                    "the command buffer was reset from a different thread than the one it was \
+                 allocated from"
+                }
+                Self::OomError(_) => "not enough memory available",
+            }
+        )
+    }
+}
+
+impl From<OomError> for CommandBufferResetError {
+    #[inline]
+    fn from(err: OomError) -> Self {
+        Self::OomError(err)
+    }
 }
 
 unsafe impl<P> DeviceOwned for PrimaryAutoCommandBuffer<P> {
@@ -2581,6 +3051,10 @@ unsafe impl<P> PrimaryCommandBuffer for PrimaryAutoCommandBuffer<P> {
             }
             SubmitState::Concurrent => (),
         };
+
+        for f in &self.on_finished {
+            f();
+        }
     }
 
     #[inline]
@@ -2853,6 +3327,7 @@ err_gen!(DispatchIndirectError {
 
 err_gen!(DrawError {
     AutoCommandBufferBuilderContextError,
+    CheckVertexInputPipelineError,
     CheckDynamicStateValidityError,
     CheckPushConstantsValidityError,
     CheckDescriptorSetsValidityError,
@@ -2862,6 +3337,7 @@ err_gen!(DrawError {
 
 err_gen!(DrawIndexedError {
     AutoCommandBufferBuilderContextError,
+    CheckVertexInputPipelineError,
     CheckDynamicStateValidityError,
     CheckPushConstantsValidityError,
     CheckDescriptorSetsValidityError,
@@ -2872,6 +3348,7 @@ err_gen!(DrawIndexedError {
 
 err_gen!(DrawIndirectError {
     AutoCommandBufferBuilderContextError,
+    CheckVertexInputPipelineError,
     CheckDynamicStateValidityError,
     CheckPushConstantsValidityError,
     CheckDescriptorSetsValidityError,
@@ -2882,6 +3359,7 @@ err_gen!(DrawIndirectError {
 
 err_gen!(DrawIndexedIndirectError {
     AutoCommandBufferBuilderContextError,
+    CheckVertexInputPipelineError,
     CheckDynamicStateValidityError,
     CheckPushConstantsValidityError,
     CheckDescriptorSetsValidityError,
@@ -2891,6 +3369,25 @@ err_gen!(DrawIndexedIndirectError {
     SyncCommandBufferBuilderError,
 });
 
+err_gen!(DrawMeshTasksError {
+    AutoCommandBufferBuilderContextError,
+    CheckDrawMeshTasksError,
+    CheckDynamicStateValidityError,
+    CheckPushConstantsValidityError,
+    CheckDescriptorSetsValidityError,
+    SyncCommandBufferBuilderError,
+});
+
+err_gen!(DrawMeshTasksIndirectError {
+    AutoCommandBufferBuilderContextError,
+    CheckDrawMeshTasksError,
+    CheckDynamicStateValidityError,
+    CheckPushConstantsValidityError,
+    CheckDescriptorSetsValidityError,
+    CheckIndirectBufferError,
+    SyncCommandBufferBuilderError,
+});
+
 err_gen!(ExecuteCommandsError {
     AutoCommandBufferBuilderContextError,
     SyncCommandBufferBuilderError,
@@ -81,6 +81,7 @@ pub use self::auto::BeginRenderPassError;
 pub use self::auto::BlitImageError;
 pub use self::auto::BuildError;
 pub use self::auto::ClearColorImageError;
+pub use self::auto::CommandBufferResetError;
 pub use self::auto::CopyBufferError;
 pub use self::auto::CopyBufferImageError;
 pub use self::auto::CopyImageError;
@@ -92,6 +93,8 @@ pub use self::auto::DrawError;
 pub use self::auto::DrawIndexedError;
 pub use self::auto::DrawIndexedIndirectError;
 pub use self::auto::DrawIndirectError;
+pub use self::auto::DrawMeshTasksError;
+pub use self::auto::DrawMeshTasksIndirectError;
 pub use self::auto::EndQueryError;
 pub use self::auto::ExecuteCommandsError;
 pub use self::auto::FillBufferError;
@@ -100,12 +103,24 @@ pub use self::auto::ResetQueryPoolError;
 pub use self::auto::SecondaryAutoCommandBuffer;
 pub use self::auto::UpdateBufferError;
 pub use self::auto::WriteTimestampError;
+pub use self::parallel::ParallelRecordError;
+pub use self::parallel::ParallelRenderPassRecorder;
+pub use self::parallel::ParallelSecondaryBuilder;
+pub use self::readback::ImageData;
+pub use self::readback::ImageReadback;
+pub use self::readback::Readback;
+pub use self::readback::ReadbackCreationError;
+pub use self::readback::ReadbackError;
+pub use self::ring::CommandBufferRing;
 pub use self::state_cacher::StateCacher;
 pub use self::state_cacher::StateCacherOutcome;
 pub use self::traits::CommandBufferExecError;
 pub use self::traits::CommandBufferExecFuture;
 pub use self::traits::PrimaryCommandBuffer;
 pub use self::traits::SecondaryCommandBuffer;
+pub use self::upload::UploadError;
+pub use self::upload::UploadFuture;
+pub use self::upload::UploadQueue;
 use crate::pipeline::depth_stencil::DynamicStencilValue;
 use crate::pipeline::viewport::{Scissor, Viewport};
 use crate::query::QueryControlFlags;
@@ -114,12 +129,16 @@ use crate::render_pass::{Framebuffer, Subpass};
 use std::sync::Arc;
 
 mod auto;
+mod parallel;
 pub mod pool;
+mod readback;
+mod ring;
 mod state_cacher;
 pub mod submit;
 pub mod synced;
 pub mod sys;
 mod traits;
+mod upload;
 pub mod validity;
 
 #[repr(C)]
@@ -149,6 +168,14 @@ pub struct DispatchIndirectCommand {
     pub z: u32,
 }
 
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DrawMeshTasksIndirectCommand {
+    pub x: u32,
+    pub y: u32,
+    pub z: u32,
+}
+
 /// The dynamic state to use for a draw command.
 // TODO: probably not the right location
 #[derive(Debug, Clone)]
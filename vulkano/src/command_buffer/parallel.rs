@@ -0,0 +1,482 @@
+// Copyright (c) 2021 The vulkano developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>,
+// at your option. All files in the project carrying such
+// notice may not be copied, modified, or distributed except
+// according to those terms.
+
+//! Recording of the draws of a subpass on multiple threads.
+
+use crate::command_buffer::pool::standard::StandardCommandPoolBuilder;
+use crate::command_buffer::pool::CommandPoolBuilderAlloc;
+use crate::command_buffer::AutoCommandBufferBuilder;
+use crate::command_buffer::AutoCommandBufferBuilderContextError;
+use crate::command_buffer::BeginError;
+use crate::command_buffer::BuildError;
+use crate::command_buffer::CommandBufferInheritance;
+use crate::command_buffer::CommandBufferInheritanceRenderPass;
+use crate::command_buffer::ExecuteCommandsError;
+use crate::command_buffer::PrimaryAutoCommandBuffer;
+use crate::command_buffer::SecondaryAutoCommandBuffer;
+use crate::device::Device;
+use crate::device::DeviceOwned;
+use crate::instance::QueueFamily;
+use crate::query::QueryPipelineStatisticFlags;
+use crate::render_pass::Framebuffer;
+use crate::render_pass::Subpass;
+use crate::VulkanObject;
+use std::error;
+use std::fmt;
+use std::panic;
+use std::panic::AssertUnwindSafe;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::thread;
+
+/// Builder of a secondary command buffer recorded by a `ParallelRenderPassRecorder`.
+pub type ParallelSecondaryBuilder =
+    AutoCommandBufferBuilder<SecondaryAutoCommandBuffer, StandardCommandPoolBuilder>;
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// Splits the draws of a subpass across a fixed set of worker threads.
+///
+/// The draws are divided by the user into a number of batches. Each batch is recorded into its
+/// own one-time-submit secondary command buffer by one of the worker threads, with the
+/// inheritance needed to execute it in the subpass. Since the worker threads live as long as the
+/// recorder, each of them keeps its own command pool of the `StandardCommandPool` from one frame
+/// to the next.
+///
+/// The resulting command buffers are always returned, or executed, in the order of their batch
+/// index, no matter which thread recorded them.
+///
+/// # Example
+///
+/// ```ignore
+/// let recorder = ParallelRenderPassRecorder::new(device.clone(), queue.family(), 4);
+///
+/// builder.begin_render_pass(framebuffer, SubpassContents::SecondaryCommandBuffers, clear_values)?;
+/// let objects = Arc::new(objects);
+/// recorder.record(&mut builder, objects.len(), move |index, builder| {
+///     let object = &objects[index];
+///     builder.draw(pipeline.clone(), &dynamic_state, object.vertices.clone(), (), ())?;
+///     Ok::<_, DrawError>(())
+/// })?;
+/// builder.end_render_pass()?;
+/// ```
+pub struct ParallelRenderPassRecorder {
+    device: Arc<Device>,
+    queue_family: u32,
+    workers: Vec<Worker>,
+}
+
+struct Worker {
+    // Sends jobs to the thread. Dropped first in order to stop the thread.
+    sender: Option<mpsc::Sender<Job>>,
+    thread: Option<thread::JoinHandle<()>>,
+}
+
+impl ParallelRenderPassRecorder {
+    /// Builds a new recorder that uses `num_threads` worker threads.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if `num_threads` is 0.
+    /// - Panics if the device and the queue family don't belong to the same physical device.
+    /// - Panics if the queue family doesn't support graphics operations.
+    ///
+    pub fn new(
+        device: Arc<Device>,
+        queue_family: QueueFamily,
+        num_threads: usize,
+    ) -> ParallelRenderPassRecorder {
+        assert!(num_threads >= 1);
+        assert_eq!(
+            device.physical_device().internal_object(),
+            queue_family.physical_device().internal_object()
+        );
+        assert!(queue_family.supports_graphics());
+
+        let workers = (0..num_threads)
+            .map(|num| {
+                let (sender, receiver) = mpsc::channel::<Job>();
+                let thread = thread::Builder::new()
+                    .name(format!("vulkano-recorder-{}", num))
+                    .spawn(move || {
+                        for job in receiver {
+                            // A panic in the user's closure is reported to `record` by the
+                            // result channel being closed, and must not stop the worker.
+                            let _ = panic::catch_unwind(AssertUnwindSafe(job));
+                        }
+                    })
+                    .expect("failed to spawn a worker thread");
+
+                Worker {
+                    sender: Some(sender),
+                    thread: Some(thread),
+                }
+            })
+            .collect();
+
+        ParallelRenderPassRecorder {
+            device,
+            queue_family: queue_family.id(),
+            workers,
+        }
+    }
+
+    /// Returns the number of worker threads.
+    #[inline]
+    pub fn num_threads(&self) -> usize {
+        self.workers.len()
+    }
+
+    /// Returns the queue family the command buffers are recorded for.
+    #[inline]
+    pub fn queue_family(&self) -> QueueFamily<'_> {
+        self.device
+            .physical_device()
+            .queue_family_by_id(self.queue_family)
+            .unwrap()
+    }
+
+    /// Records `num_batches` secondary command buffers for the current subpass of `builder`, and
+    /// executes them in order of their batch index.
+    ///
+    /// `record` is called once for each batch, with the index of the batch and the builder to
+    /// record into. The secondary command buffers inherit the occlusion and pipeline statistics
+    /// queries that are active in `builder`.
+    ///
+    /// `builder` must be inside a subpass that was started with
+    /// `SubpassContents::SecondaryCommandBuffers`.
+    pub fn record<P, F, E>(
+        &self,
+        builder: &mut AutoCommandBufferBuilder<PrimaryAutoCommandBuffer<P::Alloc>, P>,
+        num_batches: usize,
+        record: F,
+    ) -> Result<(), ParallelRecordError<E>>
+    where
+        P: CommandPoolBuilderAlloc,
+        F: Fn(usize, &mut ParallelSecondaryBuilder) -> Result<(), E> + Send + Sync + 'static,
+        E: Send + 'static,
+    {
+        assert_eq!(
+            builder.device().internal_object(),
+            self.device.internal_object()
+        );
+
+        let inheritance = builder.secondary_inheritance()?;
+        let command_buffers = self.record_inheritance(inheritance, num_batches, record)?;
+        builder.execute_commands_from_vec(command_buffers)?;
+        Ok(())
+    }
+
+    /// Records `num_batches` secondary command buffers for `subpass`, and returns them in order
+    /// of their batch index.
+    ///
+    /// `record` is called once for each batch, with the index of the batch and the builder to
+    /// record into. The command buffers don't inherit any query.
+    pub fn record_subpass<F, E>(
+        &self,
+        subpass: Subpass,
+        num_batches: usize,
+        record: F,
+    ) -> Result<Vec<SecondaryAutoCommandBuffer>, ParallelRecordError<E>>
+    where
+        F: Fn(usize, &mut ParallelSecondaryBuilder) -> Result<(), E> + Send + Sync + 'static,
+        E: Send + 'static,
+    {
+        assert_eq!(
+            subpass.render_pass().device().internal_object(),
+            self.device.internal_object()
+        );
+
+        let inheritance = CommandBufferInheritance {
+            render_pass: Some(CommandBufferInheritanceRenderPass {
+                subpass,
+                framebuffer: None,
+            }),
+            occlusion_query: None,
+            query_statistics_flags: QueryPipelineStatisticFlags::none(),
+        };
+
+        self.record_inheritance(inheritance, num_batches, record)
+    }
+
+    fn record_inheritance<F, E>(
+        &self,
+        inheritance: CommandBufferInheritance<Arc<Framebuffer<()>>>,
+        num_batches: usize,
+        record: F,
+    ) -> Result<Vec<SecondaryAutoCommandBuffer>, ParallelRecordError<E>>
+    where
+        F: Fn(usize, &mut ParallelSecondaryBuilder) -> Result<(), E> + Send + Sync + 'static,
+        E: Send + 'static,
+    {
+        let record = Arc::new(record);
+        let subpass = inheritance.render_pass.unwrap().subpass;
+        let occlusion_query = inheritance.occlusion_query;
+        let query_statistics_flags = inheritance.query_statistics_flags;
+        let (results_tx, results_rx) = mpsc::channel();
+
+        // Batches are distributed round-robin, so that each worker records every
+        // `num_threads`-th batch.
+        let num_threads = self.workers.len();
+        for (first_batch, worker) in self.workers.iter().enumerate().take(num_batches) {
+            let device = self.device.clone();
+            let queue_family = self.queue_family;
+            let subpass = subpass.clone();
+            let record = record.clone();
+            let results_tx = results_tx.clone();
+
+            let job = Box::new(move || {
+                let queue_family = device
+                    .physical_device()
+                    .queue_family_by_id(queue_family)
+                    .unwrap();
+
+                for index in (first_batch..num_batches).step_by(num_threads) {
+                    let result: Result<_, ParallelRecordError<E>> = (|| {
+                        let mut builder =
+                            AutoCommandBufferBuilder::secondary_graphics_one_time_submit_inherit_queries(
+                                device.clone(),
+                                queue_family,
+                                subpass.clone(),
+                                occlusion_query,
+                                query_statistics_flags,
+                            )?;
+                        record(index, &mut builder).map_err(ParallelRecordError::RecordError)?;
+                        Ok(builder.build()?)
+                    })();
+
+                    let failed = result.is_err();
+                    if results_tx.send((index, result)).is_err() || failed {
+                        break;
+                    }
+                }
+            });
+
+            worker
+                .sender
+                .as_ref()
+                .unwrap()
+                .send(job)
+                .map_err(|_| ParallelRecordError::WorkerPanicked)?;
+        }
+
+        drop(results_tx);
+
+        let mut command_buffers: Vec<Option<SecondaryAutoCommandBuffer>> =
+            (0..num_batches).map(|_| None).collect();
+        for _ in 0..num_batches {
+            let (index, result) = results_rx
+                .recv()
+                .map_err(|_| ParallelRecordError::WorkerPanicked)?;
+            command_buffers[index] = Some(result?);
+        }
+
+        Ok(command_buffers.into_iter().map(Option::unwrap).collect())
+    }
+}
+
+unsafe impl DeviceOwned for ParallelRenderPassRecorder {
+    #[inline]
+    fn device(&self) -> &Arc<Device> {
+        &self.device
+    }
+}
+
+impl Drop for ParallelRenderPassRecorder {
+    fn drop(&mut self) {
+        for worker in &mut self.workers {
+            worker.sender.take();
+        }
+
+        for worker in &mut self.workers {
+            if let Some(thread) = worker.thread.take() {
+                let _ = thread.join();
+            }
+        }
+    }
+}
+
+/// Error that can happen when recording with a `ParallelRenderPassRecorder`.
+#[derive(Debug, Clone)]
+pub enum ParallelRecordError<E> {
+    /// The primary command buffer is not in a state where secondary command buffers can be
+    /// executed.
+    AutoCommandBufferBuilderContextError(AutoCommandBufferBuilderContextError),
+    /// Error while starting to record a secondary command buffer.
+    BeginError(BeginError),
+    /// Error while building a secondary command buffer.
+    BuildError(BuildError),
+    /// Error while executing the secondary command buffers in the primary command buffer.
+    ExecuteCommandsError(ExecuteCommandsError),
+    /// The recording closure returned an error.
+    RecordError(E),
+    /// The recording closure panicked on a worker thread.
+    WorkerPanicked,
+}
+
+impl<E> error::Error for ParallelRecordError<E>
+where
+    E: error::Error + 'static,
+{
+    #[inline]
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            Self::AutoCommandBufferBuilderContextError(ref err) => Some(err),
+            Self::BeginError(ref err) => Some(err),
+            Self::BuildError(ref err) => Some(err),
+            Self::ExecuteCommandsError(ref err) => Some(err),
+            Self::RecordError(ref err) => Some(err),
+            Self::WorkerPanicked => None,
+        }
+    }
+}
+
+impl<E> fmt::Display for ParallelRecordError<E> {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(
+            fmt,
+            "{}",
+            match *self {
+                Self::AutoCommandBufferBuilderContextError(_) => {
+                    "the primary command buffer can't execute secondary command buffers"
+                }
+                Self::BeginError(_) => "error while starting a secondary command buffer",
+                Self::BuildError(_) => "error while building a secondary command buffer",
+                Self::ExecuteCommandsError(_) =>
+                    "error while executing the secondary command buffers",
+                Self::RecordError(_) => "the recording closure returned an error",
+                Self::WorkerPanicked => "the recording closure panicked on a worker thread",
+            }
+        )
+    }
+}
+
+impl<E> From<AutoCommandBufferBuilderContextError> for ParallelRecordError<E> {
+    #[inline]
+    fn from(err: AutoCommandBufferBuilderContextError) -> Self {
+        Self::AutoCommandBufferBuilderContextError(err)
+    }
+}
+
+impl<E> From<BeginError> for ParallelRecordError<E> {
+    #[inline]
+    fn from(err: BeginError) -> Self {
+        Self::BeginError(err)
+    }
+}
+
+impl<E> From<BuildError> for ParallelRecordError<E> {
+    #[inline]
+    fn from(err: BuildError) -> Self {
+        Self::BuildError(err)
+    }
+}
+
+impl<E> From<ExecuteCommandsError> for ParallelRecordError<E> {
+    #[inline]
+    fn from(err: ExecuteCommandsError) -> Self {
+        Self::ExecuteCommandsError(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::command_buffer::AutoCommandBufferBuilder;
+    use crate::command_buffer::AutoCommandBufferBuilderContextError;
+    use crate::command_buffer::ParallelRecordError;
+    use crate::command_buffer::ParallelRenderPassRecorder;
+    use crate::command_buffer::SecondaryCommandBuffer;
+    use crate::render_pass::RenderPass;
+    use crate::render_pass::Subpass;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+    use std::sync::Arc;
+    use std::thread;
+
+    #[test]
+    fn records_in_batch_order() {
+        let (device, queue) = gfx_dev_and_queue!();
+
+        let render_pass = Arc::new(RenderPass::empty_single_pass(device.clone()).unwrap());
+        let subpass = Subpass::from(render_pass, 0).unwrap();
+        let recorder = ParallelRenderPassRecorder::new(device, queue.family(), 3);
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let calls2 = calls.clone();
+        let command_buffers = recorder
+            .record_subpass(subpass, 8, move |_, _| {
+                calls2.fetch_add(1, Ordering::SeqCst);
+                Ok::<_, ()>(())
+            })
+            .unwrap();
+
+        assert_eq!(command_buffers.len(), 8);
+        assert_eq!(calls.load(Ordering::SeqCst), 8);
+        for cb in &command_buffers {
+            assert_eq!(cb.inheritance().render_pass.unwrap().subpass.index(), 0);
+        }
+    }
+
+    #[test]
+    fn record_error() {
+        let (device, queue) = gfx_dev_and_queue!();
+
+        let render_pass = Arc::new(RenderPass::empty_single_pass(device.clone()).unwrap());
+        let subpass = Subpass::from(render_pass, 0).unwrap();
+        let recorder = ParallelRenderPassRecorder::new(device, queue.family(), 2);
+
+        match recorder.record_subpass(
+            subpass,
+            4,
+            |index, _| if index == 2 { Err(index) } else { Ok(()) },
+        ) {
+            Err(ParallelRecordError::RecordError(2)) => (),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn worker_panic() {
+        let (device, queue) = gfx_dev_and_queue!();
+
+        let render_pass = Arc::new(RenderPass::empty_single_pass(device.clone()).unwrap());
+        let subpass = Subpass::from(render_pass, 0).unwrap();
+        let recorder = ParallelRenderPassRecorder::new(device, queue.family(), 2);
+
+        let main_thread = thread::current().id();
+        match recorder.record_subpass(subpass.clone(), 2, move |_, _| {
+            assert_ne!(thread::current().id(), main_thread);
+            panic!()
+        }) {
+            Err(ParallelRecordError::<()>::WorkerPanicked) => (),
+            _ => panic!(),
+        }
+
+        // The workers are still usable afterwards.
+        assert!(recorder
+            .record_subpass(subpass, 2, |_, _| Ok::<_, ()>(()))
+            .is_ok());
+    }
+
+    #[test]
+    fn requires_secondary_contents() {
+        let (device, queue) = gfx_dev_and_queue!();
+
+        let recorder = ParallelRenderPassRecorder::new(device.clone(), queue.family(), 1);
+        let mut builder = AutoCommandBufferBuilder::primary(device, queue.family()).unwrap();
+
+        match recorder.record(&mut builder, 1, |_, _| Ok::<_, ()>(())) {
+            Err(ParallelRecordError::AutoCommandBufferBuilderContextError(
+                AutoCommandBufferBuilderContextError::ForbiddenOutsideRenderPass,
+            )) => (),
+            _ => panic!(),
+        }
+    }
+}
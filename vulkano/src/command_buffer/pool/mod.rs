@@ -22,6 +22,7 @@ use crate::device::DeviceOwned;
 use crate::OomError;
 
 pub use self::standard::StandardCommandPool;
+pub use self::standard::StandardCommandPoolStats;
 pub use self::sys::CommandPoolTrimError;
 pub use self::sys::UnsafeCommandPool;
 pub use self::sys::UnsafeCommandPoolAlloc;
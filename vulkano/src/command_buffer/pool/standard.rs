@@ -12,6 +12,8 @@ use fnv::FnvHashMap;
 use std::marker::PhantomData;
 use std::mem::ManuallyDrop;
 use std::ptr;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Mutex;
 use std::sync::Weak;
@@ -47,12 +49,22 @@ pub struct StandardCommandPool {
 
     // For each thread, we store thread-specific info.
     per_thread: Mutex<FnvHashMap<thread::ThreadId, Weak<StandardCommandPoolPerThread>>>,
+
+    // Number of command buffers that were allocated from a Vulkan pool, per level.
+    allocated_primary: AtomicUsize,
+    allocated_secondary: AtomicUsize,
+
+    // Number of command buffers that were reused instead of being allocated, per level.
+    reused_primary: AtomicUsize,
+    reused_secondary: AtomicUsize,
 }
 
 unsafe impl Send for StandardCommandPool {}
 unsafe impl Sync for StandardCommandPool {}
 
 struct StandardCommandPoolPerThread {
+    // The thread that owns this pool.
+    thread: thread::ThreadId,
     // The Vulkan pool of this thread.
     pool: Mutex<UnsafeCommandPool>,
     // List of existing primary command buffers that are available for reuse.
@@ -78,8 +90,56 @@ impl StandardCommandPool {
             device: device,
             queue_family: queue_family.id(),
             per_thread: Mutex::new(Default::default()),
+            allocated_primary: AtomicUsize::new(0),
+            allocated_secondary: AtomicUsize::new(0),
+            reused_primary: AtomicUsize::new(0),
+            reused_secondary: AtomicUsize::new(0),
         }
     }
+
+    /// Returns statistics about the command buffers allocated from this pool.
+    ///
+    /// The values are a snapshot and can be outdated by the time this function returns if other
+    /// threads are using the pool.
+    pub fn stats(&self) -> StandardCommandPoolStats {
+        let mut available_primary = 0;
+        let mut available_secondary = 0;
+
+        for per_thread in self.per_thread.lock().unwrap().values() {
+            if let Some(per_thread) = per_thread.upgrade() {
+                available_primary += per_thread.available_primary_command_buffers.len();
+                available_secondary += per_thread.available_secondary_command_buffers.len();
+            }
+        }
+
+        StandardCommandPoolStats {
+            allocated_primary: self.allocated_primary.load(Ordering::Relaxed),
+            allocated_secondary: self.allocated_secondary.load(Ordering::Relaxed),
+            reused_primary: self.reused_primary.load(Ordering::Relaxed),
+            reused_secondary: self.reused_secondary.load(Ordering::Relaxed),
+            available_primary,
+            available_secondary,
+        }
+    }
+}
+
+/// Statistics about the command buffers of a `StandardCommandPool`.
+///
+/// Returned by `StandardCommandPool::stats`.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct StandardCommandPoolStats {
+    /// Number of primary command buffers that have been allocated from Vulkan.
+    pub allocated_primary: usize,
+    /// Number of secondary command buffers that have been allocated from Vulkan.
+    pub allocated_secondary: usize,
+    /// Number of times a primary command buffer was reused instead of being allocated.
+    pub reused_primary: usize,
+    /// Number of times a secondary command buffer was reused instead of being allocated.
+    pub reused_secondary: usize,
+    /// Number of primary command buffers currently waiting to be reused.
+    pub available_primary: usize,
+    /// Number of secondary command buffers currently waiting to be reused.
+    pub available_secondary: usize,
 }
 
 unsafe impl CommandPool for Arc<StandardCommandPool> {
@@ -102,6 +162,7 @@ unsafe impl CommandPool for Arc<StandardCommandPool> {
             let new_pool =
                 UnsafeCommandPool::new(self.device.clone(), self.queue_family(), false, true)?;
             let pt = Arc::new(StandardCommandPoolPerThread {
+                thread: this_thread,
                 pool: Mutex::new(new_pool),
                 available_primary_command_buffers: SegQueue::new(),
                 available_secondary_command_buffers: SegQueue::new(),
@@ -138,6 +199,13 @@ unsafe impl CommandPool for Arc<StandardCommandPool> {
                     break;
                 }
             }
+
+            let reused = if secondary {
+                &self.reused_secondary
+            } else {
+                &self.reused_primary
+            };
+            reused.fetch_add(output.len(), Ordering::Relaxed);
         };
 
         // Then allocate the rest.
@@ -145,7 +213,16 @@ unsafe impl CommandPool for Arc<StandardCommandPool> {
             let pool_lock = per_thread.pool.lock().unwrap();
             let num_new = count as usize - output.len();
 
-            for cmd in pool_lock.alloc_command_buffers(secondary, num_new)? {
+            let new_command_buffers = pool_lock.alloc_command_buffers(secondary, num_new)?;
+
+            let allocated = if secondary {
+                &self.allocated_secondary
+            } else {
+                &self.allocated_primary
+            };
+            allocated.fetch_add(num_new, Ordering::Relaxed);
+
+            for cmd in new_command_buffers {
                 output.push(StandardCommandPoolBuilder {
                     inner: StandardCommandPoolAlloc {
                         cmd: ManuallyDrop::new(cmd),
@@ -231,6 +308,24 @@ pub struct StandardCommandPoolAlloc {
 unsafe impl Send for StandardCommandPoolAlloc {}
 unsafe impl Sync for StandardCommandPoolAlloc {}
 
+impl StandardCommandPoolAlloc {
+    /// Turns the allocation back into a builder, so that the command buffer can be reset and
+    /// recorded again.
+    ///
+    /// Returns back `self` if the current thread is not the thread the command buffer was
+    /// allocated from, as the underlying Vulkan pool must not be accessed from other threads.
+    pub fn into_builder(self) -> Result<StandardCommandPoolBuilder, StandardCommandPoolAlloc> {
+        if self.pool.thread != thread::current().id() {
+            return Err(self);
+        }
+
+        Ok(StandardCommandPoolBuilder {
+            inner: self,
+            dummy_avoid_send_sync: PhantomData,
+        })
+    }
+}
+
 unsafe impl CommandPoolAlloc for StandardCommandPoolAlloc {
     #[inline]
     fn inner(&self) -> &UnsafeCommandPoolAlloc {
@@ -310,4 +405,47 @@ mod tests {
         drop(cb);
         assert!(pool_weak.upgrade().is_none());
     }
+
+    #[test]
+    fn stats() {
+        let (device, queue) = gfx_dev_and_queue!();
+
+        let pool = Arc::new(StandardCommandPool::new(device, queue.family()));
+
+        let cb = pool.alloc(false, 2).unwrap().collect::<Vec<_>>();
+        let stats = pool.stats();
+        assert_eq!(stats.allocated_primary, 2);
+        assert_eq!(stats.reused_primary, 0);
+
+        let keep_alive = pool.alloc(true, 1).unwrap().next().unwrap();
+        drop(cb);
+        assert_eq!(pool.stats().available_primary, 2);
+
+        let _cb = pool.alloc(false, 3).unwrap().collect::<Vec<_>>();
+        let stats = pool.stats();
+        assert_eq!(stats.allocated_primary, 3);
+        assert_eq!(stats.allocated_secondary, 1);
+        assert_eq!(stats.reused_primary, 2);
+        assert_eq!(stats.available_primary, 0);
+        drop(keep_alive);
+    }
+
+    #[test]
+    fn into_builder_same_thread() {
+        let (device, queue) = gfx_dev_and_queue!();
+
+        let pool = Arc::new(StandardCommandPool::new(device, queue.family()));
+        let cb = pool.alloc(false, 1).unwrap().next().unwrap();
+        let raw = cb.inner().internal_object();
+
+        let alloc = cb.into_alloc();
+        let builder = alloc.into_builder().ok().unwrap();
+        assert_eq!(builder.inner().internal_object(), raw);
+
+        let alloc = builder.into_alloc();
+        let alloc = std::thread::spawn(move || alloc.into_builder().err().unwrap())
+            .join()
+            .unwrap();
+        drop(alloc);
+    }
 }
@@ -273,6 +273,31 @@ pub struct UnsafeCommandPoolAlloc {
     device: Arc<Device>,
 }
 
+impl UnsafeCommandPoolAlloc {
+    /// Resets the command buffer, which puts it back in the initial state.
+    ///
+    /// If `release_resources` is true, it is a hint to the implementation that it should give
+    /// back the memory of the command buffer to the pool.
+    ///
+    /// # Safety
+    ///
+    /// - The pool must have been created with `reset_cb` set to true.
+    /// - The command buffer must not be pending execution.
+    /// - The pool must be externally synchronized.
+    ///
+    pub unsafe fn reset(&self, release_resources: bool) -> Result<(), OomError> {
+        let flags = if release_resources {
+            vk::COMMAND_BUFFER_RESET_RELEASE_RESOURCES_BIT
+        } else {
+            0
+        };
+
+        let vk = self.device.pointers();
+        check_errors(vk.ResetCommandBuffer(self.command_buffer, flags))?;
+        Ok(())
+    }
+}
+
 unsafe impl DeviceOwned for UnsafeCommandPoolAlloc {
     #[inline]
     fn device(&self) -> &Arc<Device> {
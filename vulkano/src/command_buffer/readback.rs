@@ -0,0 +1,830 @@
+// Copyright (c) 2021 The vulkano developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>,
+// at your option. All files in the project carrying such
+// notice may not be copied, modified, or distributed except
+// according to those terms.
+
+//! Reading back the content of buffers and images to the CPU.
+
+use crate::buffer::cpu_access::ReadLock;
+use crate::buffer::cpu_access::ReadLockError;
+use crate::buffer::BufferAccess;
+use crate::buffer::BufferInner;
+use crate::buffer::BufferUsage;
+use crate::buffer::CpuAccessibleBuffer;
+use crate::buffer::TypedBufferAccess;
+use crate::command_buffer::pool::CommandPoolBuilderAlloc;
+use crate::command_buffer::validity::required_len_for_format;
+use crate::command_buffer::AutoCommandBufferBuilder;
+use crate::command_buffer::CopyBufferError;
+use crate::command_buffer::CopyBufferImageError;
+use crate::command_buffer::PrimaryAutoCommandBuffer;
+use crate::device::Device;
+use crate::device::DeviceOwned;
+use crate::device::Queue;
+use crate::format::Pixel;
+use crate::image::ImageAccess;
+use crate::memory::DeviceMemoryAllocError;
+use crate::sync::AccessError;
+use std::error;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::Condvar;
+use std::sync::Mutex;
+use std::task::Context;
+use std::task::Poll;
+use std::task::Waker;
+use std::time::Duration;
+use std::time::Instant;
+
+/// Data being copied from the GPU into CPU-accessible memory.
+///
+/// Created with `Readback::from_buffer`, which records a copy command in a primary command buffer
+/// builder. The readback completes once the command buffer has been executed and its GPU future
+/// has been cleaned up, which happens when the fence of the submission is waited upon or awaited,
+/// when `cleanup_finished` is called after the GPU has finished, or automatically if the
+/// `FenceSignalFuture` has been detached with `detach`. It can then be read with `try_read`,
+/// `wait` or by awaiting it as a `std::future::Future`.
+///
+/// If the command buffer is dropped without ever having been submitted, the readback fails with
+/// `ReadbackError::Abandoned`. A submission that fails to lock the resources of the command
+/// buffer doesn't count as having been submitted.
+///
+/// # Example
+///
+/// ```ignore
+/// let readback = Readback::from_buffer(&mut builder, data_buffer.clone())?;
+/// let command_buffer = builder.build()?;
+///
+/// let future = sync::now(device.clone())
+///     .then_execute(queue.clone(), command_buffer)?
+///     .then_signal_fence_and_flush()?;
+/// future.wait(None)?;
+///
+/// let data: Vec<u32> = readback.wait(None)?;
+/// ```
+pub struct Readback<T> {
+    buffer: Arc<CpuAccessibleBuffer<[T]>>,
+    shared: Arc<Shared>,
+}
+
+impl<T> Readback<T>
+where
+    T: Copy + Send + Sync + 'static,
+{
+    /// Records a command in `builder` that copies the whole content of `source` to a new
+    /// CPU-accessible buffer, and returns a handle to that data.
+    pub fn from_buffer<P, S>(
+        builder: &mut AutoCommandBufferBuilder<PrimaryAutoCommandBuffer<P::Alloc>, P>,
+        source: S,
+    ) -> Result<Readback<T>, ReadbackCreationError>
+    where
+        P: CommandPoolBuilderAlloc,
+        S: TypedBufferAccess<Content = [T]> + Send + Sync + 'static,
+    {
+        let (readback, target) = Readback::new(builder.device().clone(), source.len())?;
+        builder.copy_buffer(source, target)?;
+        readback.finish_with(builder);
+        Ok(readback)
+    }
+
+    fn new(
+        device: Arc<Device>,
+        len: usize,
+    ) -> Result<(Readback<T>, ReadbackTarget<T>), DeviceMemoryAllocError> {
+        let buffer = unsafe {
+            CpuAccessibleBuffer::uninitialized_array(
+                device,
+                len,
+                BufferUsage::transfer_destination(),
+                true,
+            )?
+        };
+
+        let shared = Arc::new(Shared {
+            state: Mutex::new(State {
+                status: Status::Recorded,
+                gpu_locks: 0,
+                waker: None,
+            }),
+            condvar: Condvar::new(),
+        });
+
+        let target = ReadbackTarget {
+            buffer: buffer.clone(),
+            shared: shared.clone(),
+        };
+
+        Ok((Readback { buffer, shared }, target))
+    }
+
+    // Marks the data as available once an execution of the command buffer has finished. Only
+    // the command buffer knows this, as the buffer is also unlocked when a submission fails.
+    fn finish_with<P>(
+        &self,
+        builder: &mut AutoCommandBufferBuilder<PrimaryAutoCommandBuffer<P::Alloc>, P>,
+    ) where
+        P: CommandPoolBuilderAlloc,
+    {
+        let shared = self.shared.clone();
+        builder.on_finished(move || {
+            let mut state = shared.state.lock().unwrap();
+            if state.status != Status::Abandoned {
+                shared.set_status(&mut state, Status::Finished);
+            }
+        });
+    }
+
+    /// Returns the CPU-accessible buffer the data is copied to.
+    ///
+    /// This can be used to read the data in place instead of copying it to a `Vec`.
+    #[inline]
+    pub fn buffer(&self) -> &Arc<CpuAccessibleBuffer<[T]>> {
+        &self.buffer
+    }
+
+    /// Returns the number of elements being read back.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns true if there are no elements being read back.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns true if the GPU has finished writing the data.
+    #[inline]
+    pub fn is_ready(&self) -> bool {
+        self.shared.state.lock().unwrap().status == Status::Finished
+    }
+
+    /// Returns the data if the GPU has finished writing it, or `None` otherwise.
+    pub fn try_read(&self) -> Option<Result<Vec<T>, ReadbackError>> {
+        let state = self.shared.state.lock().unwrap();
+
+        match state.status {
+            Status::Recorded | Status::Submitted => None,
+            Status::Finished => Some(self.read_vec()),
+            Status::Abandoned => Some(Err(ReadbackError::Abandoned)),
+        }
+    }
+
+    /// Blocks the current thread until the GPU has finished writing the data, and returns it.
+    ///
+    /// Another thread must wait upon or clean up the GPU future of the submission, otherwise
+    /// this function only returns once the timeout has elapsed.
+    pub fn wait(&self, timeout: Option<Duration>) -> Result<Vec<T>, ReadbackError> {
+        self.shared.wait(timeout)?;
+        self.read_vec()
+    }
+
+    /// Locks the buffer for reading and returns the data without copying it, if the GPU has
+    /// finished writing it.
+    pub fn read(&self) -> Result<ReadLock<'_, [T]>, ReadbackError> {
+        match self.shared.state.lock().unwrap().status {
+            Status::Recorded | Status::Submitted => return Err(ReadbackError::NotReady),
+            Status::Finished => (),
+            Status::Abandoned => return Err(ReadbackError::Abandoned),
+        }
+
+        Ok(self.buffer.read()?)
+    }
+
+    fn read_vec(&self) -> Result<Vec<T>, ReadbackError> {
+        Ok(self.buffer.read()?.to_vec())
+    }
+}
+
+impl<T> Future for Readback<T>
+where
+    T: Copy + Send + Sync + 'static,
+{
+    type Output = Result<Vec<T>, ReadbackError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let mut state = self.shared.state.lock().unwrap();
+
+        match state.status {
+            Status::Recorded | Status::Submitted => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+            Status::Finished => Poll::Ready(self.read_vec()),
+            Status::Abandoned => Poll::Ready(Err(ReadbackError::Abandoned)),
+        }
+    }
+}
+
+impl<T> fmt::Debug for Readback<T>
+where
+    T: Send + Sync + 'static,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("Readback")
+            .field("len", &self.buffer.len())
+            .field("status", &self.shared.state.lock().unwrap().status)
+            .finish()
+    }
+}
+
+/// Region of an image being copied from the GPU into CPU-accessible memory.
+///
+/// Works like `Readback`, except that the data is returned as `ImageData`, which knows how the
+/// texels are laid out in memory.
+pub struct ImageReadback<Px> {
+    inner: Readback<Px>,
+    layout: ImageDataLayout,
+}
+
+impl<Px> ImageReadback<Px>
+where
+    Px: Pixel + Copy + Send + Sync + 'static,
+{
+    /// Records a command in `builder` that copies the whole content of the first layer and first
+    /// mipmap level of `image` to a new CPU-accessible buffer, and returns a handle to that data.
+    pub fn from_image<P, I>(
+        builder: &mut AutoCommandBufferBuilder<PrimaryAutoCommandBuffer<P::Alloc>, P>,
+        image: I,
+    ) -> Result<ImageReadback<Px>, ReadbackCreationError>
+    where
+        P: CommandPoolBuilderAlloc,
+        I: ImageAccess + Send + Sync + 'static,
+    {
+        let dims = image.dimensions().width_height_depth();
+        ImageReadback::from_image_region(builder, image, [0, 0, 0], dims, 0, 1, 0)
+    }
+
+    /// Records a command in `builder` that copies a region of `image` to a new CPU-accessible
+    /// buffer, and returns a handle to that data.
+    pub fn from_image_region<P, I>(
+        builder: &mut AutoCommandBufferBuilder<PrimaryAutoCommandBuffer<P::Alloc>, P>,
+        image: I,
+        offset: [u32; 3],
+        size: [u32; 3],
+        first_layer: u32,
+        num_layers: u32,
+        mipmap: u32,
+    ) -> Result<ImageReadback<Px>, ReadbackCreationError>
+    where
+        P: CommandPoolBuilderAlloc,
+        I: ImageAccess + Send + Sync + 'static,
+    {
+        let format = image.format();
+        let (block_width, block_height) = format.block_dimensions();
+
+        let layout = ImageDataLayout {
+            dimensions: size,
+            array_layers: num_layers,
+            row_pitch: required_len_for_format::<Px>(format, [size[0], 1, 1], 1),
+            rows: (size[1] + block_height - 1) / block_height,
+            block_dimensions: [block_width, block_height],
+        };
+
+        let len = required_len_for_format::<Px>(format, size, num_layers);
+        let (inner, target) = Readback::new(builder.device().clone(), len)?;
+        builder.copy_image_to_buffer_dimensions(
+            image,
+            target,
+            offset,
+            size,
+            first_layer,
+            num_layers,
+            mipmap,
+        )?;
+        inner.finish_with(builder);
+
+        Ok(ImageReadback { inner, layout })
+    }
+
+    /// Returns the CPU-accessible buffer the data is copied to.
+    #[inline]
+    pub fn buffer(&self) -> &Arc<CpuAccessibleBuffer<[Px]>> {
+        self.inner.buffer()
+    }
+
+    /// Returns true if the GPU has finished writing the data.
+    #[inline]
+    pub fn is_ready(&self) -> bool {
+        self.inner.is_ready()
+    }
+
+    /// Returns the data if the GPU has finished writing it, or `None` otherwise.
+    pub fn try_read(&self) -> Option<Result<ImageData<Px>, ReadbackError>> {
+        let layout = self.layout;
+        self.inner
+            .try_read()
+            .map(|result| result.map(|data| ImageData { data, layout }))
+    }
+
+    /// Blocks the current thread until the GPU has finished writing the data, and returns it.
+    ///
+    /// See `Readback::wait`.
+    pub fn wait(&self, timeout: Option<Duration>) -> Result<ImageData<Px>, ReadbackError> {
+        let data = self.inner.wait(timeout)?;
+        Ok(ImageData {
+            data,
+            layout: self.layout,
+        })
+    }
+}
+
+impl<Px> Future for ImageReadback<Px>
+where
+    Px: Pixel + Copy + Send + Sync + 'static,
+{
+    type Output = Result<ImageData<Px>, ReadbackError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let layout = self.layout;
+        Pin::new(&mut self.inner)
+            .poll(cx)
+            .map(|result| result.map(|data| ImageData { data, layout }))
+    }
+}
+
+impl<Px> fmt::Debug for ImageReadback<Px> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("ImageReadback")
+            .field("dimensions", &self.layout.dimensions)
+            .field("array_layers", &self.layout.array_layers)
+            .field("status", &self.inner.shared.state.lock().unwrap().status)
+            .finish()
+    }
+}
+
+/// Texels read back from an image.
+///
+/// The data is tightly packed: rows follow each other, then depth slices, then array layers.
+/// For block-compressed formats, a row is a row of blocks and the pitches are measured in
+/// blocks.
+#[derive(Debug, Clone)]
+pub struct ImageData<Px> {
+    data: Vec<Px>,
+    layout: ImageDataLayout,
+}
+
+#[derive(Debug, Copy, Clone)]
+struct ImageDataLayout {
+    dimensions: [u32; 3],
+    array_layers: u32,
+    // Number of `Px` in a row.
+    row_pitch: usize,
+    // Number of rows in a depth slice.
+    rows: u32,
+    block_dimensions: [u32; 2],
+}
+
+impl<Px> ImageData<Px> {
+    /// Returns the width, height and depth of the region that was read back, in texels.
+    #[inline]
+    pub fn dimensions(&self) -> [u32; 3] {
+        self.layout.dimensions
+    }
+
+    /// Returns the number of array layers that were read back.
+    #[inline]
+    pub fn array_layers(&self) -> u32 {
+        self.layout.array_layers
+    }
+
+    /// Returns the width and height in texels of a block of the format. This is `[1, 1]` for
+    /// uncompressed formats.
+    #[inline]
+    pub fn block_dimensions(&self) -> [u32; 2] {
+        self.layout.block_dimensions
+    }
+
+    /// Returns the number of `Px` between the start of a row and the start of the next one.
+    #[inline]
+    pub fn row_pitch(&self) -> usize {
+        self.layout.row_pitch
+    }
+
+    /// Returns the number of `Px` between the start of a depth slice and the start of the next
+    /// one.
+    #[inline]
+    pub fn depth_pitch(&self) -> usize {
+        self.layout.row_pitch * self.layout.rows as usize
+    }
+
+    /// Returns the number of `Px` between the start of an array layer and the start of the next
+    /// one.
+    #[inline]
+    pub fn layer_pitch(&self) -> usize {
+        self.depth_pitch() * self.layout.dimensions[2] as usize
+    }
+
+    /// Returns the row `y` of the depth slice `z` of the array layer `layer`, relative to the
+    /// region that was read back.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if the row is out of range.
+    ///
+    #[inline]
+    pub fn row(&self, y: u32, z: u32, layer: u32) -> &[Px] {
+        assert!(y < self.layout.rows);
+        assert!(z < self.layout.dimensions[2]);
+        assert!(layer < self.layout.array_layers);
+
+        let start = layer as usize * self.layer_pitch()
+            + z as usize * self.depth_pitch()
+            + y as usize * self.row_pitch();
+        &self.data[start..start + self.row_pitch()]
+    }
+
+    /// Returns all the data.
+    #[inline]
+    pub fn as_slice(&self) -> &[Px] {
+        &self.data
+    }
+
+    /// Returns all the data as a `Vec`.
+    #[inline]
+    pub fn into_vec(self) -> Vec<Px> {
+        self.data
+    }
+}
+
+struct Shared {
+    state: Mutex<State>,
+    condvar: Condvar,
+}
+
+struct State {
+    status: Status,
+    // Number of GPU locks currently held on the buffer.
+    gpu_locks: usize,
+    waker: Option<Waker>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Status {
+    // The copy command has been recorded but not submitted.
+    Recorded,
+    // The resources of the command buffer are locked for a submission, and the GPU may be
+    // writing the data.
+    Submitted,
+    // The data is available.
+    Finished,
+    // The command buffer was destroyed without being submitted.
+    Abandoned,
+}
+
+impl Shared {
+    fn wait(&self, timeout: Option<Duration>) -> Result<(), ReadbackError> {
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+        let mut state = self.state.lock().unwrap();
+
+        loop {
+            match state.status {
+                Status::Recorded | Status::Submitted => (),
+                Status::Finished => return Ok(()),
+                Status::Abandoned => return Err(ReadbackError::Abandoned),
+            }
+
+            state = match deadline {
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return Err(ReadbackError::Timeout);
+                    }
+                    self.condvar.wait_timeout(state, deadline - now).unwrap().0
+                }
+                None => self.condvar.wait(state).unwrap(),
+            };
+        }
+    }
+
+    fn set_status(&self, state: &mut State, status: Status) {
+        state.status = status;
+        self.condvar.notify_all();
+
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+// Destination of the copy command. Tracks the GPU locks taken on the buffer in order to know
+// whether a submission is in progress.
+struct ReadbackTarget<T> {
+    buffer: Arc<CpuAccessibleBuffer<[T]>>,
+    shared: Arc<Shared>,
+}
+
+unsafe impl<T> BufferAccess for ReadbackTarget<T>
+where
+    T: Send + Sync + 'static,
+{
+    #[inline]
+    fn inner(&self) -> BufferInner<'_> {
+        self.buffer.inner()
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        self.buffer.size()
+    }
+
+    #[inline]
+    fn conflicts_buffer(&self, other: &dyn BufferAccess) -> bool {
+        self.buffer.conflicts_buffer(other)
+    }
+
+    #[inline]
+    fn conflicts_image(&self, other: &dyn ImageAccess) -> bool {
+        self.buffer.conflicts_image(other)
+    }
+
+    #[inline]
+    fn conflict_key(&self) -> (u64, usize) {
+        self.buffer.conflict_key()
+    }
+
+    #[inline]
+    fn try_gpu_lock(&self, exclusive_access: bool, queue: &Queue) -> Result<(), AccessError> {
+        self.buffer.try_gpu_lock(exclusive_access, queue)?;
+
+        let mut state = self.shared.state.lock().unwrap();
+        state.gpu_locks += 1;
+        if state.status == Status::Recorded {
+            self.shared.set_status(&mut state, Status::Submitted);
+        }
+        Ok(())
+    }
+
+    #[inline]
+    unsafe fn increase_gpu_lock(&self) {
+        self.buffer.increase_gpu_lock();
+        self.shared.state.lock().unwrap().gpu_locks += 1;
+    }
+
+    #[inline]
+    unsafe fn unlock(&self) {
+        self.buffer.unlock();
+
+        // This is also called when the submission fails to lock the other resources of the
+        // command buffer, so the data isn't necessarily available. The command buffer marks the
+        // readback as finished once an execution has completed.
+        let mut state = self.shared.state.lock().unwrap();
+        debug_assert!(state.gpu_locks >= 1);
+        state.gpu_locks -= 1;
+        if state.gpu_locks == 0 && state.status == Status::Submitted {
+            self.shared.set_status(&mut state, Status::Recorded);
+        }
+    }
+}
+
+unsafe impl<T> TypedBufferAccess for ReadbackTarget<T>
+where
+    T: Send + Sync + 'static,
+{
+    type Content = [T];
+}
+
+unsafe impl<T> DeviceOwned for ReadbackTarget<T> {
+    #[inline]
+    fn device(&self) -> &Arc<Device> {
+        self.buffer.device()
+    }
+}
+
+impl<T> Drop for ReadbackTarget<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.state.lock().unwrap();
+        if state.status == Status::Recorded {
+            self.shared.set_status(&mut state, Status::Abandoned);
+        }
+    }
+}
+
+/// Error that can happen when recording a readback.
+#[derive(Debug, Clone)]
+pub enum ReadbackCreationError {
+    /// Allocating the CPU-accessible buffer failed.
+    DeviceMemoryAllocError(DeviceMemoryAllocError),
+    /// Recording the buffer copy failed.
+    CopyBufferError(CopyBufferError),
+    /// Recording the image to buffer copy failed.
+    CopyBufferImageError(CopyBufferImageError),
+}
+
+impl error::Error for ReadbackCreationError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            Self::DeviceMemoryAllocError(ref err) => Some(err),
+            Self::CopyBufferError(ref err) => Some(err),
+            Self::CopyBufferImageError(ref err) => Some(err),
+        }
+    }
+}
+
+impl fmt::Display for ReadbackCreationError {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(
+            fmt,
+            "{}",
+            match *self {
+                Self::DeviceMemoryAllocError(_) => "allocating the readback buffer failed",
+                Self::CopyBufferError(_) => "recording the buffer copy failed",
+                Self::CopyBufferImageError(_) => "recording the image to buffer copy failed",
+            }
+        )
+    }
+}
+
+impl From<DeviceMemoryAllocError> for ReadbackCreationError {
+    #[inline]
+    fn from(err: DeviceMemoryAllocError) -> Self {
+        Self::DeviceMemoryAllocError(err)
+    }
+}
+
+impl From<CopyBufferError> for ReadbackCreationError {
+    #[inline]
+    fn from(err: CopyBufferError) -> Self {
+        Self::CopyBufferError(err)
+    }
+}
+
+impl From<CopyBufferImageError> for ReadbackCreationError {
+    #[inline]
+    fn from(err: CopyBufferImageError) -> Self {
+        Self::CopyBufferImageError(err)
+    }
+}
+
+/// Error that can happen when reading the result of a readback.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReadbackError {
+    /// The GPU has not finished writing the data yet.
+    NotReady,
+    /// The timeout has elapsed before the GPU finished writing the data.
+    Timeout,
+    /// The command buffer containing the copy was destroyed without being submitted.
+    Abandoned,
+    /// The buffer could not be locked for reading.
+    ReadLockError(ReadLockError),
+}
+
+impl error::Error for ReadbackError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            Self::ReadLockError(ref err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ReadbackError {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(
+            fmt,
+            "{}",
+            match *self {
+                Self::NotReady => "the GPU has not finished writing the data yet",
+                Self::Timeout => "the timeout has elapsed before the data was ready",
+                Self::Abandoned => {
+                    "the command buffer containing the copy was destroyed without being submitted"
+                }
+                Self::ReadLockError(_) => "the buffer could not be locked for reading",
+            }
+        )
+    }
+}
+
+impl From<ReadLockError> for ReadbackError {
+    #[inline]
+    fn from(err: ReadLockError) -> Self {
+        Self::ReadLockError(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::buffer::BufferUsage;
+    use crate::buffer::CpuAccessibleBuffer;
+    use crate::command_buffer::AutoCommandBufferBuilder;
+    use crate::command_buffer::PrimaryCommandBuffer;
+    use crate::command_buffer::Readback;
+    use crate::command_buffer::ReadbackError;
+    use crate::sync::GpuFuture;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[test]
+    fn read_buffer() {
+        let (device, queue) = gfx_dev_and_queue!();
+
+        let source = CpuAccessibleBuffer::from_iter(
+            device.clone(),
+            BufferUsage::transfer_source(),
+            false,
+            0..64u32,
+        )
+        .unwrap();
+
+        let mut builder =
+            AutoCommandBufferBuilder::primary_one_time_submit(device.clone(), queue.family())
+                .unwrap();
+        let readback = Readback::from_buffer(&mut builder, source).unwrap();
+        let cb = builder.build().unwrap();
+        assert!(readback.try_read().is_none());
+
+        let future = cb
+            .execute(queue.clone())
+            .unwrap()
+            .then_signal_fence_and_flush()
+            .unwrap();
+        future.wait(None).unwrap();
+        drop(future);
+
+        assert!(readback.is_ready());
+        assert_eq!(
+            readback.wait(Some(Duration::from_secs(0))).unwrap(),
+            (0..64u32).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn failed_lock() {
+        let (device, queue) = gfx_dev_and_queue!();
+
+        let source = CpuAccessibleBuffer::from_iter(
+            device.clone(),
+            BufferUsage::transfer_source(),
+            false,
+            0..16u32,
+        )
+        .unwrap();
+
+        let mut builder =
+            AutoCommandBufferBuilder::primary_one_time_submit(device.clone(), queue.family())
+                .unwrap();
+        let readback = Readback::from_buffer(&mut builder, source.clone()).unwrap();
+        let cb = Arc::new(builder.build().unwrap());
+
+        // The source is locked by the CPU, so the submission fails after the destination may
+        // have been locked and unlocked again.
+        let lock = source.write().unwrap();
+        assert!(cb.clone().execute(queue.clone()).is_err());
+        assert!(!readback.is_ready());
+        assert!(readback.try_read().is_none());
+        assert_eq!(
+            readback.wait(Some(Duration::from_millis(1))),
+            Err(ReadbackError::Timeout)
+        );
+        drop(lock);
+
+        let future = cb
+            .execute(queue.clone())
+            .unwrap()
+            .then_signal_fence_and_flush()
+            .unwrap();
+        future.wait(None).unwrap();
+        drop(future);
+
+        assert_eq!(
+            readback.wait(Some(Duration::from_secs(0))).unwrap(),
+            (0..16u32).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn abandoned() {
+        let (device, queue) = gfx_dev_and_queue!();
+
+        let source = CpuAccessibleBuffer::from_iter(
+            device.clone(),
+            BufferUsage::transfer_source(),
+            false,
+            0..4u8,
+        )
+        .unwrap();
+
+        let mut builder =
+            AutoCommandBufferBuilder::primary_one_time_submit(device.clone(), queue.family())
+                .unwrap();
+        let readback = Readback::from_buffer(&mut builder, source).unwrap();
+        assert_eq!(
+            readback.wait(Some(Duration::from_millis(1))),
+            Err(ReadbackError::Timeout)
+        );
+
+        drop(builder);
+        assert_eq!(readback.wait(None), Err(ReadbackError::Abandoned));
+    }
+}
@@ -0,0 +1,207 @@
+// Copyright (c) 2021 The vulkano developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>,
+// at your option. All files in the project carrying such
+// notice may not be copied, modified, or distributed except
+// according to those terms.
+
+//! Ring of primary command buffers that are reused from one frame to the next.
+
+use crate::command_buffer::pool::standard::StandardCommandPoolBuilder;
+use crate::command_buffer::AutoCommandBufferBuilder;
+use crate::command_buffer::CommandBufferResetError;
+use crate::command_buffer::PrimaryAutoCommandBuffer;
+use crate::device::Device;
+use crate::device::DeviceOwned;
+use crate::instance::QueueFamily;
+use crate::OomError;
+use crate::VulkanObject;
+use std::marker::PhantomData;
+use std::mem;
+use std::sync::Arc;
+
+/// Per-thread ring of primary command buffers, with one slot per frame in flight.
+///
+/// Each frame, call `next_frame` once the fence of the frame that previously used the slot has
+/// been waited upon and its future has been cleaned up. Command buffers obtained with `primary`
+/// then reuse the Vulkan command buffers that were recorded into that slot, by resetting them
+/// instead of allocating new ones. Command buffers must be registered with `track` after they
+/// have been built, otherwise they are simply returned to the pool when dropped.
+///
+/// A command buffer of the slot is only reused if nothing else holds a reference to it. If it is
+/// still kept alive by a future, a new command buffer is allocated instead.
+///
+/// Like command buffer builders, a `CommandBufferRing` can't be moved between threads.
+pub struct CommandBufferRing {
+    device: Arc<Device>,
+    queue_family: u32,
+    // Command buffers recorded during each frame in flight.
+    frames: Vec<Vec<Arc<PrimaryAutoCommandBuffer>>>,
+    // Index within `frames` of the current frame.
+    current: usize,
+    // Unimplemented `Send` and `Sync`, since the command buffers can only be reset by the
+    // thread that allocated them.
+    dummy_avoid_send_sync: PhantomData<*const u8>,
+}
+
+impl CommandBufferRing {
+    /// Builds a new ring with `frames_in_flight` slots.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if `frames_in_flight` is 0.
+    /// - Panics if the device and the queue family don't belong to the same physical device.
+    ///
+    pub fn new(
+        device: Arc<Device>,
+        queue_family: QueueFamily,
+        frames_in_flight: usize,
+    ) -> CommandBufferRing {
+        assert!(frames_in_flight >= 1);
+        assert_eq!(
+            device.physical_device().internal_object(),
+            queue_family.physical_device().internal_object()
+        );
+
+        CommandBufferRing {
+            device,
+            queue_family: queue_family.id(),
+            frames: (0..frames_in_flight).map(|_| Vec::new()).collect(),
+            current: 0,
+            dummy_avoid_send_sync: PhantomData,
+        }
+    }
+
+    /// Returns the number of frames in flight.
+    #[inline]
+    pub fn frames_in_flight(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Returns the index of the current slot.
+    #[inline]
+    pub fn current_frame(&self) -> usize {
+        self.current
+    }
+
+    /// Returns the queue family the command buffers are allocated for.
+    #[inline]
+    pub fn queue_family(&self) -> QueueFamily<'_> {
+        self.device
+            .physical_device()
+            .queue_family_by_id(self.queue_family)
+            .unwrap()
+    }
+
+    /// Advances to the next slot of the ring.
+    ///
+    /// The command buffers of the new slot become available for reuse once every other
+    /// reference to them has been dropped.
+    #[inline]
+    pub fn next_frame(&mut self) {
+        self.current = (self.current + 1) % self.frames.len();
+    }
+
+    /// Starts recording a primary command buffer for the current frame.
+    ///
+    /// Reuses a command buffer previously tracked in the current slot if one is no longer in
+    /// use, and allocates a new one otherwise.
+    pub fn primary(
+        &mut self,
+    ) -> Result<
+        AutoCommandBufferBuilder<PrimaryAutoCommandBuffer, StandardCommandPoolBuilder>,
+        OomError,
+    > {
+        let slot = &mut self.frames[self.current];
+        let mut still_in_use = Vec::with_capacity(slot.len());
+        let mut reusable = None;
+
+        for cb in mem::take(slot) {
+            if reusable.is_some() {
+                still_in_use.push(cb);
+                continue;
+            }
+
+            match Arc::try_unwrap(cb) {
+                Ok(cb) => reusable = Some(cb),
+                Err(cb) => still_in_use.push(cb),
+            }
+        }
+
+        *slot = still_in_use;
+
+        match reusable {
+            Some(cb) => match cb.reset() {
+                Ok(builder) => Ok(builder),
+                Err(CommandBufferResetError::OomError(err)) => Err(err),
+                Err(CommandBufferResetError::WrongThread) => {
+                    unreachable!("CommandBufferRing is not Send")
+                }
+            },
+            None => AutoCommandBufferBuilder::primary(self.device.clone(), self.queue_family()),
+        }
+    }
+
+    /// Registers a built command buffer in the current slot, so that it can be reused once the
+    /// ring comes back to this slot.
+    ///
+    /// Returns the command buffer wrapped in an `Arc`, ready to be executed.
+    pub fn track(&mut self, cb: PrimaryAutoCommandBuffer) -> Arc<PrimaryAutoCommandBuffer> {
+        assert_eq!(cb.device().internal_object(), self.device.internal_object());
+
+        let cb = Arc::new(cb);
+        self.frames[self.current].push(cb.clone());
+        cb
+    }
+}
+
+unsafe impl DeviceOwned for CommandBufferRing {
+    #[inline]
+    fn device(&self) -> &Arc<Device> {
+        &self.device
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::command_buffer::CommandBufferRing;
+    use crate::command_buffer::PrimaryCommandBuffer;
+    use crate::VulkanObject;
+
+    #[test]
+    fn reuses_command_buffers() {
+        let (device, queue) = gfx_dev_and_queue!();
+
+        let mut ring = CommandBufferRing::new(device, queue.family(), 2);
+
+        let cb = ring.primary().unwrap().build().unwrap();
+        let cb = ring.track(cb);
+        let raw = cb.inner().internal_object();
+        drop(cb);
+
+        ring.next_frame();
+        let cb2 = ring.primary().unwrap().build().unwrap();
+        let cb2 = ring.track(cb2);
+        assert_ne!(cb2.inner().internal_object(), raw);
+
+        ring.next_frame();
+        let cb3 = ring.primary().unwrap().build().unwrap();
+        assert_eq!(cb3.inner().internal_object(), raw);
+        drop(cb2);
+    }
+
+    #[test]
+    fn in_use_not_reused() {
+        let (device, queue) = gfx_dev_and_queue!();
+
+        let mut ring = CommandBufferRing::new(device, queue.family(), 1);
+
+        let cb = ring.primary().unwrap().build().unwrap();
+        let cb = ring.track(cb);
+        ring.next_frame();
+        let cb2 = ring.primary().unwrap().build().unwrap();
+        assert_ne!(cb.inner().internal_object(), cb2.inner().internal_object());
+    }
+}
@@ -16,6 +16,7 @@ use std::ptr;
 use crate::buffer::sys::UnsafeBuffer;
 use crate::device::Queue;
 use crate::image::sys::UnsafeImage;
+use crate::image::ImageAspect;
 use crate::memory::DeviceMemory;
 use crate::sync::Fence;
 use crate::sync::Semaphore;
@@ -121,6 +122,25 @@ impl<'a> SubmitBindSparseBuilder<'a> {
         self.fence = fence.internal_object();
     }
 
+    /// Adds a semaphore that is going to be signaled at the end of the last batch of the
+    /// submission. If there is no batch yet, an empty batch is added.
+    ///
+    /// # Safety
+    ///
+    /// Same as `SubmitBindSparseBatchBuilder::add_signal_semaphore`.
+    ///
+    #[inline]
+    pub unsafe fn add_signal_semaphore(&mut self, semaphore: &'a Semaphore) {
+        if self.infos.is_empty() {
+            self.infos.push(SubmitBindSparseBatchBuilder::new());
+        }
+
+        self.infos
+            .last_mut()
+            .unwrap()
+            .add_signal_semaphore(semaphore);
+    }
+
     /// Attempts to merge this builder with another one.
     ///
     /// If both builders have a fence already set, then this function will return `other` as an
@@ -367,6 +387,32 @@ impl<'a> SubmitBindSparseBufferBindBuilder<'a> {
         }
     }
 
+    /// Same as `new`, but from a raw buffer handle.
+    pub(crate) unsafe fn from_raw(buffer: vk::Buffer) -> SubmitBindSparseBufferBindBuilder<'a> {
+        SubmitBindSparseBufferBindBuilder {
+            buffer,
+            binds: SmallVec::new(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Same as `add_bind` and `add_unbind`, but with a raw memory handle. A null handle unbinds.
+    pub(crate) unsafe fn add_raw_bind(
+        &mut self,
+        offset: usize,
+        size: usize,
+        memory: vk::DeviceMemory,
+        memory_offset: usize,
+    ) {
+        self.binds.push(vk::SparseMemoryBind {
+            resourceOffset: offset as vk::DeviceSize,
+            size: size as vk::DeviceSize,
+            memory,
+            memoryOffset: memory_offset as vk::DeviceSize,
+            flags: 0,
+        });
+    }
+
     pub unsafe fn add_bind(
         &mut self,
         offset: usize,
@@ -413,6 +459,37 @@ impl<'a> SubmitBindSparseImageOpaqueBindBuilder<'a> {
         }
     }
 
+    /// Same as `new`, but from a raw image handle.
+    pub(crate) unsafe fn from_raw(image: vk::Image) -> SubmitBindSparseImageOpaqueBindBuilder<'a> {
+        SubmitBindSparseImageOpaqueBindBuilder {
+            image,
+            binds: SmallVec::new(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Same as `add_bind` and `add_unbind`, but with a raw memory handle. A null handle unbinds.
+    pub(crate) unsafe fn add_raw_bind(
+        &mut self,
+        offset: usize,
+        size: usize,
+        memory: vk::DeviceMemory,
+        memory_offset: usize,
+        bind_metadata: bool,
+    ) {
+        self.binds.push(vk::SparseMemoryBind {
+            resourceOffset: offset as vk::DeviceSize,
+            size: size as vk::DeviceSize,
+            memory,
+            memoryOffset: memory_offset as vk::DeviceSize,
+            flags: if bind_metadata {
+                vk::SPARSE_MEMORY_BIND_METADATA_BIT
+            } else {
+                0
+            },
+        });
+    }
+
     pub unsafe fn add_bind(
         &mut self,
         offset: usize,
@@ -464,7 +541,95 @@ impl<'a> SubmitBindSparseImageBindBuilder<'a> {
         }
     }
 
-    // TODO: finish
+    /// Same as `new`, but from a raw image handle.
+    pub(crate) unsafe fn from_raw(image: vk::Image) -> SubmitBindSparseImageBindBuilder<'a> {
+        SubmitBindSparseImageBindBuilder {
+            image,
+            binds: SmallVec::new(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Binds memory to a region of a mipmap level and array layer of the image.
+    ///
+    /// `offset` and `extent` are in texels, and must be multiples of the sparse image block
+    /// shape unless the region touches the edge of the mipmap level.
+    ///
+    /// # Safety
+    ///
+    /// - The region must be within the image and outside of its mip tail.
+    /// - The memory must be suitable for the image, and be kept alive while it is bound.
+    ///
+    pub unsafe fn add_bind(
+        &mut self,
+        aspect: ImageAspect,
+        mip_level: u32,
+        array_layer: u32,
+        offset: [i32; 3],
+        extent: [u32; 3],
+        memory: &DeviceMemory,
+        memory_offset: usize,
+    ) {
+        self.add_raw_bind(
+            aspect,
+            mip_level,
+            array_layer,
+            offset,
+            extent,
+            memory.internal_object(),
+            memory_offset,
+        );
+    }
+
+    /// Unbinds the memory of a region of a mipmap level and array layer of the image.
+    ///
+    /// # Safety
+    ///
+    /// - The region must be within the image and outside of its mip tail.
+    ///
+    pub unsafe fn add_unbind(
+        &mut self,
+        aspect: ImageAspect,
+        mip_level: u32,
+        array_layer: u32,
+        offset: [i32; 3],
+        extent: [u32; 3],
+    ) {
+        self.add_raw_bind(aspect, mip_level, array_layer, offset, extent, 0, 0);
+    }
+
+    /// Same as `add_bind` and `add_unbind`, but with a raw memory handle. A null handle unbinds.
+    pub(crate) unsafe fn add_raw_bind(
+        &mut self,
+        aspect: ImageAspect,
+        mip_level: u32,
+        array_layer: u32,
+        offset: [i32; 3],
+        extent: [u32; 3],
+        memory: vk::DeviceMemory,
+        memory_offset: usize,
+    ) {
+        self.binds.push(vk::SparseImageMemoryBind {
+            subresource: vk::ImageSubresource {
+                aspectMask: aspect.into(),
+                mipLevel: mip_level,
+                arrayLayer: array_layer,
+            },
+            offset: vk::Offset3D {
+                x: offset[0],
+                y: offset[1],
+                z: offset[2],
+            },
+            extent: vk::Extent3D {
+                width: extent[0],
+                height: extent[1],
+                depth: extent[2],
+            },
+            memory,
+            memoryOffset: memory_offset as vk::DeviceSize,
+            flags: 0,
+        });
+    }
 }
 
 /// Error that can happen when submitting the present prototype.
@@ -11,11 +11,13 @@ use smallvec::SmallVec;
 use std::error;
 use std::fmt;
 use std::marker::PhantomData;
+use std::os::raw::c_void;
 use std::ptr;
 
 use crate::device::DeviceOwned;
 use crate::device::Queue;
 use crate::swapchain::PresentRegion;
+use crate::swapchain::PresentTime;
 use crate::swapchain::Swapchain;
 use crate::sync::Semaphore;
 
@@ -34,6 +36,10 @@ pub struct SubmitPresentBuilder<'a> {
     image_indices: SmallVec<[u32; 4]>,
     present_regions: SmallVec<[vk::PresentRegionKHR; 4]>,
     rect_layers: SmallVec<[vk::RectLayerKHR; 4]>,
+    // Only passed to Vulkan if at least one present time or present ID was set.
+    present_times: SmallVec<[vk::PresentTimeGOOGLE; 4]>,
+    has_present_times: bool,
+    present_ids: SmallVec<[u64; 4]>,
     marker: PhantomData<&'a ()>,
 }
 
@@ -47,6 +53,9 @@ impl<'a> SubmitPresentBuilder<'a> {
             image_indices: SmallVec::new(),
             present_regions: SmallVec::new(),
             rect_layers: SmallVec::new(),
+            present_times: SmallVec::new(),
+            has_present_times: false,
+            present_ids: SmallVec::new(),
             marker: PhantomData,
         }
     }
@@ -124,6 +133,50 @@ impl<'a> SubmitPresentBuilder<'a> {
 
         self.swapchains.push(swapchain.internal_object());
         self.image_indices.push(image_num);
+        self.present_times.push(vk::PresentTimeGOOGLE {
+            presentID: 0,
+            desiredPresentTime: 0,
+        });
+        self.present_ids.push(0);
+    }
+
+    /// Same as `add_swapchain`, but also allows specifying the time at which the image should be
+    /// presented and an identifier for the present operation.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if `present_time` is `Some` and the `google_display_timing` extension is not
+    ///   enabled on the device.
+    /// - Panics if `present_id` is `Some` and the `present_id` feature is not enabled on the
+    ///   device.
+    /// - Panics if `present_id` is `Some(0)`.
+    ///
+    /// # Safety
+    ///
+    /// Same as `add_swapchain`.
+    ///
+    #[inline]
+    pub unsafe fn add_swapchain_with_timing<W>(
+        &mut self,
+        swapchain: &'a Swapchain<W>,
+        image_num: u32,
+        present_region: Option<&'a PresentRegion>,
+        present_time: Option<PresentTime>,
+        present_id: Option<u64>,
+    ) {
+        self.add_swapchain(swapchain, image_num, present_region);
+
+        if let Some(present_time) = present_time {
+            assert!(swapchain.device().loaded_extensions().google_display_timing);
+            *self.present_times.last_mut().unwrap() = present_time.to_vk();
+            self.has_present_times = true;
+        }
+
+        if let Some(present_id) = present_id {
+            assert!(swapchain.device().enabled_features().present_id);
+            assert_ne!(present_id, 0);
+            *self.present_ids.last_mut().unwrap() = present_id;
+        }
     }
 
     /// Submits the command. Calls `vkQueuePresentKHR`.
@@ -140,7 +193,7 @@ impl<'a> SubmitPresentBuilder<'a> {
                 "Tried to submit a present command without any swapchain"
             );
 
-            let present_regions = {
+            let mut present_regions = {
                 if !self.present_regions.is_empty() {
                     debug_assert!(queue.device().loaded_extensions().khr_incremental_present);
                     debug_assert_eq!(self.swapchains.len(), self.present_regions.len());
@@ -160,6 +213,41 @@ impl<'a> SubmitPresentBuilder<'a> {
                 }
             };
 
+            let mut next: *const c_void = ptr::null();
+
+            let present_ids = if self.present_ids.iter().any(|&id| id != 0) {
+                Some(vk::PresentIdKHR {
+                    sType: vk::STRUCTURE_TYPE_PRESENT_ID_KHR,
+                    pNext: next,
+                    swapchainCount: self.present_ids.len() as u32,
+                    pPresentIds: self.present_ids.as_ptr(),
+                })
+            } else {
+                None
+            };
+            if let Some(ref present_ids) = present_ids {
+                next = present_ids as *const vk::PresentIdKHR as *const _;
+            }
+
+            let present_times = if self.has_present_times {
+                Some(vk::PresentTimesInfoGOOGLE {
+                    sType: vk::STRUCTURE_TYPE_PRESENT_TIMES_INFO_GOOGLE,
+                    pNext: next,
+                    swapchainCount: self.present_times.len() as u32,
+                    pTimes: self.present_times.as_ptr(),
+                })
+            } else {
+                None
+            };
+            if let Some(ref present_times) = present_times {
+                next = present_times as *const vk::PresentTimesInfoGOOGLE as *const _;
+            }
+
+            if let Some(ref mut present_regions) = present_regions {
+                present_regions.pNext = next;
+                next = present_regions as *const vk::PresentRegionsKHR as *const _;
+            }
+
             let mut results = vec![vk::SUCCESS; self.swapchains.len()];
 
             let vk = queue.device().pointers();
@@ -167,10 +255,7 @@ impl<'a> SubmitPresentBuilder<'a> {
 
             let infos = vk::PresentInfoKHR {
                 sType: vk::STRUCTURE_TYPE_PRESENT_INFO_KHR,
-                pNext: present_regions
-                    .as_ref()
-                    .map(|pr| pr as *const vk::PresentRegionsKHR as *const _)
-                    .unwrap_or(ptr::null()),
+                pNext: next,
                 waitSemaphoreCount: self.wait_semaphores.len() as u32,
                 pWaitSemaphores: self.wait_semaphores.as_ptr(),
                 swapchainCount: self.swapchains.len() as u32,
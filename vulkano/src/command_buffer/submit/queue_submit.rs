@@ -32,8 +32,16 @@ use crate::VulkanObject;
 pub struct SubmitCommandBufferBuilder<'a> {
     wait_semaphores: SmallVec<[vk::Semaphore; 16]>,
     destination_stages: SmallVec<[vk::PipelineStageFlags; 8]>,
+    // Same as `destination_stages`, but for `vkQueueSubmit2KHR`.
+    destination_stages2: SmallVec<[vk::PipelineStageFlags2KHR; 8]>,
     signal_semaphores: SmallVec<[vk::Semaphore; 16]>,
     command_buffers: SmallVec<[vk::CommandBuffer; 4]>,
+    // Device index of each wait semaphore, device mask of each command buffer and device index of
+    // each signal semaphore, for devices that span a device group. A mask of 0 means all the
+    // physical devices of the device.
+    wait_semaphore_device_indices: SmallVec<[u32; 16]>,
+    command_buffer_device_masks: SmallVec<[u32; 4]>,
+    signal_semaphore_device_indices: SmallVec<[u32; 16]>,
     fence: vk::Fence,
     marker: PhantomData<&'a ()>,
 }
@@ -45,8 +53,12 @@ impl<'a> SubmitCommandBufferBuilder<'a> {
         SubmitCommandBufferBuilder {
             wait_semaphores: SmallVec::new(),
             destination_stages: SmallVec::new(),
+            destination_stages2: SmallVec::new(),
             signal_semaphores: SmallVec::new(),
             command_buffers: SmallVec::new(),
+            wait_semaphore_device_indices: SmallVec::new(),
+            command_buffer_device_masks: SmallVec::new(),
+            signal_semaphore_device_indices: SmallVec::new(),
             fence: 0,
             marker: PhantomData,
         }
@@ -145,6 +157,28 @@ impl<'a> SubmitCommandBufferBuilder<'a> {
         // TODO: debug assert that the device supports the stages
         self.wait_semaphores.push(semaphore.internal_object());
         self.destination_stages.push(stages.into_vulkan_bits());
+        self.destination_stages2.push(stages.into_vulkan_bits2());
+        self.wait_semaphore_device_indices.push(0);
+    }
+
+    /// Same as `add_wait_semaphore`, but for a device that spans a device group. The semaphore
+    /// is waited upon by the physical device with device index `device_index`.
+    ///
+    /// # Safety
+    ///
+    /// Same as `add_wait_semaphore`. In addition:
+    ///
+    /// - `device_index` must be the index of one of the physical devices of the device.
+    ///
+    #[inline]
+    pub unsafe fn add_wait_semaphore_with_device_index(
+        &mut self,
+        semaphore: &'a Semaphore,
+        stages: PipelineStages,
+        device_index: u32,
+    ) {
+        self.add_wait_semaphore(semaphore, stages);
+        *self.wait_semaphore_device_indices.last_mut().unwrap() = device_index;
     }
 
     /// Adds a command buffer that is executed as part of this command.
@@ -168,6 +202,27 @@ impl<'a> SubmitCommandBufferBuilder<'a> {
     #[inline]
     pub unsafe fn add_command_buffer(&mut self, command_buffer: &'a UnsafeCommandBuffer) {
         self.command_buffers.push(command_buffer.internal_object());
+        self.command_buffer_device_masks.push(0);
+    }
+
+    /// Same as `add_command_buffer`, but for a device that spans a device group. The command
+    /// buffer is only executed by the physical devices in `device_mask`.
+    ///
+    /// # Safety
+    ///
+    /// Same as `add_command_buffer`. In addition:
+    ///
+    /// - `device_mask` must not be 0 and must only contain physical devices of the device.
+    ///
+    #[inline]
+    pub unsafe fn add_command_buffer_with_device_mask(
+        &mut self,
+        command_buffer: &'a UnsafeCommandBuffer,
+        device_mask: u32,
+    ) {
+        debug_assert!(device_mask != 0);
+        self.add_command_buffer(command_buffer);
+        *self.command_buffer_device_masks.last_mut().unwrap() = device_mask;
     }
 
     /// Returns the number of semaphores to signal.
@@ -193,23 +248,79 @@ impl<'a> SubmitCommandBufferBuilder<'a> {
     #[inline]
     pub unsafe fn add_signal_semaphore(&mut self, semaphore: &'a Semaphore) {
         self.signal_semaphores.push(semaphore.internal_object());
+        self.signal_semaphore_device_indices.push(0);
+    }
+
+    /// Same as `add_signal_semaphore`, but for a device that spans a device group. The semaphore
+    /// is signaled by the physical device with device index `device_index`.
+    ///
+    /// # Safety
+    ///
+    /// Same as `add_signal_semaphore`. In addition:
+    ///
+    /// - `device_index` must be the index of one of the physical devices of the device.
+    ///
+    #[inline]
+    pub unsafe fn add_signal_semaphore_with_device_index(
+        &mut self,
+        semaphore: &'a Semaphore,
+        device_index: u32,
+    ) {
+        self.add_signal_semaphore(semaphore);
+        *self.signal_semaphore_device_indices.last_mut().unwrap() = device_index;
     }
 
     /// Submits the command buffer to the given queue.
     ///
+    /// If the `synchronization2` feature is enabled on the device, `vkQueueSubmit2KHR` is used
+    /// instead of `vkQueueSubmit`.
+    ///
     /// > **Note**: This is an expensive operation, so you may want to merge as many builders as
     /// > possible together and avoid submitting them one by one.
     ///
     pub fn submit(self, queue: &Queue) -> Result<(), SubmitCommandBufferError> {
+        if queue.device().enabled_features().synchronization2 {
+            return self.submit2(queue);
+        }
+
         unsafe {
             let vk = queue.device().pointers();
+            let all_devices = queue.device().device_mask();
             let queue = queue.internal_object_guard();
 
             debug_assert_eq!(self.wait_semaphores.len(), self.destination_stages.len());
 
+            // The device group information is only passed if something else than the defaults
+            // was requested.
+            let command_buffer_device_masks = self
+                .command_buffer_device_masks
+                .iter()
+                .map(|&mask| if mask == 0 { all_devices } else { mask })
+                .collect::<SmallVec<[_; 4]>>();
+            let has_device_group_info = command_buffer_device_masks
+                .iter()
+                .any(|&mask| mask != all_devices)
+                || self.wait_semaphore_device_indices.iter().any(|&i| i != 0)
+                || self.signal_semaphore_device_indices.iter().any(|&i| i != 0);
+
+            let device_group_info = vk::DeviceGroupSubmitInfo {
+                sType: vk::STRUCTURE_TYPE_DEVICE_GROUP_SUBMIT_INFO,
+                pNext: ptr::null(),
+                waitSemaphoreCount: self.wait_semaphore_device_indices.len() as u32,
+                pWaitSemaphoreDeviceIndices: self.wait_semaphore_device_indices.as_ptr(),
+                commandBufferCount: command_buffer_device_masks.len() as u32,
+                pCommandBufferDeviceMasks: command_buffer_device_masks.as_ptr(),
+                signalSemaphoreCount: self.signal_semaphore_device_indices.len() as u32,
+                pSignalSemaphoreDeviceIndices: self.signal_semaphore_device_indices.as_ptr(),
+            };
+
             let batch = vk::SubmitInfo {
                 sType: vk::STRUCTURE_TYPE_SUBMIT_INFO,
-                pNext: ptr::null(),
+                pNext: if has_device_group_info {
+                    &device_group_info as *const _ as *const _
+                } else {
+                    ptr::null()
+                },
                 waitSemaphoreCount: self.wait_semaphores.len() as u32,
                 pWaitSemaphores: self.wait_semaphores.as_ptr(),
                 pWaitDstStageMask: self.destination_stages.as_ptr(),
@@ -224,6 +335,79 @@ impl<'a> SubmitCommandBufferBuilder<'a> {
         }
     }
 
+    // Implementation of `submit` that uses `vkQueueSubmit2KHR`.
+    fn submit2(self, queue: &Queue) -> Result<(), SubmitCommandBufferError> {
+        unsafe {
+            let vk = queue.device().pointers();
+            let queue = queue.internal_object_guard();
+
+            debug_assert_eq!(self.wait_semaphores.len(), self.destination_stages2.len());
+
+            let wait_semaphores = self
+                .wait_semaphores
+                .iter()
+                .zip(self.destination_stages2.iter())
+                .zip(self.wait_semaphore_device_indices.iter())
+                .map(
+                    |((&semaphore, &stages), &device_index)| vk::SemaphoreSubmitInfoKHR {
+                        sType: vk::STRUCTURE_TYPE_SEMAPHORE_SUBMIT_INFO_KHR,
+                        pNext: ptr::null(),
+                        semaphore,
+                        value: 0,
+                        stageMask: stages,
+                        deviceIndex: device_index,
+                    },
+                )
+                .collect::<SmallVec<[_; 16]>>();
+
+            // A device mask of 0 means all the physical devices here as well.
+            let command_buffers = self
+                .command_buffers
+                .iter()
+                .zip(self.command_buffer_device_masks.iter())
+                .map(
+                    |(&command_buffer, &device_mask)| vk::CommandBufferSubmitInfoKHR {
+                        sType: vk::STRUCTURE_TYPE_COMMAND_BUFFER_SUBMIT_INFO_KHR,
+                        pNext: ptr::null(),
+                        commandBuffer: command_buffer,
+                        deviceMask: device_mask,
+                    },
+                )
+                .collect::<SmallVec<[_; 4]>>();
+
+            // `vkQueueSubmit` signals semaphores once all the commands have completed, which
+            // corresponds to the "all commands" stage.
+            let signal_semaphores = self
+                .signal_semaphores
+                .iter()
+                .zip(self.signal_semaphore_device_indices.iter())
+                .map(|(&semaphore, &device_index)| vk::SemaphoreSubmitInfoKHR {
+                    sType: vk::STRUCTURE_TYPE_SEMAPHORE_SUBMIT_INFO_KHR,
+                    pNext: ptr::null(),
+                    semaphore,
+                    value: 0,
+                    stageMask: vk::PIPELINE_STAGE_ALL_COMMANDS_BIT as vk::PipelineStageFlags2KHR,
+                    deviceIndex: device_index,
+                })
+                .collect::<SmallVec<[_; 16]>>();
+
+            let batch = vk::SubmitInfo2KHR {
+                sType: vk::STRUCTURE_TYPE_SUBMIT_INFO_2_KHR,
+                pNext: ptr::null(),
+                flags: 0,
+                waitSemaphoreInfoCount: wait_semaphores.len() as u32,
+                pWaitSemaphoreInfos: wait_semaphores.as_ptr(),
+                commandBufferInfoCount: command_buffers.len() as u32,
+                pCommandBufferInfos: command_buffers.as_ptr(),
+                signalSemaphoreInfoCount: signal_semaphores.len() as u32,
+                pSignalSemaphoreInfos: signal_semaphores.as_ptr(),
+            };
+
+            check_errors(vk.QueueSubmit2KHR(*queue, 1, &batch, self.fence))?;
+            Ok(())
+        }
+    }
+
     /// Merges this builder with another builder.
     ///
     /// # Panic
@@ -238,8 +422,15 @@ impl<'a> SubmitCommandBufferBuilder<'a> {
 
         self.wait_semaphores.extend(other.wait_semaphores);
         self.destination_stages.extend(other.destination_stages); // TODO: meh? will be solved if we submit multiple batches
+        self.destination_stages2.extend(other.destination_stages2);
         self.signal_semaphores.extend(other.signal_semaphores);
         self.command_buffers.extend(other.command_buffers);
+        self.wait_semaphore_device_indices
+            .extend(other.wait_semaphore_device_indices);
+        self.command_buffer_device_masks
+            .extend(other.command_buffer_device_masks);
+        self.signal_semaphore_device_indices
+            .extend(other.signal_semaphore_device_indices);
 
         if self.fence == 0 {
             self.fence = other.fence;
@@ -24,6 +24,7 @@ use crate::render_pass::FramebufferAbstract;
 use crate::sync::AccessCheckError;
 use crate::sync::AccessError;
 use crate::sync::AccessFlagBits;
+use crate::sync::Event;
 use crate::sync::GpuFuture;
 use crate::sync::PipelineMemoryAccess;
 use crate::sync::PipelineStages;
@@ -35,6 +36,7 @@ use std::collections::hash_map::Entry;
 use std::error;
 use std::fmt;
 use std::hash::{Hash, Hasher};
+use std::iter;
 use std::sync::Arc;
 use std::sync::Mutex;
 
@@ -91,6 +93,58 @@ pub struct SyncCommandBufferBuilder {
 
     // True if we're a secondary command buffer.
     is_secondary: bool,
+
+    // True if barriers can be split into a `vkCmdSetEvent` and a `vkCmdWaitEvents`.
+    split_barriers: bool,
+
+    // Split barrier whose event has already been set, and that must be waited upon before the
+    // next commands are flushed.
+    pending_event: Option<PendingEvent>,
+
+    // Split barriers that have been waited upon.
+    events: Vec<SplitBarrier>,
+}
+
+// Location within commands of a split barrier. For debugging purposes, except that the event
+// must be kept alive for as long as the command buffer.
+struct SplitBarrier {
+    event: Arc<Event>,
+    // Index of the command after which the event is set.
+    set_after: usize,
+    // Index of the command before which the event is waited upon and reset.
+    wait_before: usize,
+}
+
+// Barrier that has been split into a `vkCmdSetEvent` and a `vkCmdWaitEvents`.
+struct PendingEvent {
+    event: Arc<Event>,
+    // Index of the last command that was flushed before the `vkCmdSetEvent`.
+    set_after: usize,
+    // Stages that were passed to `vkCmdSetEvent`.
+    stages: PipelineStages,
+    // Barrier to pass to `vkCmdWaitEvents`.
+    barrier: UnsafeCommandBufferBuilderPipelineBarrier,
+}
+
+impl PendingEvent {
+    // Records the `vkCmdWaitEvents`, followed by a `vkCmdResetEvent` so that the command buffer
+    // can be submitted again. `wait_before` is the index of the next command to be flushed.
+    unsafe fn wait(self, out: &mut UnsafeCommandBufferBuilder, wait_before: usize) -> SplitBarrier {
+        out.wait_events(iter::once(&*self.event), &self.barrier);
+        out.reset_event(
+            &self.event,
+            PipelineStages {
+                all_commands: true,
+                ..PipelineStages::none()
+            },
+        );
+
+        SplitBarrier {
+            event: self.event,
+            set_after: self.set_after,
+            wait_before,
+        }
+    }
 }
 
 // # How pipeline stages work in Vulkan
@@ -147,6 +201,14 @@ pub struct SyncCommandBufferBuilder {
 // command. If so, it will try to merge this barrier with the prototype and add the command to the
 // queue. If not possible, the queue will be entirely flushed and the command added to a fresh new
 // queue with a fresh new barrier prototype.
+//
+// ## Split barriers
+//
+// When the queue is flushed because of a collision, the commands that sit between the last
+// command that used the resource and the new command don't need to be waited upon. If split
+// barriers are enabled, the builder sets an event right after the last command that used the
+// resource, and waits on this event instead of adding the barrier to the prototype. The
+// independent commands can then overlap with the commands after the wait.
 
 impl fmt::Debug for SyncCommandBufferBuilder {
     #[inline]
@@ -477,9 +539,25 @@ impl SyncCommandBufferBuilder {
             barriers: Vec::new(),
             is_poisoned: false,
             is_secondary,
+            split_barriers: false,
+            pending_event: None,
+            events: Vec::new(),
         }
     }
 
+    /// Enables or disables splitting pipeline barriers into a `vkCmdSetEvent` and a
+    /// `vkCmdWaitEvents` when independent commands sit between the commands that must be
+    /// synchronized.
+    ///
+    /// # Safety
+    ///
+    /// - The queue family of the command buffer must support graphics or compute operations.
+    /// - The command buffer must not be executed multiple times simultaneously.
+    #[inline]
+    pub unsafe fn set_split_barriers(&mut self, split_barriers: bool) {
+        self.split_barriers = split_barriers;
+    }
+
     // Adds a command to be processed by the builder.
     //
     // The `resources` argument should contain each buffer or image used by the command.
@@ -581,7 +659,12 @@ impl SyncCommandBufferBuilder {
                                 || entry.get().current_layout != start_layout
                             {
                                 unsafe {
-                                    // Flush the pending barrier.
+                                    // Flush the pending barriers.
+                                    if let Some(pending) = self.pending_event.take() {
+                                        let split_barrier =
+                                            pending.wait(&mut self.inner, first_unflushed_cmd_id);
+                                        self.events.push(split_barrier);
+                                    }
                                     self.inner.pipeline_barrier(&self.pending_barrier);
                                     self.pending_barrier =
                                         UnsafeCommandBufferBuilderPipelineBarrier::new();
@@ -624,8 +707,55 @@ impl SyncCommandBufferBuilder {
                                                 command2_offset: latest_command_id,
                                             });
                                         }
-                                        for command in &mut commands_lock.commands[start..end] {
-                                            command.send(&mut self.inner);
+
+                                        // If the last command that used the resource is followed
+                                        // by independent commands, split the barrier.
+                                        let source_stages = entry.get().memory.stages;
+                                        let inner = &self.inner;
+                                        let split = if self.split_barriers
+                                            && commands_lock.latest_render_pass_enter.is_none()
+                                            && !source_stages.host
+                                        {
+                                            collision_cmd_ids
+                                                .iter()
+                                                .copied()
+                                                .filter(|command_id| *command_id >= start)
+                                                .max()
+                                                .filter(|command_id| command_id + 1 < end)
+                                                .and_then(|command_id| {
+                                                    let device = inner.device().clone();
+                                                    Event::from_pool(device)
+                                                        .ok()
+                                                        .map(|event| (command_id, Arc::new(event)))
+                                                })
+                                        } else {
+                                            None
+                                        };
+
+                                        if let Some((set_after, event)) = split {
+                                            for command in
+                                                &mut commands_lock.commands[start..=set_after]
+                                            {
+                                                command.send(&mut self.inner);
+                                            }
+                                            self.inner.set_event(&event, source_stages);
+                                            for command in
+                                                &mut commands_lock.commands[set_after + 1..end]
+                                            {
+                                                command.send(&mut self.inner);
+                                            }
+
+                                            self.pending_event = Some(PendingEvent {
+                                                event,
+                                                set_after,
+                                                stages: source_stages,
+                                                barrier:
+                                                    UnsafeCommandBufferBuilderPipelineBarrier::new(),
+                                            });
+                                        } else {
+                                            for command in &mut commands_lock.commands[start..end] {
+                                                command.send(&mut self.inner);
+                                            }
                                         }
                                         commands_lock.first_unflushed = end;
                                     }
@@ -635,7 +765,22 @@ impl SyncCommandBufferBuilder {
                             entry.key().command_ids.borrow_mut().push(latest_command_id);
                             let entry = entry.into_mut();
 
-                            // Modify the pipeline barrier to handle the collision.
+                            // If the pending split barrier already waits on all the commands that
+                            // used the resource, add the collision to it. Otherwise, modify the
+                            // pipeline barrier.
+                            let b = match self.pending_event {
+                                Some(ref mut pending)
+                                    if collision_cmd_ids
+                                        .iter()
+                                        .all(|command_id| *command_id <= pending.set_after)
+                                        && pending.stages | entry.memory.stages
+                                            == pending.stages =>
+                                {
+                                    &mut pending.barrier
+                                }
+                                _ => &mut self.pending_barrier,
+                            };
+
                             unsafe {
                                 let commands_lock = self.commands.lock().unwrap();
                                 match resource_ty {
@@ -643,7 +788,6 @@ impl SyncCommandBufferBuilder {
                                         let buf = commands_lock.commands[latest_command_id]
                                             .buffer(resource_index);
 
-                                        let b = &mut self.pending_barrier;
                                         b.add_buffer_memory_barrier(
                                             buf,
                                             entry.memory.stages,
@@ -661,7 +805,6 @@ impl SyncCommandBufferBuilder {
                                         let img = commands_lock.commands[latest_command_id]
                                             .image(resource_index);
 
-                                        let b = &mut self.pending_barrier;
                                         b.add_image_memory_barrier(
                                             img,
                                             img.current_miplevels_access(),
@@ -858,8 +1001,12 @@ impl SyncCommandBufferBuilder {
 
         // The commands that haven't been sent to the inner command buffer yet need to be sent.
         unsafe {
-            self.inner.pipeline_barrier(&self.pending_barrier);
             let start = commands_lock.first_unflushed;
+            if let Some(pending) = self.pending_event.take() {
+                let split_barrier = pending.wait(&mut self.inner, start);
+                self.events.push(split_barrier);
+            }
+            self.inner.pipeline_barrier(&self.pending_barrier);
             self.barriers.push(start); // Track inserted barriers
             for command in &mut commands_lock.commands[start..] {
                 command.send(&mut self.inner);
@@ -938,6 +1085,7 @@ impl SyncCommandBufferBuilder {
             resources: final_resources_states,
             commands: final_commands,
             barriers: self.barriers,
+            events: self.events,
         })
     }
 }
@@ -975,6 +1123,10 @@ pub struct SyncCommandBuffer {
     // Locations within commands that pipeline barriers were inserted. For debugging purposes.
     // TODO: present only in cfg(debug_assertions)?
     barriers: Vec<usize>,
+
+    // Locations within commands of the split barriers that were inserted. Also keeps their events
+    // alive.
+    events: Vec<SplitBarrier>,
 }
 
 impl SyncCommandBuffer {
@@ -1636,4 +1788,67 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn split_barrier() {
+        unsafe {
+            let (device, queue) = gfx_dev_and_queue!();
+
+            let pool = Device::standard_command_pool(&device, queue.family());
+            let allocs = pool.alloc(false, 2).unwrap().collect::<Vec<_>>();
+            let buffers = (0..3)
+                .map(|_| {
+                    CpuAccessibleBuffer::from_data(device.clone(), BufferUsage::all(), false, 0u32)
+                        .unwrap()
+                })
+                .collect::<Vec<_>>();
+
+            for (alloc, split) in allocs.iter().zip([false, true].iter()) {
+                let mut builder = SyncCommandBufferBuilder::new(
+                    alloc.inner(),
+                    CommandBufferLevel::primary(),
+                    Flags::None,
+                )
+                .unwrap();
+                builder.set_split_barriers(*split);
+
+                // The second fill is independent from the copy that reads the first buffer.
+                builder.fill_buffer(buffers[0].clone(), 42);
+                builder.fill_buffer(buffers[1].clone(), 42);
+                builder
+                    .copy_buffer(
+                        buffers[0].clone(),
+                        buffers[2].clone(),
+                        std::iter::once((0, 0, 4)),
+                    )
+                    .unwrap();
+
+                let primary = builder.build().unwrap();
+                let names = primary
+                    .commands
+                    .iter()
+                    .map(|c| c.name())
+                    .collect::<Vec<_>>();
+                assert_eq!(
+                    &names,
+                    &["vkCmdFillBuffer", "vkCmdFillBuffer", "vkCmdCopyBuffer"]
+                );
+
+                // A barrier is inserted before the copy in both cases.
+                assert_eq!(&primary.barriers, &[0, 2]);
+
+                if *split {
+                    // The event is set right after the first fill, and waited upon and reset
+                    // right before the copy, along with the barrier. The second fill sits
+                    // between the two.
+                    assert_eq!(primary.events.len(), 1);
+                    assert_eq!(primary.events[0].set_after, 0);
+                    assert_eq!(primary.events[0].wait_before, 2);
+                    assert!(primary.barriers.contains(&primary.events[0].wait_before));
+                } else {
+                    assert!(primary.events.is_empty());
+                }
+            }
+        }
+    }
 }
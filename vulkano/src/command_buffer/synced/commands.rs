@@ -21,6 +21,7 @@ use crate::command_buffer::sys::UnsafeCommandBufferBuilderColorImageClear;
 use crate::command_buffer::sys::UnsafeCommandBufferBuilderExecuteCommands;
 use crate::command_buffer::sys::UnsafeCommandBufferBuilderImageBlit;
 use crate::command_buffer::sys::UnsafeCommandBufferBuilderImageCopy;
+use crate::command_buffer::sys::UnsafeCommandBufferBuilderPipelineBarrier;
 use crate::command_buffer::CommandBufferExecError;
 use crate::command_buffer::SecondaryCommandBuffer;
 use crate::command_buffer::SubpassContents;
@@ -517,7 +518,7 @@ impl SyncCommandBufferBuilder {
                     Some((
                         PipelineMemoryAccess {
                             stages: PipelineStages {
-                                transfer: true,
+                                copy: true,
                                 ..PipelineStages::none()
                             },
                             access: AccessFlagBits {
@@ -535,7 +536,7 @@ impl SyncCommandBufferBuilder {
                     Some((
                         PipelineMemoryAccess {
                             stages: PipelineStages {
-                                transfer: true,
+                                copy: true,
                                 ..PipelineStages::none()
                             },
                             access: AccessFlagBits {
@@ -677,7 +678,7 @@ impl SyncCommandBufferBuilder {
                     Some((
                         PipelineMemoryAccess {
                             stages: PipelineStages {
-                                transfer: true,
+                                blit: true,
                                 ..PipelineStages::none()
                             },
                             access: AccessFlagBits {
@@ -695,7 +696,7 @@ impl SyncCommandBufferBuilder {
                     Some((
                         PipelineMemoryAccess {
                             stages: PipelineStages {
-                                transfer: true,
+                                blit: true,
                                 ..PipelineStages::none()
                             },
                             access: AccessFlagBits {
@@ -800,7 +801,7 @@ impl SyncCommandBufferBuilder {
                 Some((
                     PipelineMemoryAccess {
                         stages: PipelineStages {
-                            transfer: true,
+                            clear: true,
                             ..PipelineStages::none()
                         },
                         access: AccessFlagBits {
@@ -920,7 +921,7 @@ impl SyncCommandBufferBuilder {
                     Some((
                         PipelineMemoryAccess {
                             stages: PipelineStages {
-                                transfer: true,
+                                copy: true,
                                 ..PipelineStages::none()
                             },
                             access: AccessFlagBits {
@@ -938,7 +939,7 @@ impl SyncCommandBufferBuilder {
                     Some((
                         PipelineMemoryAccess {
                             stages: PipelineStages {
-                                transfer: true,
+                                copy: true,
                                 ..PipelineStages::none()
                             },
                             access: AccessFlagBits {
@@ -1070,7 +1071,7 @@ impl SyncCommandBufferBuilder {
                     Some((
                         PipelineMemoryAccess {
                             stages: PipelineStages {
-                                transfer: true,
+                                copy: true,
                                 ..PipelineStages::none()
                             },
                             access: AccessFlagBits {
@@ -1088,7 +1089,7 @@ impl SyncCommandBufferBuilder {
                     Some((
                         PipelineMemoryAccess {
                             stages: PipelineStages {
-                                transfer: true,
+                                copy: true,
                                 ..PipelineStages::none()
                             },
                             access: AccessFlagBits {
@@ -1220,7 +1221,7 @@ impl SyncCommandBufferBuilder {
                     Some((
                         PipelineMemoryAccess {
                             stages: PipelineStages {
-                                transfer: true,
+                                copy: true,
                                 ..PipelineStages::none()
                             },
                             access: AccessFlagBits {
@@ -1238,7 +1239,7 @@ impl SyncCommandBufferBuilder {
                     Some((
                         PipelineMemoryAccess {
                             stages: PipelineStages {
-                                transfer: true,
+                                copy: true,
                                 ..PipelineStages::none()
                             },
                             access: AccessFlagBits {
@@ -1347,7 +1348,7 @@ impl SyncCommandBufferBuilder {
                 Some((
                     PipelineMemoryAccess {
                         stages: PipelineStages {
-                            transfer: true,
+                            copy: true,
                             ..PipelineStages::none()
                         },
                         access: AccessFlagBits {
@@ -1835,6 +1836,120 @@ impl SyncCommandBufferBuilder {
         Ok(())
     }
 
+    /// Calls `vkCmdDrawMeshTasksEXT` or `vkCmdDrawMeshTasksNV` on the builder.
+    #[inline]
+    pub unsafe fn draw_mesh_tasks(&mut self, group_counts: [u32; 3]) {
+        struct Cmd {
+            group_counts: [u32; 3],
+        }
+
+        impl Command for Cmd {
+            fn name(&self) -> &'static str {
+                "vkCmdDrawMeshTasks"
+            }
+
+            unsafe fn send(&mut self, out: &mut UnsafeCommandBufferBuilder) {
+                out.draw_mesh_tasks(self.group_counts);
+            }
+
+            fn into_final_command(self: Box<Self>) -> Box<dyn FinalCommand + Send + Sync> {
+                Box::new("vkCmdDrawMeshTasks")
+            }
+        }
+
+        self.append_command(Cmd { group_counts }, &[]).unwrap();
+    }
+
+    /// Calls `vkCmdDrawMeshTasksIndirectEXT` on the builder.
+    #[inline]
+    pub unsafe fn draw_mesh_tasks_indirect<B>(
+        &mut self,
+        buffer: B,
+        draw_count: u32,
+        stride: u32,
+    ) -> Result<(), SyncCommandBufferBuilderError>
+    where
+        B: BufferAccess + Send + Sync + 'static,
+    {
+        struct Cmd<B> {
+            buffer: B,
+            draw_count: u32,
+            stride: u32,
+        }
+
+        impl<B> Command for Cmd<B>
+        where
+            B: BufferAccess + Send + Sync + 'static,
+        {
+            fn name(&self) -> &'static str {
+                "vkCmdDrawMeshTasksIndirectEXT"
+            }
+
+            unsafe fn send(&mut self, out: &mut UnsafeCommandBufferBuilder) {
+                out.draw_mesh_tasks_indirect(&self.buffer, self.draw_count, self.stride);
+            }
+
+            fn into_final_command(self: Box<Self>) -> Box<dyn FinalCommand + Send + Sync> {
+                struct Fin<B>(B);
+                impl<B> FinalCommand for Fin<B>
+                where
+                    B: BufferAccess + Send + Sync + 'static,
+                {
+                    fn name(&self) -> &'static str {
+                        "vkCmdDrawMeshTasksIndirectEXT"
+                    }
+                    fn buffer(&self, num: usize) -> &dyn BufferAccess {
+                        assert_eq!(num, 0);
+                        &self.0
+                    }
+                    fn buffer_name(&self, num: usize) -> Cow<'static, str> {
+                        assert_eq!(num, 0);
+                        "indirect buffer".into()
+                    }
+                }
+                Box::new(Fin(self.buffer))
+            }
+
+            fn buffer(&self, num: usize) -> &dyn BufferAccess {
+                assert_eq!(num, 0);
+                &self.buffer
+            }
+
+            fn buffer_name(&self, num: usize) -> Cow<'static, str> {
+                assert_eq!(num, 0);
+                "indirect buffer".into()
+            }
+        }
+
+        self.append_command(
+            Cmd {
+                buffer,
+                draw_count,
+                stride,
+            },
+            &[(
+                KeyTy::Buffer,
+                Some((
+                    PipelineMemoryAccess {
+                        stages: PipelineStages {
+                            draw_indirect: true,
+                            ..PipelineStages::none()
+                        },
+                        access: AccessFlagBits {
+                            indirect_command_read: true,
+                            ..AccessFlagBits::none()
+                        },
+                        exclusive: false,
+                    },
+                    ImageLayout::Undefined,
+                    ImageLayout::Undefined,
+                )),
+            )],
+        )?;
+
+        Ok(())
+    }
+
     /// Calls `vkCmdEndQuery` on the builder.
     #[inline]
     pub unsafe fn end_query(&mut self, query_pool: Arc<QueryPool>, query: u32) {
@@ -2260,6 +2375,30 @@ impl SyncCommandBufferBuilder {
         self.append_command(Cmd { event, stages }, &[]).unwrap();
     }
 
+    /// Calls `vkCmdSetDeviceMask` on the builder.
+    #[inline]
+    pub unsafe fn set_device_mask(&mut self, device_mask: u32) {
+        struct Cmd {
+            device_mask: u32,
+        }
+
+        impl Command for Cmd {
+            fn name(&self) -> &'static str {
+                "vkCmdSetDeviceMask"
+            }
+
+            unsafe fn send(&mut self, out: &mut UnsafeCommandBufferBuilder) {
+                out.set_device_mask(self.device_mask);
+            }
+
+            fn into_final_command(self: Box<Self>) -> Box<dyn FinalCommand + Send + Sync> {
+                Box::new("vkCmdSetDeviceMask")
+            }
+        }
+
+        self.append_command(Cmd { device_mask }, &[]).unwrap();
+    }
+
     /// Calls `vkCmdSetLineWidth` on the builder.
     #[inline]
     pub unsafe fn set_line_width(&mut self, line_width: f32) {
@@ -2541,6 +2680,68 @@ impl SyncCommandBufferBuilder {
         .unwrap();
     }
 
+    /// Calls `vkCmdWaitEvents` on the builder, with a global memory barrier between the given
+    /// stages and accesses.
+    #[inline]
+    pub unsafe fn wait_events<I>(
+        &mut self,
+        events: I,
+        source_stages: PipelineStages,
+        source_access: AccessFlagBits,
+        destination_stages: PipelineStages,
+        destination_access: AccessFlagBits,
+    ) where
+        I: IntoIterator<Item = Arc<Event>>,
+    {
+        struct Cmd {
+            events: SmallVec<[Arc<Event>; 4]>,
+            source_stages: PipelineStages,
+            source_access: AccessFlagBits,
+            destination_stages: PipelineStages,
+            destination_access: AccessFlagBits,
+        }
+
+        impl Command for Cmd {
+            fn name(&self) -> &'static str {
+                "vkCmdWaitEvents"
+            }
+
+            unsafe fn send(&mut self, out: &mut UnsafeCommandBufferBuilder) {
+                let mut barrier = UnsafeCommandBufferBuilderPipelineBarrier::new();
+                barrier.add_memory_barrier(
+                    self.source_stages,
+                    self.source_access,
+                    self.destination_stages,
+                    self.destination_access,
+                    false,
+                );
+                out.wait_events(self.events.iter().map(|e| &**e), &barrier);
+            }
+
+            fn into_final_command(self: Box<Self>) -> Box<dyn FinalCommand + Send + Sync> {
+                struct Fin(SmallVec<[Arc<Event>; 4]>);
+                impl FinalCommand for Fin {
+                    fn name(&self) -> &'static str {
+                        "vkCmdWaitEvents"
+                    }
+                }
+                Box::new(Fin(self.events))
+            }
+        }
+
+        self.append_command(
+            Cmd {
+                events: events.into_iter().collect(),
+                source_stages,
+                source_access,
+                destination_stages,
+                destination_access,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
     /// Calls `vkCmdWriteTimestamp` on the builder.
     #[inline]
     pub unsafe fn write_timestamp(
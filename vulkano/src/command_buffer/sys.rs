@@ -1156,6 +1156,55 @@ impl UnsafeCommandBufferBuilder {
         );
     }
 
+    /// Calls `vkCmdDrawMeshTasksEXT` on the builder, or `vkCmdDrawMeshTasksNV` if only the
+    /// `nv_mesh_shader` extension is enabled.
+    ///
+    /// With `vkCmdDrawMeshTasksNV`, the number of tasks is `group_counts[0]`, and the two other
+    /// values must be 1.
+    #[inline]
+    pub unsafe fn draw_mesh_tasks(&mut self, group_counts: [u32; 3]) {
+        let vk = self.device().pointers();
+        let cmd = self.internal_object();
+
+        if self.device().loaded_extensions().ext_mesh_shader {
+            vk.CmdDrawMeshTasksEXT(cmd, group_counts[0], group_counts[1], group_counts[2]);
+        } else {
+            debug_assert!(self.device().loaded_extensions().nv_mesh_shader);
+            debug_assert!(group_counts[1] == 1 && group_counts[2] == 1);
+            vk.CmdDrawMeshTasksNV(cmd, group_counts[0], 0);
+        }
+    }
+
+    /// Calls `vkCmdDrawMeshTasksIndirectEXT` on the builder.
+    #[inline]
+    pub unsafe fn draw_mesh_tasks_indirect<B>(&mut self, buffer: &B, draw_count: u32, stride: u32)
+    where
+        B: ?Sized + BufferAccess,
+    {
+        let vk = self.device().pointers();
+        let cmd = self.internal_object();
+
+        debug_assert!(self.device().loaded_extensions().ext_mesh_shader);
+        debug_assert!(
+            draw_count == 0
+                || ((stride % 4) == 0)
+                    && stride as usize >= mem::size_of::<vk::DrawMeshTasksIndirectCommandEXT>()
+        );
+
+        let inner = buffer.inner();
+        debug_assert!(inner.offset < inner.buffer.size());
+        debug_assert!(inner.buffer.usage_indirect_buffer());
+        debug_assert_eq!(inner.offset % 4, 0);
+
+        vk.CmdDrawMeshTasksIndirectEXT(
+            cmd,
+            inner.buffer.internal_object(),
+            inner.offset as vk::DeviceSize,
+            draw_count,
+            stride,
+        );
+    }
+
     /// Calls `vkCmdEndQuery` on the builder.
     #[inline]
     pub unsafe fn end_query(&mut self, query: Query) {
@@ -1225,6 +1274,8 @@ impl UnsafeCommandBufferBuilder {
         vk.CmdNextSubpass(cmd, subpass_contents as u32);
     }
 
+    /// Calls `vkCmdPipelineBarrier` on the builder, or `vkCmdPipelineBarrier2KHR` if the
+    /// `synchronization2` feature is enabled on the device.
     #[inline]
     pub unsafe fn pipeline_barrier(&mut self, command: &UnsafeCommandBufferBuilderPipelineBarrier) {
         // If barrier is empty, don't do anything.
@@ -1242,6 +1293,23 @@ impl UnsafeCommandBufferBuilder {
         debug_assert_ne!(command.src_stage_mask, 0);
         debug_assert_ne!(command.dst_stage_mask, 0);
 
+        if self.device().enabled_features().synchronization2 {
+            let infos = vk::DependencyInfoKHR {
+                sType: vk::STRUCTURE_TYPE_DEPENDENCY_INFO_KHR,
+                pNext: ptr::null(),
+                dependencyFlags: command.dependency_flags,
+                memoryBarrierCount: command.memory_barriers2.len() as u32,
+                pMemoryBarriers: command.memory_barriers2.as_ptr(),
+                bufferMemoryBarrierCount: command.buffer_barriers2.len() as u32,
+                pBufferMemoryBarriers: command.buffer_barriers2.as_ptr(),
+                imageMemoryBarrierCount: command.image_barriers2.len() as u32,
+                pImageMemoryBarriers: command.image_barriers2.as_ptr(),
+            };
+
+            vk.CmdPipelineBarrier2KHR(cmd, &infos);
+            return;
+        }
+
         vk.CmdPipelineBarrier(
             cmd,
             command.src_stage_mask,
@@ -1341,6 +1409,14 @@ impl UnsafeCommandBufferBuilder {
         vk.CmdSetDepthBounds(cmd, min, max);
     }
 
+    /// Calls `vkCmdSetDeviceMask` on the builder.
+    #[inline]
+    pub unsafe fn set_device_mask(&mut self, device_mask: u32) {
+        let vk = self.device().pointers();
+        let cmd = self.internal_object();
+        vk.CmdSetDeviceMaskKHR(cmd, device_mask);
+    }
+
     /// Calls `vkCmdSetEvent` on the builder.
     #[inline]
     pub unsafe fn set_event(&mut self, event: &Event, stages: PipelineStages) {
@@ -1494,14 +1570,72 @@ impl UnsafeCommandBufferBuilder {
         );
     }
 
-    /// Calls `vkCmdWriteTimestamp` on the builder.
+    /// Calls `vkCmdWaitEvents` on the builder.
+    ///
+    /// The stages and barriers of `command` are used for the wait. Its dependency flags are
+    /// ignored, as `vkCmdWaitEvents` doesn't have any.
+    ///
+    /// # Safety
+    ///
+    /// - The source stages of `command` must be the union of the stages passed to the
+    ///   `set_event` commands that signal the events.
+    /// - The events must be signaled by a previous `set_event` command of this command buffer or
+    ///   from the host.
+    #[inline]
+    pub unsafe fn wait_events<'a, I>(
+        &mut self,
+        events: I,
+        command: &UnsafeCommandBufferBuilderPipelineBarrier,
+    ) where
+        I: IntoIterator<Item = &'a Event>,
+    {
+        let events: SmallVec<[_; 4]> = events.into_iter().map(|e| e.internal_object()).collect();
+
+        if events.is_empty() {
+            return;
+        }
+
+        debug_assert_ne!(command.src_stage_mask, 0);
+        debug_assert_ne!(command.dst_stage_mask, 0);
+
+        let vk = self.device().pointers();
+        let cmd = self.internal_object();
+
+        vk.CmdWaitEvents(
+            cmd,
+            events.len() as u32,
+            events.as_ptr(),
+            command.src_stage_mask,
+            command.dst_stage_mask,
+            command.memory_barriers.len() as u32,
+            command.memory_barriers.as_ptr(),
+            command.buffer_barriers.len() as u32,
+            command.buffer_barriers.as_ptr(),
+            command.image_barriers.len() as u32,
+            command.image_barriers.as_ptr(),
+        );
+    }
+
+    /// Calls `vkCmdWriteTimestamp` on the builder, or `vkCmdWriteTimestamp2KHR` if the
+    /// `synchronization2` feature is enabled on the device.
     #[inline]
     pub unsafe fn write_timestamp(&mut self, query: Query, stage: PipelineStage) {
         let vk = self.device().pointers();
         let cmd = self.internal_object();
+
+        if self.device().enabled_features().synchronization2 {
+            vk.CmdWriteTimestamp2KHR(
+                cmd,
+                stage.into_vulkan_bits2(),
+                query.pool().internal_object(),
+                query.index(),
+            );
+            return;
+        }
+
         vk.CmdWriteTimestamp(
             cmd,
-            stage as u32,
+            stage.into_vulkan_bits(),
             query.pool().internal_object(),
             query.index(),
         );
@@ -1702,6 +1836,10 @@ pub struct UnsafeCommandBufferBuilderImageBlit {
 /// > **Note**: We use a builder-like API here so that users can pass multiple buffers or images of
 /// > multiple different types. Doing so with a single function would be very tedious in terms of
 /// > API.
+///
+/// If the `synchronization2` feature is enabled on the device, the barrier is recorded with
+/// `vkCmdPipelineBarrier2KHR` and each individual barrier only waits on the stages that were
+/// passed for it, instead of the union of the stages of all the barriers.
 pub struct UnsafeCommandBufferBuilderPipelineBarrier {
     src_stage_mask: vk::PipelineStageFlags,
     dst_stage_mask: vk::PipelineStageFlags,
@@ -1709,6 +1847,11 @@ pub struct UnsafeCommandBufferBuilderPipelineBarrier {
     memory_barriers: SmallVec<[vk::MemoryBarrier; 2]>,
     buffer_barriers: SmallVec<[vk::BufferMemoryBarrier; 8]>,
     image_barriers: SmallVec<[vk::ImageMemoryBarrier; 8]>,
+    // Same barriers as above, but in the `VK_KHR_synchronization2` format. Execution dependencies
+    // without any memory barrier are stored as memory barriers without any access.
+    memory_barriers2: SmallVec<[vk::MemoryBarrier2KHR; 2]>,
+    buffer_barriers2: SmallVec<[vk::BufferMemoryBarrier2KHR; 8]>,
+    image_barriers2: SmallVec<[vk::ImageMemoryBarrier2KHR; 8]>,
 }
 
 impl UnsafeCommandBufferBuilderPipelineBarrier {
@@ -1722,6 +1865,9 @@ impl UnsafeCommandBufferBuilderPipelineBarrier {
             memory_barriers: SmallVec::new(),
             buffer_barriers: SmallVec::new(),
             image_barriers: SmallVec::new(),
+            memory_barriers2: SmallVec::new(),
+            buffer_barriers2: SmallVec::new(),
+            image_barriers2: SmallVec::new(),
         }
     }
 
@@ -1743,6 +1889,12 @@ impl UnsafeCommandBufferBuilderPipelineBarrier {
         self.buffer_barriers
             .extend(other.buffer_barriers.into_iter());
         self.image_barriers.extend(other.image_barriers.into_iter());
+        self.memory_barriers2
+            .extend(other.memory_barriers2.into_iter());
+        self.buffer_barriers2
+            .extend(other.buffer_barriers2.into_iter());
+        self.image_barriers2
+            .extend(other.image_barriers2.into_iter());
     }
 
     /// Adds an execution dependency. This means that all the stages in `source` of the previous
@@ -1760,6 +1912,26 @@ impl UnsafeCommandBufferBuilderPipelineBarrier {
         source: PipelineStages,
         destination: PipelineStages,
         by_region: bool,
+    ) {
+        self.add_stages(source, destination, by_region);
+
+        self.memory_barriers2.push(vk::MemoryBarrier2KHR {
+            sType: vk::STRUCTURE_TYPE_MEMORY_BARRIER_2_KHR,
+            pNext: ptr::null(),
+            srcStageMask: source.into_vulkan_bits2(),
+            srcAccessMask: 0,
+            dstStageMask: destination.into_vulkan_bits2(),
+            dstAccessMask: 0,
+        });
+    }
+
+    // Adds the stages to the global stage masks used by the legacy `vkCmdPipelineBarrier`.
+    #[inline]
+    unsafe fn add_stages(
+        &mut self,
+        source: PipelineStages,
+        destination: PipelineStages,
+        by_region: bool,
     ) {
         if !by_region {
             self.dependency_flags = 0;
@@ -1793,7 +1965,7 @@ impl UnsafeCommandBufferBuilderPipelineBarrier {
         debug_assert!(source_access.is_compatible_with(&source_stage));
         debug_assert!(destination_access.is_compatible_with(&destination_stage));
 
-        self.add_execution_dependency(source_stage, destination_stage, by_region);
+        self.add_stages(source_stage, destination_stage, by_region);
 
         self.memory_barriers.push(vk::MemoryBarrier {
             sType: vk::STRUCTURE_TYPE_MEMORY_BARRIER,
@@ -1801,6 +1973,15 @@ impl UnsafeCommandBufferBuilderPipelineBarrier {
             srcAccessMask: source_access.into_vulkan_bits(),
             dstAccessMask: destination_access.into_vulkan_bits(),
         });
+
+        self.memory_barriers2.push(vk::MemoryBarrier2KHR {
+            sType: vk::STRUCTURE_TYPE_MEMORY_BARRIER_2_KHR,
+            pNext: ptr::null(),
+            srcStageMask: source_stage.into_vulkan_bits2(),
+            srcAccessMask: source_access.into_vulkan_bits2(),
+            dstStageMask: destination_stage.into_vulkan_bits2(),
+            dstAccessMask: destination_access.into_vulkan_bits2(),
+        });
     }
 
     /// Adds a buffer memory barrier. This means that all the memory writes to the given buffer by
@@ -1835,7 +2016,7 @@ impl UnsafeCommandBufferBuilderPipelineBarrier {
         debug_assert!(source_access.is_compatible_with(&source_stage));
         debug_assert!(destination_access.is_compatible_with(&destination_stage));
 
-        self.add_execution_dependency(source_stage, destination_stage, by_region);
+        self.add_stages(source_stage, destination_stage, by_region);
 
         debug_assert!(size <= buffer.size());
         let BufferInner {
@@ -1861,6 +2042,20 @@ impl UnsafeCommandBufferBuilderPipelineBarrier {
             offset: offset as vk::DeviceSize,
             size: size as vk::DeviceSize,
         });
+
+        self.buffer_barriers2.push(vk::BufferMemoryBarrier2KHR {
+            sType: vk::STRUCTURE_TYPE_BUFFER_MEMORY_BARRIER_2_KHR,
+            pNext: ptr::null(),
+            srcStageMask: source_stage.into_vulkan_bits2(),
+            srcAccessMask: source_access.into_vulkan_bits2(),
+            dstStageMask: destination_stage.into_vulkan_bits2(),
+            dstAccessMask: destination_access.into_vulkan_bits2(),
+            srcQueueFamilyIndex: src_queue,
+            dstQueueFamilyIndex: dest_queue,
+            buffer: buffer.internal_object(),
+            offset: offset as vk::DeviceSize,
+            size: size as vk::DeviceSize,
+        });
     }
 
     /// Adds an image memory barrier. This is the equivalent of `add_buffer_memory_barrier` but
@@ -1899,7 +2094,7 @@ impl UnsafeCommandBufferBuilderPipelineBarrier {
         debug_assert!(source_access.is_compatible_with(&source_stage));
         debug_assert!(destination_access.is_compatible_with(&destination_stage));
 
-        self.add_execution_dependency(source_stage, destination_stage, by_region);
+        self.add_stages(source_stage, destination_stage, by_region);
 
         debug_assert_ne!(new_layout, ImageLayout::Undefined);
         debug_assert_ne!(new_layout, ImageLayout::Preinitialized);
@@ -1923,6 +2118,14 @@ impl UnsafeCommandBufferBuilderPipelineBarrier {
         let aspects = image.format().aspects();
         let image = image.inner();
 
+        let subresource_range = vk::ImageSubresourceRange {
+            aspectMask: aspects.into(),
+            baseMipLevel: mipmaps.start + image.first_mipmap_level as u32,
+            levelCount: mipmaps.end - mipmaps.start,
+            baseArrayLayer: layers.start + image.first_layer as u32,
+            layerCount: layers.end - layers.start,
+        };
+
         self.image_barriers.push(vk::ImageMemoryBarrier {
             sType: vk::STRUCTURE_TYPE_IMAGE_MEMORY_BARRIER,
             pNext: ptr::null(),
@@ -1934,13 +2137,24 @@ impl UnsafeCommandBufferBuilderPipelineBarrier {
             dstQueueFamilyIndex: dest_queue,
             image: image.image.internal_object(),
             subresourceRange: vk::ImageSubresourceRange {
-                aspectMask: aspects.into(),
-                baseMipLevel: mipmaps.start + image.first_mipmap_level as u32,
-                levelCount: mipmaps.end - mipmaps.start,
-                baseArrayLayer: layers.start + image.first_layer as u32,
-                layerCount: layers.end - layers.start,
+                ..subresource_range
             },
         });
+
+        self.image_barriers2.push(vk::ImageMemoryBarrier2KHR {
+            sType: vk::STRUCTURE_TYPE_IMAGE_MEMORY_BARRIER_2_KHR,
+            pNext: ptr::null(),
+            srcStageMask: source_stage.into_vulkan_bits2(),
+            srcAccessMask: source_access.into_vulkan_bits2(),
+            dstStageMask: destination_stage.into_vulkan_bits2(),
+            dstAccessMask: destination_access.into_vulkan_bits2(),
+            oldLayout: current_layout as u32,
+            newLayout: new_layout as u32,
+            srcQueueFamilyIndex: src_queue,
+            dstQueueFamilyIndex: dest_queue,
+            image: image.image.internal_object(),
+            subresourceRange: subresource_range,
+        });
     }
 }
 
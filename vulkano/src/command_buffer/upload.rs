@@ -0,0 +1,805 @@
+// Copyright (c) 2021 The vulkano developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>,
+// at your option. All files in the project carrying such
+// notice may not be copied, modified, or distributed except
+// according to those terms.
+
+//! Batched uploads of data to device-local resources.
+
+use crate::buffer::staging::lcm;
+use crate::buffer::BufferAccess;
+use crate::buffer::BufferUsage;
+use crate::buffer::ImmutableBuffer;
+use crate::buffer::StagingBelt;
+use crate::buffer::TypedBufferAccess;
+use crate::command_buffer::submit::SubmitAnyBuilder;
+use crate::command_buffer::validity::check_copy_buffer;
+use crate::command_buffer::validity::check_copy_buffer_image;
+use crate::command_buffer::validity::CheckCopyBufferError;
+use crate::command_buffer::validity::CheckCopyBufferImageError;
+use crate::command_buffer::validity::CheckCopyBufferImageTy;
+use crate::command_buffer::AutoCommandBufferBuilder;
+use crate::command_buffer::BuildError;
+use crate::command_buffer::CommandBufferExecError;
+use crate::command_buffer::CommandBufferExecFuture;
+use crate::command_buffer::PrimaryAutoCommandBuffer;
+use crate::command_buffer::PrimaryCommandBuffer;
+use crate::device::Device;
+use crate::device::DeviceOwned;
+use crate::device::Queue;
+use crate::format::Format;
+use crate::format::Pixel;
+use crate::image::ImageAccess;
+use crate::image::ImageCreationError;
+use crate::image::ImageDimensions;
+use crate::image::ImageLayout;
+use crate::image::ImmutableImage;
+use crate::image::MipmapsCount;
+use crate::instance::PhysicalDevice;
+use crate::instance::QueueFamily;
+use crate::memory::DeviceMemoryAllocError;
+use crate::sync::AccessCheckError;
+use crate::sync::AccessFlagBits;
+use crate::sync::FenceSignalFuture;
+use crate::sync::FlushError;
+use crate::sync::GpuFuture;
+use crate::sync::NowFuture;
+use crate::sync::PipelineStages;
+use crate::VulkanObject;
+use std::error;
+use std::fmt;
+use std::mem;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+// A copy command recorded when the batch is submitted.
+type UploadOp = Box<dyn FnOnce(&mut AutoCommandBufferBuilder<PrimaryAutoCommandBuffer>) + Send>;
+
+type BatchFuture = FenceSignalFuture<CommandBufferExecFuture<NowFuture, PrimaryAutoCommandBuffer>>;
+
+// A buffer created by an upload, and the future of the upload.
+type BufferUpload<T> = (Arc<ImmutableBuffer<T>>, UploadFuture);
+
+/// Uploads data to buffers and images in batches.
+///
+/// The data of each upload is written to a `StagingBelt`, and the copy from the belt to the
+/// destination is added to the current batch. All the copies of a batch are recorded in a single
+/// command buffer, which is submitted when `flush` is called, when the amount of data staged
+/// in the batch exceeds the flush threshold, or when one of the futures of the batch needs to be
+/// submitted.
+///
+/// Each upload returns an `UploadFuture` that represents the moment when the destination
+/// resource is ready. All the futures of a batch share the same submission, so you can keep the
+/// future of each resource separately without creating one command buffer per resource.
+///
+/// For the best performance, use a queue of a transfer-only family if the device has one. See
+/// `UploadQueue::preferred_queue_family`. Note that such a queue can't generate mipmaps.
+///
+/// # Example
+///
+/// ```ignore
+/// let uploads = UploadQueue::new(transfer_queue.clone());
+///
+/// let meshes = level.meshes.iter().map(|mesh| {
+///     uploads.upload_buffer_iter(mesh.vertices.iter().cloned(), BufferUsage::vertex_buffer())
+/// }).collect::<Result<Vec<_>, _>>()?;
+///
+/// // Submits all the copies at once.
+/// uploads.flush()?;
+/// ```
+pub struct UploadQueue {
+    queue: Arc<Queue>,
+    belt: StagingBelt,
+    // Number of bytes staged in a batch above which it is submitted automatically.
+    flush_threshold: usize,
+    // The batch new uploads are added to.
+    current: Mutex<Arc<UploadBatch>>,
+    // Batches that have been submitted but whose staging memory may still be in use.
+    submitted: Mutex<Vec<Arc<UploadBatch>>>,
+}
+
+impl UploadQueue {
+    /// Default value of the flush threshold, in bytes.
+    pub const DEFAULT_FLUSH_THRESHOLD: usize = 32 * 1024 * 1024;
+
+    /// Builds a new `UploadQueue` that submits its copies to `queue`.
+    #[inline]
+    pub fn new(queue: Arc<Queue>) -> UploadQueue {
+        UploadQueue::with_flush_threshold(queue, UploadQueue::DEFAULT_FLUSH_THRESHOLD)
+    }
+
+    /// Builds a new `UploadQueue` that automatically submits the current batch once it contains
+    /// `flush_threshold` bytes of data.
+    pub fn with_flush_threshold(queue: Arc<Queue>, flush_threshold: usize) -> UploadQueue {
+        UploadQueue {
+            belt: StagingBelt::new(queue.device().clone()),
+            current: Mutex::new(Arc::new(UploadBatch::new(queue.clone()))),
+            submitted: Mutex::new(Vec::new()),
+            flush_threshold,
+            queue,
+        }
+    }
+
+    /// Returns the queue family that transfers should preferably be submitted to.
+    ///
+    /// This is a family that only supports transfer operations if there is one, as such families
+    /// usually map to a dedicated DMA engine. Otherwise this is the first family that supports
+    /// graphics operations.
+    pub fn preferred_queue_family(physical_device: PhysicalDevice<'_>) -> QueueFamily<'_> {
+        physical_device
+            .queue_families()
+            .find(|q| {
+                q.explicitly_supports_transfers() && !q.supports_graphics() && !q.supports_compute()
+            })
+            .or_else(|| {
+                physical_device
+                    .queue_families()
+                    .find(|q| q.supports_graphics())
+            })
+            .unwrap_or_else(|| physical_device.queue_families().next().unwrap())
+    }
+
+    /// Returns the queue the copies are submitted to.
+    #[inline]
+    pub fn queue(&self) -> &Arc<Queue> {
+        &self.queue
+    }
+
+    /// Returns the staging belt the data is written to.
+    #[inline]
+    pub fn staging_belt(&self) -> &StagingBelt {
+        &self.belt
+    }
+
+    /// Returns the number of bytes staged in the current batch.
+    #[inline]
+    pub fn pending_bytes(&self) -> usize {
+        self.current.lock().unwrap().staged_bytes()
+    }
+
+    /// Builds an `ImmutableBuffer` containing `data`.
+    pub fn upload_buffer<T>(
+        &self,
+        data: T,
+        usage: BufferUsage,
+    ) -> Result<BufferUpload<T>, UploadError>
+    where
+        T: Copy + Send + Sync + 'static,
+    {
+        let source = self.belt.chunk(data)?;
+        let (buffer, init) = unsafe {
+            ImmutableBuffer::uninitialized(
+                self.device().clone(),
+                BufferUsage {
+                    transfer_destination: true,
+                    ..usage
+                },
+            )?
+        };
+
+        let future = self.push_copy_buffer(source, init)?;
+        Ok((buffer, future))
+    }
+
+    /// Builds an `ImmutableBuffer` containing the elements of `data`.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if the length of the iterator didn't match the actual number of elements.
+    ///
+    pub fn upload_buffer_iter<T, I>(
+        &self,
+        data: I,
+        usage: BufferUsage,
+    ) -> Result<BufferUpload<[T]>, UploadError>
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+        T: Send + Sync + 'static,
+    {
+        let source = self.belt.chunk_iter(data)?;
+        let len = source.len();
+        let (buffer, init) = unsafe {
+            ImmutableBuffer::uninitialized_array(
+                self.device().clone(),
+                len,
+                BufferUsage {
+                    transfer_destination: true,
+                    ..usage
+                },
+            )?
+        };
+
+        let future = self.push_copy_buffer(source, init)?;
+        Ok((buffer, future))
+    }
+
+    /// Copies the elements of `data` to an existing buffer, for example a `DeviceLocalBuffer`.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if the length of the iterator didn't match the actual number of elements.
+    /// - Panics if `destination` doesn't belong to the same device as the queue.
+    /// - Panics if `destination` is smaller than the data.
+    ///
+    pub fn upload_to_buffer<T, I, B>(
+        &self,
+        data: I,
+        destination: B,
+    ) -> Result<UploadFuture, UploadError>
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+        T: Send + Sync + 'static,
+        B: TypedBufferAccess<Content = [T]> + Send + Sync + 'static,
+    {
+        assert_eq!(
+            destination.device().internal_object(),
+            self.device().internal_object()
+        );
+
+        let source = self.belt.chunk_iter(data)?;
+        assert!(source.size() <= destination.size());
+
+        self.push_copy_buffer(source, destination)
+    }
+
+    /// Builds an `ImmutableImage` from the texels of `data`, which fill the first mipmap level.
+    /// The other mipmap levels, if any, are generated from the first one.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if the length of the iterator didn't match the actual number of elements.
+    /// - Panics if more than one mipmap level is requested but the queue doesn't support
+    ///   graphics operations.
+    ///
+    pub fn upload_image<Px, I>(
+        &self,
+        data: I,
+        dimensions: ImageDimensions,
+        mipmaps: MipmapsCount,
+        format: Format,
+    ) -> Result<(Arc<ImmutableImage>, UploadFuture), UploadError>
+    where
+        I: IntoIterator<Item = Px>,
+        I::IntoIter: ExactSizeIterator,
+        Px: Pixel + Send + Sync + Clone + 'static,
+    {
+        // The offset of a buffer to image copy must be a multiple of 4 and of the texel size.
+        let alignment = lcm(4, format.size().unwrap_or(1));
+        let source = self.belt.chunk_aligned(data, alignment)?;
+
+        let (image, init) = ImmutableImage::uninitialized_for_upload(
+            self.device().clone(),
+            dimensions,
+            mipmaps,
+            format,
+        )?;
+        assert!(image.mipmap_levels() == 1 || self.queue.family().supports_graphics());
+
+        // Checked now so that recording the copy when the batch is submitted can't fail.
+        check_copy_buffer_image(
+            self.device(),
+            &source,
+            &init,
+            CheckCopyBufferImageTy::BufferToImage,
+            [0, 0, 0],
+            dimensions.width_height_depth(),
+            0,
+            dimensions.array_layers(),
+            0,
+        )?;
+
+        let size = source.size();
+        let image2 = image.clone();
+        let future = self.push(size, move |cbb| {
+            ImmutableImage::record_initial_upload(cbb, source, &image2, init);
+        })?;
+
+        Ok((image, future))
+    }
+
+    /// Submits the current batch, if it contains any upload.
+    ///
+    /// Also releases the staging memory of the batches that have finished executing.
+    pub fn flush(&self) -> Result<(), FlushError> {
+        let batch = {
+            let mut current = self.current.lock().unwrap();
+            if current.is_empty() {
+                None
+            } else {
+                let new = Arc::new(UploadBatch::new(self.queue.clone()));
+                Some(mem::replace(&mut *current, new))
+            }
+        };
+
+        self.cleanup_finished();
+
+        if let Some(batch) = batch {
+            let result = batch.submit().map(|_| ());
+            self.submitted.lock().unwrap().push(batch);
+            result
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Releases the staging memory of the batches that have finished executing. Never blocks.
+    pub fn cleanup_finished(&self) {
+        self.submitted
+            .lock()
+            .unwrap()
+            .retain(|batch| !batch.cleanup_and_check_finished());
+    }
+
+    // Checks the copy from `source` to `destination` and adds it to the current batch.
+    fn push_copy_buffer<S, D, T>(
+        &self,
+        source: S,
+        destination: D,
+    ) -> Result<UploadFuture, UploadError>
+    where
+        S: TypedBufferAccess<Content = T> + Send + Sync + 'static,
+        D: TypedBufferAccess<Content = T> + Send + Sync + 'static,
+        T: ?Sized,
+    {
+        check_copy_buffer(self.device(), &source, &destination)?;
+
+        let size = source.size();
+        Ok(self.push(size, move |cbb| {
+            // Can't fail, as the command buffer is outside of a render pass and the copy has
+            // been checked above.
+            cbb.copy_buffer(source, destination).unwrap();
+        })?)
+    }
+
+    // Adds an upload of `size` bytes to the current batch, and submits the batch if it exceeds
+    // the flush threshold.
+    fn push<F>(&self, size: usize, op: F) -> Result<UploadFuture, FlushError>
+    where
+        F: FnOnce(&mut AutoCommandBufferBuilder<PrimaryAutoCommandBuffer>) + Send + 'static,
+    {
+        let mut current = self.current.lock().unwrap();
+
+        // The batch may have been submitted by one of its futures in the meantime.
+        if current.is_submitted() {
+            let new = Arc::new(UploadBatch::new(self.queue.clone()));
+            let old = mem::replace(&mut *current, new);
+            self.submitted.lock().unwrap().push(old);
+        }
+
+        let staged_bytes = current.push(size, Box::new(op));
+        let future = UploadFuture {
+            batch: current.clone(),
+        };
+
+        if staged_bytes >= self.flush_threshold {
+            drop(current);
+            self.flush()?;
+        }
+
+        Ok(future)
+    }
+}
+
+unsafe impl DeviceOwned for UploadQueue {
+    #[inline]
+    fn device(&self) -> &Arc<Device> {
+        self.queue.device()
+    }
+}
+
+impl Drop for UploadQueue {
+    fn drop(&mut self) {
+        // Uploads that are still pending would never be submitted otherwise.
+        let _ = self.flush();
+    }
+}
+
+// A group of uploads that are submitted together.
+struct UploadBatch {
+    queue: Arc<Queue>,
+    state: Mutex<UploadBatchState>,
+}
+
+enum UploadBatchState {
+    // Uploads are still being added.
+    Recording {
+        ops: Vec<UploadOp>,
+        staged_bytes: usize,
+    },
+    // The command buffer has been built and submitted, or at least its submission was attempted.
+    Submitted(Arc<BatchFuture>),
+}
+
+impl UploadBatch {
+    fn new(queue: Arc<Queue>) -> UploadBatch {
+        UploadBatch {
+            queue,
+            state: Mutex::new(UploadBatchState::Recording {
+                ops: Vec::new(),
+                staged_bytes: 0,
+            }),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        match *self.state.lock().unwrap() {
+            UploadBatchState::Recording { ref ops, .. } => ops.is_empty(),
+            UploadBatchState::Submitted(_) => false,
+        }
+    }
+
+    fn is_submitted(&self) -> bool {
+        match *self.state.lock().unwrap() {
+            UploadBatchState::Recording { .. } => false,
+            UploadBatchState::Submitted(_) => true,
+        }
+    }
+
+    fn staged_bytes(&self) -> usize {
+        match *self.state.lock().unwrap() {
+            UploadBatchState::Recording { staged_bytes, .. } => staged_bytes,
+            UploadBatchState::Submitted(_) => 0,
+        }
+    }
+
+    // Adds an upload to the batch and returns the number of bytes staged so far.
+    //
+    // Must only be called while the batch is recording.
+    fn push(&self, size: usize, op: UploadOp) -> usize {
+        match *self.state.lock().unwrap() {
+            UploadBatchState::Recording {
+                ref mut ops,
+                ref mut staged_bytes,
+            } => {
+                ops.push(op);
+                *staged_bytes += size;
+                *staged_bytes
+            }
+            UploadBatchState::Submitted(_) => unreachable!(),
+        }
+    }
+
+    // Records and submits the command buffer of the batch if that wasn't done yet, and returns
+    // the future of the submission.
+    fn submit(&self) -> Result<Arc<BatchFuture>, FlushError> {
+        let mut state = self.state.lock().unwrap();
+
+        let ops = match *state {
+            UploadBatchState::Submitted(ref future) => {
+                let future = future.clone();
+                drop(state);
+                future.flush()?;
+                return Ok(future);
+            }
+            UploadBatchState::Recording { ref mut ops, .. } => mem::take(ops),
+        };
+
+        let mut cbb = AutoCommandBufferBuilder::primary_one_time_submit(
+            self.queue.device().clone(),
+            self.queue.family(),
+        )
+        .map_err(FlushError::OomError)?;
+        for op in ops {
+            op(&mut cbb);
+        }
+
+        let cb = match cbb.build() {
+            Ok(cb) => cb,
+            Err(BuildError::OomError(err)) => return Err(FlushError::OomError(err)),
+            Err(err) => unreachable!("{:?}", err),
+        };
+
+        let future = match cb.execute(self.queue.clone()) {
+            Ok(f) => Arc::new(f.then_signal_fence()),
+            Err(CommandBufferExecError::AccessError { error, .. }) => return Err(error.into()),
+            Err(err) => unreachable!("{:?}", err),
+        };
+
+        *state = UploadBatchState::Submitted(future.clone());
+        drop(state);
+
+        future.flush()?;
+        Ok(future)
+    }
+
+    // Returns true if the batch has finished executing and its resources have been released.
+    fn cleanup_and_check_finished(&self) -> bool {
+        match *self.state.lock().unwrap() {
+            UploadBatchState::Recording { .. } => false,
+            UploadBatchState::Submitted(ref future) => future.cleanup_and_check_finished(),
+        }
+    }
+}
+
+/// Represents the moment when the destination of an upload of an `UploadQueue` is ready to be
+/// used.
+///
+/// All the uploads of a batch share the same submission. If the batch hasn't been submitted yet,
+/// using this future submits it.
+#[must_use]
+#[derive(Clone)]
+pub struct UploadFuture {
+    batch: Arc<UploadBatch>,
+}
+
+impl UploadFuture {
+    /// Returns true if the batch of this upload has been submitted to the queue.
+    #[inline]
+    pub fn is_submitted(&self) -> bool {
+        self.batch.is_submitted()
+    }
+
+    /// Blocks the current thread until the upload has finished. Submits the batch if necessary.
+    pub fn wait(&self) -> Result<(), FlushError> {
+        self.batch.submit()?.wait(None)
+    }
+}
+
+unsafe impl GpuFuture for UploadFuture {
+    #[inline]
+    fn cleanup_finished(&mut self) {
+        self.batch.cleanup_and_check_finished();
+    }
+
+    #[inline]
+    unsafe fn build_submission(&self) -> Result<SubmitAnyBuilder<'_>, FlushError> {
+        // Submitting the batch also flushes its fence signal future, whose submission is then
+        // always empty.
+        self.batch.submit()?;
+        Ok(SubmitAnyBuilder::Empty)
+    }
+
+    #[inline]
+    fn flush(&self) -> Result<(), FlushError> {
+        self.batch.submit().map(|_| ())
+    }
+
+    #[inline]
+    unsafe fn signal_finished(&self) {
+        if let Ok(future) = self.batch.submit() {
+            future.signal_finished();
+        }
+    }
+
+    #[inline]
+    fn queue_change_allowed(&self) -> bool {
+        match self.batch.submit() {
+            Ok(future) => future.queue_change_allowed(),
+            Err(_) => false,
+        }
+    }
+
+    #[inline]
+    fn queue(&self) -> Option<Arc<Queue>> {
+        Some(self.batch.queue.clone())
+    }
+
+    #[inline]
+    fn check_buffer_access(
+        &self,
+        buffer: &dyn BufferAccess,
+        exclusive: bool,
+        queue: &Queue,
+    ) -> Result<Option<(PipelineStages, AccessFlagBits)>, AccessCheckError> {
+        match self.batch.submit() {
+            Ok(future) => future.check_buffer_access(buffer, exclusive, queue),
+            Err(_) => Err(AccessCheckError::Unknown),
+        }
+    }
+
+    #[inline]
+    fn check_image_access(
+        &self,
+        image: &dyn ImageAccess,
+        layout: ImageLayout,
+        exclusive: bool,
+        queue: &Queue,
+    ) -> Result<Option<(PipelineStages, AccessFlagBits)>, AccessCheckError> {
+        match self.batch.submit() {
+            Ok(future) => future.check_image_access(image, layout, exclusive, queue),
+            Err(_) => Err(AccessCheckError::Unknown),
+        }
+    }
+}
+
+unsafe impl DeviceOwned for UploadFuture {
+    #[inline]
+    fn device(&self) -> &Arc<Device> {
+        self.batch.queue.device()
+    }
+}
+
+/// Error that can happen when uploading with an `UploadQueue`.
+#[derive(Debug, Clone)]
+pub enum UploadError {
+    /// Error while allocating the staging memory or the destination buffer.
+    DeviceMemoryAllocError(DeviceMemoryAllocError),
+    /// Error while creating the destination image.
+    ImageCreationError(ImageCreationError),
+    /// The copy to the destination buffer is invalid.
+    CopyBufferError(CheckCopyBufferError),
+    /// The copy to the destination image is invalid.
+    CopyBufferImageError(CheckCopyBufferImageError),
+    /// Error while submitting a batch that exceeded the flush threshold.
+    FlushError(FlushError),
+}
+
+impl error::Error for UploadError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            Self::DeviceMemoryAllocError(ref err) => Some(err),
+            Self::ImageCreationError(ref err) => Some(err),
+            Self::CopyBufferError(ref err) => Some(err),
+            Self::CopyBufferImageError(ref err) => Some(err),
+            Self::FlushError(ref err) => Some(err),
+        }
+    }
+}
+
+impl fmt::Display for UploadError {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(
+            fmt,
+            "{}",
+            match *self {
+                Self::DeviceMemoryAllocError(_) => "error while allocating memory",
+                Self::ImageCreationError(_) => "error while creating the image",
+                Self::CopyBufferError(_) => "the copy to the buffer is invalid",
+                Self::CopyBufferImageError(_) => "the copy to the image is invalid",
+                Self::FlushError(_) => "error while submitting the uploads",
+            }
+        )
+    }
+}
+
+impl From<DeviceMemoryAllocError> for UploadError {
+    #[inline]
+    fn from(err: DeviceMemoryAllocError) -> Self {
+        Self::DeviceMemoryAllocError(err)
+    }
+}
+
+impl From<ImageCreationError> for UploadError {
+    #[inline]
+    fn from(err: ImageCreationError) -> Self {
+        Self::ImageCreationError(err)
+    }
+}
+
+impl From<CheckCopyBufferError> for UploadError {
+    #[inline]
+    fn from(err: CheckCopyBufferError) -> Self {
+        Self::CopyBufferError(err)
+    }
+}
+
+impl From<CheckCopyBufferImageError> for UploadError {
+    #[inline]
+    fn from(err: CheckCopyBufferImageError) -> Self {
+        Self::CopyBufferImageError(err)
+    }
+}
+
+impl From<FlushError> for UploadError {
+    #[inline]
+    fn from(err: FlushError) -> Self {
+        Self::FlushError(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::buffer::BufferUsage;
+    use crate::buffer::DeviceLocalBuffer;
+    use crate::command_buffer::validity::CheckCopyBufferError;
+    use crate::command_buffer::UploadError;
+    use crate::command_buffer::UploadQueue;
+    use crate::format::Format;
+    use crate::image::ImageDimensions;
+    use crate::image::MipmapsCount;
+    use crate::sync::GpuFuture;
+
+    #[test]
+    fn batches_uploads() {
+        let (device, queue) = gfx_dev_and_queue!();
+
+        let uploads = UploadQueue::new(queue.clone());
+        let (_a, fa) = uploads
+            .upload_buffer_iter(0..256u32, BufferUsage::all())
+            .unwrap();
+        let (_b, fb) = uploads.upload_buffer(12u32, BufferUsage::all()).unwrap();
+        let (_c, fc) = uploads
+            .upload_image(
+                vec![[0u8; 4]; 16],
+                ImageDimensions::Dim2d {
+                    width: 4,
+                    height: 4,
+                    array_layers: 1,
+                },
+                MipmapsCount::One,
+                Format::R8G8B8A8Unorm,
+            )
+            .unwrap();
+
+        assert_eq!(uploads.pending_bytes(), 256 * 4 + 4 + 16 * 4);
+        assert!(!fa.is_submitted());
+
+        uploads.flush().unwrap();
+        assert!(fa.is_submitted() && fb.is_submitted() && fc.is_submitted());
+        assert_eq!(uploads.pending_bytes(), 0);
+
+        fa.join(fb)
+            .join(fc)
+            .then_signal_fence_and_flush()
+            .unwrap()
+            .wait(None)
+            .unwrap();
+        drop(device);
+    }
+
+    #[test]
+    fn flush_threshold() {
+        let (_, queue) = gfx_dev_and_queue!();
+
+        let uploads = UploadQueue::with_flush_threshold(queue, 64);
+        let (_a, fa) = uploads
+            .upload_buffer_iter(0..8u32, BufferUsage::all())
+            .unwrap();
+        assert!(!fa.is_submitted());
+
+        let (_b, fb) = uploads
+            .upload_buffer_iter(0..8u32, BufferUsage::all())
+            .unwrap();
+        assert!(fa.is_submitted() && fb.is_submitted());
+        fb.wait().unwrap();
+    }
+
+    #[test]
+    fn future_submits_batch() {
+        let (_, queue) = gfx_dev_and_queue!();
+
+        let uploads = UploadQueue::new(queue);
+        let (_a, fa) = uploads
+            .upload_buffer_iter(0..8u32, BufferUsage::all())
+            .unwrap();
+        fa.wait().unwrap();
+
+        // The next upload goes to a new batch.
+        let (_b, fb) = uploads
+            .upload_buffer_iter(0..8u32, BufferUsage::all())
+            .unwrap();
+        assert!(!fb.is_submitted());
+        uploads.flush().unwrap();
+        fb.wait().unwrap();
+        uploads.cleanup_finished();
+    }
+
+    #[test]
+    fn invalid_copy_rejected() {
+        let (device, queue) = gfx_dev_and_queue!();
+
+        let uploads = UploadQueue::new(queue.clone());
+        let destination = DeviceLocalBuffer::<[u32]>::array(
+            device,
+            8,
+            BufferUsage::vertex_buffer(),
+            Some(queue.family()),
+        )
+        .unwrap();
+
+        match uploads.upload_to_buffer(0..8u32, destination) {
+            Err(UploadError::CopyBufferError(
+                CheckCopyBufferError::DestinationMissingTransferUsage,
+            )) => (),
+            _ => panic!(),
+        }
+        assert_eq!(uploads.pending_bytes(), 0);
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright (c) 2021 The vulkano developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>,
+// at your option. All files in the project carrying such
+// notice may not be copied, modified, or distributed except
+// according to those terms.
+
+use std::error;
+use std::fmt;
+
+use crate::device::Device;
+
+/// Checks whether a device mask is valid for the given device.
+pub fn check_device_mask(device: &Device, device_mask: u32) -> Result<(), CheckDeviceMaskError> {
+    if !device.loaded_extensions().khr_device_group {
+        return Err(CheckDeviceMaskError::ExtensionNotEnabled);
+    }
+
+    if device_mask == 0 {
+        return Err(CheckDeviceMaskError::EmptyMask);
+    }
+
+    if device_mask & !device.device_mask() != 0 {
+        return Err(CheckDeviceMaskError::UnknownDevice);
+    }
+
+    Ok(())
+}
+
+/// Error that can happen when validating a device mask.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CheckDeviceMaskError {
+    /// The `khr_device_group` extension must be enabled, unless the device supports Vulkan 1.1.
+    ExtensionNotEnabled,
+    /// The device mask must contain at least one physical device.
+    EmptyMask,
+    /// The device mask contains bits that don't correspond to a physical device of the device.
+    UnknownDevice,
+}
+
+impl error::Error for CheckDeviceMaskError {}
+
+impl fmt::Display for CheckDeviceMaskError {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(
+            fmt,
+            "{}",
+            match *self {
+                CheckDeviceMaskError::ExtensionNotEnabled => {
+                    "the `khr_device_group` extension must be enabled"
+                }
+                CheckDeviceMaskError::EmptyMask => "the device mask is empty",
+                CheckDeviceMaskError::UnknownDevice => {
+                    "the device mask contains physical devices that are not part of the device"
+                }
+            }
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_or_unknown_device() {
+        let (device, _) = gfx_dev_and_queue!();
+        if !device.loaded_extensions().khr_device_group {
+            return;
+        }
+
+        assert_eq!(
+            check_device_mask(&device, 0),
+            Err(CheckDeviceMaskError::EmptyMask)
+        );
+        assert_eq!(
+            check_device_mask(&device, device.device_mask() << 1),
+            Err(CheckDeviceMaskError::UnknownDevice)
+        );
+        assert_eq!(check_device_mask(&device, device.device_mask()), Ok(()));
+    }
+}
@@ -0,0 +1,188 @@
+// Copyright (c) 2021 The vulkano developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>,
+// at your option. All files in the project carrying such
+// notice may not be copied, modified, or distributed except
+// according to those terms.
+
+use std::error;
+use std::fmt;
+
+use crate::device::Device;
+use crate::pipeline::GraphicsPipelineAbstract;
+
+/// Checks whether a mesh tasks draw command can be recorded with the given pipeline.
+///
+/// `group_counts` is `None` for indirect draws. Otherwise they are checked against the limits of
+/// the physical device.
+pub fn check_draw_mesh_tasks<Gp>(
+    device: &Device,
+    pipeline: &Gp,
+    group_counts: Option<[u32; 3]>,
+) -> Result<(), CheckDrawMeshTasksError>
+where
+    Gp: ?Sized + GraphicsPipelineAbstract,
+{
+    let extensions = device.loaded_extensions();
+
+    if !extensions.ext_mesh_shader && !extensions.nv_mesh_shader {
+        return Err(CheckDrawMeshTasksError::ExtensionNotEnabled);
+    }
+
+    if !pipeline.has_mesh_shader() {
+        return Err(CheckDrawMeshTasksError::NotMeshPipeline);
+    }
+
+    let group_counts = match group_counts {
+        Some(group_counts) => group_counts,
+        None if extensions.ext_mesh_shader => return Ok(()),
+        None => return Err(CheckDrawMeshTasksError::IndirectRequiresExtMeshShader),
+    };
+
+    let physical_device = device.physical_device();
+    let properties = physical_device.properties();
+
+    let (max, max_total) = if extensions.ext_mesh_shader {
+        if pipeline.has_task_shader() {
+            (
+                properties.max_task_work_group_count,
+                properties.max_task_work_group_total_count,
+            )
+        } else {
+            (
+                properties.max_mesh_work_group_count,
+                properties.max_mesh_work_group_total_count,
+            )
+        }
+    } else {
+        if group_counts[1] != 1 || group_counts[2] != 1 {
+            return Err(CheckDrawMeshTasksError::GroupCountsUnsupported { group_counts });
+        }
+
+        let max = properties.max_draw_mesh_tasks_count;
+        (max.map(|max| [max, 1, 1]), None)
+    };
+
+    if let Some(max) = max {
+        if group_counts[0] > max[0] || group_counts[1] > max[1] || group_counts[2] > max[2] {
+            return Err(CheckDrawMeshTasksError::GroupCountsExceedLimit {
+                group_counts,
+                max_supported: max,
+            });
+        }
+    }
+
+    if let Some(max_total) = max_total {
+        let total = group_counts.iter().map(|&n| n as u64).product::<u64>();
+        if total > max_total as u64 {
+            return Err(CheckDrawMeshTasksError::TotalGroupCountExceedsLimit {
+                total,
+                max_supported: max_total,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks whether a draw command that takes vertex buffers can be recorded with the given
+/// pipeline.
+pub fn check_vertex_input_pipeline<Gp>(pipeline: &Gp) -> Result<(), CheckVertexInputPipelineError>
+where
+    Gp: ?Sized + GraphicsPipelineAbstract,
+{
+    if pipeline.has_mesh_shader() {
+        return Err(CheckVertexInputPipelineError::MeshPipeline);
+    }
+
+    Ok(())
+}
+
+/// Error that can happen when checking the validity of a mesh tasks draw command.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CheckDrawMeshTasksError {
+    /// Neither the `ext_mesh_shader` nor the `nv_mesh_shader` extension is enabled.
+    ExtensionNotEnabled,
+    /// The bound pipeline wasn't created with a mesh shader.
+    NotMeshPipeline,
+    /// Only the `nv_mesh_shader` extension is enabled, which only supports group counts along
+    /// the X dimension.
+    GroupCountsUnsupported {
+        /// The requested group counts.
+        group_counts: [u32; 3],
+    },
+    /// Indirect mesh tasks draws require the `ext_mesh_shader` extension.
+    IndirectRequiresExtMeshShader,
+    /// A group count is larger than the limit of the physical device.
+    GroupCountsExceedLimit {
+        /// The requested group counts.
+        group_counts: [u32; 3],
+        /// The maximum group counts supported.
+        max_supported: [u32; 3],
+    },
+    /// The product of the group counts is larger than the limit of the physical device.
+    TotalGroupCountExceedsLimit {
+        /// The product of the requested group counts.
+        total: u64,
+        /// The maximum product supported.
+        max_supported: u32,
+    },
+}
+
+impl error::Error for CheckDrawMeshTasksError {}
+
+impl fmt::Display for CheckDrawMeshTasksError {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(
+            fmt,
+            "{}",
+            match *self {
+                CheckDrawMeshTasksError::ExtensionNotEnabled => {
+                    "no mesh shader extension is enabled on the device"
+                }
+                CheckDrawMeshTasksError::NotMeshPipeline => {
+                    "the pipeline wasn't created with a mesh shader"
+                }
+                CheckDrawMeshTasksError::GroupCountsUnsupported { .. } => {
+                    "the Y and Z group counts must be 1 when only `nv_mesh_shader` is enabled"
+                }
+                CheckDrawMeshTasksError::IndirectRequiresExtMeshShader => {
+                    "indirect mesh tasks draws require the `ext_mesh_shader` extension"
+                }
+                CheckDrawMeshTasksError::GroupCountsExceedLimit { .. } => {
+                    "a group count is larger than the device's limits"
+                }
+                CheckDrawMeshTasksError::TotalGroupCountExceedsLimit { .. } => {
+                    "the total number of groups is larger than the device's limits"
+                }
+            }
+        )
+    }
+}
+
+/// Error that can happen when checking whether a pipeline can be used with vertex buffers.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CheckVertexInputPipelineError {
+    /// The pipeline uses a mesh shader, which can only be used with the mesh tasks draw commands.
+    MeshPipeline,
+}
+
+impl error::Error for CheckVertexInputPipelineError {}
+
+impl fmt::Display for CheckVertexInputPipelineError {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(
+            fmt,
+            "{}",
+            match *self {
+                CheckVertexInputPipelineError::MeshPipeline => {
+                    "the pipeline uses a mesh shader and can only be used with mesh tasks draws"
+                }
+            }
+        )
+    }
+}
@@ -13,16 +13,22 @@ pub use self::blit_image::{check_blit_image, CheckBlitImageError};
 pub use self::clear_color_image::{check_clear_color_image, CheckClearColorImageError};
 pub use self::copy_buffer::{check_copy_buffer, CheckCopyBuffer, CheckCopyBufferError};
 pub use self::copy_image::{check_copy_image, CheckCopyImageError};
+pub(crate) use self::copy_image_buffer::required_len_for_format;
 pub use self::copy_image_buffer::{
     check_copy_buffer_image, CheckCopyBufferImageError, CheckCopyBufferImageTy,
 };
 pub use self::debug_marker::{check_debug_marker_color, CheckColorError};
 pub use self::descriptor_sets::{check_descriptor_sets_validity, CheckDescriptorSetsValidityError};
+pub use self::device_mask::{check_device_mask, CheckDeviceMaskError};
 pub use self::dispatch::{check_dispatch, CheckDispatchError};
 pub use self::dynamic_state::{check_dynamic_state_validity, CheckDynamicStateValidityError};
 pub use self::fill_buffer::{check_fill_buffer, CheckFillBufferError};
 pub use self::index_buffer::{check_index_buffer, CheckIndexBuffer, CheckIndexBufferError};
 pub use self::indirect_buffer::{check_indirect_buffer, CheckIndirectBufferError};
+pub use self::mesh_tasks::{
+    check_draw_mesh_tasks, check_vertex_input_pipeline, CheckDrawMeshTasksError,
+    CheckVertexInputPipelineError,
+};
 pub use self::push_constants::{check_push_constants_validity, CheckPushConstantsValidityError};
 pub use self::query::{
     check_begin_query, check_copy_query_pool_results, check_end_query, check_reset_query_pool,
@@ -39,11 +45,13 @@ mod copy_image;
 mod copy_image_buffer;
 mod debug_marker;
 mod descriptor_sets;
+mod device_mask;
 mod dispatch;
 mod dynamic_state;
 mod fill_buffer;
 mod index_buffer;
 mod indirect_buffer;
+mod mesh_tasks;
 mod push_constants;
 mod query;
 mod update_buffer;
@@ -177,6 +177,10 @@ pub fn check_write_timestamp(
         return Err(CheckWriteTimestampError::StageNotSupported);
     }
 
+    if stage.requires_synchronization2() && !device.enabled_features().synchronization2 {
+        return Err(CheckWriteTimestampError::Synchronization2FeatureNotEnabled);
+    }
+
     match stage {
         PipelineStage::GeometryShader => {
             if !device.enabled_features().geometry_shader {
@@ -207,6 +211,9 @@ pub enum CheckWriteTimestampError {
     OutOfRange,
     /// The provided stage is not supported by the queue family.
     StageNotSupported,
+    /// A stage that only exists with `VK_KHR_synchronization2` was requested, but the
+    /// `synchronization2` feature was not enabled.
+    Synchronization2FeatureNotEnabled,
     /// A tessellation shader stage was requested, but the `tessellation_shader` feature was not enabled.
     TessellationShaderFeatureNotEnabled,
 }
@@ -235,6 +242,9 @@ impl fmt::Display for CheckWriteTimestampError {
                 Self::StageNotSupported => {
                     "the provided stage is not supported by the queue family"
                 }
+                Self::Synchronization2FeatureNotEnabled => {
+                    "a stage that only exists with synchronization2 was requested, but the synchronization2 feature was not enabled"
+                }
                 Self::TessellationShaderFeatureNotEnabled => {
                     "a tessellation shader stage was requested, but the tessellation_shader feature was not enabled"
                 }
@@ -91,7 +91,9 @@ impl DescriptorDesc {
     ///  tessellation_evaluation: true,
     ///  geometry: true,
     ///  fragment: true,
-    ///  compute: true
+    ///  compute: true,
+    ///  task: false,
+    ///  mesh: false
     ///}, readonly: false };
     ///let desc_sub = DescriptorDesc{ ty: Sampler, array_count: 1, stages: ShaderStages{
     ///  vertex: true,
@@ -99,7 +101,9 @@ impl DescriptorDesc {
     ///  tessellation_evaluation: false,
     ///  geometry: false,
     ///  fragment: true,
-    ///  compute: false
+    ///  compute: false,
+    ///  task: false,
+    ///  mesh: false
     ///}, readonly: true };
     ///
     ///assert_eq!(desc_super.is_superset_of(&desc_sub).unwrap(), ());
@@ -143,7 +147,9 @@ impl DescriptorDesc {
     ///  tessellation_evaluation: false,
     ///  geometry: true,
     ///  fragment: false,
-    ///  compute: true
+    ///  compute: true,
+    ///  task: false,
+    ///  mesh: false
     ///}, readonly: false };
     ///
     ///let desc_part2 = DescriptorDesc{ ty: Sampler, array_count: 1, stages: ShaderStages{
@@ -152,7 +158,9 @@ impl DescriptorDesc {
     ///  tessellation_evaluation: true,
     ///  geometry: false,
     ///  fragment: true,
-    ///  compute: true
+    ///  compute: true,
+    ///  task: false,
+    ///  mesh: false
     ///}, readonly: true };
     ///
     ///let desc_union = DescriptorDesc{ ty: Sampler, array_count: 2, stages: ShaderStages{
@@ -161,7 +169,9 @@ impl DescriptorDesc {
     ///  tessellation_evaluation: true,
     ///  geometry: true,
     ///  fragment: true,
-    ///  compute: true
+    ///  compute: true,
+    ///  task: false,
+    ///  mesh: false
     ///}, readonly: false };
     ///
     ///assert_eq!(desc_part1.union(&desc_part2), Some(desc_union));
@@ -656,10 +666,17 @@ pub struct ShaderStages {
     pub fragment: bool,
     /// `True` means that the descriptor will be used by the compute shader.
     pub compute: bool,
+    /// `True` means that the descriptor will be used by the task shader.
+    pub task: bool,
+    /// `True` means that the descriptor will be used by the mesh shader.
+    pub mesh: bool,
 }
 
 impl ShaderStages {
     /// Creates a `ShaderStages` struct will all stages set to `true`.
+    ///
+    /// The task and mesh stages are left to `false`, as they require the `nv_mesh_shader` or
+    /// `ext_mesh_shader` extension.
     // TODO: add example
     #[inline]
     pub fn all() -> ShaderStages {
@@ -670,6 +687,8 @@ impl ShaderStages {
             geometry: true,
             fragment: true,
             compute: true,
+            task: false,
+            mesh: false,
         }
     }
 
@@ -684,10 +703,14 @@ impl ShaderStages {
             geometry: false,
             fragment: false,
             compute: false,
+            task: false,
+            mesh: false,
         }
     }
 
     /// Creates a `ShaderStages` struct with all graphics stages set to `true`.
+    ///
+    /// Like `VK_SHADER_STAGE_ALL_GRAPHICS`, this doesn't include the task and mesh stages.
     // TODO: add example
     #[inline]
     pub fn all_graphics() -> ShaderStages {
@@ -698,6 +721,8 @@ impl ShaderStages {
             geometry: true,
             fragment: true,
             compute: false,
+            task: false,
+            mesh: false,
         }
     }
 
@@ -712,6 +737,8 @@ impl ShaderStages {
             geometry: false,
             fragment: false,
             compute: true,
+            task: false,
+            mesh: false,
         }
     }
 
@@ -725,6 +752,8 @@ impl ShaderStages {
             && (self.geometry || !other.geometry)
             && (self.fragment || !other.fragment)
             && (self.compute || !other.compute)
+            && (self.task || !other.task)
+            && (self.mesh || !other.mesh)
         {
             Ok(())
         } else {
@@ -742,6 +771,22 @@ impl ShaderStages {
             || (self.geometry && other.geometry)
             || (self.fragment && other.fragment)
             || (self.compute && other.compute)
+            || (self.task && other.task)
+            || (self.mesh && other.mesh)
+    }
+
+    #[inline]
+    pub(crate) fn from_vulkan_bits(val: vk::ShaderStageFlags) -> ShaderStages {
+        ShaderStages {
+            vertex: (val & vk::SHADER_STAGE_VERTEX_BIT) != 0,
+            tessellation_control: (val & vk::SHADER_STAGE_TESSELLATION_CONTROL_BIT) != 0,
+            tessellation_evaluation: (val & vk::SHADER_STAGE_TESSELLATION_EVALUATION_BIT) != 0,
+            geometry: (val & vk::SHADER_STAGE_GEOMETRY_BIT) != 0,
+            fragment: (val & vk::SHADER_STAGE_FRAGMENT_BIT) != 0,
+            compute: (val & vk::SHADER_STAGE_COMPUTE_BIT) != 0,
+            task: (val & vk::SHADER_STAGE_TASK_BIT_NV) != 0,
+            mesh: (val & vk::SHADER_STAGE_MESH_BIT_NV) != 0,
+        }
     }
 
     #[inline]
@@ -765,6 +810,12 @@ impl ShaderStages {
         if self.compute {
             result |= vk::SHADER_STAGE_COMPUTE_BIT;
         }
+        if self.task {
+            result |= vk::SHADER_STAGE_TASK_BIT_NV;
+        }
+        if self.mesh {
+            result |= vk::SHADER_STAGE_MESH_BIT_NV;
+        }
         result
     }
 }
@@ -781,6 +832,8 @@ impl BitOr for ShaderStages {
             geometry: self.geometry || other.geometry,
             fragment: self.fragment || other.fragment,
             compute: self.compute || other.compute,
+            task: self.task || other.task,
+            mesh: self.mesh || other.mesh,
         }
     }
 }
@@ -795,6 +848,8 @@ impl From<ShaderStages> for PipelineStages {
             geometry_shader: stages.geometry,
             fragment_shader: stages.fragment,
             compute_shader: stages.compute,
+            task_shader: stages.task,
+            mesh_shader: stages.mesh,
             ..PipelineStages::none()
         }
     }
@@ -21,6 +21,8 @@ use crate::descriptor::descriptor_set::DescriptorPoolAlloc;
 use crate::descriptor::descriptor_set::DescriptorPoolAllocError;
 use crate::descriptor::descriptor_set::DescriptorSet;
 use crate::descriptor::descriptor_set::DescriptorSetDesc;
+use crate::descriptor::descriptor_set::DescriptorUpdateTemplate;
+use crate::descriptor::descriptor_set::DescriptorsCount;
 use crate::descriptor::descriptor_set::UnsafeDescriptorPool;
 use crate::descriptor::descriptor_set::UnsafeDescriptorSet;
 use crate::descriptor::descriptor_set::UnsafeDescriptorSetLayout;
@@ -70,9 +72,15 @@ use crate::VulkanObject;
 /// Note that `next()` requires exclusive (`mut`) access to the pool. You can use a `Mutex` around
 /// the pool if you can't provide this.
 ///
+/// If the `khr_descriptor_update_template` extension or Vulkan 1.1 is available, the descriptors
+/// of each set are written with a `DescriptorUpdateTemplate` created from the layout, which is
+/// cheaper for the CPU.
+///
 #[derive(Clone)]
 pub struct FixedSizeDescriptorSetsPool {
     layout: Arc<UnsafeDescriptorSetLayout>,
+    // Template used to write the descriptors of the sets, if supported by the device.
+    template: Option<Arc<DescriptorUpdateTemplate>>,
     // We hold a local implementation of the `DescriptorPool` trait for our own purpose. Since we
     // don't want to expose this trait impl in our API, we use a separate struct.
     pool: LocalPool,
@@ -84,8 +92,19 @@ impl FixedSizeDescriptorSetsPool {
     pub fn new(layout: Arc<UnsafeDescriptorSetLayout>) -> FixedSizeDescriptorSetsPool {
         let device = layout.device().clone();
 
+        // Creating a template is only an optimization, so we fall back to regular writes if it
+        // fails.
+        let template = if device.loaded_extensions().khr_descriptor_update_template
+            && *layout.descriptors_count() != DescriptorsCount::zero()
+        {
+            DescriptorUpdateTemplate::from_layout(layout.clone()).ok()
+        } else {
+            None
+        };
+
         FixedSizeDescriptorSetsPool {
             layout,
+            template,
             pool: LocalPool {
                 device,
                 next_capacity: 3,
@@ -310,7 +329,9 @@ impl<'a, R> FixedSizeDescriptorSetBuilder<'a, R> {
     /// Builds a `FixedSizeDescriptorSet` from the builder.
     #[inline]
     pub fn build(self) -> Result<FixedSizeDescriptorSet<R>, PersistentDescriptorSetBuildError> {
-        let inner = self.inner.build_with_pool(&mut self.pool.pool)?;
+        let inner = self
+            .inner
+            .build_with_pool_and_template(&mut self.pool.pool, self.pool.template.as_deref())?;
         Ok(FixedSizeDescriptorSet { inner })
     }
 
@@ -70,6 +70,12 @@ pub use self::sys::UnsafeDescriptorPool;
 pub use self::sys::UnsafeDescriptorPoolAllocIter;
 pub use self::sys::UnsafeDescriptorSet;
 pub use self::unsafe_layout::UnsafeDescriptorSetLayout;
+pub use self::update_template::DescriptorBufferInfo;
+pub use self::update_template::DescriptorBufferViewInfo;
+pub use self::update_template::DescriptorImageInfo;
+pub use self::update_template::DescriptorUpdateTemplate;
+pub use self::update_template::DescriptorUpdateTemplateCreationError;
+pub use self::update_template::DescriptorUpdateTemplateEntry;
 
 pub mod collection;
 
@@ -78,6 +84,7 @@ mod persistent;
 mod std_pool;
 mod sys;
 mod unsafe_layout;
+mod update_template;
 
 /// Trait for objects that contain a collection of resources that will be accessible by shaders.
 ///
@@ -25,6 +25,7 @@ use crate::descriptor::descriptor_set::DescriptorPool;
 use crate::descriptor::descriptor_set::DescriptorPoolAlloc;
 use crate::descriptor::descriptor_set::DescriptorSet;
 use crate::descriptor::descriptor_set::DescriptorSetDesc;
+use crate::descriptor::descriptor_set::DescriptorUpdateTemplate;
 use crate::descriptor::descriptor_set::DescriptorWrite;
 use crate::descriptor::descriptor_set::StdDescriptorPoolAlloc;
 use crate::descriptor::descriptor_set::UnsafeDescriptorSet;
@@ -201,6 +202,20 @@ impl<R> PersistentDescriptorSetBuilder<R> {
         self,
         pool: &mut P,
     ) -> Result<PersistentDescriptorSet<R, P::Alloc>, PersistentDescriptorSetBuildError>
+    where
+        P: ?Sized + DescriptorPool,
+    {
+        self.build_with_pool_and_template(pool, None)
+    }
+
+    // Same as `build_with_pool`, but writes the descriptors with `template` if there is one. The
+    // template must have been created with `DescriptorUpdateTemplate::from_layout` from the
+    // layout of the builder.
+    pub(crate) fn build_with_pool_and_template<P>(
+        self,
+        pool: &mut P,
+        template: Option<&DescriptorUpdateTemplate>,
+    ) -> Result<PersistentDescriptorSet<R, P::Alloc>, PersistentDescriptorSetBuildError>
     where
         P: ?Sized + DescriptorPool,
     {
@@ -222,8 +237,16 @@ impl<R> PersistentDescriptorSetBuilder<R> {
 
         let set = unsafe {
             let mut set = pool.alloc(&self.layout)?;
-            set.inner_mut()
-                .write(pool.device(), self.writes.into_iter());
+            match template {
+                Some(template) => {
+                    debug_assert!(Arc::ptr_eq(template.layout(), &self.layout));
+                    set.inner_mut()
+                        .write_with_template(template, self.writes.into_iter())
+                }
+                None => set
+                    .inner_mut()
+                    .write(pool.device(), self.writes.into_iter()),
+            }
             set
         };
 
@@ -21,6 +21,11 @@ use crate::buffer::BufferAccess;
 use crate::buffer::BufferInner;
 use crate::buffer::BufferView;
 use crate::descriptor::descriptor::DescriptorType;
+use crate::descriptor::descriptor_set::update_template::RawDescriptor;
+use crate::descriptor::descriptor_set::DescriptorBufferInfo;
+use crate::descriptor::descriptor_set::DescriptorBufferViewInfo;
+use crate::descriptor::descriptor_set::DescriptorImageInfo;
+use crate::descriptor::descriptor_set::DescriptorUpdateTemplate;
 use crate::descriptor::descriptor_set::UnsafeDescriptorSetLayout;
 use crate::device::Device;
 use crate::device::DeviceOwned;
@@ -766,6 +771,77 @@ impl UnsafeDescriptorSet {
             );
         }
     }
+
+    /// Modifies a descriptor set by reading the descriptors from `data`, as described by the
+    /// entries of `template`.
+    ///
+    /// # Safety
+    ///
+    /// - The `UnsafeDescriptorSetLayout` of `template` must be the layout this set was created
+    ///   with.
+    /// - The content of `data` must match the entries of the template. Each descriptor must be a
+    ///   `DescriptorImageInfo`, a `DescriptorBufferInfo` or a `DescriptorBufferViewInfo`
+    ///   depending on its type.
+    /// - Doesn't keep the resources alive. You have to do that yourself.
+    /// - Updating a descriptor set obeys synchronization rules that aren't checked here. Once a
+    ///   command buffer contains a pointer/reference to a descriptor set, it is illegal to write
+    ///   to it.
+    ///
+    #[inline]
+    pub unsafe fn update_with_template<D>(&mut self, template: &DescriptorUpdateTemplate, data: &D)
+    where
+        D: ?Sized,
+    {
+        let device = template.device();
+        let vk = device.pointers();
+
+        vk.UpdateDescriptorSetWithTemplateKHR(
+            device.internal_object(),
+            self.set,
+            template.internal_object(),
+            data as *const D as *const _,
+        );
+    }
+
+    /// Same as `write`, but performs the writes with a single call to
+    /// `update_with_template`.
+    ///
+    /// The writes must cover every descriptor of the layout, as the template updates all of
+    /// them.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if `template` wasn't created with `DescriptorUpdateTemplate::from_layout`.
+    /// - Panics if a write doesn't match a binding of the layout of `template`.
+    ///
+    /// # Safety
+    ///
+    /// Same as `write` and `update_with_template`. In addition, leaving a descriptor unwritten
+    /// is undefined behavior.
+    ///
+    pub unsafe fn write_with_template<I>(&mut self, template: &DescriptorUpdateTemplate, writes: I)
+    where
+        I: Iterator<Item = DescriptorWrite>,
+    {
+        let empty = RawDescriptor {
+            buffer_view: DescriptorBufferViewInfo { view: 0 },
+        };
+        let mut data: SmallVec<[RawDescriptor; 32]> =
+            SmallVec::from_elem(empty, template.packed_len());
+
+        for indiv_write in writes {
+            let first_slot = template
+                .packed_binding(indiv_write.binding)
+                .expect("a descriptor write doesn't match the layout of the template")
+                + indiv_write.first_array_element as usize;
+
+            for (slot, elem) in data[first_slot..].iter_mut().zip(indiv_write.inner.iter()) {
+                *slot = elem.to_raw();
+            }
+        }
+
+        self.update_with_template(template, &data[..]);
+    }
 }
 
 unsafe impl VulkanObject for UnsafeDescriptorSet {
@@ -811,6 +887,53 @@ enum DescriptorWriteInner {
     InputAttachment(vk::ImageView, vk::ImageLayout),
 }
 
+impl DescriptorWriteInner {
+    #[inline]
+    fn to_raw(&self) -> RawDescriptor {
+        match *self {
+            DescriptorWriteInner::Sampler(sampler) => RawDescriptor {
+                image: DescriptorImageInfo {
+                    sampler,
+                    image_view: 0,
+                    image_layout: 0,
+                },
+            },
+            DescriptorWriteInner::CombinedImageSampler(sampler, image_view, image_layout) => {
+                RawDescriptor {
+                    image: DescriptorImageInfo {
+                        sampler,
+                        image_view,
+                        image_layout,
+                    },
+                }
+            }
+            DescriptorWriteInner::StorageImage(image_view, image_layout)
+            | DescriptorWriteInner::SampledImage(image_view, image_layout)
+            | DescriptorWriteInner::InputAttachment(image_view, image_layout) => RawDescriptor {
+                image: DescriptorImageInfo {
+                    sampler: 0,
+                    image_view,
+                    image_layout,
+                },
+            },
+            DescriptorWriteInner::UniformTexelBuffer(view)
+            | DescriptorWriteInner::StorageTexelBuffer(view) => RawDescriptor {
+                buffer_view: DescriptorBufferViewInfo { view },
+            },
+            DescriptorWriteInner::UniformBuffer(buffer, offset, size)
+            | DescriptorWriteInner::StorageBuffer(buffer, offset, size)
+            | DescriptorWriteInner::DynamicUniformBuffer(buffer, offset, size)
+            | DescriptorWriteInner::DynamicStorageBuffer(buffer, offset, size) => RawDescriptor {
+                buffer: DescriptorBufferInfo {
+                    buffer,
+                    offset: offset as u64,
+                    range: size as u64,
+                },
+            },
+        }
+    }
+}
+
 macro_rules! smallvec {
     ($elem:expr) => {{
         let mut s = SmallVec::new();
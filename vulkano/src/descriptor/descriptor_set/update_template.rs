@@ -0,0 +1,637 @@
+// Copyright (c) 2021 The vulkano developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>,
+// at your option. All files in the project carrying such
+// notice may not be copied, modified, or distributed except
+// according to those terms.
+
+use smallvec::SmallVec;
+use std::error;
+use std::fmt;
+use std::mem;
+use std::mem::MaybeUninit;
+use std::ptr;
+use std::sync::Arc;
+
+use crate::buffer::BufferAccess;
+use crate::buffer::BufferInner;
+use crate::buffer::BufferView;
+use crate::descriptor::descriptor::DescriptorType;
+use crate::descriptor::descriptor_set::DescriptorSetDesc;
+use crate::descriptor::descriptor_set::UnsafeDescriptorSetLayout;
+use crate::device::Device;
+use crate::device::DeviceOwned;
+use crate::image::view::ImageViewAbstract;
+use crate::image::ImageDescriptorLayouts;
+use crate::sampler::Sampler;
+
+use crate::check_errors;
+use crate::vk;
+use crate::Error;
+use crate::OomError;
+use crate::VulkanObject;
+
+/// Describes how to update all the descriptors of a descriptor set, or a part of them, from a
+/// single block of memory.
+///
+/// Updating a descriptor set with a template with `UnsafeDescriptorSet::update_with_template`
+/// is cheaper than building a list of `DescriptorWrite`s, as the implementation reads the
+/// descriptors directly from the memory that is passed to it. The data is usually a `#[repr(C)]`
+/// struct made of `DescriptorImageInfo`, `DescriptorBufferInfo` and `DescriptorBufferViewInfo`
+/// fields, and each entry of the template describes where the descriptors of a binding are
+/// located in this struct.
+///
+/// This requires the `khr_descriptor_update_template` extension or Vulkan 1.1.
+///
+/// # Example
+///
+/// ```no_run
+/// # use std::mem;
+/// # use std::sync::Arc;
+/// # use vulkano::descriptor::descriptor::DescriptorType;
+/// # use vulkano::descriptor::descriptor_set::{DescriptorBufferInfo, DescriptorImageInfo};
+/// # use vulkano::descriptor::descriptor_set::{DescriptorUpdateTemplate, DescriptorUpdateTemplateEntry};
+/// # use vulkano::descriptor::descriptor_set::{UnsafeDescriptorSet, UnsafeDescriptorSetLayout};
+/// # let layout: Arc<UnsafeDescriptorSetLayout> = return;
+/// # let set: &mut UnsafeDescriptorSet = return;
+/// # let buffer: Arc<vulkano::buffer::CpuAccessibleBuffer<[f32]>> = return;
+/// # let image_view: Arc<vulkano::image::view::ImageView<Arc<vulkano::image::ImmutableImage>>> = return;
+/// # let sampler: Arc<vulkano::sampler::Sampler> = return;
+/// #[repr(C)]
+/// struct Descriptors {
+///     uniforms: DescriptorBufferInfo,
+///     texture: DescriptorImageInfo,
+/// }
+///
+/// let template = DescriptorUpdateTemplate::new(
+///     layout.clone(),
+///     vec![
+///         DescriptorUpdateTemplateEntry {
+///             binding: 0,
+///             first_array_element: 0,
+///             descriptor_count: 1,
+///             ty: DescriptorType::UniformBuffer,
+///             offset: 0,
+///             stride: mem::size_of::<DescriptorBufferInfo>(),
+///         },
+///         DescriptorUpdateTemplateEntry {
+///             binding: 1,
+///             first_array_element: 0,
+///             descriptor_count: 1,
+///             ty: DescriptorType::CombinedImageSampler,
+///             offset: mem::size_of::<DescriptorBufferInfo>(),
+///             stride: mem::size_of::<DescriptorImageInfo>(),
+///         },
+///     ],
+/// )
+/// .unwrap();
+///
+/// let descriptors = Descriptors {
+///     uniforms: DescriptorBufferInfo::new(&buffer),
+///     texture: DescriptorImageInfo::combined_image_sampler(&sampler, &image_view),
+/// };
+///
+/// unsafe {
+///     set.update_with_template(&template, &descriptors);
+/// }
+/// ```
+pub struct DescriptorUpdateTemplate {
+    template: vk::DescriptorUpdateTemplateKHR,
+    device: Arc<Device>,
+    layout: Arc<UnsafeDescriptorSetLayout>,
+    entries: SmallVec<[DescriptorUpdateTemplateEntry; 8]>,
+    // For templates created with `from_layout`, the index of the first `RawDescriptor` of each
+    // binding in the data.
+    packed_bindings: Option<SmallVec<[Option<usize>; 32]>>,
+}
+
+impl DescriptorUpdateTemplate {
+    /// Builds a new template that updates descriptor sets of the given layout.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if `entries` is empty.
+    /// - Panics if an entry has a `descriptor_count` of 0.
+    /// - Panics if an entry doesn't match a binding of the layout, either because the binding is
+    ///   empty, because its type is different, or because the entry goes past the end of its
+    ///   array.
+    ///
+    pub fn new<I>(
+        layout: Arc<UnsafeDescriptorSetLayout>,
+        entries: I,
+    ) -> Result<Arc<DescriptorUpdateTemplate>, DescriptorUpdateTemplateCreationError>
+    where
+        I: IntoIterator<Item = DescriptorUpdateTemplateEntry>,
+    {
+        let entries = entries.into_iter().collect();
+        DescriptorUpdateTemplate::new_impl(layout, entries, None)
+    }
+
+    /// Builds a new template that updates all the descriptors of descriptor sets of the given
+    /// layout at once.
+    ///
+    /// Each descriptor is read from a slot of `mem::size_of::<DescriptorImageInfo>()` bytes, in
+    /// the order of the bindings and array elements. This is the template that
+    /// `UnsafeDescriptorSet::write_with_template` expects.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if the layout doesn't contain any descriptor.
+    ///
+    pub fn from_layout(
+        layout: Arc<UnsafeDescriptorSetLayout>,
+    ) -> Result<Arc<DescriptorUpdateTemplate>, DescriptorUpdateTemplateCreationError> {
+        let (entries, packed_bindings) = packed_entries(&*layout);
+        DescriptorUpdateTemplate::new_impl(layout, entries, Some(packed_bindings))
+    }
+
+    fn new_impl(
+        layout: Arc<UnsafeDescriptorSetLayout>,
+        entries: SmallVec<[DescriptorUpdateTemplateEntry; 8]>,
+        packed_bindings: Option<SmallVec<[Option<usize>; 32]>>,
+    ) -> Result<Arc<DescriptorUpdateTemplate>, DescriptorUpdateTemplateCreationError> {
+        let device = layout.device().clone();
+
+        if !device.loaded_extensions().khr_descriptor_update_template {
+            return Err(DescriptorUpdateTemplateCreationError::MissingExtension(
+                "khr_descriptor_update_template",
+            ));
+        }
+
+        assert!(!entries.is_empty());
+
+        for entry in entries.iter() {
+            assert_ne!(entry.descriptor_count, 0);
+            let desc = layout
+                .descriptor(entry.binding as usize)
+                .expect("the binding of a template entry is empty in the layout");
+            assert_eq!(desc.ty.ty(), entry.ty);
+            assert!(entry.first_array_element + entry.descriptor_count <= desc.array_count);
+        }
+
+        let raw_entries = entries
+            .iter()
+            .map(|entry| vk::DescriptorUpdateTemplateEntryKHR {
+                dstBinding: entry.binding,
+                dstArrayElement: entry.first_array_element,
+                descriptorCount: entry.descriptor_count,
+                descriptorType: entry.ty as u32,
+                offset: entry.offset,
+                stride: entry.stride,
+            })
+            .collect::<SmallVec<[_; 8]>>();
+
+        let template = unsafe {
+            let infos = vk::DescriptorUpdateTemplateCreateInfoKHR {
+                sType: vk::STRUCTURE_TYPE_DESCRIPTOR_UPDATE_TEMPLATE_CREATE_INFO_KHR,
+                pNext: ptr::null(),
+                flags: 0, // reserved
+                descriptorUpdateEntryCount: raw_entries.len() as u32,
+                pDescriptorUpdateEntries: raw_entries.as_ptr(),
+                templateType: vk::DESCRIPTOR_UPDATE_TEMPLATE_TYPE_DESCRIPTOR_SET_KHR,
+                descriptorSetLayout: layout.internal_object(),
+                // Only used for push descriptors templates.
+                pipelineBindPoint: 0,
+                pipelineLayout: 0,
+                set: 0,
+            };
+
+            let mut output = MaybeUninit::uninit();
+            let vk = device.pointers();
+            check_errors(vk.CreateDescriptorUpdateTemplateKHR(
+                device.internal_object(),
+                &infos,
+                ptr::null(),
+                output.as_mut_ptr(),
+            ))?;
+            output.assume_init()
+        };
+
+        Ok(Arc::new(DescriptorUpdateTemplate {
+            template,
+            device,
+            layout,
+            entries,
+            packed_bindings,
+        }))
+    }
+
+    /// Returns the layout of the descriptor sets that this template updates.
+    #[inline]
+    pub fn layout(&self) -> &Arc<UnsafeDescriptorSetLayout> {
+        &self.layout
+    }
+
+    /// Returns the entries of the template.
+    #[inline]
+    pub fn entries(&self) -> &[DescriptorUpdateTemplateEntry] {
+        &self.entries
+    }
+
+    /// Returns the index of the first slot of `binding` in the data, if the template was created
+    /// with `from_layout`.
+    #[inline]
+    pub(crate) fn packed_binding(&self, binding: u32) -> Option<usize> {
+        let packed_bindings = self
+            .packed_bindings
+            .as_ref()
+            .expect("the template wasn't created with `DescriptorUpdateTemplate::from_layout`");
+        packed_bindings
+            .get(binding as usize)
+            .cloned()
+            .unwrap_or(None)
+    }
+
+    /// Returns the number of slots in the data, if the template was created with `from_layout`.
+    #[inline]
+    pub(crate) fn packed_len(&self) -> usize {
+        self.entries
+            .iter()
+            .map(|entry| entry.descriptor_count as usize)
+            .sum()
+    }
+}
+
+// Builds one entry for each non-empty binding, with all the descriptors following each other.
+fn packed_entries<L>(
+    layout: &L,
+) -> (
+    SmallVec<[DescriptorUpdateTemplateEntry; 8]>,
+    SmallVec<[Option<usize>; 32]>,
+)
+where
+    L: ?Sized + DescriptorSetDesc,
+{
+    let stride = mem::size_of::<RawDescriptor>();
+    let mut entries = SmallVec::new();
+    let mut packed_bindings = SmallVec::new();
+    let mut next_slot = 0;
+
+    for binding in 0..layout.num_bindings() {
+        let desc = match layout.descriptor(binding) {
+            Some(desc) if desc.array_count != 0 => desc,
+            _ => {
+                packed_bindings.push(None);
+                continue;
+            }
+        };
+
+        entries.push(DescriptorUpdateTemplateEntry {
+            binding: binding as u32,
+            first_array_element: 0,
+            descriptor_count: desc.array_count,
+            ty: desc.ty.ty(),
+            offset: next_slot * stride,
+            stride,
+        });
+        packed_bindings.push(Some(next_slot));
+        next_slot += desc.array_count as usize;
+    }
+
+    (entries, packed_bindings)
+}
+
+unsafe impl DeviceOwned for DescriptorUpdateTemplate {
+    #[inline]
+    fn device(&self) -> &Arc<Device> {
+        &self.device
+    }
+}
+
+impl fmt::Debug for DescriptorUpdateTemplate {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        fmt.debug_struct("DescriptorUpdateTemplate")
+            .field("raw", &self.template)
+            .field("device", &self.device)
+            .field("layout", &self.layout)
+            .field("entries", &self.entries)
+            .finish()
+    }
+}
+
+unsafe impl VulkanObject for DescriptorUpdateTemplate {
+    type Object = vk::DescriptorUpdateTemplateKHR;
+
+    const TYPE: vk::ObjectType = vk::OBJECT_TYPE_DESCRIPTOR_UPDATE_TEMPLATE_KHR;
+
+    #[inline]
+    fn internal_object(&self) -> vk::DescriptorUpdateTemplateKHR {
+        self.template
+    }
+}
+
+impl Drop for DescriptorUpdateTemplate {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            let vk = self.device.pointers();
+            vk.DestroyDescriptorUpdateTemplateKHR(
+                self.device.internal_object(),
+                self.template,
+                ptr::null(),
+            );
+        }
+    }
+}
+
+/// Describes where the descriptors of a binding are located in the data passed to
+/// `UnsafeDescriptorSet::update_with_template`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DescriptorUpdateTemplateEntry {
+    /// The binding to update.
+    pub binding: u32,
+    /// The first array element of the binding to update.
+    pub first_array_element: u32,
+    /// The number of array elements to update.
+    pub descriptor_count: u32,
+    /// The type of the descriptors. Must match the layout.
+    pub ty: DescriptorType,
+    /// Offset in bytes of the first descriptor in the data.
+    ///
+    /// The descriptor must be a `DescriptorImageInfo` for samplers, images and input attachments,
+    /// a `DescriptorBufferInfo` for buffers, and a `DescriptorBufferViewInfo` for texel buffers.
+    pub offset: usize,
+    /// Number of bytes between two consecutive descriptors in the data.
+    pub stride: usize,
+}
+
+/// Content of a sampler, image or input attachment descriptor, as read by a
+/// `DescriptorUpdateTemplate`.
+///
+/// Has the same layout as a `VkDescriptorImageInfo`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(C)]
+pub struct DescriptorImageInfo {
+    pub(crate) sampler: vk::Sampler,
+    pub(crate) image_view: vk::ImageView,
+    pub(crate) image_layout: vk::ImageLayout,
+}
+
+impl DescriptorImageInfo {
+    /// Describes a sampler descriptor.
+    #[inline]
+    pub fn sampler(sampler: &Arc<Sampler>) -> DescriptorImageInfo {
+        DescriptorImageInfo {
+            sampler: sampler.internal_object(),
+            image_view: 0,
+            image_layout: 0,
+        }
+    }
+
+    /// Describes a sampled image descriptor.
+    #[inline]
+    pub fn sampled_image<I>(image_view: &I) -> DescriptorImageInfo
+    where
+        I: ImageViewAbstract,
+    {
+        DescriptorImageInfo {
+            sampler: 0,
+            image_view: image_view.inner().internal_object(),
+            image_layout: descriptor_layouts(image_view).sampled_image as u32,
+        }
+    }
+
+    /// Describes a storage image descriptor.
+    #[inline]
+    pub fn storage_image<I>(image_view: &I) -> DescriptorImageInfo
+    where
+        I: ImageViewAbstract,
+    {
+        DescriptorImageInfo {
+            sampler: 0,
+            image_view: image_view.inner().internal_object(),
+            image_layout: descriptor_layouts(image_view).storage_image as u32,
+        }
+    }
+
+    /// Describes a combined image sampler descriptor.
+    #[inline]
+    pub fn combined_image_sampler<I>(sampler: &Arc<Sampler>, image_view: &I) -> DescriptorImageInfo
+    where
+        I: ImageViewAbstract,
+    {
+        DescriptorImageInfo {
+            sampler: sampler.internal_object(),
+            image_view: image_view.inner().internal_object(),
+            image_layout: descriptor_layouts(image_view).combined_image_sampler as u32,
+        }
+    }
+
+    /// Describes an input attachment descriptor.
+    #[inline]
+    pub fn input_attachment<I>(image_view: &I) -> DescriptorImageInfo
+    where
+        I: ImageViewAbstract,
+    {
+        DescriptorImageInfo {
+            sampler: 0,
+            image_view: image_view.inner().internal_object(),
+            image_layout: descriptor_layouts(image_view).input_attachment as u32,
+        }
+    }
+}
+
+#[inline]
+fn descriptor_layouts<I>(image_view: &I) -> ImageDescriptorLayouts
+where
+    I: ImageViewAbstract,
+{
+    image_view
+        .image()
+        .descriptor_layouts()
+        .expect("descriptor_layouts must return Some when used in an image view")
+}
+
+/// Content of a uniform or storage buffer descriptor, dynamic or not, as read by a
+/// `DescriptorUpdateTemplate`.
+///
+/// Has the same layout as a `VkDescriptorBufferInfo`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(C)]
+pub struct DescriptorBufferInfo {
+    pub(crate) buffer: vk::Buffer,
+    pub(crate) offset: vk::DeviceSize,
+    pub(crate) range: vk::DeviceSize,
+}
+
+impl DescriptorBufferInfo {
+    /// Describes a descriptor that covers the whole `buffer`.
+    #[inline]
+    pub fn new<B>(buffer: &B) -> DescriptorBufferInfo
+    where
+        B: BufferAccess,
+    {
+        let size = buffer.size();
+        let BufferInner { buffer, offset } = buffer.inner();
+
+        DescriptorBufferInfo {
+            buffer: buffer.internal_object(),
+            offset: offset as u64,
+            range: size as u64,
+        }
+    }
+}
+
+/// Content of a uniform or storage texel buffer descriptor, as read by a
+/// `DescriptorUpdateTemplate`.
+///
+/// Has the same layout as a `VkBufferView`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(C)]
+pub struct DescriptorBufferViewInfo {
+    pub(crate) view: vk::BufferView,
+}
+
+impl DescriptorBufferViewInfo {
+    /// Describes a descriptor of the given buffer view.
+    #[inline]
+    pub fn new<B>(view: &BufferView<B>) -> DescriptorBufferViewInfo
+    where
+        B: BufferAccess,
+    {
+        DescriptorBufferViewInfo {
+            view: view.internal_object(),
+        }
+    }
+}
+
+// A slot that can contain any kind of descriptor, used in the data of the templates built with
+// `DescriptorUpdateTemplate::from_layout`.
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub(crate) union RawDescriptor {
+    pub(crate) image: DescriptorImageInfo,
+    pub(crate) buffer: DescriptorBufferInfo,
+    pub(crate) buffer_view: DescriptorBufferViewInfo,
+}
+
+/// Error that can happen when creating a `DescriptorUpdateTemplate`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DescriptorUpdateTemplateCreationError {
+    /// Not enough memory.
+    OomError(OomError),
+    /// An extension required by the template is not enabled on the device.
+    MissingExtension(&'static str),
+}
+
+impl error::Error for DescriptorUpdateTemplateCreationError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            DescriptorUpdateTemplateCreationError::OomError(ref err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for DescriptorUpdateTemplateCreationError {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            DescriptorUpdateTemplateCreationError::OomError(_) => write!(fmt, "not enough memory"),
+            DescriptorUpdateTemplateCreationError::MissingExtension(ext) => {
+                write!(fmt, "the `{}` extension is not enabled", ext)
+            }
+        }
+    }
+}
+
+impl From<OomError> for DescriptorUpdateTemplateCreationError {
+    #[inline]
+    fn from(err: OomError) -> DescriptorUpdateTemplateCreationError {
+        DescriptorUpdateTemplateCreationError::OomError(err)
+    }
+}
+
+impl From<Error> for DescriptorUpdateTemplateCreationError {
+    #[inline]
+    fn from(err: Error) -> DescriptorUpdateTemplateCreationError {
+        match err {
+            err @ Error::OutOfHostMemory => {
+                DescriptorUpdateTemplateCreationError::OomError(OomError::from(err))
+            }
+            err @ Error::OutOfDeviceMemory => {
+                DescriptorUpdateTemplateCreationError::OomError(OomError::from(err))
+            }
+            _ => panic!("unexpected error: {:?}", err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::packed_entries;
+    use super::DescriptorBufferInfo;
+    use super::DescriptorBufferViewInfo;
+    use super::DescriptorImageInfo;
+    use super::RawDescriptor;
+    use crate::descriptor::descriptor::DescriptorBufferDesc;
+    use crate::descriptor::descriptor::DescriptorDesc;
+    use crate::descriptor::descriptor::DescriptorDescTy;
+    use crate::descriptor::descriptor::DescriptorType;
+    use crate::descriptor::descriptor::ShaderStages;
+    use crate::descriptor::descriptor_set::DescriptorSetDesc;
+    use crate::vk;
+    use std::mem;
+
+    struct Layout(Vec<Option<DescriptorDesc>>);
+
+    unsafe impl DescriptorSetDesc for Layout {
+        fn num_bindings(&self) -> usize {
+            self.0.len()
+        }
+
+        fn descriptor(&self, binding: usize) -> Option<DescriptorDesc> {
+            self.0.get(binding).cloned().unwrap_or(None)
+        }
+    }
+
+    fn buffer_desc(array_count: u32) -> Option<DescriptorDesc> {
+        Some(DescriptorDesc {
+            ty: DescriptorDescTy::Buffer(DescriptorBufferDesc {
+                dynamic: Some(false),
+                storage: false,
+            }),
+            array_count,
+            stages: ShaderStages::all_graphics(),
+            readonly: true,
+        })
+    }
+
+    #[test]
+    fn raw_layouts() {
+        assert_eq!(
+            mem::size_of::<DescriptorImageInfo>(),
+            mem::size_of::<vk::DescriptorImageInfo>()
+        );
+        assert_eq!(
+            mem::size_of::<DescriptorBufferInfo>(),
+            mem::size_of::<vk::DescriptorBufferInfo>()
+        );
+        assert_eq!(
+            mem::size_of::<DescriptorBufferViewInfo>(),
+            mem::size_of::<vk::BufferView>()
+        );
+        assert_eq!(mem::size_of::<RawDescriptor>(), 24);
+    }
+
+    #[test]
+    fn packs_bindings() {
+        let layout = Layout(vec![buffer_desc(1), None, buffer_desc(0), buffer_desc(3)]);
+        let (entries, packed_bindings) = packed_entries(&layout);
+        let stride = mem::size_of::<RawDescriptor>();
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].binding, 0);
+        assert_eq!(entries[0].descriptor_count, 1);
+        assert_eq!(entries[0].ty, DescriptorType::UniformBuffer);
+        assert_eq!(entries[0].offset, 0);
+        assert_eq!(entries[1].binding, 3);
+        assert_eq!(entries[1].descriptor_count, 3);
+        assert_eq!(entries[1].offset, stride);
+        assert_eq!(entries[1].stride, stride);
+        assert_eq!(&packed_bindings[..], &[Some(0), None, None, Some(1)]);
+    }
+}
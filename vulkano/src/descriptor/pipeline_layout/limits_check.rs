@@ -462,6 +462,8 @@ struct Counter {
     tess_ctl: u32,
     tess_eval: u32,
     frag: u32,
+    task: u32,
+    mesh: u32,
 }
 
 impl Counter {
@@ -485,6 +487,12 @@ impl Counter {
         if stages.fragment {
             self.frag += num;
         }
+        if stages.task {
+            self.task += num;
+        }
+        if stages.mesh {
+            self.mesh += num;
+        }
     }
 
     fn max_per_stage(&self) -> u32 {
@@ -507,6 +515,12 @@ impl Counter {
         if self.frag > max {
             max = self.frag;
         }
+        if self.task > max {
+            max = self.task;
+        }
+        if self.mesh > max {
+            max = self.mesh;
+        }
         max
     }
 }
@@ -15,13 +15,25 @@ use std::ptr;
 use std::str;
 
 use crate::check_errors;
+use crate::extensions::ExtensionRequirement;
+use crate::extensions::ExtensionRestrictionError;
 use crate::extensions::SupportedExtensionsError;
+use crate::instance::InstanceExtensions;
 use crate::instance::PhysicalDevice;
+use crate::version::Version;
 use crate::vk;
 use crate::VulkanObject;
 
 macro_rules! device_extensions {
-    ($sname:ident, $rawname:ident, [$($ext_req_if_supported:ident,)*], $($ext:ident => $s:expr,)*) => (
+    (
+        $sname:ident, $rawname:ident, [$($ext_req_if_supported:ident,)*],
+        $($ext:ident => $s:literal {
+            core: $core:expr,
+            $(api_version: $api_version:expr,)?
+            requires: [$($dep:ident),*],
+            requires_instance: [$($instance_dep:ident),*],
+        },)*
+    ) => (
         extensions! {
             $sname, $rawname,
             $( $ext => $s,)*
@@ -117,35 +129,111 @@ macro_rules! device_extensions {
                     ..Self::none()
                 }
             }
+
+            /// Returns the extensions whose functionality is part of the core API of a device
+            /// of version `api_version`.
+            ///
+            /// These extensions don't need to be enabled explicitly on such a device, and are
+            /// included in `Device::loaded_extensions()`.
+            pub fn promoted_to_core(api_version: Version) -> Self {
+                let mut extensions = Self::none();
+                $(
+                    let core: Option<Version> = $core;
+                    if core.map_or(false, |core| api_version >= core) {
+                        extensions.$ext = true;
+                    }
+                )*
+                extensions
+            }
+
+            /// Returns this list with the device extensions that its extensions depend on added,
+            /// recursively.
+            ///
+            /// Dependencies that are part of the core API of a device of version `api_version`
+            /// are not added. The instance extensions that the extensions depend on can't be
+            /// added this way, and must be enabled when creating the instance.
+            pub fn ensure_dependencies(&self, api_version: Version) -> Self {
+                let core = Self::promoted_to_core(api_version);
+                let mut extensions = *self;
+
+                loop {
+                    let mut changed = false;
+                    $(
+                        if extensions.$ext {
+                            $(
+                                if !extensions.$dep && !core.$dep {
+                                    extensions.$dep = true;
+                                    changed = true;
+                                }
+                            )*
+                        }
+                    )*
+
+                    if !changed {
+                        return extensions;
+                    }
+                }
+            }
+
+            /// Checks that everything the extensions of this list depend on is available on a
+            /// device of version `api_version`, created from an instance with
+            /// `instance_extensions` enabled.
+            ///
+            /// A dependency is available if it is enabled as well, or if it is part of the core
+            /// API of that version.
+            pub fn check_requirements(
+                &self,
+                api_version: Version,
+                instance_extensions: &InstanceExtensions,
+            ) -> Result<(), ExtensionRestrictionError> {
+                let available = self.union(&Self::promoted_to_core(api_version));
+
+                $(
+                    if self.$ext {
+                        let extension = str::from_utf8(&$s[..]).unwrap();
+
+                        $(
+                            let required: Version = $api_version;
+                            if api_version < required {
+                                return Err(ExtensionRestrictionError {
+                                    extension,
+                                    requires: ExtensionRequirement::ApiVersion(required),
+                                });
+                            }
+                        )?
+
+                        $(
+                            if !available.$dep {
+                                return Err(ExtensionRestrictionError {
+                                    extension,
+                                    requires: ExtensionRequirement::DeviceExtension(
+                                        Self::name_of_field(stringify!($dep)),
+                                    ),
+                                });
+                            }
+                        )*
+
+                        $(
+                            if !instance_extensions.$instance_dep {
+                                return Err(ExtensionRestrictionError {
+                                    extension,
+                                    requires: ExtensionRequirement::InstanceExtension(
+                                        InstanceExtensions::name_of_field(stringify!($instance_dep)),
+                                    ),
+                                });
+                            }
+                        )*
+                    }
+                )*
+
+                Ok(())
+            }
         }
     );
 }
 
-device_extensions! {
-    DeviceExtensions,
-    RawDeviceExtensions,
-    [ // required if supported extensions
-        // https://vulkan.lunarg.com/doc/view/1.2.162.1/mac/1.2-extensions/vkspec.html#VUID-VkDeviceCreateInfo-pProperties-04451
-        khr_portability_subset,
-    ],
-    khr_swapchain => b"VK_KHR_swapchain",
-    khr_display_swapchain => b"VK_KHR_display_swapchain",
-    khr_sampler_mirror_clamp_to_edge => b"VK_KHR_sampler_mirror_clamp_to_edge",
-    khr_maintenance1 => b"VK_KHR_maintenance1",
-    khr_get_memory_requirements2 => b"VK_KHR_get_memory_requirements2",
-    khr_dedicated_allocation => b"VK_KHR_dedicated_allocation",
-    khr_incremental_present => b"VK_KHR_incremental_present",
-    khr_16bit_storage => b"VK_KHR_16bit_storage",
-    khr_8bit_storage => b"VK_KHR_8bit_storage",
-    khr_storage_buffer_storage_class => b"VK_KHR_storage_buffer_storage_class",
-    ext_debug_utils => b"VK_EXT_debug_utils",
-    khr_multiview => b"VK_KHR_multiview",
-    ext_full_screen_exclusive => b"VK_EXT_full_screen_exclusive",
-    khr_external_memory => b"VK_KHR_external_memory",
-    khr_external_memory_fd => b"VK_KHR_external_memory_fd",
-    ext_external_memory_dmabuf => b"VK_EXT_external_memory_dma_buf",
-    khr_portability_subset => b"VK_KHR_portability_subset",
-}
+// The table is generated from the Vulkan registry by `autogen`.
+include!("../autogen/device_extensions.rs");
 
 /// This helper type can only be instantiated inside this module.
 /// See `*Extensions::_unbuildable`.
@@ -156,6 +244,8 @@ pub struct Unbuildable(());
 #[cfg(test)]
 mod tests {
     use crate::device::{DeviceExtensions, RawDeviceExtensions};
+    use crate::extensions::{ExtensionRequirement, ExtensionRestrictionError};
+    use crate::instance::{InstanceExtensions, Version};
 
     #[test]
     fn empty_extensions() {
@@ -173,4 +263,97 @@ mod tests {
             }
         )
     }
+
+    #[test]
+    fn promoted_to_core() {
+        let v1_0 = DeviceExtensions::promoted_to_core(Version::V1_0);
+        assert_eq!(v1_0, DeviceExtensions::none());
+
+        let v1_1 = DeviceExtensions::promoted_to_core(Version::V1_1);
+        assert!(v1_1.khr_external_semaphore);
+        assert!(v1_1.khr_dedicated_allocation);
+        assert!(!v1_1.khr_external_semaphore_fd);
+        assert!(!v1_1.khr_8bit_storage);
+
+        let v1_2 = DeviceExtensions::promoted_to_core(Version::V1_2);
+        assert!(v1_2.khr_8bit_storage);
+        assert!(!v1_2.khr_sampler_mirror_clamp_to_edge);
+    }
+
+    #[test]
+    fn ensure_dependencies() {
+        let extensions = DeviceExtensions {
+            ext_external_memory_dmabuf: true,
+            ..DeviceExtensions::none()
+        };
+
+        assert_eq!(
+            extensions.ensure_dependencies(Version::V1_0),
+            DeviceExtensions {
+                ext_external_memory_dmabuf: true,
+                khr_external_memory_fd: true,
+                khr_external_memory: true,
+                ..DeviceExtensions::none()
+            }
+        );
+        assert_eq!(
+            extensions.ensure_dependencies(Version::V1_1),
+            DeviceExtensions {
+                ext_external_memory_dmabuf: true,
+                khr_external_memory_fd: true,
+                ..DeviceExtensions::none()
+            }
+        );
+    }
+
+    #[test]
+    fn check_requirements() {
+        let instance_extensions = InstanceExtensions {
+            khr_external_memory_capabilities: true,
+            ..InstanceExtensions::none()
+        };
+        let extensions = DeviceExtensions {
+            khr_external_memory_fd: true,
+            ..DeviceExtensions::none()
+        };
+
+        assert_eq!(
+            extensions.check_requirements(Version::V1_0, &instance_extensions),
+            Err(ExtensionRestrictionError {
+                extension: "VK_KHR_external_memory_fd",
+                requires: ExtensionRequirement::DeviceExtension("VK_KHR_external_memory"),
+            })
+        );
+        assert_eq!(
+            extensions.check_requirements(Version::V1_1, &instance_extensions),
+            Ok(())
+        );
+
+        let extensions = extensions.ensure_dependencies(Version::V1_0);
+        assert_eq!(
+            extensions.check_requirements(Version::V1_0, &instance_extensions),
+            Ok(())
+        );
+        assert_eq!(
+            extensions.check_requirements(Version::V1_0, &InstanceExtensions::none()),
+            Err(ExtensionRestrictionError {
+                extension: "VK_KHR_external_memory",
+                requires: ExtensionRequirement::InstanceExtension(
+                    "VK_KHR_external_memory_capabilities"
+                ),
+            })
+        );
+
+        let extensions = DeviceExtensions {
+            ext_subgroup_size_control: true,
+            ..DeviceExtensions::none()
+        };
+        assert_eq!(
+            extensions.check_requirements(Version::V1_0, &InstanceExtensions::none()),
+            Err(ExtensionRestrictionError {
+                extension: "VK_EXT_subgroup_size_control",
+                requires: ExtensionRequirement::ApiVersion(Version::V1_1),
+            })
+        );
+    }
 }
@@ -91,6 +91,7 @@
 
 use fnv::FnvHasher;
 use smallvec::SmallVec;
+use std::cmp;
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::error;
@@ -99,6 +100,7 @@ use std::fmt;
 use std::hash::BuildHasherDefault;
 use std::hash::Hash;
 use std::hash::Hasher;
+use std::iter;
 use std::mem::MaybeUninit;
 use std::ops::Deref;
 use std::ptr;
@@ -110,9 +112,13 @@ use std::sync::Weak;
 use crate::command_buffer::pool::StandardCommandPool;
 use crate::descriptor::descriptor_set::StdDescriptorPool;
 use crate::instance::Instance;
+use crate::instance::MemoryHeap;
 use crate::instance::PhysicalDevice;
+use crate::instance::PhysicalDeviceGroup;
 use crate::instance::QueueFamily;
+use crate::instance::Version;
 use crate::memory::pool::StdMemoryPool;
+use crate::memory::PeerMemoryFeatures;
 
 use crate::check_errors;
 use crate::vk;
@@ -124,8 +130,16 @@ use crate::VulkanObject;
 
 pub use self::extensions::DeviceExtensions;
 pub use self::extensions::RawDeviceExtensions;
+pub use self::selector::DeviceSelectionError;
+pub use self::selector::DeviceSelector;
+pub use self::selector::RejectedDevice;
+pub use self::selector::RejectionReason;
+pub use self::selector::SelectedDevice;
+pub use crate::extensions::ExtensionRequirement;
+pub use crate::extensions::ExtensionRestrictionError;
 pub use crate::features::Features;
 mod extensions;
+mod selector;
 
 use crate::format::Format;
 use crate::image::ImageCreateFlags;
@@ -138,8 +152,11 @@ use crate::image::ImageUsage;
 pub struct Device {
     instance: Arc<Instance>,
     physical_device: usize,
+    // Indices of all the physical devices of the device group, starting with `physical_device`.
+    physical_devices: SmallVec<[usize; 4]>,
     device: vk::Device,
     vk: vk::DevicePointers,
+    api_version: Version,
     standard_pool: Mutex<Weak<StdMemoryPool>>,
     standard_descriptor_pool: Mutex<Weak<StdDescriptorPool>>,
     standard_command_pools:
@@ -147,6 +164,7 @@ pub struct Device {
     features: Features,
     extensions: DeviceExtensions,
     active_queue_families: SmallVec<[u32; 8]>,
+    queue_global_priorities: SmallVec<[(u32, QueueGlobalPriority); 8]>,
     allocation_count: Mutex<u32>,
     fence_pool: Mutex<Vec<vk::Fence>>,
     semaphore_pool: Mutex<Vec<vk::Semaphore>>,
@@ -173,12 +191,18 @@ impl Device {
     ///   queue with a lower value. Note however that no guarantee can be made on the way the
     ///   priority value is handled by the implementation.
     ///
+    /// The extensions that the requested extensions depend on must be requested as well, unless
+    /// they are part of the core API of the device's version. See
+    /// `DeviceExtensions::ensure_dependencies`. Requesting an extension that is part of the core
+    /// API is never required, but is allowed even if the device doesn't advertise it.
+    ///
     /// # Panic
     ///
     /// - Panics if one of the queue families doesn't belong to the given device.
     ///
     // TODO: return Arc<Queue> and handle synchronization in the Queue
     // TODO: should take the PhysicalDevice by value
+    #[inline]
     pub fn new<'a, I, Ext>(
         phys: PhysicalDevice,
         requested_features: &Features,
@@ -188,17 +212,128 @@ impl Device {
     where
         I: IntoIterator<Item = (QueueFamily<'a>, f32)>,
         Ext: Into<RawDeviceExtensions>,
+    {
+        Device::with_global_priorities(
+            phys,
+            requested_features,
+            extensions,
+            queue_families,
+            iter::empty(),
+        )
+    }
+
+    /// Same as `new`, but also requests a global priority for the queues of some queue families.
+    ///
+    /// The global priority of a queue is relative to the queues of all the other devices and
+    /// processes of the system, while the priorities passed alongside the queue families are
+    /// only relative to the other queues of the device. The `ext_global_priority` extension must
+    /// be enabled.
+    ///
+    /// The system may refuse to grant high global priorities, for example if the process doesn't
+    /// have the right privileges. If so, the highest requested priorities are lowered one level
+    /// at a time until the device can be created. Use `Queue::global_priority` to know which
+    /// priority was actually granted.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if one of the queue families doesn't belong to the given device.
+    /// - Panics if `global_priorities` is not empty and the `ext_global_priority` extension is
+    ///   not enabled.
+    /// - Panics if a queue family appears twice in `global_priorities`, or if no queue of one of
+    ///   its queue families is requested.
+    ///
+    #[inline]
+    pub fn with_global_priorities<'a, 'b, I, G, Ext>(
+        phys: PhysicalDevice,
+        requested_features: &Features,
+        extensions: Ext,
+        queue_families: I,
+        global_priorities: G,
+    ) -> Result<(Arc<Device>, QueuesIter), DeviceCreationError>
+    where
+        I: IntoIterator<Item = (QueueFamily<'a>, f32)>,
+        G: IntoIterator<Item = (QueueFamily<'b>, QueueGlobalPriority)>,
+        Ext: Into<RawDeviceExtensions>,
+    {
+        Device::new_inner(
+            phys,
+            None,
+            requested_features,
+            extensions,
+            queue_families,
+            global_priorities,
+        )
+    }
+
+    /// Builds a new Vulkan device that spans all the physical devices of a group.
+    ///
+    /// All the physical devices of a group support the same features, extensions and queue
+    /// families. The queue families must be obtained from the first physical device of the
+    /// group. Each queue can then execute work on any of the physical devices of the group,
+    /// as selected with device masks when recording and submitting command buffers.
+    ///
+    /// If the group only contains one physical device, this is the same as `new`.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if one of the queue families doesn't belong to the first physical device of the
+    ///   group.
+    ///
+    #[inline]
+    pub fn with_device_group<'a, I, Ext>(
+        group: PhysicalDeviceGroup,
+        requested_features: &Features,
+        extensions: Ext,
+        queue_families: I,
+    ) -> Result<(Arc<Device>, QueuesIter), DeviceCreationError>
+    where
+        I: IntoIterator<Item = (QueueFamily<'a>, f32)>,
+        Ext: Into<RawDeviceExtensions>,
+    {
+        let phys = group.physical_devices().next().unwrap();
+        Device::new_inner(
+            phys,
+            Some(group),
+            requested_features,
+            extensions,
+            queue_families,
+            iter::empty(),
+        )
+    }
+
+    fn new_inner<'a, 'b, I, G, Ext>(
+        phys: PhysicalDevice,
+        group: Option<PhysicalDeviceGroup>,
+        requested_features: &Features,
+        extensions: Ext,
+        queue_families: I,
+        global_priorities: G,
+    ) -> Result<(Arc<Device>, QueuesIter), DeviceCreationError>
+    where
+        I: IntoIterator<Item = (QueueFamily<'a>, f32)>,
+        G: IntoIterator<Item = (QueueFamily<'b>, QueueGlobalPriority)>,
+        Ext: Into<RawDeviceExtensions>,
     {
         let queue_families = queue_families.into_iter();
 
+        let physical_devices = match group {
+            Some(group) => group.physical_devices().collect(),
+            None => Some(phys).into_iter().collect::<SmallVec<[_; 4]>>(),
+        };
+        let physical_device_handles = physical_devices
+            .iter()
+            .map(|p| p.internal_object())
+            .collect::<SmallVec<[_; 4]>>();
+
         if !phys.supported_features().superset_of(&requested_features) {
             return Err(DeviceCreationError::FeatureNotPresent);
         }
 
         let vk_i = phys.instance().pointers();
 
-        // this variable will contain the queue family ID and queue ID of each requested queue
-        let mut output_queues: SmallVec<[(u32, u32); 8]> = SmallVec::new();
+        // this variable will contain the queue family ID, queue ID and priority of each requested
+        // queue
+        let mut output_queues: SmallVec<[(u32, u32, f32); 8]> = SmallVec::new();
 
         // Device layers were deprecated in Vulkan 1.0.13, and device layer requests should be
         // ignored by the driver. For backwards compatibility, the spec recommends passing the
@@ -215,12 +350,53 @@ impl Device {
             .map(|layer| layer.as_ptr())
             .collect::<SmallVec<[_; 16]>>();
 
-        let extensions = extensions.into();
+        let api_version = cmp::min(phys.instance().api_version(), phys.api_version());
+        let promoted_extensions = DeviceExtensions::promoted_to_core(api_version);
+
+        let mut extensions: RawDeviceExtensions = extensions.into();
+        let known_extensions = DeviceExtensions::from(&extensions);
+        known_extensions
+            .check_requirements(api_version, &phys.instance().loaded_extensions())
+            .map_err(DeviceCreationError::ExtensionRestrictionNotMet)?;
+
+        // Extensions that are part of the core API don't have to be advertised by the device,
+        // in which case they must not be passed to the driver.
+        let requested_promoted = known_extensions.intersection(&promoted_extensions);
+        if requested_promoted != DeviceExtensions::none() {
+            let unsupported =
+                requested_promoted.difference(&DeviceExtensions::supported_by_device(phys));
+            extensions = extensions.difference(&(&unsupported).into());
+        }
+
         let extensions_list = extensions
             .iter()
             .map(|extension| extension.as_ptr())
             .collect::<SmallVec<[_; 16]>>();
 
+        let mut global_priorities = global_priorities
+            .into_iter()
+            .map(|(queue_family, priority)| {
+                assert_eq!(
+                    queue_family.physical_device().internal_object(),
+                    phys.internal_object()
+                );
+                (queue_family.id(), priority)
+            })
+            .collect::<SmallVec<[_; 8]>>();
+        if !global_priorities.is_empty() {
+            assert!(
+                known_extensions.ext_global_priority,
+                "the `ext_global_priority` extension must be enabled to request global priorities"
+            );
+        }
+        for (n, &(family, _)) in global_priorities.iter().enumerate() {
+            assert!(
+                global_priorities[..n].iter().all(|&(f, _)| f != family),
+                "queue family {} appears twice in the global priorities",
+                family
+            );
+        }
+
         let mut requested_features = requested_features.clone();
         // Always enabled; see below.
         requested_features.robust_buffer_access = true;
@@ -244,7 +420,7 @@ impl Device {
 
                 // adding to `queues` and `output_queues`
                 if let Some(q) = queues.iter_mut().find(|q| q.0 == queue_family.id()) {
-                    output_queues.push((queue_family.id(), q.1.len() as u32));
+                    output_queues.push((queue_family.id(), q.1.len() as u32, priority));
                     q.1.push(priority);
                     if q.1.len() > queue_family.queues_count() {
                         return Err(DeviceCreationError::TooManyQueuesForFamily);
@@ -252,23 +428,17 @@ impl Device {
                     continue;
                 }
                 queues.push((queue_family.id(), vec![priority]));
-                output_queues.push((queue_family.id(), 0));
+                output_queues.push((queue_family.id(), 0, priority));
             }
 
-            // turning `queues` into an array of `vkDeviceQueueCreateInfo` suitable for Vulkan
-            let queues = queues
-                .iter()
-                .map(|&(queue_id, ref priorities)| {
-                    vk::DeviceQueueCreateInfo {
-                        sType: vk::STRUCTURE_TYPE_DEVICE_QUEUE_CREATE_INFO,
-                        pNext: ptr::null(),
-                        flags: 0, // reserved
-                        queueFamilyIndex: queue_id,
-                        queueCount: priorities.len() as u32,
-                        pQueuePriorities: priorities.as_ptr(),
-                    }
-                })
-                .collect::<SmallVec<[_; 16]>>();
+            for &(family, _) in global_priorities.iter() {
+                assert!(
+                    queues.iter().any(|q| q.0 == family),
+                    "a global priority is requested for queue family {}, but none of its \
+                     queues is",
+                    family
+                );
+            }
 
             // TODO: The plan regarding `robustBufferAccess` is to check the shaders' code to see
             //       if they can possibly perform out-of-bounds reads and writes. If the user tries
@@ -289,36 +459,94 @@ impl Device {
 
             let features = requested_features.into_vulkan_features_v2();
 
-            let infos = vk::DeviceCreateInfo {
-                sType: vk::STRUCTURE_TYPE_DEVICE_CREATE_INFO,
-                pNext: features.base_ptr() as *const _,
-                flags: 0, // reserved
-                queueCreateInfoCount: queues.len() as u32,
-                pQueueCreateInfos: queues.as_ptr(),
-                enabledLayerCount: layers_ptr.len() as u32,
-                ppEnabledLayerNames: layers_ptr.as_ptr(),
-                enabledExtensionCount: extensions_list.len() as u32,
-                ppEnabledExtensionNames: extensions_list.as_ptr(),
-                pEnabledFeatures: ptr::null(),
-            };
-
-            let mut output = MaybeUninit::uninit();
-            check_errors(vk_i.CreateDevice(
-                phys.internal_object(),
-                &infos,
-                ptr::null(),
-                output.as_mut_ptr(),
-            ))?;
-            output.assume_init()
+            loop {
+                // the global priority of each entry of `queues`, if one was requested
+                let priority_infos = queues
+                    .iter()
+                    .map(|&(queue_id, _)| {
+                        global_priorities
+                            .iter()
+                            .find(|&&(family, _)| family == queue_id)
+                            .map(|&(_, priority)| vk::DeviceQueueGlobalPriorityCreateInfoEXT {
+                                sType: vk::STRUCTURE_TYPE_DEVICE_QUEUE_GLOBAL_PRIORITY_CREATE_INFO_EXT,
+                                pNext: ptr::null(),
+                                globalPriority: priority as u32,
+                            })
+                    })
+                    .collect::<SmallVec<[_; 16]>>();
+
+                // turning `queues` into an array of `vkDeviceQueueCreateInfo` suitable for Vulkan
+                let queue_infos = queues
+                    .iter()
+                    .zip(priority_infos.iter())
+                    .map(|(&(queue_id, ref priorities), priority_info)| {
+                        vk::DeviceQueueCreateInfo {
+                            sType: vk::STRUCTURE_TYPE_DEVICE_QUEUE_CREATE_INFO,
+                            pNext: priority_info
+                                .as_ref()
+                                .map_or(ptr::null(), |info| info as *const _ as *const _),
+                            flags: 0, // reserved
+                            queueFamilyIndex: queue_id,
+                            queueCount: priorities.len() as u32,
+                            pQueuePriorities: priorities.as_ptr(),
+                        }
+                    })
+                    .collect::<SmallVec<[_; 16]>>();
+
+                // only needed if the device spans more than one physical device
+                let group_info = vk::DeviceGroupDeviceCreateInfo {
+                    sType: vk::STRUCTURE_TYPE_DEVICE_GROUP_DEVICE_CREATE_INFO,
+                    pNext: features.base_ptr() as *const _,
+                    physicalDeviceCount: physical_device_handles.len() as u32,
+                    pPhysicalDevices: physical_device_handles.as_ptr(),
+                };
+
+                let infos = vk::DeviceCreateInfo {
+                    sType: vk::STRUCTURE_TYPE_DEVICE_CREATE_INFO,
+                    pNext: if physical_device_handles.len() > 1 {
+                        &group_info as *const _ as *const _
+                    } else {
+                        features.base_ptr() as *const _
+                    },
+                    flags: 0, // reserved
+                    queueCreateInfoCount: queue_infos.len() as u32,
+                    pQueueCreateInfos: queue_infos.as_ptr(),
+                    enabledLayerCount: layers_ptr.len() as u32,
+                    ppEnabledLayerNames: layers_ptr.as_ptr(),
+                    enabledExtensionCount: extensions_list.len() as u32,
+                    ppEnabledExtensionNames: extensions_list.as_ptr(),
+                    pEnabledFeatures: ptr::null(),
+                };
+
+                let mut output = MaybeUninit::uninit();
+                match check_errors(vk_i.CreateDevice(
+                    phys.internal_object(),
+                    &infos,
+                    ptr::null(),
+                    output.as_mut_ptr(),
+                )) {
+                    Err(Error::NotPermitted) if lower_global_priorities(&mut global_priorities) => {
+                        continue
+                    }
+                    Err(Error::NotPermitted) if !global_priorities.is_empty() => {
+                        return Err(DeviceCreationError::GlobalPriorityNotPermitted)
+                    }
+                    result => {
+                        result?;
+                    }
+                }
+                break output.assume_init();
+            }
         };
 
         // loading the function pointers of the newly-created device
-        let vk = vk::DevicePointers::load(|name| unsafe {
-            vk_i.GetDeviceProcAddr(device, name.as_ptr()) as *const _
-        });
+        let vk = vk::DevicePointers::load_with_version(
+            api_version.into_vulkan_version(),
+            |name| unsafe { vk_i.GetDeviceProcAddr(device, name.as_ptr()) as *const _ },
+        );
 
         let mut active_queue_families: SmallVec<[u32; 8]> = SmallVec::new();
-        for (queue_family, _) in output_queues.iter() {
+        for (queue_family, _, _) in output_queues.iter() {
             if let None = active_queue_families
                 .iter()
                 .find(|&&qf| qf == *queue_family)
@@ -330,8 +558,10 @@ impl Device {
         let device = Arc::new(Device {
             instance: phys.instance().clone(),
             physical_device: phys.index(),
+            physical_devices: physical_devices.iter().map(|p| p.index()).collect(),
             device: device,
             vk: vk,
+            api_version,
             standard_pool: Mutex::new(Weak::new()),
             standard_descriptor_pool: Mutex::new(Weak::new()),
             standard_command_pools: Mutex::new(Default::default()),
@@ -340,8 +570,9 @@ impl Device {
                 robust_buffer_access: true,
                 ..requested_features.clone()
             },
-            extensions: (&extensions).into(),
+            extensions: DeviceExtensions::from(&extensions).union(&promoted_extensions),
             active_queue_families,
+            queue_global_priorities: global_priorities,
             allocation_count: Mutex::new(0),
             fence_pool: Mutex::new(Vec::new()),
             semaphore_pool: Mutex::new(Vec::new()),
@@ -392,6 +623,68 @@ impl Device {
         PhysicalDevice::from_index(&self.instance, self.physical_device).unwrap()
     }
 
+    /// Returns the physical devices of the device group this device spans, starting with
+    /// `physical_device()`.
+    ///
+    /// Unless the device was created with `with_device_group`, this only contains
+    /// `physical_device()`. The position of a physical device in this list is its device index,
+    /// and bit `n` of a device mask designates the physical device with device index `n`.
+    #[inline]
+    pub fn physical_devices(&self) -> impl ExactSizeIterator<Item = PhysicalDevice<'_>> {
+        let instance = &self.instance;
+        self.physical_devices
+            .iter()
+            .map(move |&index| PhysicalDevice::from_index(instance, index).unwrap())
+    }
+
+    /// Returns the device mask that contains all the physical devices of the device.
+    #[inline]
+    pub fn device_mask(&self) -> u32 {
+        (1u64 << self.physical_devices.len()) as u32 - 1
+    }
+
+    /// Returns how the physical device with device index `local_device_index` can access memory
+    /// of `heap` that is physically located on the physical device with device index
+    /// `remote_device_index`.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if `heap` doesn't belong to the physical device of this device.
+    /// - Panics if one of the device indices is out of range, or if both are equal.
+    /// - Panics if the `khr_device_group` extension is not enabled and the device doesn't
+    ///   support Vulkan 1.1.
+    ///
+    pub fn peer_memory_features(
+        &self,
+        heap: MemoryHeap,
+        local_device_index: u32,
+        remote_device_index: u32,
+    ) -> PeerMemoryFeatures {
+        assert_eq!(
+            heap.physical_device().internal_object(),
+            self.physical_device().internal_object()
+        );
+        assert!((local_device_index as usize) < self.physical_devices.len());
+        assert!((remote_device_index as usize) < self.physical_devices.len());
+        assert_ne!(local_device_index, remote_device_index);
+        assert!(
+            self.extensions.khr_device_group,
+            "the `khr_device_group` extension must be enabled"
+        );
+
+        unsafe {
+            let mut output = 0;
+            self.vk.GetDeviceGroupPeerMemoryFeaturesKHR(
+                self.device,
+                heap.id(),
+                local_device_index,
+                remote_device_index,
+                &mut output,
+            );
+            PeerMemoryFeatures::from_vulkan_bits(output)
+        }
+    }
+
     /// Returns an iterator to the list of queues families that this device uses.
     ///
     /// > **Note**: Will return `-> impl ExactSizeIterator<Item = QueueFamily>` in the future.
@@ -414,7 +707,19 @@ impl Device {
         &self.features
     }
 
+    /// Returns the version of the Vulkan API that the device supports.
+    ///
+    /// This is the lowest of the version of the instance and of the version of the physical
+    /// device.
+    #[inline]
+    pub fn api_version(&self) -> Version {
+        self.api_version
+    }
+
     /// Returns the list of extensions that have been loaded.
+    ///
+    /// This list contains the extensions that were passed to `Device::new()`, plus the
+    /// extensions that are part of the core API of the device's version.
     #[inline]
     pub fn loaded_extensions(&self) -> &DeviceExtensions {
         &self.extensions
@@ -650,7 +955,7 @@ where
 pub struct QueuesIter {
     next_queue: usize,
     device: Arc<Device>,
-    families_and_ids: SmallVec<[(u32, u32); 8]>,
+    families_and_ids: SmallVec<[(u32, u32, f32); 8]>,
 }
 
 unsafe impl DeviceOwned for QueuesIter {
@@ -664,7 +969,7 @@ impl Iterator for QueuesIter {
 
     fn next(&mut self) -> Option<Arc<Queue>> {
         unsafe {
-            let &(family, id) = match self.families_and_ids.get(self.next_queue) {
+            let &(family, id, priority) = match self.families_and_ids.get(self.next_queue) {
                 Some(a) => a,
                 None => return None,
             };
@@ -681,6 +986,7 @@ impl Iterator for QueuesIter {
                 device: self.device.clone(),
                 family: family,
                 id: id,
+                priority,
             }))
         }
     }
@@ -708,6 +1014,8 @@ pub enum DeviceCreationError {
     FeatureNotPresent,
     /// Some of the requested device extensions are not supported by the physical device.
     ExtensionNotPresent,
+    /// One of the requested device extensions is enabled without something it depends on.
+    ExtensionRestrictionNotMet(ExtensionRestrictionError),
     /// Tried to create too many queues for a given family.
     TooManyQueuesForFamily,
     /// The priority of one of the queues is out of the [0.0; 1.0] range.
@@ -716,9 +1024,19 @@ pub enum DeviceCreationError {
     OutOfHostMemory,
     /// There is no memory available on the device (ie. video memory).
     OutOfDeviceMemory,
+    /// The system refused to grant a global queue priority, even after lowering it to medium.
+    GlobalPriorityNotPermitted,
 }
 
-impl error::Error for DeviceCreationError {}
+impl error::Error for DeviceCreationError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            DeviceCreationError::ExtensionRestrictionNotMet(ref err) => Some(err),
+            _ => None,
+        }
+    }
+}
 
 impl fmt::Display for DeviceCreationError {
     #[inline]
@@ -746,10 +1064,16 @@ impl fmt::Display for DeviceCreationError {
                 DeviceCreationError::ExtensionNotPresent => {
                     "some of the requested device extensions are not supported by the physical device"
                 }
+                DeviceCreationError::ExtensionRestrictionNotMet(_) => {
+                    "one of the requested device extensions is enabled without something it depends on"
+                }
                 DeviceCreationError::TooManyObjects => {
                     "you have reached the limit to the number of devices that can be created from the
                  same physical device"
                 }
+                DeviceCreationError::GlobalPriorityNotPermitted => {
+                    "the system refused to grant a global queue priority"
+                }
             }
         )
     }
@@ -771,6 +1095,41 @@ impl From<Error> for DeviceCreationError {
     }
 }
 
+/// Global priority of a queue, relative to the queues of the other devices and processes of the
+/// system.
+///
+/// See `Device::with_global_priorities`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum QueueGlobalPriority {
+    Low = vk::QUEUE_GLOBAL_PRIORITY_LOW_EXT,
+    /// The priority of queues for which no global priority is requested.
+    Medium = vk::QUEUE_GLOBAL_PRIORITY_MEDIUM_EXT,
+    High = vk::QUEUE_GLOBAL_PRIORITY_HIGH_EXT,
+    Realtime = vk::QUEUE_GLOBAL_PRIORITY_REALTIME_EXT,
+}
+
+// Lowers the highest of the requested global priorities by one level, unless they are all medium
+// or lower. Returns false if nothing was lowered.
+fn lower_global_priorities(priorities: &mut [(u32, QueueGlobalPriority)]) -> bool {
+    let highest = match priorities.iter().map(|&(_, priority)| priority).max() {
+        Some(highest) if highest > QueueGlobalPriority::Medium => highest,
+        _ => return false,
+    };
+    let lowered = match highest {
+        QueueGlobalPriority::Realtime => QueueGlobalPriority::High,
+        _ => QueueGlobalPriority::Medium,
+    };
+
+    for (_, priority) in priorities.iter_mut() {
+        if *priority == highest {
+            *priority = lowered;
+        }
+    }
+
+    true
+}
+
 /// Represents a queue where commands can be submitted.
 // TODO: should use internal synchronization?
 #[derive(Debug)]
@@ -779,6 +1138,7 @@ pub struct Queue {
     device: Arc<Device>,
     family: u32,
     id: u32, // id within family
+    priority: f32,
 }
 
 impl Queue {
@@ -811,6 +1171,26 @@ impl Queue {
         self.id
     }
 
+    /// Returns the priority of this queue relative to the other queues of the device, as passed
+    /// when creating the device.
+    #[inline]
+    pub fn priority(&self) -> f32 {
+        self.priority
+    }
+
+    /// Returns the global priority that was granted to this queue, if one was requested with
+    /// `Device::with_global_priorities`.
+    ///
+    /// This can be lower than the requested priority if the system refused to grant it.
+    #[inline]
+    pub fn global_priority(&self) -> Option<QueueGlobalPriority> {
+        self.device
+            .queue_global_priorities
+            .iter()
+            .find(|&&(family, _)| family == self.family)
+            .map(|&(_, priority)| priority)
+    }
+
     /// Waits until all work on this queue has finished.
     ///
     /// Just like `Device::wait()`, you shouldn't have to call this function in a typical program.
@@ -853,6 +1233,7 @@ mod tests {
     use crate::device::Device;
     use crate::device::DeviceCreationError;
     use crate::device::DeviceExtensions;
+    use crate::device::QueueGlobalPriority;
     use crate::features::Features;
     use crate::instance;
     use std::sync::Arc;
@@ -942,4 +1323,24 @@ mod tests {
             _ => panic!(),
         };
     }
+
+    #[test]
+    fn lower_global_priorities() {
+        let mut priorities = [
+            (0, QueueGlobalPriority::Realtime),
+            (1, QueueGlobalPriority::High),
+            (2, QueueGlobalPriority::Low),
+        ];
+
+        assert!(super::lower_global_priorities(&mut priorities));
+        assert_eq!(priorities[0].1, QueueGlobalPriority::High);
+        assert_eq!(priorities[1].1, QueueGlobalPriority::High);
+
+        assert!(super::lower_global_priorities(&mut priorities));
+        assert_eq!(priorities[0].1, QueueGlobalPriority::Medium);
+        assert_eq!(priorities[1].1, QueueGlobalPriority::Medium);
+        assert_eq!(priorities[2].1, QueueGlobalPriority::Low);
+
+        assert!(!super::lower_global_priorities(&mut priorities));
+    }
 }
@@ -0,0 +1,723 @@
+// Copyright (c) 2021 The vulkano developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>,
+// at your option. All files in the project carrying such
+// notice may not be copied, modified, or distributed except
+// according to those terms.
+
+//! Choosing a physical device and creating a device from it.
+//!
+//! Most applications go through the same steps when starting up: enumerate the physical devices,
+//! discard those that miss an extension, a feature or a kind of queue, pick the "best" one among
+//! the remaining devices and create a `Device` with the right queues. The `DeviceSelector` does
+//! all of this.
+//!
+//! ```no_run
+//! use vulkano::device::DeviceExtensions;
+//! use vulkano::device::DeviceSelector;
+//! use vulkano::instance::Instance;
+//! use vulkano::instance::InstanceExtensions;
+//!
+//! let instance = Instance::new(None, &InstanceExtensions::none(), None).unwrap();
+//!
+//! let selected = DeviceSelector::new()
+//!     .required_extensions(DeviceExtensions {
+//!         khr_storage_buffer_storage_class: true,
+//!         ..DeviceExtensions::none()
+//!     })
+//!     .graphics_queue()
+//!     .async_compute_queue()
+//!     .require_limit("max_push_constants_size >= 256", |limits| {
+//!         limits.max_push_constants_size() >= 256
+//!     })
+//!     .select(&instance)
+//!     .unwrap();
+//!
+//! println!("Using device: {}", selected.device.physical_device().name());
+//! let graphics_queue = selected.graphics_queue.unwrap();
+//! ```
+
+use std::cmp;
+use std::error;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::device::Device;
+use crate::device::DeviceCreationError;
+use crate::device::DeviceExtensions;
+use crate::device::Features;
+use crate::device::Queue;
+use crate::instance::Instance;
+use crate::instance::Limits;
+use crate::instance::PhysicalDevice;
+use crate::instance::PhysicalDeviceType;
+use crate::instance::QueueFamily;
+use crate::swapchain::CapabilitiesError;
+use crate::swapchain::Surface;
+
+// Checks whether the limits of a physical device meet a requirement.
+type LimitCheck<'s> = Box<dyn Fn(&Limits) -> bool + 's>;
+
+// Checks whether a queue family can present to the surface.
+type SurfaceSupportCheck<'s> = Box<dyn Fn(QueueFamily) -> Result<bool, CapabilitiesError> + 's>;
+
+/// Chooses the most suitable physical device of an instance and creates a `Device` from it.
+///
+/// A physical device is suitable if it supports all the required extensions, features, limits and
+/// queues. Among the suitable devices, the one whose type comes first in the preference order is
+/// chosen. Ties are broken by the number of optional extensions and features that are supported,
+/// then by the order of enumeration.
+pub struct DeviceSelector<'s> {
+    required_extensions: DeviceExtensions,
+    optional_extensions: DeviceExtensions,
+    required_features: Features,
+    optional_features: Features,
+    limits: Vec<(&'static str, LimitCheck<'s>)>,
+    device_types: Vec<PhysicalDeviceType>,
+    queues: QueueNeeds,
+    surface_support: Option<SurfaceSupportCheck<'s>>,
+}
+
+impl<'s> DeviceSelector<'s> {
+    /// Builds a `DeviceSelector` with no requirements.
+    ///
+    /// By default all device types are accepted, in the order discrete, integrated, virtual, CPU
+    /// and other.
+    #[inline]
+    pub fn new() -> DeviceSelector<'s> {
+        DeviceSelector {
+            required_extensions: DeviceExtensions::none(),
+            optional_extensions: DeviceExtensions::none(),
+            required_features: Features::none(),
+            optional_features: Features::none(),
+            limits: Vec::new(),
+            device_types: vec![
+                PhysicalDeviceType::DiscreteGpu,
+                PhysicalDeviceType::IntegratedGpu,
+                PhysicalDeviceType::VirtualGpu,
+                PhysicalDeviceType::Cpu,
+                PhysicalDeviceType::Other,
+            ],
+            queues: QueueNeeds::default(),
+            surface_support: None,
+        }
+    }
+
+    /// Adds extensions that the device must support. They are enabled on the created device,
+    /// along with the extensions they depend on.
+    #[inline]
+    pub fn required_extensions(mut self, extensions: DeviceExtensions) -> Self {
+        self.required_extensions = self.required_extensions.union(&extensions);
+        self
+    }
+
+    /// Adds extensions that are enabled on the created device if the device supports them.
+    #[inline]
+    pub fn optional_extensions(mut self, extensions: DeviceExtensions) -> Self {
+        self.optional_extensions = self.optional_extensions.union(&extensions);
+        self
+    }
+
+    /// Adds features that the device must support. They are enabled on the created device.
+    #[inline]
+    pub fn required_features(mut self, features: Features) -> Self {
+        self.required_features = self.required_features.union(&features);
+        self
+    }
+
+    /// Adds features that are enabled on the created device if the device supports them.
+    #[inline]
+    pub fn optional_features(mut self, features: Features) -> Self {
+        self.optional_features = self.optional_features.union(&features);
+        self
+    }
+
+    /// Adds a check on the limits of the device. Devices for which `check` returns false are
+    /// rejected, and `description` is used to report why.
+    #[inline]
+    pub fn require_limit<F>(mut self, description: &'static str, check: F) -> Self
+    where
+        F: Fn(&Limits) -> bool + 's,
+    {
+        self.limits.push((description, Box::new(check)));
+        self
+    }
+
+    /// Sets the device types that are accepted, from most preferred to least preferred. Devices
+    /// whose type is not in the list are rejected.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if `types` is empty.
+    #[inline]
+    pub fn device_types<I>(mut self, types: I) -> Self
+    where
+        I: IntoIterator<Item = PhysicalDeviceType>,
+    {
+        self.device_types = types.into_iter().collect();
+        assert!(!self.device_types.is_empty());
+        self
+    }
+
+    /// Requires a queue family that supports graphics operations.
+    #[inline]
+    pub fn graphics_queue(mut self) -> Self {
+        self.queues.graphics = true;
+        self
+    }
+
+    /// Requires a queue family that can present to `surface`.
+    ///
+    /// If a graphics queue is also required, a family that supports both is preferred.
+    #[inline]
+    pub fn present_queue<W>(mut self, surface: &'s Surface<W>) -> Self {
+        self.queues.present = true;
+        self.surface_support = Some(Box::new(move |family| surface.is_supported(family)));
+        self
+    }
+
+    /// Requires a queue family that supports compute operations and that is distinct from the
+    /// graphics queue family, so that compute work can run alongside graphics work.
+    #[inline]
+    pub fn async_compute_queue(mut self) -> Self {
+        self.queues.async_compute = true;
+        self
+    }
+
+    /// Requires a queue family that supports transfer operations but neither graphics nor
+    /// compute operations. Such families usually map to the DMA engines of the device.
+    #[inline]
+    pub fn dedicated_transfer_queue(mut self) -> Self {
+        self.queues.dedicated_transfer = true;
+        self
+    }
+
+    /// Checks every physical device of `instance`, picks the best one and creates a `Device`
+    /// with one queue for each of the queue families that were chosen.
+    ///
+    /// If no queue was requested, the device is created with one queue of its first family.
+    pub fn select(self, instance: &Arc<Instance>) -> Result<SelectedDevice, DeviceSelectionError> {
+        let mut rejected = Vec::new();
+        let mut best: Option<(Candidate, (usize, cmp::Reverse<usize>))> = None;
+
+        for physical_device in PhysicalDevice::enumerate(instance) {
+            match self.check(physical_device) {
+                Ok(candidate) => {
+                    let score = self.score(physical_device, &candidate);
+                    let is_better = match best {
+                        Some((_, best_score)) => score < best_score,
+                        None => true,
+                    };
+                    if is_better {
+                        best = Some((candidate, score));
+                    }
+                }
+                Err(reasons) => rejected.push(RejectedDevice {
+                    index: physical_device.index(),
+                    name: physical_device.name().to_owned(),
+                    reasons,
+                }),
+            }
+        }
+
+        let candidate = match best {
+            Some((candidate, _)) => candidate,
+            None => return Err(DeviceSelectionError::NoSuitableDevice(rejected)),
+        };
+
+        let physical_device = PhysicalDevice::from_index(instance, candidate.index).unwrap();
+        let mut families: Vec<u32> = Vec::new();
+        for &id in [
+            candidate.families.graphics,
+            candidate.families.present,
+            candidate.families.compute,
+            candidate.families.transfer,
+        ]
+        .iter()
+        .flatten()
+        {
+            if !families.contains(&id) {
+                families.push(id);
+            }
+        }
+        if families.is_empty() {
+            families.push(0);
+        }
+
+        let (device, queues) = Device::new(
+            physical_device,
+            &candidate.features,
+            &candidate.extensions,
+            families
+                .iter()
+                .map(|&id| (physical_device.queue_family_by_id(id).unwrap(), 1.0)),
+        )?;
+        let queues: Vec<Arc<Queue>> = queues.collect();
+        let queue_of = |id: Option<u32>| {
+            id.map(|id| {
+                queues
+                    .iter()
+                    .find(|queue| queue.family().id() == id)
+                    .unwrap()
+                    .clone()
+            })
+        };
+
+        Ok(SelectedDevice {
+            graphics_queue: queue_of(candidate.families.graphics),
+            present_queue: queue_of(candidate.families.present),
+            compute_queue: queue_of(candidate.families.compute),
+            transfer_queue: queue_of(candidate.families.transfer),
+            device,
+            queues,
+            rejected,
+        })
+    }
+
+    // Checks all the requirements against a physical device. Returns every reason the device
+    // doesn't meet them, instead of stopping at the first one.
+    fn check(&self, physical_device: PhysicalDevice) -> Result<Candidate, Vec<RejectionReason>> {
+        let mut reasons = Vec::new();
+
+        let ty = physical_device.ty();
+        if !self.device_types.contains(&ty) {
+            reasons.push(RejectionReason::DeviceTypeNotAccepted(ty));
+        }
+
+        let api_version = cmp::min(
+            physical_device.instance().api_version(),
+            physical_device.api_version(),
+        );
+        let supported_extensions = DeviceExtensions::supported_by_device(physical_device)
+            .union(&DeviceExtensions::promoted_to_core(api_version));
+        let required_extensions = self.required_extensions.ensure_dependencies(api_version);
+        let missing_extensions = required_extensions.difference(&supported_extensions);
+        if missing_extensions != DeviceExtensions::none() {
+            reasons.push(RejectionReason::MissingExtensions(missing_extensions));
+        }
+
+        let supported_features = physical_device.supported_features();
+        if !supported_features.superset_of(&self.required_features) {
+            reasons.push(RejectionReason::MissingFeatures(
+                self.required_features.difference(supported_features),
+            ));
+        }
+
+        let limits = physical_device.limits();
+        for &(description, ref check) in &self.limits {
+            if !check(&limits) {
+                reasons.push(RejectionReason::LimitNotMet(description));
+            }
+        }
+
+        let mut families = Vec::with_capacity(physical_device.queue_families().len());
+        let mut surface_error = None;
+        for family in physical_device.queue_families() {
+            let present = match self.surface_support {
+                Some(ref is_supported) => match is_supported(family) {
+                    Ok(present) => present,
+                    Err(err) => {
+                        // Only the first error is reported, as the other families would
+                        // usually fail for the same reason.
+                        surface_error.get_or_insert(err);
+                        false
+                    }
+                },
+                None => false,
+            };
+
+            families.push(FamilyCaps {
+                id: family.id(),
+                graphics: family.supports_graphics(),
+                compute: family.supports_compute(),
+                transfer: family.explicitly_supports_transfers(),
+                present,
+            });
+        }
+
+        if let Some(err) = surface_error {
+            reasons.push(RejectionReason::SurfaceError(err));
+        }
+
+        let families = match self.queues.choose(&families) {
+            Ok(families) => Some(families),
+            Err(mut queue_reasons) => {
+                reasons.append(&mut queue_reasons);
+                None
+            }
+        };
+
+        if !reasons.is_empty() {
+            return Err(reasons);
+        }
+
+        let optional_extensions = self
+            .optional_extensions
+            .ensure_dependencies(api_version)
+            .intersection(&supported_extensions);
+        let optional_features = self.optional_features.intersection(supported_features);
+
+        Ok(Candidate {
+            index: physical_device.index(),
+            extensions: required_extensions
+                .union(&optional_extensions)
+                .ensure_dependencies(api_version),
+            features: self.required_features.union(&optional_features),
+            families: families.unwrap(),
+        })
+    }
+
+    // Lower is better.
+    fn score(
+        &self,
+        physical_device: PhysicalDevice,
+        candidate: &Candidate,
+    ) -> (usize, cmp::Reverse<usize>) {
+        let type_rank = self
+            .device_types
+            .iter()
+            .position(|&ty| ty == physical_device.ty())
+            .unwrap();
+        let optional_count = candidate
+            .extensions
+            .intersection(&self.optional_extensions)
+            .difference(&self.required_extensions)
+            .count()
+            + candidate
+                .features
+                .difference(&self.required_features)
+                .count();
+
+        (type_rank, cmp::Reverse(optional_count))
+    }
+}
+
+impl<'s> Default for DeviceSelector<'s> {
+    #[inline]
+    fn default() -> Self {
+        DeviceSelector::new()
+    }
+}
+
+/// The device chosen by a `DeviceSelector`, along with its queues.
+#[derive(Debug)]
+pub struct SelectedDevice {
+    /// The newly-created device.
+    pub device: Arc<Device>,
+    /// All the queues that were created, one per chosen queue family.
+    pub queues: Vec<Arc<Queue>>,
+    /// Queue supporting graphics operations, if one was requested.
+    pub graphics_queue: Option<Arc<Queue>>,
+    /// Queue able to present to the surface, if one was requested. Can be the same queue as
+    /// `graphics_queue`.
+    pub present_queue: Option<Arc<Queue>>,
+    /// Queue supporting compute operations in a family distinct from the graphics family, if one
+    /// was requested.
+    pub compute_queue: Option<Arc<Queue>>,
+    /// Queue of a family only supporting transfer operations, if one was requested.
+    pub transfer_queue: Option<Arc<Queue>>,
+    /// The physical devices that didn't meet the requirements.
+    pub rejected: Vec<RejectedDevice>,
+}
+
+/// A physical device that was rejected by a `DeviceSelector`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RejectedDevice {
+    /// Index of the physical device, as returned by `PhysicalDevice::index`.
+    pub index: usize,
+    /// Name of the physical device.
+    pub name: String,
+    /// All the requirements that the physical device doesn't meet.
+    pub reasons: Vec<RejectionReason>,
+}
+
+/// Reason why a physical device was rejected by a `DeviceSelector`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RejectionReason {
+    /// The type of the device is not in the list of accepted types.
+    DeviceTypeNotAccepted(PhysicalDeviceType),
+    /// The device doesn't support these required extensions.
+    MissingExtensions(DeviceExtensions),
+    /// The device doesn't support these required features.
+    MissingFeatures(Features),
+    /// The limit check with this description failed.
+    LimitNotMet(&'static str),
+    /// No queue family supports graphics operations.
+    NoGraphicsQueue,
+    /// No queue family can present to the surface.
+    NoPresentQueue,
+    /// No queue family other than the graphics family supports compute operations.
+    NoAsyncComputeQueue,
+    /// No queue family supports transfer operations without graphics or compute operations.
+    NoDedicatedTransferQueue,
+    /// Querying whether a queue family can present to the surface failed.
+    SurfaceError(CapabilitiesError),
+}
+
+impl fmt::Display for RejectionReason {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            RejectionReason::DeviceTypeNotAccepted(ty) => {
+                write!(fmt, "the device type {:?} is not accepted", ty)
+            }
+            RejectionReason::MissingExtensions(ref extensions) => {
+                write!(fmt, "the extensions {:?} are not supported", extensions)
+            }
+            RejectionReason::MissingFeatures(ref features) => {
+                write!(fmt, "the features {:?} are not supported", features)
+            }
+            RejectionReason::LimitNotMet(description) => {
+                write!(fmt, "the limit `{}` is not met", description)
+            }
+            RejectionReason::NoGraphicsQueue => {
+                write!(fmt, "no queue family supports graphics operations")
+            }
+            RejectionReason::NoPresentQueue => {
+                write!(fmt, "no queue family can present to the surface")
+            }
+            RejectionReason::NoAsyncComputeQueue => write!(
+                fmt,
+                "no queue family other than the graphics family supports compute operations"
+            ),
+            RejectionReason::NoDedicatedTransferQueue => {
+                write!(fmt, "no queue family is dedicated to transfer operations")
+            }
+            RejectionReason::SurfaceError(ref err) => {
+                write!(fmt, "failed to query surface support: {}", err)
+            }
+        }
+    }
+}
+
+/// Error that can happen when selecting a device.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeviceSelectionError {
+    /// None of the physical devices meets the requirements. Contains the reasons each device was
+    /// rejected.
+    NoSuitableDevice(Vec<RejectedDevice>),
+    /// Creating the device from the chosen physical device failed.
+    DeviceCreationError(DeviceCreationError),
+}
+
+impl error::Error for DeviceSelectionError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            DeviceSelectionError::DeviceCreationError(ref err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for DeviceSelectionError {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            DeviceSelectionError::NoSuitableDevice(ref rejected) => {
+                write!(fmt, "no physical device meets the requirements")?;
+                for device in rejected {
+                    write!(fmt, "; {} (#{}):", device.name, device.index)?;
+                    for (n, reason) in device.reasons.iter().enumerate() {
+                        let sep = if n == 0 { " " } else { ", " };
+                        write!(fmt, "{}{}", sep, reason)?;
+                    }
+                }
+                Ok(())
+            }
+            DeviceSelectionError::DeviceCreationError(_) => {
+                write!(fmt, "failed to create the device")
+            }
+        }
+    }
+}
+
+impl From<DeviceCreationError> for DeviceSelectionError {
+    #[inline]
+    fn from(err: DeviceCreationError) -> DeviceSelectionError {
+        DeviceSelectionError::DeviceCreationError(err)
+    }
+}
+
+// A physical device that meets the requirements.
+struct Candidate {
+    index: usize,
+    extensions: DeviceExtensions,
+    features: Features,
+    families: ChosenFamilies,
+}
+
+// What a queue family is capable of.
+#[derive(Debug, Copy, Clone)]
+struct FamilyCaps {
+    id: u32,
+    graphics: bool,
+    compute: bool,
+    transfer: bool,
+    present: bool,
+}
+
+// Ids of the queue families picked for each requested kind of queue.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+struct ChosenFamilies {
+    graphics: Option<u32>,
+    present: Option<u32>,
+    compute: Option<u32>,
+    transfer: Option<u32>,
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+struct QueueNeeds {
+    graphics: bool,
+    present: bool,
+    async_compute: bool,
+    dedicated_transfer: bool,
+}
+
+impl QueueNeeds {
+    fn choose(&self, families: &[FamilyCaps]) -> Result<ChosenFamilies, Vec<RejectionReason>> {
+        let mut reasons = Vec::new();
+        let mut chosen = ChosenFamilies::default();
+
+        // Prefer a graphics family that can also present, so that a single queue does both.
+        let graphics = families
+            .iter()
+            .find(|f| f.graphics && (!self.present || f.present))
+            .or_else(|| families.iter().find(|f| f.graphics));
+
+        if self.graphics {
+            match graphics {
+                Some(f) => chosen.graphics = Some(f.id),
+                None => reasons.push(RejectionReason::NoGraphicsQueue),
+            }
+        }
+
+        if self.present {
+            let present = chosen
+                .graphics
+                .filter(|&id| families.iter().any(|f| f.id == id && f.present))
+                .or_else(|| families.iter().find(|f| f.present).map(|f| f.id));
+            match present {
+                Some(id) => chosen.present = Some(id),
+                None => reasons.push(RejectionReason::NoPresentQueue),
+            }
+        }
+
+        if self.async_compute {
+            // Even if no graphics queue was requested, async compute only makes sense next to
+            // the family that graphics work would go to.
+            let graphics_id = graphics.map(|f| f.id);
+            let compute = families
+                .iter()
+                .filter(|f| f.compute && Some(f.id) != graphics_id)
+                .min_by_key(|f| f.graphics);
+            match compute {
+                Some(f) => chosen.compute = Some(f.id),
+                None => reasons.push(RejectionReason::NoAsyncComputeQueue),
+            }
+        }
+
+        if self.dedicated_transfer {
+            match families
+                .iter()
+                .find(|f| f.transfer && !f.graphics && !f.compute)
+            {
+                Some(f) => chosen.transfer = Some(f.id),
+                None => reasons.push(RejectionReason::NoDedicatedTransferQueue),
+            }
+        }
+
+        if reasons.is_empty() {
+            Ok(chosen)
+        } else {
+            Err(reasons)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ChosenFamilies;
+    use super::FamilyCaps;
+    use super::QueueNeeds;
+    use crate::device::DeviceSelectionError;
+    use crate::device::RejectedDevice;
+    use crate::device::RejectionReason;
+    use crate::instance::PhysicalDeviceType;
+
+    fn family(id: u32, graphics: bool, compute: bool, transfer: bool, present: bool) -> FamilyCaps {
+        FamilyCaps {
+            id,
+            graphics,
+            compute,
+            transfer,
+            present,
+        }
+    }
+
+    #[test]
+    fn choose_queue_families() {
+        let families = [
+            family(0, true, true, true, false),
+            family(1, true, true, true, true),
+            family(2, false, true, true, false),
+            family(3, false, false, true, false),
+        ];
+        let needs = QueueNeeds {
+            graphics: true,
+            present: true,
+            async_compute: true,
+            dedicated_transfer: true,
+        };
+
+        assert_eq!(
+            needs.choose(&families),
+            Ok(ChosenFamilies {
+                graphics: Some(1),
+                present: Some(1),
+                compute: Some(2),
+                transfer: Some(3),
+            })
+        );
+    }
+
+    #[test]
+    fn choose_queue_families_reports_all_failures() {
+        let families = [family(0, true, true, true, false)];
+        let needs = QueueNeeds {
+            graphics: true,
+            present: true,
+            async_compute: true,
+            dedicated_transfer: true,
+        };
+
+        assert_eq!(
+            needs.choose(&families),
+            Err(vec![
+                RejectionReason::NoPresentQueue,
+                RejectionReason::NoAsyncComputeQueue,
+                RejectionReason::NoDedicatedTransferQueue,
+            ])
+        );
+    }
+
+    #[test]
+    fn no_suitable_device_display() {
+        let err = DeviceSelectionError::NoSuitableDevice(vec![RejectedDevice {
+            index: 0,
+            name: "llvmpipe".to_owned(),
+            reasons: vec![
+                RejectionReason::DeviceTypeNotAccepted(PhysicalDeviceType::Cpu),
+                RejectionReason::NoGraphicsQueue,
+            ],
+        }]);
+
+        assert_eq!(
+            err.to_string(),
+            "no physical device meets the requirements; llvmpipe (#0): the device type Cpu is \
+             not accepted, no queue family supports graphics operations"
+        );
+    }
+}
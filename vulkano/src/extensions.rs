@@ -11,6 +11,7 @@ use std::error;
 use std::fmt;
 
 use crate::instance::loader::LoadingError;
+use crate::version::Version;
 use crate::Error;
 use crate::OomError;
 
@@ -73,6 +74,21 @@ macro_rules! extensions {
                     _unbuildable: Unbuildable(())
                 }
             }
+
+            // Returns the number of extensions in the list.
+            pub(crate) fn count(&self) -> usize {
+                0 $(+ self.$ext as usize)*
+            }
+
+            // Returns the name of the extension whose field is named `field`.
+            pub(crate) fn name_of_field(field: &str) -> &'static str {
+                $(
+                    if field == stringify!($ext) {
+                        return str::from_utf8(&$s[..]).unwrap();
+                    }
+                )*
+                panic!("unknown extension field `{}`", field)
+            }
         }
 
         impl fmt::Debug for $sname {
@@ -173,6 +189,50 @@ macro_rules! extensions {
     );
 }
 
+/// Something that an extension requires in order to be enabled.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ExtensionRequirement {
+    /// A minimum version of the Vulkan API.
+    ApiVersion(Version),
+    /// A device extension, or the version of the API it was promoted to.
+    DeviceExtension(&'static str),
+    /// An instance extension, or the version of the API it was promoted to.
+    InstanceExtension(&'static str),
+}
+
+impl fmt::Display for ExtensionRequirement {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            Self::ApiVersion(version) => write!(fmt, "Vulkan {}", version),
+            Self::DeviceExtension(ext) => write!(fmt, "the device extension `{}`", ext),
+            Self::InstanceExtension(ext) => write!(fmt, "the instance extension `{}`", ext),
+        }
+    }
+}
+
+/// Error that can happen when an extension is enabled without something it requires.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ExtensionRestrictionError {
+    /// The extension that was enabled.
+    pub extension: &'static str,
+    /// The requirement of the extension that isn't met.
+    pub requires: ExtensionRequirement,
+}
+
+impl error::Error for ExtensionRestrictionError {}
+
+impl fmt::Display for ExtensionRestrictionError {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(
+            fmt,
+            "the extension `{}` requires {}",
+            self.extension, self.requires
+        )
+    }
+}
+
 /// Error that can happen when loading the list of layers.
 #[derive(Clone, Debug)]
 pub enum SupportedExtensionsError {
@@ -358,6 +358,33 @@ impl ImmutableImage {
         B: BufferAccess + TypedBufferAccess<Content = [Px]> + 'static + Clone + Send + Sync,
         Px: Pixel + Send + Sync + Clone + 'static,
     {
+        let (image, init) = ImmutableImage::uninitialized_for_upload(
+            source.device().clone(),
+            dimensions,
+            mipmaps,
+            format,
+        )?;
+
+        let mut cbb = AutoCommandBufferBuilder::new(source.device().clone(), queue.family())?;
+        ImmutableImage::record_initial_upload(&mut cbb, source, &image, init);
+        let cb = cbb.build().unwrap();
+
+        let future = match cb.execute(queue) {
+            Ok(f) => f,
+            Err(e) => unreachable!("{:?}", e),
+        };
+
+        Ok((image, future))
+    }
+
+    // Creates an image that is going to be filled by `record_initial_upload`. Shared between
+    // `from_buffer` and the `UploadQueue`.
+    pub(crate) fn uninitialized_for_upload(
+        device: Arc<Device>,
+        dimensions: ImageDimensions,
+        mipmaps: MipmapsCount,
+        format: Format,
+    ) -> Result<(Arc<Self>, Arc<SubImage>), ImageCreationError> {
         let need_to_generate_mipmaps = has_mipmaps(mipmaps);
         let usage = ImageUsage {
             transfer_destination: true,
@@ -369,14 +396,14 @@ impl ImmutableImage {
         let layout = ImageLayout::ShaderReadOnlyOptimal;
 
         let (image, initializer) = ImmutableImage::uninitialized(
-            source.device().clone(),
+            device.clone(),
             dimensions,
             format,
             mipmaps,
             usage,
             flags,
             layout,
-            source.device().active_queue_families(),
+            device.active_queue_families(),
         )?;
 
         let init = SubImage::new(
@@ -388,37 +415,41 @@ impl ImmutableImage {
             ImageLayout::ShaderReadOnlyOptimal,
         );
 
-        let mut cbb = AutoCommandBufferBuilder::new(source.device().clone(), queue.family())?;
+        Ok((image, init))
+    }
+
+    // Records the copy of `source` to the first mipmap level of an image created with
+    // `uninitialized_for_upload`, and the generation of the other levels.
+    pub(crate) fn record_initial_upload<L, B, Px>(
+        cbb: &mut AutoCommandBufferBuilder<L>,
+        source: B,
+        image: &Arc<Self>,
+        init: Arc<SubImage>,
+    ) where
+        B: BufferAccess + TypedBufferAccess<Content = [Px]> + 'static + Send + Sync,
+        Px: Pixel + Send + Sync + Clone + 'static,
+    {
         cbb.copy_buffer_to_image_dimensions(
             source,
             init,
             [0, 0, 0],
-            dimensions.width_height_depth(),
+            image.dimensions.width_height_depth(),
             0,
-            dimensions.array_layers(),
+            image.dimensions.array_layers(),
             0,
         )
         .unwrap();
 
-        if need_to_generate_mipmaps {
+        if image.mipmap_levels() > 1 {
             generate_mipmaps(
-                &mut cbb,
+                cbb,
                 image.clone(),
                 image.dimensions,
                 ImageLayout::ShaderReadOnlyOptimal,
             );
         }
 
-        let cb = cbb.build().unwrap();
-
-        let future = match cb.execute(queue) {
-            Ok(f) => f,
-            Err(e) => unreachable!("{:?}", e),
-        };
-
         image.initialized.store(true, Ordering::Relaxed);
-
-        Ok((image, future))
     }
 }
 
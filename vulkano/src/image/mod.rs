@@ -52,6 +52,7 @@ pub use self::attachment::AttachmentImage;
 pub use self::immutable::ImmutableImage;
 pub use self::layout::ImageDescriptorLayouts;
 pub use self::layout::ImageLayout;
+pub use self::sparse::SparseImage;
 pub use self::storage::StorageImage;
 pub use self::swapchain::SwapchainImage;
 pub use self::sys::ImageCreationError;
@@ -66,6 +67,7 @@ mod aspect;
 pub mod attachment; // TODO: make private
 pub mod immutable; // TODO: make private
 mod layout;
+mod sparse;
 mod storage;
 pub mod swapchain; // TODO: make private
 pub mod sys;
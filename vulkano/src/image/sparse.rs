@@ -0,0 +1,855 @@
+// Copyright (c) 2021 The vulkano developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>,
+// at your option. All files in the project carrying such
+// notice may not be copied, modified, or distributed except
+// according to those terms.
+
+//! Image whose memory is only partially resident.
+//!
+//! A `SparseImage` is divided in blocks whose shape is chosen by the Vulkan implementation and
+//! can be queried with `block_shape()`. Each block of each mipmap level and array layer can be
+//! made resident or non-resident individually, which allocates or frees memory from a memory
+//! pool. This is typically used for virtual texturing, where only the parts of a huge texture
+//! that are visible are kept in memory.
+//!
+//! The smallest mipmap levels, starting from `mip_tail_first_level()`, are too small to be
+//! divided in blocks and form the *mip tail*, which can only be made resident all at once.
+//!
+//! Changing the residency returns a `SparseBinds` object which must be submitted to a queue that
+//! supports sparse binding with `GpuFuture::then_bind_sparse`.
+//!
+//! ```no_run
+//! # use std::sync::Arc;
+//! # use vulkano::device::Queue;
+//! use vulkano::format::Format;
+//! use vulkano::image::ImageDimensions;
+//! use vulkano::image::MipmapsCount;
+//! use vulkano::image::SparseImage;
+//! use vulkano::sync::GpuFuture;
+//!
+//! # let queue: Arc<Queue> = return;
+//! let image = SparseImage::new(
+//!     queue.device().clone(),
+//!     ImageDimensions::Dim2d { width: 16384, height: 16384, array_layers: 1 },
+//!     Format::R8G8B8A8Unorm,
+//!     MipmapsCount::Log2,
+//!     Some(queue.family()),
+//! )
+//! .unwrap();
+//!
+//! // Make the mip tail and the top-left block of the first mipmap level resident.
+//! let mut binds = SparseImage::bind_mip_tail(&image, 0).unwrap();
+//! binds.merge(SparseImage::bind_blocks(&image, 0, 0, [0..1, 0..1, 0..1]).unwrap());
+//!
+//! let future = vulkano::sync::now(queue.device().clone())
+//!     .then_bind_sparse(queue.clone(), binds)
+//!     .then_signal_semaphore_and_flush()
+//!     .unwrap();
+//! ```
+
+use fnv::FnvHashMap;
+use smallvec::SmallVec;
+use std::cmp;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::ops::Range;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use crate::buffer::BufferAccess;
+use crate::device::Device;
+use crate::device::DeviceOwned;
+use crate::format::ClearValue;
+use crate::format::Format;
+use crate::format::FormatTy;
+use crate::image::sys::ImageCreationError;
+use crate::image::sys::SparseImageMemoryRequirements;
+use crate::image::sys::UnsafeImage;
+use crate::image::traits::ImageAccess;
+use crate::image::traits::ImageClearValue;
+use crate::image::traits::ImageContent;
+use crate::image::ImageAspect;
+use crate::image::ImageCreateFlags;
+use crate::image::ImageDescriptorLayouts;
+use crate::image::ImageDimensions;
+use crate::image::ImageInner;
+use crate::image::ImageLayout;
+use crate::image::ImageUsage;
+use crate::image::MipmapsCount;
+use crate::instance::QueueFamily;
+use crate::memory::pool::choose_allocation_memory_type;
+use crate::memory::pool::AllocFromRequirementsFilter;
+use crate::memory::pool::AllocLayout;
+use crate::memory::pool::MappingRequirement;
+use crate::memory::pool::MemoryPool;
+use crate::memory::pool::MemoryPoolAlloc;
+use crate::memory::pool::StdMemoryPool;
+use crate::memory::DeviceMemoryAllocError;
+use crate::memory::SparseBinds;
+use crate::sync::AccessError;
+use crate::sync::Sharing;
+
+/// Image whose memory is made resident block by block.
+///
+/// See [the documentation of the module](index.html) for more info.
+pub struct SparseImage<A = Arc<StdMemoryPool>>
+where
+    A: MemoryPool,
+{
+    // Inner implementation.
+    image: UnsafeImage,
+
+    // Pool the memory is allocated from.
+    pool: A,
+
+    // Memory type the memory is allocated from.
+    memory_type: u32,
+
+    // Size in bytes of a sparse block.
+    block_size: usize,
+
+    // Sparse requirements of the color aspect, and of the metadata if the implementation needs
+    // it.
+    requirements: SparseImageMemoryRequirements,
+    metadata_requirements: Option<SparseImageMemoryRequirements>,
+
+    // Memory bound to each resident block, indexed by mipmap level, array layer and position of
+    // the block.
+    blocks: Mutex<FnvHashMap<BlockKey, Arc<A::Alloc>>>,
+
+    // Memory bound to the mip tail of each array layer, or to the single mip tail.
+    mip_tails: Mutex<Vec<Option<MipTail<A::Alloc>>>>,
+
+    // Queue families allowed to access this image.
+    queue_families: SmallVec<[u32; 4]>,
+
+    // Number of times this image is locked on the GPU side.
+    gpu_lock: AtomicUsize,
+}
+
+// Mipmap level, array layer and position of a block.
+type BlockKey = (u32, u32, [u32; 3]);
+
+struct MipTail<M> {
+    memory: Option<Arc<M>>,
+    metadata: Option<Arc<M>>,
+}
+
+impl SparseImage {
+    /// Creates a new sparse image with the given dimensions, format and number of mipmaps.
+    /// The memory is allocated from the standard memory pool of the device, and the whole image
+    /// is initially non-resident.
+    ///
+    /// Returns `FormatNotSupported` if the format is a depth or stencil format.
+    #[inline]
+    pub fn new<'a, M, I>(
+        device: Arc<Device>,
+        dimensions: ImageDimensions,
+        format: Format,
+        mipmaps: M,
+        queue_families: I,
+    ) -> Result<Arc<SparseImage>, ImageCreationError>
+    where
+        M: Into<MipmapsCount>,
+        I: IntoIterator<Item = QueueFamily<'a>>,
+    {
+        let usage = ImageUsage {
+            transfer_source: true,
+            transfer_destination: true,
+            sampled: true,
+            ..ImageUsage::none()
+        };
+
+        let pool = Device::standard_pool(&device);
+        SparseImage::with_pool(pool, dimensions, format, mipmaps, usage, queue_families)
+    }
+}
+
+impl<A> SparseImage<A>
+where
+    A: MemoryPool,
+{
+    /// Same as `new`, but allows specifying the usage and the pool to allocate memory from.
+    pub fn with_pool<'a, M, I>(
+        pool: A,
+        dimensions: ImageDimensions,
+        format: Format,
+        mipmaps: M,
+        usage: ImageUsage,
+        queue_families: I,
+    ) -> Result<Arc<SparseImage<A>>, ImageCreationError>
+    where
+        M: Into<MipmapsCount>,
+        I: IntoIterator<Item = QueueFamily<'a>>,
+    {
+        match format.ty() {
+            FormatTy::Depth | FormatTy::DepthStencil | FormatTy::Stencil => {
+                return Err(ImageCreationError::FormatNotSupported)
+            }
+            _ => (),
+        };
+
+        let device = pool.device().clone();
+
+        let queue_families = queue_families
+            .into_iter()
+            .map(|f| f.id())
+            .collect::<SmallVec<[u32; 4]>>();
+
+        let flags = ImageCreateFlags {
+            sparse_binding: true,
+            sparse_residency: true,
+            ..ImageCreateFlags::none()
+        };
+
+        let (image, mem_reqs) = unsafe {
+            let sharing = if queue_families.len() >= 2 {
+                Sharing::Concurrent(queue_families.iter().cloned())
+            } else {
+                Sharing::Exclusive
+            };
+
+            UnsafeImage::new(
+                device.clone(),
+                usage,
+                format,
+                flags,
+                dimensions,
+                1,
+                mipmaps,
+                sharing,
+                false,
+                false,
+            )?
+        };
+
+        let sparse_reqs = image.sparse_memory_requirements();
+        let requirements = match sparse_reqs
+            .iter()
+            .find(|r| r.format_properties.aspects.color)
+        {
+            Some(r) => *r,
+            None => return Err(ImageCreationError::FormatNotSupported),
+        };
+        let metadata_requirements = sparse_reqs
+            .iter()
+            .find(|r| r.format_properties.aspects.metadata)
+            .cloned();
+
+        let memory_type = choose_allocation_memory_type(
+            &device,
+            &mem_reqs,
+            |t| {
+                if t.is_device_local() {
+                    AllocFromRequirementsFilter::Preferred
+                } else {
+                    AllocFromRequirementsFilter::Allowed
+                }
+            },
+            MappingRequirement::DoNotMap,
+        )
+        .id();
+
+        let num_mip_tails = if requirements.format_properties.single_miptail {
+            1
+        } else {
+            dimensions.array_layers() as usize
+        };
+
+        Ok(Arc::new(SparseImage {
+            image,
+            pool,
+            memory_type,
+            // For sparse resources, the alignment is the size of a sparse block.
+            block_size: mem_reqs.alignment,
+            requirements,
+            metadata_requirements,
+            blocks: Mutex::new(FnvHashMap::default()),
+            mip_tails: Mutex::new((0..num_mip_tails).map(|_| None).collect()),
+            queue_families,
+            gpu_lock: AtomicUsize::new(0),
+        }))
+    }
+
+    /// Returns the dimensions of the image.
+    #[inline]
+    pub fn dimensions(&self) -> ImageDimensions {
+        self.image.dimensions()
+    }
+
+    /// Returns the sparse memory requirements of the color aspect of the image.
+    #[inline]
+    pub fn sparse_memory_requirements(&self) -> &SparseImageMemoryRequirements {
+        &self.requirements
+    }
+
+    /// Returns the width, height and depth in texels of a block of the image.
+    #[inline]
+    pub fn block_shape(&self) -> [u32; 3] {
+        self.requirements.format_properties.image_granularity
+    }
+
+    /// Returns the size in bytes of the memory of a block.
+    #[inline]
+    pub fn block_size(&self) -> usize {
+        self.block_size
+    }
+
+    /// Returns the first mipmap level that is part of the mip tail. Equal to the number of
+    /// mipmap levels if the image has no mip tail.
+    #[inline]
+    pub fn mip_tail_first_level(&self) -> u32 {
+        cmp::min(
+            self.requirements.mip_tail_first_lod,
+            self.image.mipmap_levels(),
+        )
+    }
+
+    /// Returns the number of blocks of a mipmap level in each dimension.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if the mipmap level is part of the mip tail.
+    ///
+    pub fn num_blocks(&self, mip_level: u32) -> [u32; 3] {
+        assert!(mip_level < self.mip_tail_first_level());
+
+        let dimensions = self
+            .dimensions()
+            .mipmap_dimensions(mip_level)
+            .unwrap()
+            .width_height_depth();
+        let shape = self.block_shape();
+
+        [
+            (dimensions[0] + shape[0] - 1) / shape[0],
+            (dimensions[1] + shape[1] - 1) / shape[1],
+            (dimensions[2] + shape[2] - 1) / shape[2],
+        ]
+    }
+
+    /// Returns true if the block at the given position of a mipmap level and array layer is
+    /// resident.
+    #[inline]
+    pub fn is_block_resident(&self, mip_level: u32, array_layer: u32, block: [u32; 3]) -> bool {
+        self.blocks
+            .lock()
+            .unwrap()
+            .contains_key(&(mip_level, array_layer, block))
+    }
+
+    /// Returns the number of resident blocks, not counting the mip tails.
+    #[inline]
+    pub fn num_resident_blocks(&self) -> usize {
+        self.blocks.lock().unwrap().len()
+    }
+
+    /// Returns true if the mip tail of the given array layer is resident.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if the array layer is out of range.
+    ///
+    #[inline]
+    pub fn is_mip_tail_resident(&self, array_layer: u32) -> bool {
+        assert!(array_layer < self.dimensions().array_layers());
+        self.mip_tails.lock().unwrap()[self.mip_tail_index(array_layer)].is_some()
+    }
+
+    // Returns the index within `mip_tails` of the mip tail of an array layer.
+    #[inline]
+    fn mip_tail_index(&self, array_layer: u32) -> usize {
+        if self.requirements.format_properties.single_miptail {
+            0
+        } else {
+            array_layer as usize
+        }
+    }
+
+    // Returns the offset and extent in texels of a block.
+    fn block_region(&self, mip_level: u32, block: [u32; 3]) -> ([i32; 3], [u32; 3]) {
+        let dimensions = self
+            .dimensions()
+            .mipmap_dimensions(mip_level)
+            .unwrap()
+            .width_height_depth();
+        let shape = self.block_shape();
+
+        let mut offset = [0; 3];
+        let mut extent = [0; 3];
+        for n in 0..3 {
+            let start = block[n] * shape[n];
+            offset[n] = start as i32;
+            extent[n] = cmp::min(shape[n], dimensions[n] - start);
+        }
+        (offset, extent)
+    }
+
+    // Checks that a mipmap level, array layer and range of blocks are valid.
+    fn check_blocks(&self, mip_level: u32, array_layer: u32, blocks: &[Range<u32>; 3]) {
+        assert!(array_layer < self.dimensions().array_layers());
+        let num_blocks = self.num_blocks(mip_level);
+        for n in 0..3 {
+            assert!(blocks[n].start <= blocks[n].end && blocks[n].end <= num_blocks[n]);
+        }
+    }
+}
+
+impl<A> SparseImage<A>
+where
+    A: MemoryPool + Send + Sync + 'static,
+    A::Alloc: Send + Sync + 'static,
+{
+    /// Allocates memory for the blocks of the range that aren't resident yet, and returns the
+    /// operations that bind this memory to the image.
+    ///
+    /// `blocks` contains the range of blocks in each dimension, as returned by `num_blocks`.
+    ///
+    /// The blocks are considered resident as soon as this function returns. The returned binds
+    /// must be submitted with `GpuFuture::then_bind_sparse` before the GPU accesses them.
+    ///
+    /// If the allocation fails, no block is made resident.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if the mipmap level is part of the mip tail.
+    /// - Panics if the array layer or the blocks are out of range.
+    ///
+    pub fn bind_blocks(
+        me: &Arc<Self>,
+        mip_level: u32,
+        array_layer: u32,
+        blocks: [Range<u32>; 3],
+    ) -> Result<SparseBinds, DeviceMemoryAllocError> {
+        me.check_blocks(mip_level, array_layer, &blocks);
+        let mut table = me.blocks.lock().unwrap();
+
+        let mut allocs = Vec::new();
+        for z in blocks[2].clone() {
+            for y in blocks[1].clone() {
+                for x in blocks[0].clone() {
+                    let block = [x, y, z];
+                    if table.contains_key(&(mip_level, array_layer, block)) {
+                        continue;
+                    }
+
+                    allocs.push((block, me.alloc(me.block_size)?));
+                }
+            }
+        }
+
+        let mut binds = SparseBinds::new(me.device().clone());
+        for (block, alloc) in allocs {
+            binds.add_image_bind(
+                &me.image,
+                ImageAspect::Color,
+                mip_level,
+                array_layer,
+                me.block_region(mip_level, block),
+                Some((alloc.memory(), alloc.offset())),
+            );
+            table.insert((mip_level, array_layer, block), alloc);
+        }
+        binds.keep_alive(me.clone());
+
+        Ok(binds)
+    }
+
+    /// Returns the operations that unbind the memory of the resident blocks of the range. The
+    /// memory is freed once the operations have been executed.
+    ///
+    /// The blocks are considered non-resident as soon as this function returns.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if the mipmap level is part of the mip tail.
+    /// - Panics if the array layer or the blocks are out of range.
+    ///
+    /// # Safety
+    ///
+    /// - The returned binds must be submitted with `GpuFuture::then_bind_sparse`. If they are
+    ///   dropped instead, the memory is freed while it is still bound to the image.
+    /// - The GPU operations that access the blocks must be finished or ordered before the
+    ///   submission of the binds.
+    ///
+    pub unsafe fn unbind_blocks(
+        me: &Arc<Self>,
+        mip_level: u32,
+        array_layer: u32,
+        blocks: [Range<u32>; 3],
+    ) -> SparseBinds {
+        me.check_blocks(mip_level, array_layer, &blocks);
+        let mut table = me.blocks.lock().unwrap();
+
+        let mut binds = SparseBinds::new(me.device().clone());
+        for z in blocks[2].clone() {
+            for y in blocks[1].clone() {
+                for x in blocks[0].clone() {
+                    let block = [x, y, z];
+                    if let Some(alloc) = table.remove(&(mip_level, array_layer, block)) {
+                        binds.add_image_bind(
+                            &me.image,
+                            ImageAspect::Color,
+                            mip_level,
+                            array_layer,
+                            me.block_region(mip_level, block),
+                            None,
+                        );
+                        binds.keep_alive(alloc);
+                    }
+                }
+            }
+        }
+        binds.keep_alive(me.clone());
+
+        binds
+    }
+
+    /// Allocates memory for the mip tail of an array layer if it isn't resident yet, and returns
+    /// the operations that bind it to the image. If all the array layers share the same mip tail,
+    /// then `array_layer` is ignored.
+    ///
+    /// If the implementation requires it, this also binds memory to the metadata of the image.
+    /// The metadata must be resident before the image is used by the GPU.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if the array layer is out of range.
+    ///
+    pub fn bind_mip_tail(
+        me: &Arc<Self>,
+        array_layer: u32,
+    ) -> Result<SparseBinds, DeviceMemoryAllocError> {
+        assert!(array_layer < me.dimensions().array_layers());
+        let index = me.mip_tail_index(array_layer);
+        let mut mip_tails = me.mip_tails.lock().unwrap();
+
+        let mut binds = SparseBinds::new(me.device().clone());
+        if mip_tails[index].is_some() {
+            return Ok(binds);
+        }
+
+        let memory = if me.mip_tail_first_level() < me.image.mipmap_levels() {
+            Some(me.alloc(me.requirements.mip_tail_size)?)
+        } else {
+            None
+        };
+        let metadata = match me.metadata_requirements {
+            Some(ref reqs) => Some(me.alloc(reqs.mip_tail_size)?),
+            None => None,
+        };
+
+        if let Some(ref memory) = memory {
+            let reqs = &me.requirements;
+            binds.add_image_opaque_bind(
+                &me.image,
+                reqs.mip_tail_offset + index * reqs.mip_tail_stride,
+                reqs.mip_tail_size,
+                Some((memory.memory(), memory.offset())),
+                false,
+            );
+        }
+        if let Some(ref metadata) = metadata {
+            let reqs = me.metadata_requirements.as_ref().unwrap();
+            binds.add_image_opaque_bind(
+                &me.image,
+                reqs.mip_tail_offset + index * reqs.mip_tail_stride,
+                reqs.mip_tail_size,
+                Some((metadata.memory(), metadata.offset())),
+                true,
+            );
+        }
+        binds.keep_alive(me.clone());
+
+        mip_tails[index] = Some(MipTail { memory, metadata });
+
+        Ok(binds)
+    }
+
+    /// Returns the operations that unbind the memory of the mip tail of an array layer, and of
+    /// the corresponding metadata. The memory is freed once the operations have been executed.
+    ///
+    /// The mip tail is considered non-resident as soon as this function returns.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if the array layer is out of range.
+    ///
+    /// # Safety
+    ///
+    /// - The returned binds must be submitted with `GpuFuture::then_bind_sparse`. If they are
+    ///   dropped instead, the memory is freed while it is still bound to the image.
+    /// - The GPU operations that access the mip tail must be finished or ordered before the
+    ///   submission of the binds.
+    ///
+    pub unsafe fn unbind_mip_tail(me: &Arc<Self>, array_layer: u32) -> SparseBinds {
+        assert!(array_layer < me.dimensions().array_layers());
+        let index = me.mip_tail_index(array_layer);
+        let mut mip_tails = me.mip_tails.lock().unwrap();
+
+        let mut binds = SparseBinds::new(me.device().clone());
+        if let Some(mip_tail) = mip_tails[index].take() {
+            if me.mip_tail_first_level() < me.image.mipmap_levels() {
+                let reqs = &me.requirements;
+                binds.add_image_opaque_bind(
+                    &me.image,
+                    reqs.mip_tail_offset + index * reqs.mip_tail_stride,
+                    reqs.mip_tail_size,
+                    None,
+                    false,
+                );
+            }
+            if let Some(ref reqs) = me.metadata_requirements {
+                binds.add_image_opaque_bind(
+                    &me.image,
+                    reqs.mip_tail_offset + index * reqs.mip_tail_stride,
+                    reqs.mip_tail_size,
+                    None,
+                    true,
+                );
+            }
+
+            if let Some(memory) = mip_tail.memory {
+                binds.keep_alive(memory);
+            }
+            if let Some(metadata) = mip_tail.metadata {
+                binds.keep_alive(metadata);
+            }
+        }
+        binds.keep_alive(me.clone());
+
+        binds
+    }
+
+    // Allocates memory for a block or a mip tail.
+    fn alloc(&self, size: usize) -> Result<Arc<A::Alloc>, DeviceMemoryAllocError> {
+        let mem_ty = self
+            .device()
+            .physical_device()
+            .memory_type_by_id(self.memory_type)
+            .unwrap();
+
+        let alloc = self.pool.alloc_generic(
+            mem_ty,
+            size,
+            self.block_size,
+            AllocLayout::Optimal,
+            MappingRequirement::DoNotMap,
+        )?;
+        debug_assert!((alloc.offset() % self.block_size) == 0);
+        Ok(Arc::new(alloc))
+    }
+}
+
+unsafe impl<A> DeviceOwned for SparseImage<A>
+where
+    A: MemoryPool,
+{
+    #[inline]
+    fn device(&self) -> &Arc<Device> {
+        self.image.device()
+    }
+}
+
+unsafe impl<A> ImageAccess for SparseImage<A>
+where
+    A: MemoryPool,
+{
+    #[inline]
+    fn inner(&self) -> ImageInner<'_> {
+        ImageInner {
+            image: &self.image,
+            first_layer: 0,
+            num_layers: self.dimensions().array_layers() as usize,
+            first_mipmap_level: 0,
+            num_mipmap_levels: self.image.mipmap_levels() as usize,
+        }
+    }
+
+    #[inline]
+    fn initial_layout_requirement(&self) -> ImageLayout {
+        ImageLayout::General
+    }
+
+    #[inline]
+    fn final_layout_requirement(&self) -> ImageLayout {
+        ImageLayout::General
+    }
+
+    #[inline]
+    fn descriptor_layouts(&self) -> Option<ImageDescriptorLayouts> {
+        Some(ImageDescriptorLayouts {
+            storage_image: ImageLayout::General,
+            combined_image_sampler: ImageLayout::General,
+            sampled_image: ImageLayout::General,
+            input_attachment: ImageLayout::General,
+        })
+    }
+
+    #[inline]
+    fn conflicts_buffer(&self, other: &dyn BufferAccess) -> bool {
+        false
+    }
+
+    #[inline]
+    fn conflicts_image(&self, other: &dyn ImageAccess) -> bool {
+        self.conflict_key() == other.conflict_key() // TODO:
+    }
+
+    #[inline]
+    fn conflict_key(&self) -> u64 {
+        self.image.key()
+    }
+
+    #[inline]
+    fn try_gpu_lock(&self, _: bool, expected_layout: ImageLayout) -> Result<(), AccessError> {
+        // TODO: handle initial layout transition
+        if expected_layout != ImageLayout::General && expected_layout != ImageLayout::Undefined {
+            return Err(AccessError::UnexpectedImageLayout {
+                requested: expected_layout,
+                allowed: ImageLayout::General,
+            });
+        }
+
+        let val = self
+            .gpu_lock
+            .compare_exchange(0, 1, Ordering::SeqCst, Ordering::SeqCst)
+            .unwrap_or_else(|e| e);
+        if val == 0 {
+            Ok(())
+        } else {
+            Err(AccessError::AlreadyInUse)
+        }
+    }
+
+    #[inline]
+    unsafe fn increase_gpu_lock(&self) {
+        let val = self.gpu_lock.fetch_add(1, Ordering::SeqCst);
+        debug_assert!(val >= 1);
+    }
+
+    #[inline]
+    unsafe fn unlock(&self, new_layout: Option<ImageLayout>) {
+        assert!(new_layout.is_none() || new_layout == Some(ImageLayout::General));
+        self.gpu_lock.fetch_sub(1, Ordering::SeqCst);
+    }
+
+    #[inline]
+    fn current_miplevels_access(&self) -> std::ops::Range<u32> {
+        0..self.mipmap_levels()
+    }
+
+    #[inline]
+    fn current_layer_levels_access(&self) -> std::ops::Range<u32> {
+        0..self.dimensions().array_layers()
+    }
+}
+
+unsafe impl<A> ImageClearValue<ClearValue> for SparseImage<A>
+where
+    A: MemoryPool,
+{
+    #[inline]
+    fn decode(&self, value: ClearValue) -> Option<ClearValue> {
+        Some(self.image.format().decode_clear_value(value))
+    }
+}
+
+unsafe impl<P, A> ImageContent<P> for SparseImage<A>
+where
+    A: MemoryPool,
+{
+    #[inline]
+    fn matches_format(&self) -> bool {
+        true // FIXME:
+    }
+}
+
+impl<A> PartialEq for SparseImage<A>
+where
+    A: MemoryPool,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        ImageAccess::inner(self) == ImageAccess::inner(other)
+    }
+}
+
+impl<A> Eq for SparseImage<A> where A: MemoryPool {}
+
+impl<A> Hash for SparseImage<A>
+where
+    A: MemoryPool,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        ImageAccess::inner(self).hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::format::Format;
+    use crate::image::ImageCreationError;
+    use crate::image::ImageDimensions;
+    use crate::image::MipmapsCount;
+    use crate::image::SparseImage;
+
+    #[test]
+    fn bind_blocks() {
+        let (device, queue) = gfx_dev_and_queue!(sparse_binding, sparse_residency_image2d);
+
+        let image = SparseImage::new(
+            device,
+            ImageDimensions::Dim2d {
+                width: 1024,
+                height: 1024,
+                array_layers: 1,
+            },
+            Format::R8G8B8A8Unorm,
+            MipmapsCount::Log2,
+            Some(queue.family()),
+        )
+        .unwrap();
+
+        let shape = image.block_shape();
+        let num_blocks = image.num_blocks(0);
+        assert_eq!(num_blocks[0], (1024 + shape[0] - 1) / shape[0]);
+
+        let binds = SparseImage::bind_blocks(&image, 0, 0, [0..1, 0..1, 0..1]).unwrap();
+        assert!(!binds.is_empty());
+        assert!(image.is_block_resident(0, 0, [0, 0, 0]));
+        assert_eq!(image.num_resident_blocks(), 1);
+
+        let binds = unsafe { SparseImage::unbind_blocks(&image, 0, 0, [0..1, 0..1, 0..1]) };
+        assert!(!binds.is_empty());
+        assert_eq!(image.num_resident_blocks(), 0);
+    }
+
+    #[test]
+    fn depth_format_not_supported() {
+        let (device, queue) = gfx_dev_and_queue!(sparse_binding, sparse_residency_image2d);
+
+        let result = SparseImage::new(
+            device,
+            ImageDimensions::Dim2d {
+                width: 1024,
+                height: 1024,
+                array_layers: 1,
+            },
+            Format::D16Unorm,
+            MipmapsCount::One,
+            Some(queue.family()),
+        );
+
+        match result {
+            Err(ImageCreationError::FormatNotSupported) => (),
+            _ => panic!(),
+        }
+    }
+}
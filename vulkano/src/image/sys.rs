@@ -19,6 +19,7 @@ use crate::format::Format;
 use crate::format::FormatFeatures;
 use crate::format::FormatTy;
 use crate::image::ImageAspect;
+use crate::image::ImageAspects;
 use crate::image::ImageCreateFlags;
 use crate::image::ImageDimensions;
 use crate::image::ImageUsage;
@@ -134,14 +135,21 @@ impl UnsafeImage {
     ) -> Result<(UnsafeImage, MemoryRequirements), ImageCreationError> {
         // TODO: doesn't check that the proper features are enabled
 
-        if flags.sparse_binding
-            || flags.sparse_residency
-            || flags.sparse_aliased
-            || flags.mutable_format
-        {
+        if flags.mutable_format {
             unimplemented!();
         }
 
+        // Checking sparse features.
+        if (flags.sparse_residency || flags.sparse_aliased) && !flags.sparse_binding {
+            return Err(ImageCreationError::CreationFlagRequirementsNotMet);
+        }
+        if flags.sparse_binding && !device.enabled_features().sparse_binding {
+            return Err(ImageCreationError::SparseBindingFeatureNotEnabled);
+        }
+        if flags.sparse_aliased && !device.enabled_features().sparse_residency_aliased {
+            return Err(ImageCreationError::SparseResidencyAliasedFeatureNotEnabled);
+        }
+
         let vk = device.pointers();
         let vk_i = device.instance().pointers();
 
@@ -426,6 +434,34 @@ impl UnsafeImage {
             }
         }
 
+        if flags.sparse_residency {
+            if linear_tiling {
+                return Err(ImageCreationError::CreationFlagRequirementsNotMet);
+            }
+
+            let features = device.enabled_features();
+            let feature_enabled = match ty {
+                vk::IMAGE_TYPE_2D => features.sparse_residency_image2d,
+                vk::IMAGE_TYPE_3D => features.sparse_residency_image3d,
+                _ => return Err(ImageCreationError::CreationFlagRequirementsNotMet),
+            };
+            if !feature_enabled {
+                return Err(ImageCreationError::SparseResidencyImageFeatureNotEnabled);
+            }
+
+            let samples_enabled = match num_samples {
+                1 => true,
+                2 => features.sparse_residency2_samples,
+                4 => features.sparse_residency4_samples,
+                8 => features.sparse_residency8_samples,
+                16 => features.sparse_residency16_samples,
+                _ => false,
+            };
+            if !samples_enabled {
+                return Err(ImageCreationError::SparseResidencyImageFeatureNotEnabled);
+            }
+        }
+
         // Checking the dimensions against the limits.
         if array_layers > device.physical_device().limits().max_image_array_layers() {
             let err = ImageCreationError::UnsupportedDimensions { dimensions };
@@ -555,7 +591,7 @@ impl UnsafeImage {
             let mut output2 = if device.loaded_extensions().khr_dedicated_allocation {
                 Some(vk::MemoryDedicatedRequirementsKHR {
                     sType: vk::STRUCTURE_TYPE_MEMORY_DEDICATED_REQUIREMENTS_KHR,
-                    pNext: ptr::null(),
+                    pNext: ptr::null_mut(),
                     prefersDedicatedAllocation: mem::zeroed(),
                     requiresDedicatedAllocation: mem::zeroed(),
                 })
@@ -700,6 +736,41 @@ impl UnsafeImage {
         self.image
     }
 
+    /// Queries the sparse memory requirements of the image, one entry per group of aspects.
+    ///
+    /// Returns an empty list if the image wasn't created with the `sparse_residency` flag.
+    pub fn sparse_memory_requirements(&self) -> Vec<SparseImageMemoryRequirements> {
+        if !self.flags.sparse_residency {
+            return Vec::new();
+        }
+
+        unsafe {
+            let vk = self.device.pointers();
+
+            let mut num = 0;
+            vk.GetImageSparseMemoryRequirements(
+                self.device.internal_object(),
+                self.image,
+                &mut num,
+                ptr::null_mut(),
+            );
+
+            let mut output = Vec::with_capacity(num as usize);
+            vk.GetImageSparseMemoryRequirements(
+                self.device.internal_object(),
+                self.image,
+                &mut num,
+                output.as_mut_ptr(),
+            );
+            output.set_len(num as usize);
+
+            output
+                .into_iter()
+                .map(SparseImageMemoryRequirements::from_vulkan_reqs)
+                .collect()
+        }
+    }
+
     /// Queries the layout of an image in memory. Only valid for images with linear tiling.
     ///
     /// This function is only valid for images with a color format. See the other similar functions
@@ -915,6 +986,13 @@ pub enum ImageCreationError {
     UnsupportedUsage,
     /// The `shader_storage_image_multisample` feature must be enabled to create such an image.
     ShaderStorageImageMultisampleFeatureNotEnabled,
+    /// Sparse binding was requested but the corresponding feature wasn't enabled.
+    SparseBindingFeatureNotEnabled,
+    /// Sparse residency was requested but the feature corresponding to the type and number of
+    /// samples of the image wasn't enabled.
+    SparseResidencyImageFeatureNotEnabled,
+    /// Sparse aliasing was requested but the corresponding feature wasn't enabled.
+    SparseResidencyAliasedFeatureNotEnabled,
 }
 
 impl error::Error for ImageCreationError {
@@ -958,6 +1036,15 @@ impl fmt::Display for ImageCreationError {
                     "the `shader_storage_image_multisample` feature must be enabled to create such \
                  an image"
                 }
+                ImageCreationError::SparseBindingFeatureNotEnabled => {
+                    "sparse binding was requested but the corresponding feature wasn't enabled"
+                }
+                ImageCreationError::SparseResidencyImageFeatureNotEnabled => {
+                    "sparse residency was requested but the corresponding feature wasn't enabled"
+                }
+                ImageCreationError::SparseResidencyAliasedFeatureNotEnabled => {
+                    "sparse aliasing was requested but the corresponding feature wasn't enabled"
+                }
             }
         )
     }
@@ -988,6 +1075,70 @@ impl From<Error> for ImageCreationError {
     }
 }
 
+/// Sparse memory requirements of some aspects of an image.
+///
+/// Obtained by calling `sparse_memory_requirements` on the image.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SparseImageMemoryRequirements {
+    /// Aspects and block shape the requirements apply to.
+    pub format_properties: SparseImageFormatProperties,
+    /// First mipmap level that is part of the mip tail. Mipmap levels starting from this one
+    /// can only be bound all at once, with opaque binds.
+    pub mip_tail_first_lod: u32,
+    /// Size in bytes of the mip tail.
+    pub mip_tail_size: usize,
+    /// Offset of the mip tail within the opaque memory range of the image.
+    pub mip_tail_offset: usize,
+    /// Offset between the mip tails of two consecutive array layers.
+    pub mip_tail_stride: usize,
+}
+
+impl SparseImageMemoryRequirements {
+    #[inline]
+    fn from_vulkan_reqs(reqs: vk::SparseImageMemoryRequirements) -> SparseImageMemoryRequirements {
+        SparseImageMemoryRequirements {
+            format_properties: SparseImageFormatProperties {
+                aspects: reqs.formatProperties.aspectMask.into(),
+                image_granularity: [
+                    reqs.formatProperties.imageGranularity.width,
+                    reqs.formatProperties.imageGranularity.height,
+                    reqs.formatProperties.imageGranularity.depth,
+                ],
+                single_miptail: (reqs.formatProperties.flags
+                    & vk::SPARSE_IMAGE_FORMAT_SINGLE_MIPTAIL_BIT)
+                    != 0,
+                aligned_mip_size: (reqs.formatProperties.flags
+                    & vk::SPARSE_IMAGE_FORMAT_ALIGNED_MIP_SIZE_BIT)
+                    != 0,
+                nonstandard_block_size: (reqs.formatProperties.flags
+                    & vk::SPARSE_IMAGE_FORMAT_NONSTANDARD_BLOCK_SIZE_BIT)
+                    != 0,
+            },
+            mip_tail_first_lod: reqs.imageMipTailFirstLod,
+            mip_tail_size: reqs.imageMipTailSize as usize,
+            mip_tail_offset: reqs.imageMipTailOffset as usize,
+            mip_tail_stride: reqs.imageMipTailStride as usize,
+        }
+    }
+}
+
+/// Block shape and properties of the sparse memory of an image.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SparseImageFormatProperties {
+    /// Aspects these properties apply to.
+    pub aspects: ImageAspects,
+    /// Width, height and depth in texels of a sparse image block. Memory is bound to the image
+    /// in multiples of this shape.
+    pub image_granularity: [u32; 3],
+    /// If true, all the array layers share a single mip tail.
+    pub single_miptail: bool,
+    /// If true, the first mipmap level whose dimensions aren't a multiple of the block shape
+    /// starts the mip tail.
+    pub aligned_mip_size: bool,
+    /// If true, the block shape doesn't match the standard block shape of the format.
+    pub nonstandard_block_size: bool,
+}
+
 /// Describes the memory layout of an image with linear tiling.
 ///
 /// Obtained by calling `*_linear_layout` on the image.
@@ -1371,4 +1522,116 @@ mod tests {
             _ => panic!(),
         };
     }
+
+    #[test]
+    fn sparse_residency_without_binding() {
+        let (device, _) = gfx_dev_and_queue!();
+
+        let usage = ImageUsage {
+            sampled: true,
+            ..ImageUsage::none()
+        };
+
+        let res = unsafe {
+            UnsafeImage::new(
+                device,
+                usage,
+                Format::R8G8B8A8Unorm,
+                ImageCreateFlags {
+                    sparse_residency: true,
+                    ..ImageCreateFlags::none()
+                },
+                ImageDimensions::Dim2d {
+                    width: 32,
+                    height: 32,
+                    array_layers: 1,
+                },
+                1,
+                1,
+                Sharing::Exclusive::<Empty<_>>,
+                false,
+                false,
+            )
+        };
+
+        match res {
+            Err(ImageCreationError::CreationFlagRequirementsNotMet) => (),
+            _ => panic!(),
+        };
+    }
+
+    #[test]
+    fn missing_feature_sparse_binding() {
+        let (device, _) = gfx_dev_and_queue!();
+
+        let usage = ImageUsage {
+            sampled: true,
+            ..ImageUsage::none()
+        };
+
+        let res = unsafe {
+            UnsafeImage::new(
+                device,
+                usage,
+                Format::R8G8B8A8Unorm,
+                ImageCreateFlags {
+                    sparse_binding: true,
+                    ..ImageCreateFlags::none()
+                },
+                ImageDimensions::Dim2d {
+                    width: 32,
+                    height: 32,
+                    array_layers: 1,
+                },
+                1,
+                1,
+                Sharing::Exclusive::<Empty<_>>,
+                false,
+                false,
+            )
+        };
+
+        match res {
+            Err(ImageCreationError::SparseBindingFeatureNotEnabled) => (),
+            _ => panic!(),
+        };
+    }
+
+    #[test]
+    fn missing_feature_sparse_residency() {
+        let (device, _) = gfx_dev_and_queue!(sparse_binding);
+
+        let usage = ImageUsage {
+            sampled: true,
+            ..ImageUsage::none()
+        };
+
+        let res = unsafe {
+            UnsafeImage::new(
+                device,
+                usage,
+                Format::R8G8B8A8Unorm,
+                ImageCreateFlags {
+                    sparse_binding: true,
+                    sparse_residency: true,
+                    ..ImageCreateFlags::none()
+                },
+                ImageDimensions::Dim2d {
+                    width: 32,
+                    height: 32,
+                    array_layers: 1,
+                },
+                1,
+                1,
+                Sharing::Exclusive::<Empty<_>>,
+                false,
+                false,
+            )
+        };
+
+        match res {
+            Err(ImageCreationError::SparseResidencyImageFeatureNotEnabled) => (),
+            _ => panic!(),
+        };
+    }
 }
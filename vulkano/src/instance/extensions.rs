@@ -15,13 +15,22 @@ use std::ptr;
 use std::str;
 
 use crate::check_errors;
+use crate::extensions::ExtensionRequirement;
+use crate::extensions::ExtensionRestrictionError;
 use crate::extensions::SupportedExtensionsError;
 use crate::instance::loader;
 use crate::instance::loader::LoadingError;
+use crate::version::Version;
 use crate::vk;
 
 macro_rules! instance_extensions {
-    ($sname:ident, $rawname:ident, $($ext:ident => $s:expr,)*) => (
+    (
+        $sname:ident, $rawname:ident,
+        $($ext:ident => $s:literal {
+            core: $core:expr,
+            requires: [$($dep:ident),*],
+        },)*
+    ) => (
         extensions! {
             $sname, $rawname,
             $( $ext => $s,)*
@@ -138,29 +147,85 @@ macro_rules! instance_extensions {
                     Err(SupportedExtensionsError::OomError(e)) => panic!("{:?}", e),
                 }
             }
+
+            /// Returns the extensions whose functionality is part of the core API of an instance
+            /// of version `api_version`.
+            ///
+            /// These extensions don't need to be enabled explicitly on such an instance, and are
+            /// included in `Instance::loaded_extensions()`.
+            pub fn promoted_to_core(api_version: Version) -> Self {
+                let mut extensions = Self::none();
+                $(
+                    let core: Option<Version> = $core;
+                    if core.map_or(false, |core| api_version >= core) {
+                        extensions.$ext = true;
+                    }
+                )*
+                extensions
+            }
+
+            /// Returns this list with the instance extensions that its extensions depend on
+            /// added, recursively.
+            ///
+            /// Dependencies that are part of the core API of an instance of version
+            /// `api_version` are not added.
+            pub fn ensure_dependencies(&self, api_version: Version) -> Self {
+                let core = Self::promoted_to_core(api_version);
+                let mut extensions = *self;
+
+                loop {
+                    let mut changed = false;
+                    $(
+                        if extensions.$ext {
+                            $(
+                                if !extensions.$dep && !core.$dep {
+                                    extensions.$dep = true;
+                                    changed = true;
+                                }
+                            )*
+                        }
+                    )*
+
+                    if !changed {
+                        return extensions;
+                    }
+                }
+            }
+
+            /// Checks that the extensions that the extensions of this list depend on are
+            /// available on an instance of version `api_version`.
+            ///
+            /// A dependency is available if it is enabled as well, or if it is part of the core
+            /// API of that version.
+            pub fn check_requirements(
+                &self,
+                api_version: Version,
+            ) -> Result<(), ExtensionRestrictionError> {
+                let available = self.union(&Self::promoted_to_core(api_version));
+
+                $(
+                    if self.$ext {
+                        $(
+                            if !available.$dep {
+                                return Err(ExtensionRestrictionError {
+                                    extension: str::from_utf8(&$s[..]).unwrap(),
+                                    requires: ExtensionRequirement::InstanceExtension(
+                                        Self::name_of_field(stringify!($dep)),
+                                    ),
+                                });
+                            }
+                        )*
+                    }
+                )*
+
+                Ok(())
+            }
         }
     );
 }
 
-instance_extensions! {
-    InstanceExtensions,
-    RawInstanceExtensions,
-    khr_surface => b"VK_KHR_surface",
-    khr_display => b"VK_KHR_display",
-    khr_xlib_surface => b"VK_KHR_xlib_surface",
-    khr_xcb_surface => b"VK_KHR_xcb_surface",
-    khr_wayland_surface => b"VK_KHR_wayland_surface",
-    khr_android_surface => b"VK_KHR_android_surface",
-    khr_win32_surface => b"VK_KHR_win32_surface",
-    ext_debug_utils => b"VK_EXT_debug_utils",
-    mvk_ios_surface => b"VK_MVK_ios_surface",
-    mvk_macos_surface => b"VK_MVK_macos_surface",
-    mvk_moltenvk => b"VK_MVK_moltenvk",     // TODO: confirm that it's an instance extension
-    nn_vi_surface => b"VK_NN_vi_surface",
-    ext_swapchain_colorspace => b"VK_EXT_swapchain_colorspace",
-    khr_get_physical_device_properties2 => b"VK_KHR_get_physical_device_properties2",
-    khr_get_surface_capabilities2 => b"VK_KHR_get_surface_capabilities2",
-}
+// The table is generated from the Vulkan registry by `autogen`.
+include!("../autogen/instance_extensions.rs");
 
 /// This helper type can only be instantiated inside this module.
 /// See `*Extensions::_unbuildable`.
@@ -170,11 +235,36 @@ pub struct Unbuildable(());
 
 #[cfg(test)]
 mod tests {
-    use crate::instance::{InstanceExtensions, RawInstanceExtensions};
+    use crate::instance::{InstanceExtensions, RawInstanceExtensions, Version};
 
     #[test]
     fn empty_extensions() {
         let i: RawInstanceExtensions = (&InstanceExtensions::none()).into();
         assert!(i.iter().next().is_none());
     }
+
+    #[test]
+    fn ensure_dependencies() {
+        let extensions = InstanceExtensions {
+            khr_external_fence_capabilities: true,
+            khr_xcb_surface: true,
+            ..InstanceExtensions::none()
+        };
+
+        assert_eq!(
+            extensions.ensure_dependencies(Version::V1_0),
+            InstanceExtensions {
+                khr_external_fence_capabilities: true,
+                khr_get_physical_device_properties2: true,
+                khr_xcb_surface: true,
+                khr_surface: true,
+                ..InstanceExtensions::none()
+            }
+        );
+        assert!(extensions.check_requirements(Version::V1_1).is_err());
+        assert!(extensions
+            .ensure_dependencies(Version::V1_1)
+            .check_requirements(Version::V1_1)
+            .is_ok());
+    }
 }
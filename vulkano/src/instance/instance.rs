@@ -8,12 +8,16 @@
 // according to those terms.
 
 use crate::check_errors;
+use crate::descriptor::descriptor::ShaderStages;
+use crate::device::{DeviceExtensions, RawDeviceExtensions};
+use crate::extensions::ExtensionRestrictionError;
 use crate::features::{Features, FeaturesFfi};
 use crate::instance::limits::Limits;
 use crate::instance::loader;
 use crate::instance::loader::FunctionPointers;
 use crate::instance::loader::Loader;
 use crate::instance::loader::LoadingError;
+use crate::instance::properties::Properties;
 use crate::instance::{InstanceExtensions, RawInstanceExtensions};
 use crate::sync::PipelineStage;
 use crate::version::Version;
@@ -23,6 +27,7 @@ use crate::OomError;
 use crate::VulkanObject;
 use smallvec::SmallVec;
 use std::borrow::Cow;
+use std::cmp;
 use std::error;
 use std::ffi::CStr;
 use std::ffi::CString;
@@ -95,7 +100,9 @@ pub struct Instance {
     instance: vk::Instance,
     //alloc: Option<Box<Alloc + Send + Sync>>,
     physical_devices: Vec<PhysicalDeviceInfos>,
+    physical_device_groups: Vec<PhysicalDeviceGroupInfos>,
     vk: vk::InstancePointers,
+    api_version: Version,
     extensions: RawInstanceExtensions,
     layers: SmallVec<[CString; 16]>,
     function_pointers: OwnedOrRef<FunctionPointers<Box<dyn Loader + Send + Sync>>>,
@@ -191,6 +198,14 @@ impl Instance {
             None => Some(&def),
         };
 
+        // The version of the instance is the lowest of the requested version and of the version
+        // supported by the loader.
+        let api_version = cmp::min(MAX_API_VERSION, function_pointers.api_version()?);
+
+        InstanceExtensions::from(&extensions)
+            .check_requirements(api_version)
+            .map_err(InstanceCreationError::ExtensionRestrictionNotMet)?;
+
         // Building the CStrings from the `str`s within `app_infos`.
         // They need to be created ahead of time, since we pass pointers to them.
         let app_infos_strings = if let Some(app_infos) = app_infos {
@@ -235,12 +250,7 @@ impl Instance {
                     .engine_version
                     .map(|v| v.into_vulkan_version())
                     .unwrap_or(0),
-                apiVersion: Version {
-                    major: 1,
-                    minor: 1,
-                    patch: 0,
-                }
-                .into_vulkan_version(), // TODO:
+                apiVersion: api_version.into_vulkan_version(),
             })
         } else {
             None
@@ -282,9 +292,14 @@ impl Instance {
 
         // Loading the function pointers of the newly-created instance.
         let vk = {
-            vk::InstancePointers::load(|name| unsafe {
-                mem::transmute(function_pointers.get_instance_proc_addr(instance, name.as_ptr()))
-            })
+            vk::InstancePointers::load_with_version(
+                api_version.into_vulkan_version(),
+                |name| unsafe {
+                    mem::transmute(
+                        function_pointers.get_instance_proc_addr(instance, name.as_ptr()),
+                    )
+                },
+            )
         };
 
         // Enumerating all physical devices.
@@ -298,25 +313,40 @@ impl Instance {
             devices
         };
 
-        let vk_khr_get_physical_device_properties2 =
-            CString::new(b"VK_KHR_get_physical_device_properties2".to_vec()).unwrap();
-
         // Getting the properties of all physical devices.
-        // If possible, we use VK_KHR_get_physical_device_properties2.
-        let physical_devices = if extensions
-            .iter()
-            .any(|v| *v == vk_khr_get_physical_device_properties2)
+        // If possible, we use VK_KHR_get_physical_device_properties2 or its core equivalent.
+        let physical_devices = if InstanceExtensions::from(&extensions)
+            .union(&InstanceExtensions::promoted_to_core(api_version))
+            .khr_get_physical_device_properties2
         {
-            Instance::init_physical_devices2(&vk, physical_devices, &extensions)
+            Instance::init_physical_devices2(&vk, physical_devices, api_version, &extensions)
         } else {
             Instance::init_physical_devices(&vk, physical_devices)
         };
 
+        // Enumerating the groups of physical devices. Without VK_KHR_device_group_creation or its
+        // core equivalent, each physical device is alone in its group.
+        let physical_device_groups = if InstanceExtensions::from(&extensions)
+            .union(&InstanceExtensions::promoted_to_core(api_version))
+            .khr_device_group_creation
+        {
+            Instance::init_physical_device_groups(&vk, instance, &physical_devices)?
+        } else {
+            (0..physical_devices.len())
+                .map(|index| PhysicalDeviceGroupInfos {
+                    devices: Some(index).into_iter().collect(),
+                    subset_allocation: false,
+                })
+                .collect()
+        };
+
         Ok(Arc::new(Instance {
             instance,
             //alloc: None,
             physical_devices,
+            physical_device_groups,
             vk,
+            api_version,
             extensions,
             layers,
             function_pointers,
@@ -363,6 +393,7 @@ impl Instance {
                 device,
                 properties,
                 extended_properties: PhysicalDeviceExtendedProperties::empty(),
+                device_properties: Properties::default(),
                 memory,
                 queue_families,
                 available_features: Features::from_vulkan_features(available_features),
@@ -371,22 +402,120 @@ impl Instance {
         output
     }
 
+    /// Enumerate the groups of physical devices with VK_KHR_device_group_creation
+    fn init_physical_device_groups(
+        vk: &vk::InstancePointers,
+        instance: vk::Instance,
+        physical_devices: &[PhysicalDeviceInfos],
+    ) -> Result<Vec<PhysicalDeviceGroupInfos>, InstanceCreationError> {
+        let groups = unsafe {
+            let mut num = 0;
+            check_errors(vk.EnumeratePhysicalDeviceGroupsKHR(
+                instance,
+                &mut num,
+                ptr::null_mut(),
+            ))?;
+
+            let mut groups = (0..num)
+                .map(|_| vk::PhysicalDeviceGroupProperties {
+                    sType: vk::STRUCTURE_TYPE_PHYSICAL_DEVICE_GROUP_PROPERTIES,
+                    pNext: ptr::null_mut(),
+                    physicalDeviceCount: 0,
+                    physicalDevices: [0; vk::MAX_DEVICE_GROUP_SIZE as usize],
+                    subsetAllocation: vk::FALSE,
+                })
+                .collect::<Vec<_>>();
+            check_errors(vk.EnumeratePhysicalDeviceGroupsKHR(
+                instance,
+                &mut num,
+                groups.as_mut_ptr(),
+            ))?;
+            groups.truncate(num as usize);
+            groups
+        };
+
+        Ok(groups
+            .iter()
+            .map(|group| PhysicalDeviceGroupInfos {
+                devices: group.physicalDevices[..group.physicalDeviceCount as usize]
+                    .iter()
+                    .map(|&handle| {
+                        physical_devices
+                            .iter()
+                            .position(|infos| infos.device == handle)
+                            .expect("physical device group contains an unknown physical device")
+                    })
+                    .collect(),
+                subset_allocation: group.subsetAllocation != vk::FALSE,
+            })
+            .collect())
+    }
+
     /// Initialize all physical devices, but use VK_KHR_get_physical_device_properties2
     /// TODO: Query extension-specific physical device properties, once a new instance extension is supported.
     fn init_physical_devices2(
         vk: &vk::InstancePointers,
         physical_devices: Vec<vk::PhysicalDevice>,
+        api_version: Version,
         extensions: &RawInstanceExtensions,
     ) -> Vec<PhysicalDeviceInfos> {
+        let instance_extensions = InstanceExtensions::from(extensions)
+            .union(&InstanceExtensions::promoted_to_core(api_version));
+
         let mut output = Vec::with_capacity(physical_devices.len());
 
         for device in physical_devices.into_iter() {
             let mut extended_properties = PhysicalDeviceExtendedProperties::empty();
 
+            // Extension-specific properties can only be chained if the extension is supported.
+            let device_extensions = unsafe {
+                let mut num = 0;
+                let mut properties = Vec::new();
+                if vk.EnumerateDeviceExtensionProperties(
+                    device,
+                    ptr::null(),
+                    &mut num,
+                    ptr::null_mut(),
+                ) == vk::SUCCESS
+                {
+                    properties.reserve(num as usize);
+                    if vk.EnumerateDeviceExtensionProperties(
+                        device,
+                        ptr::null(),
+                        &mut num,
+                        properties.as_mut_ptr(),
+                    ) == vk::SUCCESS
+                    {
+                        properties.set_len(num as usize);
+                    }
+                }
+                DeviceExtensions::from(&RawDeviceExtensions::new(properties.iter().map(
+                    |p: &vk::ExtensionProperties| {
+                        CStr::from_ptr(p.extensionName.as_ptr()).to_owned()
+                    },
+                )))
+            };
+            let subgroup_size_control_supported = device_extensions.ext_subgroup_size_control;
+
             let properties: vk::PhysicalDeviceProperties = unsafe {
+                let mut subgroup_size_control_properties =
+                    vk::PhysicalDeviceSubgroupSizeControlPropertiesEXT {
+                        sType:
+                            vk::STRUCTURE_TYPE_PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_PROPERTIES_EXT,
+                        pNext: ptr::null_mut(),
+                        minSubgroupSize: 0,
+                        maxSubgroupSize: 0,
+                        maxComputeWorkgroupSubgroups: 0,
+                        requiredSubgroupSizeStages: 0,
+                    };
+
                 let mut subgroup_properties = vk::PhysicalDeviceSubgroupProperties {
                     sType: vk::STRUCTURE_TYPE_PHYSICAL_DEVICE_SUBGROUP_PROPERTIES,
-                    pNext: ptr::null_mut(),
+                    pNext: if subgroup_size_control_supported {
+                        &mut subgroup_size_control_properties as *mut _ as *mut _
+                    } else {
+                        ptr::null_mut()
+                    },
                     subgroupSize: 0,
                     supportedStages: 0,
                     supportedOperations: 0,
@@ -395,7 +524,7 @@ impl Instance {
 
                 let mut output = vk::PhysicalDeviceProperties2KHR {
                     sType: vk::STRUCTURE_TYPE_PHYSICAL_DEVICE_PROPERTIES_2_KHR,
-                    pNext: &mut subgroup_properties,
+                    pNext: &mut subgroup_properties as *mut _ as *mut _,
                     properties: mem::zeroed(),
                 };
 
@@ -403,13 +532,50 @@ impl Instance {
 
                 extended_properties = PhysicalDeviceExtendedProperties {
                     subgroup_size: Some(subgroup_properties.subgroupSize),
+                    subgroup_supported_stages: Some(ShaderStages::from_vulkan_bits(
+                        subgroup_properties.supportedStages,
+                    )),
+                    subgroup_supported_operations: Some(SubgroupFeatures::from_vulkan_bits(
+                        subgroup_properties.supportedOperations,
+                    )),
+                    subgroup_quad_operations_in_all_stages: Some(
+                        subgroup_properties.quadOperationsInAllStages != 0,
+                    ),
 
                     ..extended_properties
                 };
 
+                if subgroup_size_control_supported {
+                    extended_properties = PhysicalDeviceExtendedProperties {
+                        min_subgroup_size: Some(subgroup_size_control_properties.minSubgroupSize),
+                        max_subgroup_size: Some(subgroup_size_control_properties.maxSubgroupSize),
+                        max_compute_workgroup_subgroups: Some(
+                            subgroup_size_control_properties.maxComputeWorkgroupSubgroups,
+                        ),
+                        required_subgroup_size_stages: Some(ShaderStages::from_vulkan_bits(
+                            subgroup_size_control_properties.requiredSubgroupSizeStages,
+                        )),
+
+                        ..extended_properties
+                    };
+                }
+
                 output.properties
             };
 
+            let device_properties = unsafe {
+                Properties::query(
+                    vk,
+                    device,
+                    cmp::min(
+                        api_version,
+                        Version::from_vulkan_version(properties.apiVersion),
+                    ),
+                    &device_extensions,
+                    &instance_extensions,
+                )
+            };
+
             let queue_families = unsafe {
                 let mut num = 0;
                 vk.GetPhysicalDeviceQueueFamilyProperties2KHR(device, &mut num, ptr::null_mut());
@@ -454,6 +620,7 @@ impl Instance {
                 device,
                 properties,
                 extended_properties,
+                device_properties,
                 memory,
                 queue_families,
                 available_features,
@@ -476,9 +643,19 @@ impl Instance {
         &self.vk
     }
 
+    /// Returns the version of the Vulkan API that the instance was created with.
+    ///
+    /// This is the lowest of the version requested by vulkano and of the version supported by
+    /// the loader.
+    #[inline]
+    pub fn api_version(&self) -> Version {
+        self.api_version
+    }
+
     /// Returns the list of extensions that have been loaded.
     ///
-    /// This list is equal to what was passed to `Instance::new()`.
+    /// This list contains what was passed to `Instance::new()`, plus the extensions that are
+    /// part of the core API of the instance's version.
     ///
     /// # Example
     ///
@@ -488,11 +665,12 @@ impl Instance {
     ///
     /// let extensions = InstanceExtensions::supported_by_core().unwrap();
     /// let instance = Instance::new(None, &extensions, None).unwrap();
-    /// assert_eq!(instance.loaded_extensions(), extensions);
+    /// assert_eq!(instance.loaded_extensions().intersection(&extensions), extensions);
     /// ```
     #[inline]
     pub fn loaded_extensions(&self) -> InstanceExtensions {
         InstanceExtensions::from(&self.extensions)
+            .union(&InstanceExtensions::promoted_to_core(self.api_version))
     }
 
     #[inline]
@@ -647,6 +825,9 @@ impl<'a> Default for ApplicationInfo<'a> {
     }
 }
 
+// The highest version of Vulkan that vulkano requests when creating an instance.
+const MAX_API_VERSION: Version = Version::V1_1;
+
 /// Error that can happen when creating an instance.
 #[derive(Clone, Debug)]
 pub enum InstanceCreationError {
@@ -660,6 +841,8 @@ pub enum InstanceCreationError {
     LayerNotPresent,
     /// One of the requested extensions is missing.
     ExtensionNotPresent,
+    /// One of the requested extensions is enabled without something it depends on.
+    ExtensionRestrictionNotMet(ExtensionRestrictionError),
     /// The version requested is not supported by the implementation.
     // TODO: more info about this once the question of the version has been resolved
     IncompatibleDriver,
@@ -671,6 +854,7 @@ impl error::Error for InstanceCreationError {
         match *self {
             InstanceCreationError::LoadingError(ref err) => Some(err),
             InstanceCreationError::OomError(ref err) => Some(err),
+            InstanceCreationError::ExtensionRestrictionNotMet(ref err) => Some(err),
             _ => None,
         }
     }
@@ -689,6 +873,8 @@ impl fmt::Display for InstanceCreationError {
                 InstanceCreationError::InitializationFailed => "initialization failed",
                 InstanceCreationError::LayerNotPresent => "layer not present",
                 InstanceCreationError::ExtensionNotPresent => "extension not present",
+                InstanceCreationError::ExtensionRestrictionNotMet(_) =>
+                    "an extension is enabled without something it depends on",
                 InstanceCreationError::IncompatibleDriver => "incompatible driver",
             }
         )
@@ -728,11 +914,18 @@ struct PhysicalDeviceInfos {
     device: vk::PhysicalDevice,
     properties: vk::PhysicalDeviceProperties,
     extended_properties: PhysicalDeviceExtendedProperties,
+    device_properties: Properties,
     queue_families: Vec<vk::QueueFamilyProperties>,
     memory: vk::PhysicalDeviceMemoryProperties,
     available_features: Features,
 }
 
+struct PhysicalDeviceGroupInfos {
+    // Indices of the physical devices of the group.
+    devices: SmallVec<[usize; 4]>,
+    subset_allocation: bool,
+}
+
 /// Represents additional information related to Physical Devices fetched from
 /// `vkGetPhysicalDeviceProperties` call. Certain features available only when
 /// appropriate `Instance` extensions enabled. The core extension required
@@ -741,12 +934,26 @@ struct PhysicalDeviceInfos {
 /// TODO: Only a small subset of available properties(https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceProperties2.html) is implemented at this moment.
 pub struct PhysicalDeviceExtendedProperties {
     subgroup_size: Option<u32>,
+    subgroup_supported_stages: Option<ShaderStages>,
+    subgroup_supported_operations: Option<SubgroupFeatures>,
+    subgroup_quad_operations_in_all_stages: Option<bool>,
+    min_subgroup_size: Option<u32>,
+    max_subgroup_size: Option<u32>,
+    max_compute_workgroup_subgroups: Option<u32>,
+    required_subgroup_size_stages: Option<ShaderStages>,
 }
 
 impl PhysicalDeviceExtendedProperties {
     fn empty() -> Self {
         Self {
             subgroup_size: None,
+            subgroup_supported_stages: None,
+            subgroup_supported_operations: None,
+            subgroup_quad_operations_in_all_stages: None,
+            min_subgroup_size: None,
+            max_subgroup_size: None,
+            max_compute_workgroup_subgroups: None,
+            required_subgroup_size_stages: None,
         }
     }
 
@@ -757,6 +964,105 @@ impl PhysicalDeviceExtendedProperties {
     pub fn subgroup_size(&self) -> &Option<u32> {
         &self.subgroup_size
     }
+
+    /// The shader stages in which subgroup operations are supported.
+    ///
+    /// See https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceSubgroupProperties.html for details
+    #[inline]
+    pub fn subgroup_supported_stages(&self) -> &Option<ShaderStages> {
+        &self.subgroup_supported_stages
+    }
+
+    /// The subgroup operations that are supported in the stages returned by
+    /// `subgroup_supported_stages`.
+    ///
+    /// See https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceSubgroupProperties.html for details
+    #[inline]
+    pub fn subgroup_supported_operations(&self) -> &Option<SubgroupFeatures> {
+        &self.subgroup_supported_operations
+    }
+
+    /// Whether quad subgroup operations are supported in all stages, or only in the fragment
+    /// and compute stages.
+    ///
+    /// See https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceSubgroupProperties.html for details
+    #[inline]
+    pub fn subgroup_quad_operations_in_all_stages(&self) -> &Option<bool> {
+        &self.subgroup_quad_operations_in_all_stages
+    }
+
+    /// The minimum subgroup size that can be required for a shader stage.
+    ///
+    /// Only available if the physical device supports the `ext_subgroup_size_control` extension.
+    /// See https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceSubgroupSizeControlPropertiesEXT.html for details
+    #[inline]
+    pub fn min_subgroup_size(&self) -> &Option<u32> {
+        &self.min_subgroup_size
+    }
+
+    /// The maximum subgroup size that can be required for a shader stage.
+    ///
+    /// Only available if the physical device supports the `ext_subgroup_size_control` extension.
+    /// See https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceSubgroupSizeControlPropertiesEXT.html for details
+    #[inline]
+    pub fn max_subgroup_size(&self) -> &Option<u32> {
+        &self.max_subgroup_size
+    }
+
+    /// The maximum number of subgroups in a compute workgroup.
+    ///
+    /// Only available if the physical device supports the `ext_subgroup_size_control` extension.
+    /// See https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceSubgroupSizeControlPropertiesEXT.html for details
+    #[inline]
+    pub fn max_compute_workgroup_subgroups(&self) -> &Option<u32> {
+        &self.max_compute_workgroup_subgroups
+    }
+
+    /// The shader stages for which a subgroup size can be required.
+    ///
+    /// Only available if the physical device supports the `ext_subgroup_size_control` extension.
+    /// See https://www.khronos.org/registry/vulkan/specs/1.2-extensions/man/html/VkPhysicalDeviceSubgroupSizeControlPropertiesEXT.html for details
+    #[inline]
+    pub fn required_subgroup_size_stages(&self) -> &Option<ShaderStages> {
+        &self.required_subgroup_size_stages
+    }
+}
+
+/// Describes which subgroup operations are supported by a physical device.
+///
+/// Each field corresponds to a `GroupNonUniform*` SPIR-V capability.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct SubgroupFeatures {
+    pub basic: bool,
+    pub vote: bool,
+    pub arithmetic: bool,
+    pub ballot: bool,
+    pub shuffle: bool,
+    pub shuffle_relative: bool,
+    pub clustered: bool,
+    pub quad: bool,
+}
+
+impl SubgroupFeatures {
+    /// Builds a `SubgroupFeatures` with all values set to false.
+    #[inline]
+    pub fn none() -> SubgroupFeatures {
+        SubgroupFeatures::default()
+    }
+
+    #[inline]
+    pub(crate) fn from_vulkan_bits(val: vk::SubgroupFeatureFlags) -> SubgroupFeatures {
+        SubgroupFeatures {
+            basic: (val & vk::SUBGROUP_FEATURE_BASIC_BIT) != 0,
+            vote: (val & vk::SUBGROUP_FEATURE_VOTE_BIT) != 0,
+            arithmetic: (val & vk::SUBGROUP_FEATURE_ARITHMETIC_BIT) != 0,
+            ballot: (val & vk::SUBGROUP_FEATURE_BALLOT_BIT) != 0,
+            shuffle: (val & vk::SUBGROUP_FEATURE_SHUFFLE_BIT) != 0,
+            shuffle_relative: (val & vk::SUBGROUP_FEATURE_SHUFFLE_RELATIVE_BIT) != 0,
+            clustered: (val & vk::SUBGROUP_FEATURE_CLUSTERED_BIT) != 0,
+            quad: (val & vk::SUBGROUP_FEATURE_QUAD_BIT) != 0,
+        }
+    }
 }
 
 /// Represents one of the available devices on this machine.
@@ -1026,6 +1332,16 @@ impl<'a> PhysicalDevice<'a> {
         &self.infos().extended_properties
     }
 
+    /// Returns the properties of the physical device that were added by Vulkan 1.1 and 1.2.
+    ///
+    /// The properties are only available if the instance was created with Vulkan 1.1 or with
+    /// `VK_KHR_get_physical_device_properties2`, and if the physical device supports the version
+    /// or the extension that provides them.
+    #[inline]
+    pub fn properties(&self) -> &Properties {
+        &self.infos().device_properties
+    }
+
     // Internal function to make it easier to get the infos of this device.
     #[inline]
     fn infos(&self) -> &'a PhysicalDeviceInfos {
@@ -1078,6 +1394,115 @@ impl<'a> Iterator for PhysicalDevicesIter<'a> {
 
 impl<'a> ExactSizeIterator for PhysicalDevicesIter<'a> {}
 
+/// A group of physical devices that can be used together by a single `Device`.
+///
+/// Physical devices of the same group are usually GPUs of the same model that are linked
+/// together, so that they can access each other's memory. If the instance doesn't support the
+/// `khr_device_group_creation` extension or Vulkan 1.1, each physical device is alone in its
+/// group.
+///
+/// # Example
+///
+/// ```no_run
+/// # use vulkano::instance::Instance;
+/// # use vulkano::instance::InstanceExtensions;
+/// use vulkano::instance::PhysicalDeviceGroup;
+///
+/// # let instance = Instance::new(None, &InstanceExtensions::none(), None).unwrap();
+/// for group in PhysicalDeviceGroup::enumerate(&instance) {
+///     let names: Vec<_> = group.physical_devices().map(|p| p.name().to_owned()).collect();
+///     println!("Device group: {:?}", names);
+/// }
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct PhysicalDeviceGroup<'a> {
+    instance: &'a Arc<Instance>,
+    group: usize,
+}
+
+impl<'a> PhysicalDeviceGroup<'a> {
+    /// Returns an iterator that enumerates the groups of physical devices available.
+    #[inline]
+    pub fn enumerate(instance: &'a Arc<Instance>) -> PhysicalDeviceGroupsIter<'a> {
+        PhysicalDeviceGroupsIter {
+            instance,
+            current_id: 0,
+        }
+    }
+
+    /// Returns the instance corresponding to this group.
+    #[inline]
+    pub fn instance(&self) -> &'a Arc<Instance> {
+        &self.instance
+    }
+
+    /// Returns the index of the group in the groups list.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.group
+    }
+
+    /// Returns the physical devices of the group.
+    ///
+    /// The position of a physical device in this list is its *device index* in the group. Bit
+    /// `n` of a device mask designates the physical device with device index `n`.
+    #[inline]
+    pub fn physical_devices(&self) -> impl ExactSizeIterator<Item = PhysicalDevice<'a>> {
+        let instance = self.instance;
+        self.infos()
+            .devices
+            .iter()
+            .map(move |&device| PhysicalDevice { instance, device })
+    }
+
+    /// Returns true if memory can be allocated on a subset of the physical devices of the group.
+    /// Otherwise, device masks passed when allocating memory must include all the physical
+    /// devices.
+    #[inline]
+    pub fn supports_subset_allocation(&self) -> bool {
+        self.infos().subset_allocation
+    }
+
+    #[inline]
+    fn infos(&self) -> &'a PhysicalDeviceGroupInfos {
+        &self.instance.physical_device_groups[self.group]
+    }
+}
+
+/// Iterator for all the groups of physical devices.
+#[derive(Debug, Clone)]
+pub struct PhysicalDeviceGroupsIter<'a> {
+    instance: &'a Arc<Instance>,
+    current_id: usize,
+}
+
+impl<'a> Iterator for PhysicalDeviceGroupsIter<'a> {
+    type Item = PhysicalDeviceGroup<'a>;
+
+    #[inline]
+    fn next(&mut self) -> Option<PhysicalDeviceGroup<'a>> {
+        if self.current_id >= self.instance.physical_device_groups.len() {
+            return None;
+        }
+
+        let group = PhysicalDeviceGroup {
+            instance: self.instance,
+            group: self.current_id,
+        };
+
+        self.current_id += 1;
+        Some(group)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.instance.physical_device_groups.len() - self.current_id;
+        (len, Some(len))
+    }
+}
+
+impl<'a> ExactSizeIterator for PhysicalDeviceGroupsIter<'a> {}
+
 /// Type of a physical device.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
 #[repr(u32)]
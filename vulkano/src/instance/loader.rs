@@ -21,7 +21,10 @@
 //! By default vulkano will use the `auto_loader()` function, which tries to automatically load
 //! a Vulkan implementation from the system.
 
+use crate::check_errors;
+use crate::version::Version;
 use crate::vk;
+use crate::OomError;
 use crate::SafeDeref;
 use lazy_static::lazy_static;
 use shared_library;
@@ -149,6 +152,35 @@ impl<L> FunctionPointers<L> {
     {
         self.loader.get_instance_proc_addr(instance, name)
     }
+
+    /// Returns the highest version of Vulkan that the loader supports for instances.
+    ///
+    /// This is version 1.0 if the loader doesn't provide `vkEnumerateInstanceVersion`.
+    pub fn api_version(&self) -> Result<Version, OomError>
+    where
+        L: Loader,
+    {
+        unsafe {
+            let name = b"vkEnumerateInstanceVersion\0";
+            let ptr: *const c_void = mem::transmute(
+                self.loader
+                    .get_instance_proc_addr(0, name.as_ptr() as *const c_char),
+            );
+            if ptr.is_null() {
+                return Ok(Version {
+                    major: 1,
+                    minor: 0,
+                    patch: 0,
+                });
+            }
+
+            let enumerate_instance_version: extern "system" fn(*mut u32) -> vk::Result =
+                mem::transmute(ptr);
+            let mut version = 0;
+            check_errors(enumerate_instance_version(&mut version))?;
+            Ok(Version::from_vulkan_version(version))
+        }
+    }
 }
 
 /// Expression that returns a loader that assumes that Vulkan is linked to the executable you're
@@ -116,16 +116,28 @@ pub use self::instance::MemoryHeapsIter;
 pub use self::instance::MemoryType;
 pub use self::instance::MemoryTypesIter;
 pub use self::instance::PhysicalDevice;
+pub use self::instance::PhysicalDeviceExtendedProperties;
+pub use self::instance::PhysicalDeviceGroup;
+pub use self::instance::PhysicalDeviceGroupsIter;
 pub use self::instance::PhysicalDeviceType;
 pub use self::instance::PhysicalDevicesIter;
 pub use self::instance::QueueFamiliesIter;
 pub use self::instance::QueueFamily;
+pub use self::instance::SubgroupFeatures;
 pub use self::layers::layers_list;
 pub use self::layers::LayerProperties;
 pub use self::layers::LayersIterator;
 pub use self::layers::LayersListError;
 pub use self::limits::Limits;
 pub use self::loader::LoadingError;
+pub use self::properties::ConformanceVersion;
+pub use self::properties::DriverId;
+pub use self::properties::PointClippingBehavior;
+pub use self::properties::Properties;
+pub use self::properties::ResolveModes;
+pub use self::properties::ShaderFloatControlsIndependence;
+pub use crate::extensions::ExtensionRequirement;
+pub use crate::extensions::ExtensionRestrictionError;
 pub use crate::version::Version;
 
 pub mod debug;
@@ -135,3 +147,4 @@ mod extensions;
 mod instance;
 mod layers;
 mod limits;
+mod properties;
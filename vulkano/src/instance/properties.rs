@@ -0,0 +1,749 @@
+// Copyright (c) 2021 The vulkano developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>,
+// at your option. All files in the project carrying such
+// notice may not be copied, modified, or distributed except
+// according to those terms.
+
+//! The `Properties` struct provides the properties of a physical device that were added by
+//! Vulkan 1.1 and 1.2, and by some extensions.
+//!
+//! These properties are queried with `vkGetPhysicalDeviceProperties2`, by chaining the structs of
+//! the Vulkan version supported by the physical device or, for older versions, the structs of the
+//! extensions that were promoted to it.
+
+use crate::descriptor::descriptor::ShaderStages;
+use crate::device::DeviceExtensions;
+use crate::instance::InstanceExtensions;
+use crate::instance::SubgroupFeatures;
+use crate::instance::Version;
+use crate::vk;
+use std::ffi::CStr;
+use std::mem;
+use std::os::raw::c_void;
+use std::ptr;
+
+/// Properties of a physical device that were added by Vulkan 1.1 and 1.2, or by the extensions
+/// they were promoted from, and properties of some extensions that aren't part of Vulkan.
+///
+/// Each property is `None` if neither the version of Vulkan nor the extensions supported by the
+/// physical device provide it, or if the instance wasn't able to query it. Querying requires
+/// `VK_KHR_get_physical_device_properties2` or an instance of Vulkan 1.1 or later.
+#[derive(Clone, Debug, Default)]
+pub struct Properties {
+    /// Universally unique identifier of the physical device.
+    pub device_uuid: Option<[u8; 16]>,
+    /// Universally unique identifier of the version of the driver.
+    pub driver_uuid: Option<[u8; 16]>,
+    /// Locally unique identifier of the physical device, on Windows.
+    pub device_luid: Option<[u8; 8]>,
+    /// Node of the physical device in a linked device adapter, on Windows.
+    pub device_node_mask: Option<u32>,
+
+    /// The default number of invocations in each subgroup.
+    pub subgroup_size: Option<u32>,
+    /// The shader stages in which subgroup operations are supported.
+    pub subgroup_supported_stages: Option<ShaderStages>,
+    /// The subgroup operations that are supported.
+    pub subgroup_supported_operations: Option<SubgroupFeatures>,
+    /// Whether quad subgroup operations are supported in all stages, or only in the fragment
+    /// and compute stages.
+    pub subgroup_quad_operations_in_all_stages: Option<bool>,
+
+    /// How points are clipped.
+    pub point_clipping_behavior: Option<PointClippingBehavior>,
+    /// The maximum number of views in a subpass with multiview enabled.
+    pub max_multiview_view_count: Option<u32>,
+    /// The maximum instance index of a draw in a subpass with multiview enabled.
+    pub max_multiview_instance_index: Option<u32>,
+    /// Whether protected memory accesses that are not allowed return undefined values instead of
+    /// resulting in a device loss.
+    pub protected_no_fault: Option<bool>,
+    /// The maximum number of descriptors in a single descriptor set.
+    pub max_per_set_descriptors: Option<u32>,
+    /// The maximum size of a single memory allocation.
+    pub max_memory_allocation_size: Option<u64>,
+
+    /// The driver of the physical device.
+    pub driver_id: Option<DriverId>,
+    /// The name of the driver.
+    pub driver_name: Option<String>,
+    /// Additional information about the driver, such as its version.
+    pub driver_info: Option<String>,
+    /// The version of the Vulkan conformance test suite that the driver passed.
+    pub conformance_version: Option<ConformanceVersion>,
+
+    /// How the denormal behaviors of floating-point types can be set independently.
+    pub denorm_behavior_independence: Option<ShaderFloatControlsIndependence>,
+    /// How the rounding modes of floating-point types can be set independently.
+    pub rounding_mode_independence: Option<ShaderFloatControlsIndependence>,
+    pub shader_signed_zero_inf_nan_preserve_float16: Option<bool>,
+    pub shader_signed_zero_inf_nan_preserve_float32: Option<bool>,
+    pub shader_signed_zero_inf_nan_preserve_float64: Option<bool>,
+    pub shader_denorm_preserve_float16: Option<bool>,
+    pub shader_denorm_preserve_float32: Option<bool>,
+    pub shader_denorm_preserve_float64: Option<bool>,
+    pub shader_denorm_flush_to_zero_float16: Option<bool>,
+    pub shader_denorm_flush_to_zero_float32: Option<bool>,
+    pub shader_denorm_flush_to_zero_float64: Option<bool>,
+    pub shader_rounding_mode_rte_float16: Option<bool>,
+    pub shader_rounding_mode_rte_float32: Option<bool>,
+    pub shader_rounding_mode_rte_float64: Option<bool>,
+    pub shader_rounding_mode_rtz_float16: Option<bool>,
+    pub shader_rounding_mode_rtz_float32: Option<bool>,
+    pub shader_rounding_mode_rtz_float64: Option<bool>,
+
+    /// The maximum number of descriptors that can be created in all the pools created with
+    /// update-after-bind enabled.
+    pub max_update_after_bind_descriptors_in_all_pools: Option<u32>,
+    pub shader_uniform_buffer_array_non_uniform_indexing_native: Option<bool>,
+    pub shader_sampled_image_array_non_uniform_indexing_native: Option<bool>,
+    pub shader_storage_buffer_array_non_uniform_indexing_native: Option<bool>,
+    pub shader_storage_image_array_non_uniform_indexing_native: Option<bool>,
+    pub shader_input_attachment_array_non_uniform_indexing_native: Option<bool>,
+    pub robust_buffer_access_update_after_bind: Option<bool>,
+    pub quad_divergent_implicit_lod: Option<bool>,
+    pub max_per_stage_descriptor_update_after_bind_samplers: Option<u32>,
+    pub max_per_stage_descriptor_update_after_bind_uniform_buffers: Option<u32>,
+    pub max_per_stage_descriptor_update_after_bind_storage_buffers: Option<u32>,
+    pub max_per_stage_descriptor_update_after_bind_sampled_images: Option<u32>,
+    pub max_per_stage_descriptor_update_after_bind_storage_images: Option<u32>,
+    pub max_per_stage_descriptor_update_after_bind_input_attachments: Option<u32>,
+    pub max_per_stage_update_after_bind_resources: Option<u32>,
+    pub max_descriptor_set_update_after_bind_samplers: Option<u32>,
+    pub max_descriptor_set_update_after_bind_uniform_buffers: Option<u32>,
+    pub max_descriptor_set_update_after_bind_uniform_buffers_dynamic: Option<u32>,
+    pub max_descriptor_set_update_after_bind_storage_buffers: Option<u32>,
+    pub max_descriptor_set_update_after_bind_storage_buffers_dynamic: Option<u32>,
+    pub max_descriptor_set_update_after_bind_sampled_images: Option<u32>,
+    pub max_descriptor_set_update_after_bind_storage_images: Option<u32>,
+    pub max_descriptor_set_update_after_bind_input_attachments: Option<u32>,
+
+    /// The modes that are supported to resolve depth attachments.
+    pub supported_depth_resolve_modes: Option<ResolveModes>,
+    /// The modes that are supported to resolve stencil attachments.
+    pub supported_stencil_resolve_modes: Option<ResolveModes>,
+    /// Whether the depth and stencil resolve modes can be different if one of them is none.
+    pub independent_resolve_none: Option<bool>,
+    /// Whether the depth and stencil resolve modes can be any combination of supported modes.
+    pub independent_resolve: Option<bool>,
+
+    /// Whether min/max filtering is supported for all single-component formats that support
+    /// linear filtering, instead of a minimum set of formats.
+    pub filter_minmax_single_component_formats: Option<bool>,
+    /// Whether the component mapping of an image view is applied with min/max filtering.
+    pub filter_minmax_image_component_mapping: Option<bool>,
+
+    /// The maximum difference between the current value of a timeline semaphore and the value
+    /// of any pending signal or wait operation.
+    pub max_timeline_semaphore_value_difference: Option<u64>,
+
+    /// The sample counts supported for color attachments with an integer format.
+    pub framebuffer_integer_color_sample_counts: Option<u32>, // FIXME: SampleCountFlag
+
+    /// The maximum number of workgroups of a mesh tasks draw with `VK_NV_mesh_shader`.
+    pub max_draw_mesh_tasks_count: Option<u32>,
+    /// The maximum total number of task shader workgroups of a mesh tasks draw with
+    /// `VK_EXT_mesh_shader`.
+    pub max_task_work_group_total_count: Option<u32>,
+    /// The maximum number of task shader workgroups of a mesh tasks draw in each dimension, with
+    /// `VK_EXT_mesh_shader`.
+    pub max_task_work_group_count: Option<[u32; 3]>,
+    /// The maximum total number of mesh shader workgroups of a mesh tasks draw without a task
+    /// shader, with `VK_EXT_mesh_shader`.
+    pub max_mesh_work_group_total_count: Option<u32>,
+    /// The maximum number of mesh shader workgroups of a mesh tasks draw without a task shader in
+    /// each dimension, with `VK_EXT_mesh_shader`.
+    pub max_mesh_work_group_count: Option<[u32; 3]>,
+}
+
+// Copies the fields that have the same names in the Vulkan 1.2 struct and in the structs of the
+// extensions it was promoted from.
+macro_rules! copy_driver_properties {
+    ($out:ident, $src:expr) => {
+        $out.driver_id = Some(DriverId::from_vulkan($src.driverID));
+        $out.driver_name = Some(c_str_to_string(&$src.driverName));
+        $out.driver_info = Some(c_str_to_string(&$src.driverInfo));
+        $out.conformance_version = Some(ConformanceVersion {
+            major: $src.conformanceVersion.major,
+            minor: $src.conformanceVersion.minor,
+            subminor: $src.conformanceVersion.subminor,
+            patch: $src.conformanceVersion.patch,
+        });
+    };
+}
+
+macro_rules! copy_fields {
+    ($out:ident, $src:expr, bool { $($field:ident => $vk:ident,)* }) => {
+        $($out.$field = Some($src.$vk != 0);)*
+    };
+    ($out:ident, $src:expr, value { $($field:ident => $vk:ident,)* }) => {
+        $($out.$field = Some($src.$vk);)*
+    };
+}
+
+macro_rules! copy_float_controls {
+    ($out:ident, $src:expr) => {
+        $out.denorm_behavior_independence = Some(ShaderFloatControlsIndependence::from_vulkan(
+            $src.denormBehaviorIndependence,
+        ));
+        $out.rounding_mode_independence = Some(ShaderFloatControlsIndependence::from_vulkan(
+            $src.roundingModeIndependence,
+        ));
+        copy_fields!($out, $src, bool {
+            shader_signed_zero_inf_nan_preserve_float16 => shaderSignedZeroInfNanPreserveFloat16,
+            shader_signed_zero_inf_nan_preserve_float32 => shaderSignedZeroInfNanPreserveFloat32,
+            shader_signed_zero_inf_nan_preserve_float64 => shaderSignedZeroInfNanPreserveFloat64,
+            shader_denorm_preserve_float16 => shaderDenormPreserveFloat16,
+            shader_denorm_preserve_float32 => shaderDenormPreserveFloat32,
+            shader_denorm_preserve_float64 => shaderDenormPreserveFloat64,
+            shader_denorm_flush_to_zero_float16 => shaderDenormFlushToZeroFloat16,
+            shader_denorm_flush_to_zero_float32 => shaderDenormFlushToZeroFloat32,
+            shader_denorm_flush_to_zero_float64 => shaderDenormFlushToZeroFloat64,
+            shader_rounding_mode_rte_float16 => shaderRoundingModeRTEFloat16,
+            shader_rounding_mode_rte_float32 => shaderRoundingModeRTEFloat32,
+            shader_rounding_mode_rte_float64 => shaderRoundingModeRTEFloat64,
+            shader_rounding_mode_rtz_float16 => shaderRoundingModeRTZFloat16,
+            shader_rounding_mode_rtz_float32 => shaderRoundingModeRTZFloat32,
+            shader_rounding_mode_rtz_float64 => shaderRoundingModeRTZFloat64,
+        });
+    };
+}
+
+macro_rules! copy_descriptor_indexing {
+    ($out:ident, $src:expr) => {
+        copy_fields!($out, $src, bool {
+            shader_uniform_buffer_array_non_uniform_indexing_native =>
+                shaderUniformBufferArrayNonUniformIndexingNative,
+            shader_sampled_image_array_non_uniform_indexing_native =>
+                shaderSampledImageArrayNonUniformIndexingNative,
+            shader_storage_buffer_array_non_uniform_indexing_native =>
+                shaderStorageBufferArrayNonUniformIndexingNative,
+            shader_storage_image_array_non_uniform_indexing_native =>
+                shaderStorageImageArrayNonUniformIndexingNative,
+            shader_input_attachment_array_non_uniform_indexing_native =>
+                shaderInputAttachmentArrayNonUniformIndexingNative,
+            robust_buffer_access_update_after_bind => robustBufferAccessUpdateAfterBind,
+            quad_divergent_implicit_lod => quadDivergentImplicitLod,
+        });
+        copy_fields!($out, $src, value {
+            max_update_after_bind_descriptors_in_all_pools =>
+                maxUpdateAfterBindDescriptorsInAllPools,
+            max_per_stage_descriptor_update_after_bind_samplers =>
+                maxPerStageDescriptorUpdateAfterBindSamplers,
+            max_per_stage_descriptor_update_after_bind_uniform_buffers =>
+                maxPerStageDescriptorUpdateAfterBindUniformBuffers,
+            max_per_stage_descriptor_update_after_bind_storage_buffers =>
+                maxPerStageDescriptorUpdateAfterBindStorageBuffers,
+            max_per_stage_descriptor_update_after_bind_sampled_images =>
+                maxPerStageDescriptorUpdateAfterBindSampledImages,
+            max_per_stage_descriptor_update_after_bind_storage_images =>
+                maxPerStageDescriptorUpdateAfterBindStorageImages,
+            max_per_stage_descriptor_update_after_bind_input_attachments =>
+                maxPerStageDescriptorUpdateAfterBindInputAttachments,
+            max_per_stage_update_after_bind_resources => maxPerStageUpdateAfterBindResources,
+            max_descriptor_set_update_after_bind_samplers =>
+                maxDescriptorSetUpdateAfterBindSamplers,
+            max_descriptor_set_update_after_bind_uniform_buffers =>
+                maxDescriptorSetUpdateAfterBindUniformBuffers,
+            max_descriptor_set_update_after_bind_uniform_buffers_dynamic =>
+                maxDescriptorSetUpdateAfterBindUniformBuffersDynamic,
+            max_descriptor_set_update_after_bind_storage_buffers =>
+                maxDescriptorSetUpdateAfterBindStorageBuffers,
+            max_descriptor_set_update_after_bind_storage_buffers_dynamic =>
+                maxDescriptorSetUpdateAfterBindStorageBuffersDynamic,
+            max_descriptor_set_update_after_bind_sampled_images =>
+                maxDescriptorSetUpdateAfterBindSampledImages,
+            max_descriptor_set_update_after_bind_storage_images =>
+                maxDescriptorSetUpdateAfterBindStorageImages,
+            max_descriptor_set_update_after_bind_input_attachments =>
+                maxDescriptorSetUpdateAfterBindInputAttachments,
+        });
+    };
+}
+
+macro_rules! copy_depth_stencil_resolve {
+    ($out:ident, $src:expr) => {
+        $out.supported_depth_resolve_modes =
+            Some(ResolveModes::from_vulkan_bits($src.supportedDepthResolveModes));
+        $out.supported_stencil_resolve_modes =
+            Some(ResolveModes::from_vulkan_bits($src.supportedStencilResolveModes));
+        copy_fields!($out, $src, bool {
+            independent_resolve_none => independentResolveNone,
+            independent_resolve => independentResolve,
+        });
+    };
+}
+
+impl Properties {
+    /// Queries the properties of `physical_device`.
+    ///
+    /// `api_version` is the version of Vulkan that both the instance and the physical device
+    /// support, and `device_extensions` are the extensions supported by the physical device.
+    ///
+    /// # Safety
+    ///
+    /// - `vk.GetPhysicalDeviceProperties2KHR` must be available, either through
+    ///   `VK_KHR_get_physical_device_properties2` or through Vulkan 1.1.
+    ///
+    pub(crate) unsafe fn query(
+        vk: &vk::InstancePointers,
+        physical_device: vk::PhysicalDevice,
+        api_version: Version,
+        device_extensions: &DeviceExtensions,
+        instance_extensions: &InstanceExtensions,
+    ) -> Properties {
+        let mut vulkan11: vk::PhysicalDeviceVulkan11Properties = mem::zeroed();
+        let mut vulkan12: vk::PhysicalDeviceVulkan12Properties = mem::zeroed();
+        let mut id: vk::PhysicalDeviceIDProperties = mem::zeroed();
+        let mut subgroup: vk::PhysicalDeviceSubgroupProperties = mem::zeroed();
+        let mut point_clipping: vk::PhysicalDevicePointClippingProperties = mem::zeroed();
+        let mut multiview: vk::PhysicalDeviceMultiviewProperties = mem::zeroed();
+        let mut protected_memory: vk::PhysicalDeviceProtectedMemoryProperties = mem::zeroed();
+        let mut maintenance3: vk::PhysicalDeviceMaintenance3Properties = mem::zeroed();
+        let mut driver: vk::PhysicalDeviceDriverProperties = mem::zeroed();
+        let mut float_controls: vk::PhysicalDeviceFloatControlsProperties = mem::zeroed();
+        let mut descriptor_indexing: vk::PhysicalDeviceDescriptorIndexingProperties = mem::zeroed();
+        let mut depth_stencil_resolve: vk::PhysicalDeviceDepthStencilResolveProperties =
+            mem::zeroed();
+        let mut filter_minmax: vk::PhysicalDeviceSamplerFilterMinmaxProperties = mem::zeroed();
+        let mut timeline_semaphore: vk::PhysicalDeviceTimelineSemaphoreProperties = mem::zeroed();
+        let mut mesh_shader_nv: vk::PhysicalDeviceMeshShaderPropertiesNV = mem::zeroed();
+        let mut mesh_shader_ext: vk::PhysicalDeviceMeshShaderPropertiesEXT = mem::zeroed();
+
+        // Chains `$s` in front of the structs that were chained before. Evaluates to true.
+        let mut next: *mut c_void = ptr::null_mut();
+        macro_rules! chain {
+            ($s:ident, $ty:expr) => {{
+                $s.sType = $ty;
+                $s.pNext = next as _;
+                next = &mut $s as *mut _ as *mut c_void;
+                true
+            }};
+        }
+
+        let core11 = api_version >= Version::V1_1;
+        let core12 = api_version >= Version::V1_2;
+
+        // With Vulkan 1.2, the properties of Vulkan 1.1 and 1.2 are grouped in two structs.
+        // Before that, each of them belongs to the struct of the extension that added it.
+        let has_vulkan11 = core12
+            && chain!(
+                vulkan11,
+                vk::STRUCTURE_TYPE_PHYSICAL_DEVICE_VULKAN_1_1_PROPERTIES
+            );
+        let has_vulkan12 = core12
+            && chain!(
+                vulkan12,
+                vk::STRUCTURE_TYPE_PHYSICAL_DEVICE_VULKAN_1_2_PROPERTIES
+            );
+
+        let has_id = !core12
+            && (core11
+                || instance_extensions.khr_external_memory_capabilities
+                || instance_extensions.khr_external_semaphore_capabilities
+                || instance_extensions.khr_external_fence_capabilities)
+            && chain!(id, vk::STRUCTURE_TYPE_PHYSICAL_DEVICE_ID_PROPERTIES);
+        let has_subgroup = !core12
+            && core11
+            && chain!(
+                subgroup,
+                vk::STRUCTURE_TYPE_PHYSICAL_DEVICE_SUBGROUP_PROPERTIES
+            );
+        let has_point_clipping = !core12
+            && (core11 || device_extensions.khr_maintenance2)
+            && chain!(
+                point_clipping,
+                vk::STRUCTURE_TYPE_PHYSICAL_DEVICE_POINT_CLIPPING_PROPERTIES
+            );
+        let has_multiview = !core12
+            && (core11 || device_extensions.khr_multiview)
+            && chain!(
+                multiview,
+                vk::STRUCTURE_TYPE_PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES
+            );
+        let has_protected_memory = !core12
+            && core11
+            && chain!(
+                protected_memory,
+                vk::STRUCTURE_TYPE_PHYSICAL_DEVICE_PROTECTED_MEMORY_PROPERTIES
+            );
+        let has_maintenance3 = !core12
+            && (core11 || device_extensions.khr_maintenance3)
+            && chain!(
+                maintenance3,
+                vk::STRUCTURE_TYPE_PHYSICAL_DEVICE_MAINTENANCE_3_PROPERTIES
+            );
+
+        let has_driver = !core12
+            && device_extensions.khr_driver_properties
+            && chain!(driver, vk::STRUCTURE_TYPE_PHYSICAL_DEVICE_DRIVER_PROPERTIES);
+        let has_float_controls = !core12
+            && device_extensions.khr_shader_float_controls
+            && chain!(
+                float_controls,
+                vk::STRUCTURE_TYPE_PHYSICAL_DEVICE_FLOAT_CONTROLS_PROPERTIES
+            );
+        let has_descriptor_indexing = !core12
+            && device_extensions.ext_descriptor_indexing
+            && chain!(
+                descriptor_indexing,
+                vk::STRUCTURE_TYPE_PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_PROPERTIES
+            );
+        let has_depth_stencil_resolve = !core12
+            && device_extensions.khr_depth_stencil_resolve
+            && chain!(
+                depth_stencil_resolve,
+                vk::STRUCTURE_TYPE_PHYSICAL_DEVICE_DEPTH_STENCIL_RESOLVE_PROPERTIES
+            );
+        let has_filter_minmax = !core12
+            && device_extensions.ext_sampler_filter_minmax
+            && chain!(
+                filter_minmax,
+                vk::STRUCTURE_TYPE_PHYSICAL_DEVICE_SAMPLER_FILTER_MINMAX_PROPERTIES
+            );
+        let has_timeline_semaphore = !core12
+            && device_extensions.khr_timeline_semaphore
+            && chain!(
+                timeline_semaphore,
+                vk::STRUCTURE_TYPE_PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_PROPERTIES
+            );
+
+        // Properties of extensions that aren't part of any Vulkan version.
+        let has_mesh_shader_nv = device_extensions.nv_mesh_shader
+            && chain!(
+                mesh_shader_nv,
+                vk::STRUCTURE_TYPE_PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_NV
+            );
+        let has_mesh_shader_ext = device_extensions.ext_mesh_shader
+            && chain!(
+                mesh_shader_ext,
+                vk::STRUCTURE_TYPE_PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_EXT
+            );
+
+        let mut output = vk::PhysicalDeviceProperties2KHR {
+            sType: vk::STRUCTURE_TYPE_PHYSICAL_DEVICE_PROPERTIES_2_KHR,
+            pNext: next as _,
+            properties: mem::zeroed(),
+        };
+        vk.GetPhysicalDeviceProperties2KHR(physical_device, &mut output);
+
+        let mut out = Properties::default();
+
+        if has_vulkan11 {
+            out.device_uuid = Some(vulkan11.deviceUUID);
+            out.driver_uuid = Some(vulkan11.driverUUID);
+            if vulkan11.deviceLUIDValid != 0 {
+                out.device_luid = Some(vulkan11.deviceLUID);
+                out.device_node_mask = Some(vulkan11.deviceNodeMask);
+            }
+            out.subgroup_size = Some(vulkan11.subgroupSize);
+            out.subgroup_supported_stages = Some(ShaderStages::from_vulkan_bits(
+                vulkan11.subgroupSupportedStages,
+            ));
+            out.subgroup_supported_operations = Some(SubgroupFeatures::from_vulkan_bits(
+                vulkan11.subgroupSupportedOperations,
+            ));
+            out.subgroup_quad_operations_in_all_stages =
+                Some(vulkan11.subgroupQuadOperationsInAllStages != 0);
+            out.point_clipping_behavior = Some(PointClippingBehavior::from_vulkan(
+                vulkan11.pointClippingBehavior,
+            ));
+            copy_fields!(out, vulkan11, value {
+                max_multiview_view_count => maxMultiviewViewCount,
+                max_multiview_instance_index => maxMultiviewInstanceIndex,
+                max_per_set_descriptors => maxPerSetDescriptors,
+                max_memory_allocation_size => maxMemoryAllocationSize,
+            });
+            out.protected_no_fault = Some(vulkan11.protectedNoFault != 0);
+        }
+
+        if has_vulkan12 {
+            copy_driver_properties!(out, vulkan12);
+            copy_float_controls!(out, vulkan12);
+            copy_descriptor_indexing!(out, vulkan12);
+            copy_depth_stencil_resolve!(out, vulkan12);
+            copy_fields!(out, vulkan12, bool {
+                filter_minmax_single_component_formats => filterMinmaxSingleComponentFormats,
+                filter_minmax_image_component_mapping => filterMinmaxImageComponentMapping,
+            });
+            copy_fields!(out, vulkan12, value {
+                max_timeline_semaphore_value_difference => maxTimelineSemaphoreValueDifference,
+                framebuffer_integer_color_sample_counts => framebufferIntegerColorSampleCounts,
+            });
+        }
+
+        if has_id {
+            out.device_uuid = Some(id.deviceUUID);
+            out.driver_uuid = Some(id.driverUUID);
+            if id.deviceLUIDValid != 0 {
+                out.device_luid = Some(id.deviceLUID);
+                out.device_node_mask = Some(id.deviceNodeMask);
+            }
+        }
+
+        if has_subgroup {
+            out.subgroup_size = Some(subgroup.subgroupSize);
+            out.subgroup_supported_stages =
+                Some(ShaderStages::from_vulkan_bits(subgroup.supportedStages));
+            out.subgroup_supported_operations = Some(SubgroupFeatures::from_vulkan_bits(
+                subgroup.supportedOperations,
+            ));
+            out.subgroup_quad_operations_in_all_stages =
+                Some(subgroup.quadOperationsInAllStages != 0);
+        }
+
+        if has_point_clipping {
+            out.point_clipping_behavior = Some(PointClippingBehavior::from_vulkan(
+                point_clipping.pointClippingBehavior,
+            ));
+        }
+
+        if has_multiview {
+            copy_fields!(out, multiview, value {
+                max_multiview_view_count => maxMultiviewViewCount,
+                max_multiview_instance_index => maxMultiviewInstanceIndex,
+            });
+        }
+
+        if has_protected_memory {
+            out.protected_no_fault = Some(protected_memory.protectedNoFault != 0);
+        }
+
+        if has_maintenance3 {
+            copy_fields!(out, maintenance3, value {
+                max_per_set_descriptors => maxPerSetDescriptors,
+                max_memory_allocation_size => maxMemoryAllocationSize,
+            });
+        }
+
+        if has_driver {
+            copy_driver_properties!(out, driver);
+        }
+
+        if has_float_controls {
+            copy_float_controls!(out, float_controls);
+        }
+
+        if has_descriptor_indexing {
+            copy_descriptor_indexing!(out, descriptor_indexing);
+        }
+
+        if has_depth_stencil_resolve {
+            copy_depth_stencil_resolve!(out, depth_stencil_resolve);
+        }
+
+        if has_filter_minmax {
+            copy_fields!(out, filter_minmax, bool {
+                filter_minmax_single_component_formats => filterMinmaxSingleComponentFormats,
+                filter_minmax_image_component_mapping => filterMinmaxImageComponentMapping,
+            });
+        }
+
+        if has_timeline_semaphore {
+            out.max_timeline_semaphore_value_difference =
+                Some(timeline_semaphore.maxTimelineSemaphoreValueDifference);
+        }
+
+        if has_mesh_shader_nv {
+            out.max_draw_mesh_tasks_count = Some(mesh_shader_nv.maxDrawMeshTasksCount);
+        }
+
+        if has_mesh_shader_ext {
+            copy_fields!(out, mesh_shader_ext, value {
+                max_task_work_group_total_count => maxTaskWorkGroupTotalCount,
+                max_task_work_group_count => maxTaskWorkGroupCount,
+                max_mesh_work_group_total_count => maxMeshWorkGroupTotalCount,
+                max_mesh_work_group_count => maxMeshWorkGroupCount,
+            });
+        }
+
+        out
+    }
+}
+
+// Turns a null-terminated fixed-size array returned by Vulkan into a `String`.
+fn c_str_to_string(chars: &[std::os::raw::c_char]) -> String {
+    debug_assert!(chars.contains(&0));
+    unsafe { CStr::from_ptr(chars.as_ptr()) }
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// How points are clipped.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PointClippingBehavior {
+    /// Points are culled if they are outside of any clip plane, including user-defined ones.
+    AllClipPlanes,
+    /// Points are only culled if they are outside of a user-defined clip plane.
+    UserClipPlanesOnly,
+}
+
+impl PointClippingBehavior {
+    #[inline]
+    fn from_vulkan(val: vk::PointClippingBehavior) -> PointClippingBehavior {
+        match val {
+            vk::POINT_CLIPPING_BEHAVIOR_USER_CLIP_PLANES_ONLY => {
+                PointClippingBehavior::UserClipPlanesOnly
+            }
+            _ => PointClippingBehavior::AllClipPlanes,
+        }
+    }
+}
+
+/// Identifies the driver of a physical device.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DriverId {
+    AMDProprietary,
+    AMDOpenSource,
+    MesaRADV,
+    NvidiaProprietary,
+    IntelProprietaryWindows,
+    IntelOpenSourceMesa,
+    ImaginationProprietary,
+    QualcommProprietary,
+    ARMProprietary,
+    GoogleSwiftshader,
+    GGPProprietary,
+    BroadcomProprietary,
+    MesaLLVMpipe,
+    MoltenVK,
+    CoreAVIProprietary,
+    JuiceProprietary,
+    VeriSiliconProprietary,
+    /// A driver that vulkano doesn't know about, with its raw identifier.
+    Other(u32),
+}
+
+impl DriverId {
+    #[inline]
+    fn from_vulkan(val: vk::DriverId) -> DriverId {
+        match val {
+            vk::DRIVER_ID_AMD_PROPRIETARY => DriverId::AMDProprietary,
+            vk::DRIVER_ID_AMD_OPEN_SOURCE => DriverId::AMDOpenSource,
+            vk::DRIVER_ID_MESA_RADV => DriverId::MesaRADV,
+            vk::DRIVER_ID_NVIDIA_PROPRIETARY => DriverId::NvidiaProprietary,
+            vk::DRIVER_ID_INTEL_PROPRIETARY_WINDOWS => DriverId::IntelProprietaryWindows,
+            vk::DRIVER_ID_INTEL_OPEN_SOURCE_MESA => DriverId::IntelOpenSourceMesa,
+            vk::DRIVER_ID_IMAGINATION_PROPRIETARY => DriverId::ImaginationProprietary,
+            vk::DRIVER_ID_QUALCOMM_PROPRIETARY => DriverId::QualcommProprietary,
+            vk::DRIVER_ID_ARM_PROPRIETARY => DriverId::ARMProprietary,
+            vk::DRIVER_ID_GOOGLE_SWIFTSHADER => DriverId::GoogleSwiftshader,
+            vk::DRIVER_ID_GGP_PROPRIETARY => DriverId::GGPProprietary,
+            vk::DRIVER_ID_BROADCOM_PROPRIETARY => DriverId::BroadcomProprietary,
+            vk::DRIVER_ID_MESA_LLVMPIPE => DriverId::MesaLLVMpipe,
+            vk::DRIVER_ID_MOLTENVK => DriverId::MoltenVK,
+            vk::DRIVER_ID_COREAVI_PROPRIETARY => DriverId::CoreAVIProprietary,
+            vk::DRIVER_ID_JUICE_PROPRIETARY => DriverId::JuiceProprietary,
+            vk::DRIVER_ID_VERISILICON_PROPRIETARY => DriverId::VeriSiliconProprietary,
+            other => DriverId::Other(other),
+        }
+    }
+}
+
+/// The version of the Vulkan conformance test suite that a driver passed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ConformanceVersion {
+    pub major: u8,
+    pub minor: u8,
+    pub subminor: u8,
+    pub patch: u8,
+}
+
+/// How the floating-point controls of different bit widths can be set independently of each
+/// other.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ShaderFloatControlsIndependence {
+    /// Only the 32-bit controls can be set independently of the others. The 16-bit and 64-bit
+    /// controls must be set to the same value.
+    Float32Only,
+    /// All bit widths can be set independently.
+    All,
+    /// The controls must be set to the same value for all bit widths.
+    None,
+}
+
+impl ShaderFloatControlsIndependence {
+    #[inline]
+    fn from_vulkan(val: vk::ShaderFloatControlsIndependence) -> ShaderFloatControlsIndependence {
+        match val {
+            vk::SHADER_FLOAT_CONTROLS_INDEPENDENCE_32_BIT_ONLY => {
+                ShaderFloatControlsIndependence::Float32Only
+            }
+            vk::SHADER_FLOAT_CONTROLS_INDEPENDENCE_ALL => ShaderFloatControlsIndependence::All,
+            _ => ShaderFloatControlsIndependence::None,
+        }
+    }
+}
+
+/// A set of modes that can be used to resolve a multisampled depth or stencil attachment.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ResolveModes {
+    /// The value of sample 0 is written.
+    pub sample_zero: bool,
+    /// The average of all samples is written.
+    pub average: bool,
+    /// The minimum of all samples is written.
+    pub min: bool,
+    /// The maximum of all samples is written.
+    pub max: bool,
+}
+
+impl ResolveModes {
+    /// Builds a `ResolveModes` with all values set to false.
+    #[inline]
+    pub fn none() -> ResolveModes {
+        ResolveModes::default()
+    }
+
+    #[inline]
+    pub(crate) fn from_vulkan_bits(val: vk::ResolveModeFlags) -> ResolveModes {
+        ResolveModes {
+            sample_zero: (val & vk::RESOLVE_MODE_SAMPLE_ZERO_BIT) != 0,
+            average: (val & vk::RESOLVE_MODE_AVERAGE_BIT) != 0,
+            min: (val & vk::RESOLVE_MODE_MIN_BIT) != 0,
+            max: (val & vk::RESOLVE_MODE_MAX_BIT) != 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::instance::PhysicalDevice;
+    use crate::instance::ResolveModes;
+    use crate::instance::Version;
+    use crate::vk;
+
+    #[test]
+    fn resolve_modes_from_bits() {
+        assert_eq!(ResolveModes::from_vulkan_bits(0), ResolveModes::none());
+        assert_eq!(
+            ResolveModes::from_vulkan_bits(
+                vk::RESOLVE_MODE_SAMPLE_ZERO_BIT | vk::RESOLVE_MODE_MAX_BIT
+            ),
+            ResolveModes {
+                sample_zero: true,
+                max: true,
+                ..ResolveModes::none()
+            }
+        );
+    }
+
+    #[test]
+    fn properties_match_api_version() {
+        let instance = instance!();
+
+        for physical_device in PhysicalDevice::enumerate(&instance) {
+            let properties = physical_device.properties();
+
+            if instance.api_version() >= Version::V1_1
+                && physical_device.api_version() >= Version::V1_1
+            {
+                assert!(properties.device_uuid.is_some());
+                assert!(properties.max_per_set_descriptors.is_some());
+            }
+        }
+    }
+}
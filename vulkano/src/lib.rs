@@ -212,6 +212,8 @@ pub(crate) enum Error {
     IncompatibleDisplay = vk::ERROR_INCOMPATIBLE_DISPLAY_KHR,
     ValidationFailed = vk::ERROR_VALIDATION_FAILED_EXT,
     OutOfPoolMemory = vk::ERROR_OUT_OF_POOL_MEMORY_KHR,
+    InvalidExternalHandle = vk::ERROR_INVALID_EXTERNAL_HANDLE,
+    NotPermitted = vk::ERROR_NOT_PERMITTED_EXT,
     FullscreenExclusiveLost = vk::ERROR_FULL_SCREEN_EXCLUSIVE_MODE_LOST_EXT,
 }
 
@@ -242,6 +244,8 @@ fn check_errors(result: vk::Result) -> Result<Success, Error> {
         vk::ERROR_INCOMPATIBLE_DISPLAY_KHR => Err(Error::IncompatibleDisplay),
         vk::ERROR_VALIDATION_FAILED_EXT => Err(Error::ValidationFailed),
         vk::ERROR_OUT_OF_POOL_MEMORY_KHR => Err(Error::OutOfPoolMemory),
+        vk::ERROR_INVALID_EXTERNAL_HANDLE => Err(Error::InvalidExternalHandle),
+        vk::ERROR_NOT_PERMITTED_EXT => Err(Error::NotPermitted),
         vk::ERROR_FULL_SCREEN_EXCLUSIVE_MODE_LOST_EXT => Err(Error::FullscreenExclusiveLost),
         vk::ERROR_INVALID_SHADER_NV => panic!(
             "Vulkan function returned \
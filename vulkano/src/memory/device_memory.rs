@@ -544,6 +544,24 @@ impl DeviceMemory {
         self.size
     }
 
+    /// Returns how the physical device with device index `local_device_index` can access this
+    /// memory when it is physically located on the physical device with device index
+    /// `remote_device_index`.
+    ///
+    /// See `Device::peer_memory_features`.
+    #[inline]
+    pub fn peer_memory_features(
+        &self,
+        local_device_index: u32,
+        remote_device_index: u32,
+    ) -> PeerMemoryFeatures {
+        self.device.peer_memory_features(
+            self.memory_type().heap(),
+            local_device_index,
+            remote_device_index,
+        )
+    }
+
     /// Exports the device memory into a Unix file descriptor.  The caller retains ownership of the
     /// file, as per the Vulkan spec.
     ///
@@ -969,6 +987,34 @@ impl<'a, T: ?Sized + 'a> Drop for CpuAccess<'a, T> {
     }
 }
 
+/// Describes how a physical device of a device group can access memory that is physically
+/// located on another physical device of the group.
+///
+/// See `Device::peer_memory_features`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct PeerMemoryFeatures {
+    /// The memory can be the source of copy commands.
+    pub copy_src: bool,
+    /// The memory can be the destination of copy commands.
+    pub copy_dst: bool,
+    /// The memory can be read by any kind of access.
+    pub generic_src: bool,
+    /// The memory can be written by any kind of access.
+    pub generic_dst: bool,
+}
+
+impl PeerMemoryFeatures {
+    #[inline]
+    pub(crate) fn from_vulkan_bits(bits: vk::PeerMemoryFeatureFlags) -> PeerMemoryFeatures {
+        PeerMemoryFeatures {
+            copy_src: bits & vk::PEER_MEMORY_FEATURE_COPY_SRC_BIT != 0,
+            copy_dst: bits & vk::PEER_MEMORY_FEATURE_COPY_DST_BIT != 0,
+            generic_src: bits & vk::PEER_MEMORY_FEATURE_GENERIC_SRC_BIT != 0,
+            generic_dst: bits & vk::PEER_MEMORY_FEATURE_GENERIC_DST_BIT != 0,
+        }
+    }
+}
+
 /// Error type returned by functions related to `DeviceMemory`.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum DeviceMemoryAllocError {
@@ -99,12 +99,18 @@ pub use self::device_memory::DeviceMemoryAllocError;
 pub use self::device_memory::DeviceMemoryBuilder;
 pub use self::device_memory::DeviceMemoryMapping;
 pub use self::device_memory::MappedDeviceMemory;
+pub use self::device_memory::PeerMemoryFeatures;
 pub use self::external_memory_handle_type::ExternalMemoryHandleType;
 pub use self::pool::MemoryPool;
+pub use self::sparse::SparseBindFuture;
+pub use self::sparse::SparseBinds;
+
+pub(crate) use self::sparse::then_bind_sparse;
 
 mod device_memory;
 mod external_memory_handle_type;
 pub mod pool;
+mod sparse;
 
 /// Represents requirements expressed by the Vulkan implementation when it comes to binding memory
 /// to a resource.
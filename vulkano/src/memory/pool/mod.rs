@@ -27,7 +27,7 @@ mod host_visible;
 mod non_host_visible;
 mod pool;
 
-fn choose_allocation_memory_type<'s, F>(
+pub(crate) fn choose_allocation_memory_type<'s, F>(
     device: &'s Arc<Device>,
     requirements: &MemoryRequirements,
     mut filter: F,
@@ -0,0 +1,467 @@
+// Copyright (c) 2021 The vulkano developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>,
+// at your option. All files in the project carrying such
+// notice may not be copied, modified, or distributed except
+// according to those terms.
+
+use std::any::Any;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use crate::buffer::sys::UnsafeBuffer;
+use crate::buffer::BufferAccess;
+use crate::command_buffer::submit::SubmitAnyBuilder;
+use crate::command_buffer::submit::SubmitBindSparseBatchBuilder;
+use crate::command_buffer::submit::SubmitBindSparseBufferBindBuilder;
+use crate::command_buffer::submit::SubmitBindSparseBuilder;
+use crate::command_buffer::submit::SubmitBindSparseImageBindBuilder;
+use crate::command_buffer::submit::SubmitBindSparseImageOpaqueBindBuilder;
+use crate::device::Device;
+use crate::device::DeviceOwned;
+use crate::device::Queue;
+use crate::image::sys::UnsafeImage;
+use crate::image::ImageAccess;
+use crate::image::ImageAspect;
+use crate::image::ImageLayout;
+use crate::memory::DeviceMemory;
+use crate::sync::AccessCheckError;
+use crate::sync::AccessFlagBits;
+use crate::sync::FlushError;
+use crate::sync::GpuFuture;
+use crate::sync::PipelineStages;
+use crate::sync::Semaphore;
+use crate::vk;
+use crate::VulkanObject;
+
+/// List of sparse memory bind and unbind operations, waiting to be submitted to a queue.
+///
+/// Obtained from the methods of `SparseBuffer` and `SparseImage` that change which pages are
+/// resident, and submitted with `GpuFuture::then_bind_sparse`. Several lists can be merged in
+/// order to submit them all at once.
+///
+/// The list keeps alive the resources and the memory it refers to, including the memory that is
+/// being unbound, until the GPU has finished executing the operations.
+pub struct SparseBinds {
+    device: Arc<Device>,
+    buffer_binds: Vec<BufferBind>,
+    image_opaque_binds: Vec<ImageOpaqueBind>,
+    image_binds: Vec<ImageBind>,
+    resources: Vec<Arc<dyn Any + Send + Sync>>,
+}
+
+#[derive(Debug, Copy, Clone)]
+struct BufferBind {
+    buffer: vk::Buffer,
+    offset: usize,
+    size: usize,
+    memory: vk::DeviceMemory,
+    memory_offset: usize,
+}
+
+#[derive(Debug, Copy, Clone)]
+struct ImageOpaqueBind {
+    image: vk::Image,
+    offset: usize,
+    size: usize,
+    memory: vk::DeviceMemory,
+    memory_offset: usize,
+    metadata: bool,
+}
+
+#[derive(Debug, Copy, Clone)]
+struct ImageBind {
+    image: vk::Image,
+    aspect: ImageAspect,
+    mip_level: u32,
+    array_layer: u32,
+    offset: [i32; 3],
+    extent: [u32; 3],
+    memory: vk::DeviceMemory,
+    memory_offset: usize,
+}
+
+impl SparseBinds {
+    /// Builds a new empty list.
+    #[inline]
+    pub fn new(device: Arc<Device>) -> SparseBinds {
+        SparseBinds {
+            device,
+            buffer_binds: Vec::new(),
+            image_opaque_binds: Vec::new(),
+            image_binds: Vec::new(),
+            resources: Vec::new(),
+        }
+    }
+
+    /// Returns true if the list doesn't contain any operation.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.buffer_binds.is_empty()
+            && self.image_opaque_binds.is_empty()
+            && self.image_binds.is_empty()
+    }
+
+    /// Appends the operations of `other` to this list.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if the two lists don't belong to the same device.
+    ///
+    pub fn merge(&mut self, other: SparseBinds) {
+        assert_eq!(
+            self.device.internal_object(),
+            other.device.internal_object()
+        );
+
+        self.buffer_binds.extend(other.buffer_binds);
+        self.image_opaque_binds.extend(other.image_opaque_binds);
+        self.image_binds.extend(other.image_binds);
+        self.resources.extend(other.resources);
+    }
+
+    /// Adds an operation that binds `memory` to a range of `buffer`, or unbinds the range if
+    /// `memory` is `None`. The `usize` is the offset in bytes within the memory.
+    pub(crate) fn add_buffer_bind(
+        &mut self,
+        buffer: &UnsafeBuffer,
+        offset: usize,
+        size: usize,
+        memory: Option<(&DeviceMemory, usize)>,
+    ) {
+        self.buffer_binds.push(BufferBind {
+            buffer: buffer.internal_object(),
+            offset,
+            size,
+            memory: memory.map(|(m, _)| m.internal_object()).unwrap_or(0),
+            memory_offset: memory.map(|(_, offset)| offset).unwrap_or(0),
+        });
+    }
+
+    /// Adds an operation that binds `memory` to an opaque range of `image`, or unbinds the range
+    /// if `memory` is `None`. The `usize` is the offset in bytes within the memory.
+    pub(crate) fn add_image_opaque_bind(
+        &mut self,
+        image: &UnsafeImage,
+        offset: usize,
+        size: usize,
+        memory: Option<(&DeviceMemory, usize)>,
+        metadata: bool,
+    ) {
+        self.image_opaque_binds.push(ImageOpaqueBind {
+            image: image.internal_object(),
+            offset,
+            size,
+            memory: memory.map(|(m, _)| m.internal_object()).unwrap_or(0),
+            memory_offset: memory.map(|(_, offset)| offset).unwrap_or(0),
+            metadata,
+        });
+    }
+
+    /// Adds an operation that binds `memory` to a region of `image`, or unbinds the region if
+    /// `memory` is `None`. The region is given as an offset and an extent in texels, and the
+    /// `usize` is the offset in bytes within the memory.
+    pub(crate) fn add_image_bind(
+        &mut self,
+        image: &UnsafeImage,
+        aspect: ImageAspect,
+        mip_level: u32,
+        array_layer: u32,
+        (offset, extent): ([i32; 3], [u32; 3]),
+        memory: Option<(&DeviceMemory, usize)>,
+    ) {
+        self.image_binds.push(ImageBind {
+            image: image.internal_object(),
+            aspect,
+            mip_level,
+            array_layer,
+            offset,
+            extent,
+            memory: memory.map(|(m, _)| m.internal_object()).unwrap_or(0),
+            memory_offset: memory.map(|(_, offset)| offset).unwrap_or(0),
+        });
+    }
+
+    /// Keeps `resource` alive until the operations have been executed.
+    pub(crate) fn keep_alive<T>(&mut self, resource: Arc<T>)
+    where
+        T: Send + Sync + 'static,
+    {
+        self.resources.push(resource);
+    }
+
+    // Adds the operations to `batch`. Consecutive operations on the same resource are grouped
+    // together.
+    unsafe fn add_to_batch<'a>(&'a self, batch: &mut SubmitBindSparseBatchBuilder<'a>) {
+        let mut current: Option<SubmitBindSparseBufferBindBuilder> = None;
+        let mut current_handle = 0;
+        for bind in self.buffer_binds.iter() {
+            if current.is_none() || current_handle != bind.buffer {
+                if let Some(builder) = current.take() {
+                    batch.add_buffer(builder);
+                }
+                current = Some(SubmitBindSparseBufferBindBuilder::from_raw(bind.buffer));
+                current_handle = bind.buffer;
+            }
+
+            current.as_mut().unwrap().add_raw_bind(
+                bind.offset,
+                bind.size,
+                bind.memory,
+                bind.memory_offset,
+            );
+        }
+        if let Some(builder) = current {
+            batch.add_buffer(builder);
+        }
+
+        let mut current: Option<SubmitBindSparseImageOpaqueBindBuilder> = None;
+        let mut current_handle = 0;
+        for bind in self.image_opaque_binds.iter() {
+            if current.is_none() || current_handle != bind.image {
+                if let Some(builder) = current.take() {
+                    batch.add_image_opaque(builder);
+                }
+                current = Some(SubmitBindSparseImageOpaqueBindBuilder::from_raw(bind.image));
+                current_handle = bind.image;
+            }
+
+            current.as_mut().unwrap().add_raw_bind(
+                bind.offset,
+                bind.size,
+                bind.memory,
+                bind.memory_offset,
+                bind.metadata,
+            );
+        }
+        if let Some(builder) = current {
+            batch.add_image_opaque(builder);
+        }
+
+        let mut current: Option<SubmitBindSparseImageBindBuilder> = None;
+        let mut current_handle = 0;
+        for bind in self.image_binds.iter() {
+            if current.is_none() || current_handle != bind.image {
+                if let Some(builder) = current.take() {
+                    batch.add_image(builder);
+                }
+                current = Some(SubmitBindSparseImageBindBuilder::from_raw(bind.image));
+                current_handle = bind.image;
+            }
+
+            current.as_mut().unwrap().add_raw_bind(
+                bind.aspect,
+                bind.mip_level,
+                bind.array_layer,
+                bind.offset,
+                bind.extent,
+                bind.memory,
+                bind.memory_offset,
+            );
+        }
+        if let Some(builder) = current {
+            batch.add_image(builder);
+        }
+    }
+}
+
+unsafe impl DeviceOwned for SparseBinds {
+    #[inline]
+    fn device(&self) -> &Arc<Device> {
+        &self.device
+    }
+}
+
+/// Builds a new sparse bind future.
+#[inline]
+pub(crate) fn then_bind_sparse<F>(
+    future: F,
+    queue: Arc<Queue>,
+    binds: SparseBinds,
+) -> SparseBindFuture<F>
+where
+    F: GpuFuture,
+{
+    assert_eq!(
+        future.device().internal_object(),
+        binds.device().internal_object()
+    );
+    assert_eq!(
+        queue.device().internal_object(),
+        binds.device().internal_object()
+    );
+    assert!(queue.family().supports_sparse_binding());
+
+    if !future.queue_change_allowed() {
+        assert!(future.queue().unwrap().is_same(&queue));
+    }
+
+    let semaphore = Semaphore::from_pool(binds.device().clone()).unwrap();
+
+    SparseBindFuture {
+        previous: future,
+        queue,
+        binds,
+        semaphore,
+        submitted: Mutex::new(false),
+        finished: AtomicBool::new(false),
+    }
+}
+
+/// Represents sparse memory being bound after a previous event.
+#[must_use = "Dropping this object will immediately block the thread until the GPU has finished \
+              processing the submission"]
+pub struct SparseBindFuture<F>
+where
+    F: GpuFuture,
+{
+    previous: F,
+    queue: Arc<Queue>,
+    binds: SparseBinds,
+    // Semaphore used to order the bind operations after the previous submission, if any.
+    semaphore: Semaphore,
+    // True if the bind operations have already been submitted.
+    // If flush is called multiple times, we want to block so that only one flushing is executed.
+    // Therefore we use a `Mutex<bool>` and not an `AtomicBool`.
+    submitted: Mutex<bool>,
+    finished: AtomicBool,
+}
+
+unsafe impl<F> GpuFuture for SparseBindFuture<F>
+where
+    F: GpuFuture,
+{
+    #[inline]
+    fn cleanup_finished(&mut self) {
+        self.previous.cleanup_finished();
+    }
+
+    unsafe fn build_submission(&self) -> Result<SubmitAnyBuilder<'_>, FlushError> {
+        let mut batch = match self.previous.build_submission()? {
+            SubmitAnyBuilder::Empty => SubmitBindSparseBatchBuilder::new(),
+            SubmitAnyBuilder::SemaphoresWait(sem) => sem.into(),
+            SubmitAnyBuilder::CommandBuffer(mut builder) => {
+                // Sparse bind operations aren't ordered with command buffers submitted to the
+                // same queue, so we have to go through a semaphore.
+                debug_assert_eq!(builder.num_signal_semaphores(), 0);
+                builder.add_signal_semaphore(&self.semaphore);
+                builder.submit(&self.queue)?;
+
+                let mut batch = SubmitBindSparseBatchBuilder::new();
+                batch.add_wait_semaphore(&self.semaphore);
+                batch
+            }
+            SubmitAnyBuilder::QueuePresent(present) => {
+                present.submit(&self.queue)?;
+                SubmitBindSparseBatchBuilder::new()
+            }
+            SubmitAnyBuilder::BindSparse(mut builder) => {
+                // Same remark as above, batches of the same submission start in order but can
+                // finish in any order.
+                builder.add_signal_semaphore(&self.semaphore);
+
+                let mut batch = SubmitBindSparseBatchBuilder::new();
+                batch.add_wait_semaphore(&self.semaphore);
+                self.binds.add_to_batch(&mut batch);
+                builder.add(batch);
+                return Ok(SubmitAnyBuilder::BindSparse(builder));
+            }
+        };
+
+        self.binds.add_to_batch(&mut batch);
+        let mut builder = SubmitBindSparseBuilder::new();
+        builder.add(batch);
+        Ok(SubmitAnyBuilder::BindSparse(builder))
+    }
+
+    #[inline]
+    fn flush(&self) -> Result<(), FlushError> {
+        unsafe {
+            let mut submitted = self.submitted.lock().unwrap();
+            if *submitted {
+                return Ok(());
+            }
+
+            match self.build_submission()? {
+                SubmitAnyBuilder::Empty => {}
+                SubmitAnyBuilder::BindSparse(builder) => {
+                    builder.submit(&self.queue)?;
+                }
+                _ => unreachable!(),
+            };
+
+            // Only write `true` here in order to try again next time if we failed to submit.
+            *submitted = true;
+            Ok(())
+        }
+    }
+
+    #[inline]
+    unsafe fn signal_finished(&self) {
+        self.finished.store(true, Ordering::SeqCst);
+        self.previous.signal_finished();
+    }
+
+    #[inline]
+    fn queue_change_allowed(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn queue(&self) -> Option<Arc<Queue>> {
+        Some(self.queue.clone())
+    }
+
+    #[inline]
+    fn check_buffer_access(
+        &self,
+        buffer: &dyn BufferAccess,
+        exclusive: bool,
+        queue: &Queue,
+    ) -> Result<Option<(PipelineStages, AccessFlagBits)>, AccessCheckError> {
+        self.previous.check_buffer_access(buffer, exclusive, queue)
+    }
+
+    #[inline]
+    fn check_image_access(
+        &self,
+        image: &dyn ImageAccess,
+        layout: ImageLayout,
+        exclusive: bool,
+        queue: &Queue,
+    ) -> Result<Option<(PipelineStages, AccessFlagBits)>, AccessCheckError> {
+        self.previous
+            .check_image_access(image, layout, exclusive, queue)
+    }
+}
+
+unsafe impl<F> DeviceOwned for SparseBindFuture<F>
+where
+    F: GpuFuture,
+{
+    #[inline]
+    fn device(&self) -> &Arc<Device> {
+        self.binds.device()
+    }
+}
+
+impl<F> Drop for SparseBindFuture<F>
+where
+    F: GpuFuture,
+{
+    fn drop(&mut self) {
+        unsafe {
+            if !*self.finished.get_mut() {
+                // TODO: handle errors?
+                self.flush().unwrap();
+                // Block until the queue finished.
+                self.queue.wait().unwrap();
+                self.previous.signal_finished();
+            }
+        }
+    }
+}
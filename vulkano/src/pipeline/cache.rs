@@ -20,12 +20,27 @@
 //! Once that is done, you can extract the data from the cache and store it. See the documentation
 //! of [`get_data`](struct.PipelineCache.html#method.get_data) for example of how to store the data
 //! on the disk, and [`with_data`](struct.PipelineCache.html#method.with_data) for how to reload it.
-
+//!
+//! The data of a cache can only be reused by the same physical device and driver version.
+//! [`load_validated`](struct.PipelineCache.html#method.load_validated) checks the header of the
+//! data before handing it to the implementation, and
+//! [`save_to_dir`](struct.PipelineCache.html#method.save_to_dir) and
+//! [`load_from_dir`](struct.PipelineCache.html#method.load_from_dir) store one file per device
+//! in a directory.
+
+use std::error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::io::Write;
 use std::mem::MaybeUninit;
+use std::path::Path;
+use std::path::PathBuf;
 use std::ptr;
 use std::sync::Arc;
 
 use crate::device::Device;
+use crate::instance::PhysicalDevice;
 
 use crate::check_errors;
 use crate::vk;
@@ -52,7 +67,9 @@ impl PipelineCache {
     ///
     /// This example loads a cache from a file, if it exists.
     /// See [`get_data`](#method.get_data) for how to store the data in a file.
-    /// TODO: there's a header in the cached data that must be checked ; talk about this
+    ///
+    /// The header of the data isn't checked. Use [`load_validated`](#method.load_validated)
+    /// instead if the data could have been produced by another device or driver version.
     ///
     /// ```
     /// # use std::sync::Arc;
@@ -87,6 +104,57 @@ impl PipelineCache {
         PipelineCache::new_impl(device, Some(initial_data))
     }
 
+    /// Builds a new pipeline cache from existing data, after checking that the data was produced
+    /// by the same physical device and driver version as `device`.
+    ///
+    /// The header of the data is compared against the vendor ID, device ID and pipeline cache
+    /// UUID of the physical device. If the header is invalid or doesn't match, the data is
+    /// discarded and an empty cache is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use vulkano::device::Device;
+    /// use std::fs;
+    /// use vulkano::pipeline::cache::PipelineCache;
+    /// # let device: Arc<Device> = return;
+    ///
+    /// let data = fs::read("pipeline_cache.bin").unwrap_or_default();
+    /// let cache = PipelineCache::load_validated(device.clone(), &data).unwrap();
+    /// ```
+    pub fn load_validated(
+        device: Arc<Device>,
+        data: &[u8],
+    ) -> Result<Arc<PipelineCache>, OomError> {
+        let compatible = match PipelineCacheHeader::parse(data) {
+            Some(header) => header.is_compatible_with(device.physical_device()),
+            None => false,
+        };
+
+        unsafe {
+            if compatible {
+                PipelineCache::new_impl(device, Some(data))
+            } else {
+                PipelineCache::new_impl(device, None)
+            }
+        }
+    }
+
+    /// Loads the pipeline cache that `save_to_dir` stored in `dir` for the physical device of
+    /// `device`.
+    ///
+    /// If there is no such file, if it can't be read or if its header doesn't match the
+    /// physical device, an empty cache is returned.
+    pub fn load_from_dir<P>(device: Arc<Device>, dir: P) -> Result<Arc<PipelineCache>, OomError>
+    where
+        P: AsRef<Path>,
+    {
+        let path = cache_file_path(dir.as_ref(), device.physical_device());
+        let data = fs::read(path).unwrap_or_default();
+        PipelineCache::load_validated(device, &data)
+    }
+
     /// Builds a new empty pipeline cache.
     ///
     /// # Example
@@ -225,6 +293,160 @@ impl PipelineCache {
             Ok(data)
         }
     }
+
+    /// Stores the data of the cache in `dir`, in a file whose name depends on the physical
+    /// device. Returns the path of the file.
+    ///
+    /// The directory is created if it doesn't exist. The data is first written to a temporary
+    /// file which then replaces the previous file, so that a crash never leaves a truncated
+    /// cache behind. Use [`load_from_dir`](#method.load_from_dir) to reload it.
+    pub fn save_to_dir<P>(&self, dir: P) -> Result<PathBuf, PipelineCacheSaveError>
+    where
+        P: AsRef<Path>,
+    {
+        let data = self.get_data()?;
+
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)?;
+
+        let path = cache_file_path(dir, self.device.physical_device());
+        let tmp_path = path.with_extension("bin.tmp");
+
+        let result = fs::File::create(&tmp_path)
+            .and_then(|mut file| {
+                file.write_all(&data)?;
+                file.sync_all()
+            })
+            .and_then(|_| fs::rename(&tmp_path, &path));
+
+        if let Err(err) = result {
+            let _ = fs::remove_file(&tmp_path);
+            return Err(err.into());
+        }
+
+        Ok(path)
+    }
+}
+
+// Returns the path of the file used by `save_to_dir` and `load_from_dir`.
+fn cache_file_path(dir: &Path, physical_device: PhysicalDevice) -> PathBuf {
+    let uuid: String = physical_device
+        .uuid()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+
+    dir.join(format!(
+        "{:08x}-{:08x}-{}.bin",
+        physical_device.pci_vendor_id(),
+        physical_device.pci_device_id(),
+        uuid
+    ))
+}
+
+/// Header found at the start of the data of a pipeline cache.
+///
+/// Corresponds to `VkPipelineCacheHeaderVersionOne`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PipelineCacheHeader {
+    /// Vendor ID of the physical device that produced the data.
+    pub vendor_id: u32,
+    /// Device ID of the physical device that produced the data.
+    pub device_id: u32,
+    /// Pipeline cache UUID of the physical device that produced the data.
+    pub pipeline_cache_uuid: [u8; 16],
+}
+
+impl PipelineCacheHeader {
+    /// Parses the header at the start of `data`, as returned by `PipelineCache::get_data`.
+    ///
+    /// Returns `None` if the data is too short or if the header has an unknown version.
+    pub fn parse(data: &[u8]) -> Option<PipelineCacheHeader> {
+        // The fields of the header are always stored least significant byte first.
+        let read_u32 = |offset: usize| {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&data[offset..offset + 4]);
+            u32::from_le_bytes(bytes)
+        };
+
+        if data.len() < 32 {
+            return None;
+        }
+
+        let header_size = read_u32(0);
+        let header_version = read_u32(4);
+
+        if header_size < 32
+            || header_size as usize > data.len()
+            || header_version != vk::PIPELINE_CACHE_HEADER_VERSION_ONE
+        {
+            return None;
+        }
+
+        let mut pipeline_cache_uuid = [0; 16];
+        pipeline_cache_uuid.copy_from_slice(&data[16..32]);
+
+        Some(PipelineCacheHeader {
+            vendor_id: read_u32(8),
+            device_id: read_u32(12),
+            pipeline_cache_uuid,
+        })
+    }
+
+    /// Returns true if data with this header can be used with `physical_device`.
+    #[inline]
+    pub fn is_compatible_with(&self, physical_device: PhysicalDevice) -> bool {
+        self.vendor_id == physical_device.pci_vendor_id()
+            && self.device_id == physical_device.pci_device_id()
+            && &self.pipeline_cache_uuid == physical_device.uuid()
+    }
+}
+
+/// Error that can happen when saving a pipeline cache to disk.
+#[derive(Debug)]
+pub enum PipelineCacheSaveError {
+    /// Not enough memory.
+    OomError(OomError),
+    /// Error while writing the file.
+    IoError(io::Error),
+}
+
+impl error::Error for PipelineCacheSaveError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            PipelineCacheSaveError::OomError(ref err) => Some(err),
+            PipelineCacheSaveError::IoError(ref err) => Some(err),
+        }
+    }
+}
+
+impl fmt::Display for PipelineCacheSaveError {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(
+            fmt,
+            "{}",
+            match *self {
+                PipelineCacheSaveError::OomError(_) => "not enough memory available",
+                PipelineCacheSaveError::IoError(_) => "error while writing the cache file",
+            }
+        )
+    }
+}
+
+impl From<OomError> for PipelineCacheSaveError {
+    #[inline]
+    fn from(err: OomError) -> PipelineCacheSaveError {
+        PipelineCacheSaveError::OomError(err)
+    }
+}
+
+impl From<io::Error> for PipelineCacheSaveError {
+    #[inline]
+    fn from(err: io::Error) -> PipelineCacheSaveError {
+        PipelineCacheSaveError::IoError(err)
+    }
 }
 
 unsafe impl VulkanObject for PipelineCache {
@@ -256,6 +478,7 @@ mod tests {
     use crate::descriptor::pipeline_layout::PipelineLayoutDesc;
     use crate::descriptor::pipeline_layout::PipelineLayoutDescPcRange;
     use crate::pipeline::cache::PipelineCache;
+    use crate::pipeline::cache::PipelineCacheHeader;
     use crate::pipeline::shader::ShaderModule;
     use crate::pipeline::ComputePipeline;
 
@@ -529,4 +752,41 @@ mod tests {
 
         assert_eq!(cache_data, second_data);
     }
+
+    #[test]
+    fn header_parse() {
+        let mut data = vec![32, 0, 0, 0, 1, 0, 0, 0, 0xde, 0x10, 0, 0, 0x34, 0x12, 0, 0];
+        data.extend((0..16).map(|n| n as u8));
+        data.extend(&[0xff; 8]);
+
+        let header = PipelineCacheHeader::parse(&data).unwrap();
+        assert_eq!(header.vendor_id, 0x10de);
+        assert_eq!(header.device_id, 0x1234);
+        assert_eq!(header.pipeline_cache_uuid[15], 15);
+    }
+
+    #[test]
+    fn header_parse_invalid() {
+        assert!(PipelineCacheHeader::parse(&[]).is_none());
+        assert!(PipelineCacheHeader::parse(&[0; 31]).is_none());
+
+        let mut data = vec![0; 32];
+        data[0] = 32;
+        data[4] = 2; // unknown version
+        assert!(PipelineCacheHeader::parse(&data).is_none());
+
+        data[0] = 64; // header larger than the data
+        data[4] = 1;
+        assert!(PipelineCacheHeader::parse(&data).is_none());
+    }
+
+    #[test]
+    fn load_validated_rejects_foreign_data() {
+        let (device, queue) = gfx_dev_and_queue!();
+
+        let data = [0xffu8; 64];
+        let cache = PipelineCache::load_validated(device, &data).unwrap();
+        let cache_data = cache.get_data().unwrap();
+        assert_ne!(&cache_data[..], &data[..]);
+    }
 }
@@ -0,0 +1,266 @@
+// Copyright (c) 2021 The vulkano developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>,
+// at your option. All files in the project carrying such
+// notice may not be copied, modified, or distributed except
+// according to those terms.
+
+//! Compile pipelines in the background.
+//!
+//! Creating a pipeline can take a long time, as this is the moment when the Vulkan
+//! implementation compiles the shaders. A `PipelineCompiler` owns a pool of worker threads on
+//! which pipelines can be built while the rest of the application keeps running.
+//!
+//! Each call to [`spawn`](struct.PipelineCompiler.html#method.spawn) returns a
+//! `PipelineCompileFuture`, which can either be waited upon or polled as a regular Rust future.
+//!
+//! ```no_run
+//! # use std::sync::Arc;
+//! # use vulkano::device::Device;
+//! use vulkano::pipeline::cache::PipelineCache;
+//! use vulkano::pipeline::compiler::PipelineCompiler;
+//!
+//! # let device: Arc<Device> = return;
+//! let cache = PipelineCache::empty(device.clone()).unwrap();
+//! let compiler = PipelineCompiler::new(4);
+//!
+//! let future = compiler.spawn({
+//!     let device = device.clone();
+//!     let cache = cache.clone();
+//!     move || {
+//!         // Build one or several pipelines here, for example with
+//!         // `GraphicsPipelineBuilder::build_many` or `ComputePipelineBuilder::build_many`,
+//!         // passing `cache` so that the work done by all the threads ends up in the same
+//!         // cache.
+//! #       let _ = (device, cache);
+//!     }
+//! });
+//!
+//! // ... do something else in the meantime ...
+//!
+//! future.wait();
+//! ```
+
+use std::fmt;
+use std::future::Future;
+use std::panic;
+use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::sync::Condvar;
+use std::sync::Mutex;
+use std::task::Context;
+use std::task::Poll;
+use std::task::Waker;
+use std::thread;
+use std::thread::JoinHandle;
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// Pool of threads dedicated to building pipelines.
+///
+/// See [the documentation of the module](index.html) for more info.
+///
+/// Dropping the compiler waits for all the jobs that were spawned on it to finish.
+pub struct PipelineCompiler {
+    sender: Mutex<Option<mpsc::Sender<Job>>>,
+    threads: Vec<JoinHandle<()>>,
+}
+
+impl PipelineCompiler {
+    /// Starts a new compiler with `num_threads` worker threads.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if `num_threads` is 0.
+    ///
+    pub fn new(num_threads: usize) -> PipelineCompiler {
+        assert_ne!(num_threads, 0);
+
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        let threads = (0..num_threads)
+            .map(|num| {
+                let receiver = receiver.clone();
+                thread::Builder::new()
+                    .name(format!("vulkano pipeline compiler #{}", num))
+                    .spawn(move || loop {
+                        let job = match receiver.lock().unwrap().recv() {
+                            Ok(job) => job,
+                            Err(_) => return,
+                        };
+
+                        job();
+                    })
+                    .expect("failed to spawn a pipeline compiler thread")
+            })
+            .collect();
+
+        PipelineCompiler {
+            sender: Mutex::new(Some(sender)),
+            threads,
+        }
+    }
+
+    /// Returns the number of worker threads of this compiler.
+    #[inline]
+    pub fn num_threads(&self) -> usize {
+        self.threads.len()
+    }
+
+    /// Runs `job` on one of the worker threads, and returns a future that resolves to its
+    /// result.
+    ///
+    /// If `job` panics, the panic is propagated to whoever waits for or polls the future.
+    pub fn spawn<F, T>(&self, job: F) -> PipelineCompileFuture<T>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let shared = Arc::new(Shared {
+            state: Mutex::new(State {
+                result: None,
+                waker: None,
+            }),
+            condvar: Condvar::new(),
+        });
+
+        let job: Job = {
+            let shared = shared.clone();
+            Box::new(move || {
+                let result = panic::catch_unwind(AssertUnwindSafe(job));
+                let mut state = shared.state.lock().unwrap();
+                state.result = Some(result);
+                if let Some(waker) = state.waker.take() {
+                    waker.wake();
+                }
+                shared.condvar.notify_all();
+            })
+        };
+
+        self.sender
+            .lock()
+            .unwrap()
+            .as_ref()
+            .unwrap()
+            .send(job)
+            .expect("the pipeline compiler threads have stopped");
+
+        PipelineCompileFuture { shared }
+    }
+}
+
+impl Drop for PipelineCompiler {
+    #[inline]
+    fn drop(&mut self) {
+        // Dropping the sender stops the threads once the remaining jobs are done.
+        self.sender.lock().unwrap().take();
+
+        for thread in self.threads.drain(..) {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl fmt::Debug for PipelineCompiler {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            fmt,
+            "<Vulkan pipeline compiler with {} threads>",
+            self.threads.len()
+        )
+    }
+}
+
+/// Result of a job spawned on a `PipelineCompiler`.
+///
+/// Implements `std::future::Future`, and can also be waited upon synchronously with `wait`.
+pub struct PipelineCompileFuture<T> {
+    shared: Arc<Shared<T>>,
+}
+
+struct Shared<T> {
+    state: Mutex<State<T>>,
+    condvar: Condvar,
+}
+
+struct State<T> {
+    result: Option<thread::Result<T>>,
+    waker: Option<Waker>,
+}
+
+impl<T> PipelineCompileFuture<T> {
+    /// Returns true if the job has finished.
+    #[inline]
+    pub fn is_ready(&self) -> bool {
+        self.shared.state.lock().unwrap().result.is_some()
+    }
+
+    /// Blocks the current thread until the job has finished, and returns its result.
+    pub fn wait(self) -> T {
+        let mut state = self.shared.state.lock().unwrap();
+
+        loop {
+            if let Some(result) = state.result.take() {
+                return unwrap_result(result);
+            }
+
+            state = self.shared.condvar.wait(state).unwrap();
+        }
+    }
+}
+
+impl<T> Future for PipelineCompileFuture<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<T> {
+        let mut state = self.shared.state.lock().unwrap();
+
+        if let Some(result) = state.result.take() {
+            return Poll::Ready(unwrap_result(result));
+        }
+
+        state.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+impl<T> fmt::Debug for PipelineCompileFuture<T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("PipelineCompileFuture")
+            .field("ready", &self.is_ready())
+            .finish()
+    }
+}
+
+#[inline]
+fn unwrap_result<T>(result: thread::Result<T>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(payload) => panic::resume_unwind(payload),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pipeline::compiler::PipelineCompiler;
+
+    #[test]
+    fn spawn_and_wait() {
+        let compiler = PipelineCompiler::new(2);
+        let futures: Vec<_> = (0..8u32).map(|n| compiler.spawn(move || n * 2)).collect();
+        let results: Vec<_> = futures.into_iter().map(|f| f.wait()).collect();
+        assert_eq!(results, (0..8u32).map(|n| n * 2).collect::<Vec<_>>());
+    }
+
+    #[test]
+    #[should_panic(expected = "job failed")]
+    fn panic_is_propagated() {
+        let compiler = PipelineCompiler::new(1);
+        compiler.spawn(|| panic!("job failed")).wait()
+    }
+}
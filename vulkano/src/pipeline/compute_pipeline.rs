@@ -7,12 +7,14 @@
 // notice may not be copied, modified, or distributed except
 // according to those terms.
 
+use smallvec::SmallVec;
 use std::error;
 use std::fmt;
 use std::marker::PhantomData;
 use std::mem;
-use std::mem::MaybeUninit;
+use std::os::raw::c_char;
 use std::ptr;
+use std::slice;
 use std::sync::Arc;
 
 use crate::descriptor::descriptor::DescriptorDesc;
@@ -28,6 +30,7 @@ use crate::descriptor::pipeline_layout::PipelineLayoutSys;
 use crate::pipeline::cache::PipelineCache;
 use crate::pipeline::shader::EntryPointAbstract;
 use crate::pipeline::shader::SpecializationConstants;
+use crate::pipeline::BasePipeline;
 
 use crate::check_errors;
 use crate::device::Device;
@@ -52,6 +55,7 @@ use crate::VulkanObject;
 pub struct ComputePipeline<Pl> {
     inner: Inner,
     pipeline_layout: Pl,
+    allows_derivatives: bool,
 }
 
 struct Inner {
@@ -60,6 +64,28 @@ struct Inner {
 }
 
 impl ComputePipeline<()> {
+    /// Starts the building process of a compute pipeline that runs `shader`.
+    ///
+    /// Unlike `new`, the returned builder allows creating derivative pipelines and building
+    /// several pipelines at once with `ComputePipelineBuilder::build_many`.
+    #[inline]
+    pub fn start<'a, Cs>(
+        shader: &'a Cs,
+        specialization: &'a Cs::SpecializationConstants,
+    ) -> ComputePipelineBuilder<'a, Cs>
+    where
+        Cs: EntryPointAbstract,
+    {
+        ComputePipelineBuilder {
+            shader,
+            specialization,
+            required_subgroup_size: None,
+            cache: None,
+            allow_derivatives: false,
+            base_pipeline: None,
+        }
+    }
+
     /// Builds a new `ComputePipeline`.
     pub fn new<Cs>(
         device: Arc<Device>,
@@ -71,16 +97,32 @@ impl ComputePipeline<()> {
         Cs::PipelineLayout: Clone,
         Cs: EntryPointAbstract,
     {
-        unsafe {
-            let pipeline_layout = shader.layout().clone().build(device.clone())?;
-            ComputePipeline::with_unchecked_pipeline_layout(
-                device,
-                shader,
-                specialization,
-                pipeline_layout,
-                cache,
-            )
-        }
+        let mut builder = ComputePipeline::start(shader, specialization);
+        builder.cache = cache;
+        builder.build(device)
+    }
+
+    /// Same as `new`, but the shader will be run with the given subgroup size.
+    ///
+    /// This requires the `ext_subgroup_size_control` extension and the `subgroup_size_control`
+    /// feature to be enabled. The subgroup size must be a power of two between the
+    /// `min_subgroup_size` and `max_subgroup_size` properties of the physical device, and the
+    /// compute stage must be in its `required_subgroup_size_stages`.
+    pub fn with_required_subgroup_size<Cs>(
+        device: Arc<Device>,
+        shader: &Cs,
+        specialization: &Cs::SpecializationConstants,
+        required_subgroup_size: u32,
+        cache: Option<Arc<PipelineCache>>,
+    ) -> Result<ComputePipeline<PipelineLayout<Cs::PipelineLayout>>, ComputePipelineCreationError>
+    where
+        Cs::PipelineLayout: Clone,
+        Cs: EntryPointAbstract,
+    {
+        let mut builder = ComputePipeline::start(shader, specialization)
+            .required_subgroup_size(required_subgroup_size);
+        builder.cache = cache;
+        builder.build(device)
     }
 }
 
@@ -101,16 +143,9 @@ impl<Pl> ComputePipeline<Pl> {
         Cs: EntryPointAbstract,
         Pl: PipelineLayoutAbstract,
     {
-        unsafe {
-            PipelineLayoutSuperset::ensure_superset_of(&pipeline_layout, shader.layout())?;
-            ComputePipeline::with_unchecked_pipeline_layout(
-                device,
-                shader,
-                specialization,
-                pipeline_layout,
-                cache,
-            )
-        }
+        let mut builder = ComputePipeline::start(shader, specialization);
+        builder.cache = cache;
+        builder.with_pipeline_layout(device, pipeline_layout)
     }
 
     /// Same as `with_pipeline_layout`, but doesn't check whether the pipeline layout is a
@@ -127,66 +162,403 @@ impl<Pl> ComputePipeline<Pl> {
         Cs: EntryPointAbstract,
         Pl: PipelineLayoutAbstract,
     {
-        let vk = device.pointers();
+        let mut builder = ComputePipeline::start(shader, specialization);
+        builder.cache = cache;
+        builder.with_unchecked_pipeline_layout(device, pipeline_layout)
+    }
+}
+
+/// Prototype for a `ComputePipeline`.
+///
+/// Created with `ComputePipeline::start`.
+pub struct ComputePipelineBuilder<'a, Cs>
+where
+    Cs: EntryPointAbstract,
+{
+    shader: &'a Cs,
+    specialization: &'a Cs::SpecializationConstants,
+    required_subgroup_size: Option<u32>,
+    cache: Option<Arc<PipelineCache>>,
+    allow_derivatives: bool,
+    base_pipeline: Option<BasePipeline>,
+}
+
+impl<'a, Cs> ComputePipelineBuilder<'a, Cs>
+where
+    Cs: EntryPointAbstract,
+{
+    /// Runs the shader with the given subgroup size.
+    ///
+    /// See `ComputePipeline::with_required_subgroup_size` for the requirements.
+    #[inline]
+    pub fn required_subgroup_size(mut self, size: u32) -> Self {
+        self.required_subgroup_size = Some(size);
+        self
+    }
+
+    /// Sets the pipeline cache to use when building the pipeline.
+    #[inline]
+    pub fn build_with_cache(mut self, pipeline_cache: Arc<PipelineCache>) -> Self {
+        self.cache = Some(pipeline_cache);
+        self
+    }
+
+    /// Allows other pipelines to be derived from the pipeline being built.
+    ///
+    /// Creating a pipeline that derives from another one can be faster, as the implementation
+    /// can reuse what it compiled for the base pipeline.
+    #[inline]
+    pub fn allow_derivatives(mut self) -> Self {
+        self.allow_derivatives = true;
+        self
+    }
+
+    /// Makes the pipeline being built a derivative of `base`.
+    ///
+    /// `base` must have been built with `allow_derivatives`, otherwise building the pipeline
+    /// will return an error.
+    #[inline]
+    pub fn derive_from<L>(mut self, base: Arc<ComputePipeline<L>>) -> Self
+    where
+        L: Send + Sync + 'static,
+    {
+        self.base_pipeline = Some(BasePipeline::Pipeline {
+            handle: base.inner.pipeline,
+            device: base.inner.device.internal_object(),
+            allows_derivatives: base.allows_derivatives,
+            _keep_alive: base,
+        });
+        self
+    }
+
+    /// Makes the pipeline being built a derivative of the pipeline at index `base_index` of the
+    /// same `build_many` batch.
+    ///
+    /// The base pipeline must come before this one in the batch and must have been built with
+    /// `allow_derivatives`.
+    #[inline]
+    pub fn derive_from_index(mut self, base_index: usize) -> Self {
+        self.base_pipeline = Some(BasePipeline::Index(base_index));
+        self
+    }
+
+    /// Builds the compute pipeline, using the pipeline layout that the shader expects.
+    pub fn build(
+        self,
+        device: Arc<Device>,
+    ) -> Result<ComputePipeline<PipelineLayout<Cs::PipelineLayout>>, ComputePipelineCreationError>
+    where
+        Cs::PipelineLayout: Clone,
+    {
+        let pipeline_layout = self.shader.layout().clone().build(device.clone())?;
+        unsafe { self.with_unchecked_pipeline_layout(device, pipeline_layout) }
+    }
+
+    /// Builds the compute pipeline with a specific pipeline layout.
+    ///
+    /// An error will be returned if the pipeline layout isn't a superset of what the shader
+    /// uses.
+    pub fn with_pipeline_layout<Pl>(
+        self,
+        device: Arc<Device>,
+        pipeline_layout: Pl,
+    ) -> Result<ComputePipeline<Pl>, ComputePipelineCreationError>
+    where
+        Pl: PipelineLayoutAbstract,
+    {
+        PipelineLayoutSuperset::ensure_superset_of(&pipeline_layout, self.shader.layout())?;
+        unsafe { self.with_unchecked_pipeline_layout(device, pipeline_layout) }
+    }
+
+    /// Same as `with_pipeline_layout`, but doesn't check whether the pipeline layout is a
+    /// superset of what the shader expects.
+    ///
+    /// # Safety
+    ///
+    /// - `pipeline_layout` must be a superset of the layout that the shader expects.
+    ///
+    pub unsafe fn with_unchecked_pipeline_layout<Pl>(
+        self,
+        device: Arc<Device>,
+        pipeline_layout: Pl,
+    ) -> Result<ComputePipeline<Pl>, ComputePipelineCreationError>
+    where
+        Pl: PipelineLayoutAbstract,
+    {
+        let mut infos = self.create_infos(&device, &pipeline_layout, &[])?;
+        let pipelines =
+            create_compute_pipelines(&device, self.cache.as_ref(), slice::from_mut(&mut infos))?;
+
+        Ok(self.into_pipeline(device, pipeline_layout, pipelines[0]))
+    }
+
+    /// Builds several compute pipelines at once, using the pipeline layouts that the shaders
+    /// expect.
+    ///
+    /// All the pipelines are created with a single `vkCreateComputePipelines` call, which is
+    /// usually faster than building them one by one. Since the call takes a single pipeline
+    /// cache, `cache` is used for the whole batch if it is `Some`. Otherwise, the cache passed to
+    /// `build_with_cache` of the first builder that has one is used. In both cases, the caches of
+    /// the other builders are ignored.
+    ///
+    /// A builder of the batch can use `derive_from_index` to derive from a builder that comes
+    /// before it in the batch.
+    pub fn build_many<I>(
+        device: Arc<Device>,
+        builders: I,
+        cache: Option<Arc<PipelineCache>>,
+    ) -> Result<Vec<AutoLayoutComputePipeline<Cs>>, ComputePipelineCreationError>
+    where
+        I: IntoIterator<Item = Self>,
+        Cs::PipelineLayout: Clone,
+    {
+        let builders: Vec<Self> = builders.into_iter().collect();
+        let layouts = builders
+            .iter()
+            .map(|builder| builder.shader.layout().clone().build(device.clone()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut infos = Vec::with_capacity(builders.len());
+        let mut batch_allow_derivatives = Vec::with_capacity(builders.len());
+        for (builder, layout) in builders.iter().zip(layouts.iter()) {
+            infos.push(builder.create_infos(&device, layout, &batch_allow_derivatives)?);
+            batch_allow_derivatives.push(builder.allow_derivatives);
+        }
+
+        let cache = cache.or_else(|| builders.iter().find_map(|builder| builder.cache.clone()));
+        let pipelines = unsafe { create_compute_pipelines(&device, cache.as_ref(), &mut infos)? };
+        drop(infos);
+
+        Ok(builders
+            .into_iter()
+            .zip(layouts)
+            .zip(pipelines)
+            .map(|((builder, layout), pipeline)| {
+                builder.into_pipeline(device.clone(), layout, pipeline)
+            })
+            .collect())
+    }
+
+    // Checks the parameters of the builder and turns them into the Vulkan structures describing
+    // the pipeline.
+    //
+    // `batch_allow_derivatives` contains the `allow_derivatives` value of each pipeline that
+    // comes before this one in the same `vkCreateComputePipelines` call.
+    //
+    // The returned structures point into the shader and the specialization constants of the
+    // builder, which must therefore be kept alive until the pipeline has been created.
+    fn create_infos<Pl>(
+        &self,
+        device: &Arc<Device>,
+        pipeline_layout: &Pl,
+        batch_allow_derivatives: &[bool],
+    ) -> Result<Box<ComputePipelineCreateInfos>, ComputePipelineCreationError>
+    where
+        Pl: PipelineLayoutAbstract,
+    {
+        if let Some(size) = self.required_subgroup_size {
+            if !device.loaded_extensions().ext_subgroup_size_control
+                || !device.enabled_features().subgroup_size_control
+            {
+                return Err(ComputePipelineCreationError::SubgroupSizeControlFeatureNotEnabled);
+            }
+
+            let physical_device = device.physical_device();
+            let properties = physical_device.extended_properties();
+            let min = properties.min_subgroup_size().unwrap_or(0);
+            let max = properties.max_subgroup_size().unwrap_or(0);
+            let stage_supported = matches!(
+                properties.required_subgroup_size_stages(),
+                Some(stages) if stages.compute
+            );
+
+            if !size.is_power_of_two() || size < min || size > max || !stage_supported {
+                return Err(
+                    ComputePipelineCreationError::RequiredSubgroupSizeNotSupported {
+                        requested: size,
+                        min,
+                        max,
+                    },
+                );
+            }
+        }
+
+        let (base_pipeline_handle, base_pipeline_index) = match self.base_pipeline {
+            None => (0, -1),
+            Some(BasePipeline::Pipeline {
+                handle,
+                device: base_device,
+                allows_derivatives,
+                ..
+            }) => {
+                if base_device != device.internal_object() {
+                    return Err(ComputePipelineCreationError::BasePipelineDeviceMismatch);
+                }
+
+                if !allows_derivatives {
+                    return Err(ComputePipelineCreationError::BasePipelineDoesntAllowDerivatives);
+                }
+
+                (handle, -1)
+            }
+            Some(BasePipeline::Index(base_index)) => {
+                match batch_allow_derivatives.get(base_index) {
+                    Some(true) => (0, base_index as i32),
+                    Some(false) => {
+                        return Err(
+                            ComputePipelineCreationError::BasePipelineDoesntAllowDerivatives,
+                        )
+                    }
+                    None => {
+                        return Err(ComputePipelineCreationError::InvalidBasePipelineIndex {
+                            index: batch_allow_derivatives.len(),
+                            base_index,
+                        })
+                    }
+                }
+            }
+        };
+
+        let mut flags = 0;
+        if self.allow_derivatives {
+            flags |= vk::PIPELINE_CREATE_ALLOW_DERIVATIVES_BIT;
+        }
+        if self.base_pipeline.is_some() {
+            flags |= vk::PIPELINE_CREATE_DERIVATIVE_BIT;
+        }
+
+        let spec_descriptors = Cs::SpecializationConstants::descriptors();
 
-        let pipeline = {
-            let spec_descriptors = Cs::SpecializationConstants::descriptors();
-            let specialization = vk::SpecializationInfo {
+        Ok(Box::new(ComputePipelineCreateInfos {
+            flags,
+            module: self.shader.module().internal_object(),
+            name: self.shader.name().as_ptr(),
+            specialization: vk::SpecializationInfo {
                 mapEntryCount: spec_descriptors.len() as u32,
                 pMapEntries: spec_descriptors.as_ptr() as *const _,
-                dataSize: mem::size_of_val(specialization),
-                pData: specialization as *const Cs::SpecializationConstants as *const _,
-            };
+                dataSize: mem::size_of_val(self.specialization),
+                pData: self.specialization as *const Cs::SpecializationConstants as *const _,
+            },
+            required_subgroup_size_info: self.required_subgroup_size.map(|size| {
+                vk::PipelineShaderStageRequiredSubgroupSizeCreateInfoEXT {
+                    sType: vk::STRUCTURE_TYPE_PIPELINE_SHADER_STAGE_REQUIRED_SUBGROUP_SIZE_CREATE_INFO_EXT,
+                    pNext: ptr::null_mut(),
+                    requiredSubgroupSize: size,
+                }
+            }),
+            layout: PipelineLayoutAbstract::sys(pipeline_layout).internal_object(),
+            base_pipeline_handle,
+            base_pipeline_index,
+        }))
+    }
+
+    // Turns the builder into the `ComputePipeline` object, once `pipeline` has been created from
+    // the result of `create_infos`.
+    fn into_pipeline<Pl>(
+        self,
+        device: Arc<Device>,
+        pipeline_layout: Pl,
+        pipeline: vk::Pipeline,
+    ) -> ComputePipeline<Pl> {
+        ComputePipeline {
+            inner: Inner { device, pipeline },
+            pipeline_layout,
+            allows_derivatives: self.allow_derivatives,
+        }
+    }
+}
+
+// Compute pipeline whose layout was inferred from its shader.
+type AutoLayoutComputePipeline<Cs> =
+    ComputePipeline<PipelineLayout<<Cs as EntryPointAbstract>::PipelineLayout>>;
 
-            let stage = vk::PipelineShaderStageCreateInfo {
+// Vulkan structures describing a compute pipeline, as produced by
+// `ComputePipelineBuilder::create_infos`.
+//
+// The structures point to each other, so the pointers are only filled by `create_info` once the
+// object is boxed.
+struct ComputePipelineCreateInfos {
+    flags: vk::PipelineCreateFlags,
+    module: vk::ShaderModule,
+    name: *const c_char,
+    specialization: vk::SpecializationInfo,
+    required_subgroup_size_info: Option<vk::PipelineShaderStageRequiredSubgroupSizeCreateInfoEXT>,
+    layout: vk::PipelineLayout,
+    base_pipeline_handle: vk::Pipeline,
+    base_pipeline_index: i32,
+}
+
+impl ComputePipelineCreateInfos {
+    fn create_info(&self) -> vk::ComputePipelineCreateInfo {
+        vk::ComputePipelineCreateInfo {
+            sType: vk::STRUCTURE_TYPE_COMPUTE_PIPELINE_CREATE_INFO,
+            pNext: ptr::null(),
+            flags: self.flags,
+            stage: vk::PipelineShaderStageCreateInfo {
                 sType: vk::STRUCTURE_TYPE_PIPELINE_SHADER_STAGE_CREATE_INFO,
-                pNext: ptr::null(),
+                pNext: match self.required_subgroup_size_info {
+                    Some(ref info) => info as *const _ as *const _,
+                    None => ptr::null(),
+                },
                 flags: 0,
                 stage: vk::SHADER_STAGE_COMPUTE_BIT,
-                module: shader.module().internal_object(),
-                pName: shader.name().as_ptr(),
-                pSpecializationInfo: if specialization.dataSize == 0 {
+                module: self.module,
+                pName: self.name,
+                pSpecializationInfo: if self.specialization.dataSize == 0 {
                     ptr::null()
                 } else {
-                    &specialization
+                    &self.specialization
                 },
-            };
+            },
+            layout: self.layout,
+            basePipelineHandle: self.base_pipeline_handle,
+            basePipelineIndex: self.base_pipeline_index,
+        }
+    }
+}
 
-            let infos = vk::ComputePipelineCreateInfo {
-                sType: vk::STRUCTURE_TYPE_COMPUTE_PIPELINE_CREATE_INFO,
-                pNext: ptr::null(),
-                flags: 0,
-                stage: stage,
-                layout: PipelineLayoutAbstract::sys(&pipeline_layout).internal_object(),
-                basePipelineHandle: 0,
-                basePipelineIndex: 0,
-            };
-
-            let cache_handle = match cache {
-                Some(ref cache) => cache.internal_object(),
-                None => vk::NULL_HANDLE,
-            };
-
-            let mut output = MaybeUninit::uninit();
-            check_errors(vk.CreateComputePipelines(
-                device.internal_object(),
-                cache_handle,
-                1,
-                &infos,
-                ptr::null(),
-                output.as_mut_ptr(),
-            ))?;
-            output.assume_init()
-        };
+// Creates all the pipelines described by `infos` with a single `vkCreateComputePipelines` call.
+//
+// The objects referenced by `infos` must still be alive.
+unsafe fn create_compute_pipelines(
+    device: &Device,
+    cache: Option<&Arc<PipelineCache>>,
+    infos: &mut [Box<ComputePipelineCreateInfos>],
+) -> Result<Vec<vk::Pipeline>, ComputePipelineCreationError> {
+    let vk = device.pointers();
 
-        Ok(ComputePipeline {
-            inner: Inner {
-                device: device.clone(),
-                pipeline: pipeline,
-            },
-            pipeline_layout: pipeline_layout,
-        })
+    let create_infos = infos
+        .iter()
+        .map(|infos| infos.create_info())
+        .collect::<SmallVec<[_; 8]>>();
+
+    let cache_handle = match cache {
+        Some(cache) => cache.internal_object(),
+        None => vk::NULL_HANDLE,
+    };
+
+    let mut output = vec![vk::NULL_HANDLE; create_infos.len()];
+    let result = check_errors(vk.CreateComputePipelines(
+        device.internal_object(),
+        cache_handle,
+        create_infos.len() as u32,
+        create_infos.as_ptr(),
+        ptr::null(),
+        output.as_mut_ptr(),
+    ));
+
+    if let Err(err) = result {
+        // The pipelines of the batch that could be created must still be destroyed.
+        for &pipeline in output.iter() {
+            if pipeline != vk::NULL_HANDLE {
+                vk.DestroyPipeline(device.internal_object(), pipeline, ptr::null());
+            }
+        }
+
+        return Err(err.into());
     }
+
+    Ok(output)
 }
 
 impl<Pl> fmt::Debug for ComputePipeline<Pl> {
@@ -208,6 +580,14 @@ impl<Pl> ComputePipeline<Pl> {
     pub fn layout(&self) -> &Pl {
         &self.pipeline_layout
     }
+
+    /// Returns true if other pipelines can be derived from this one.
+    ///
+    /// See `ComputePipelineBuilder::allow_derivatives`.
+    #[inline]
+    pub fn allows_derivatives(&self) -> bool {
+        self.allows_derivatives
+    }
 }
 
 /// Trait implemented on all compute pipelines.
@@ -336,6 +716,30 @@ pub enum ComputePipelineCreationError {
     PipelineLayoutCreationError(PipelineLayoutCreationError),
     /// The pipeline layout is not compatible with what the shader expects.
     IncompatiblePipelineLayout(PipelineLayoutNotSupersetError),
+    /// A subgroup size was required, but the `ext_subgroup_size_control` extension or the
+    /// `subgroup_size_control` feature isn't enabled.
+    SubgroupSizeControlFeatureNotEnabled,
+    /// The required subgroup size isn't a power of two within the supported range, or the
+    /// device doesn't support requiring a subgroup size for compute shaders.
+    RequiredSubgroupSizeNotSupported {
+        /// The requested subgroup size.
+        requested: u32,
+        /// The minimum supported subgroup size.
+        min: u32,
+        /// The maximum supported subgroup size.
+        max: u32,
+    },
+    /// The base pipeline was not created with `allow_derivatives`.
+    BasePipelineDoesntAllowDerivatives,
+    /// The base pipeline was created with a different device.
+    BasePipelineDeviceMismatch,
+    /// The base pipeline index doesn't refer to an earlier pipeline of the same batch.
+    InvalidBasePipelineIndex {
+        /// Index of the pipeline in the batch.
+        index: usize,
+        /// Index of the base pipeline that was requested.
+        base_index: usize,
+    },
 }
 
 impl error::Error for ComputePipelineCreationError {
@@ -345,6 +749,7 @@ impl error::Error for ComputePipelineCreationError {
             ComputePipelineCreationError::OomError(ref err) => Some(err),
             ComputePipelineCreationError::PipelineLayoutCreationError(ref err) => Some(err),
             ComputePipelineCreationError::IncompatiblePipelineLayout(ref err) => Some(err),
+            _ => None,
         }
     }
 }
@@ -363,6 +768,21 @@ impl fmt::Display for ComputePipelineCreationError {
                 ComputePipelineCreationError::IncompatiblePipelineLayout(_) => {
                     "the pipeline layout is not compatible with what the shader expects"
                 }
+                ComputePipelineCreationError::SubgroupSizeControlFeatureNotEnabled => {
+                    "a subgroup size was required, but subgroup size control isn't enabled"
+                }
+                ComputePipelineCreationError::RequiredSubgroupSizeNotSupported { .. } => {
+                    "the required subgroup size isn't supported by the device"
+                }
+                ComputePipelineCreationError::BasePipelineDoesntAllowDerivatives => {
+                    "the base pipeline was not created with `allow_derivatives`"
+                }
+                ComputePipelineCreationError::BasePipelineDeviceMismatch => {
+                    "the base pipeline was created with a different device"
+                }
+                ComputePipelineCreationError::InvalidBasePipelineIndex { .. } => {
+                    "the base pipeline index doesn't refer to an earlier pipeline of the same batch"
+                }
             }
         )
     }
@@ -414,13 +834,17 @@ mod tests {
     use crate::descriptor::descriptor::DescriptorDescTy;
     use crate::descriptor::descriptor::ShaderStages;
     use crate::descriptor::descriptor_set::PersistentDescriptorSet;
+    use crate::descriptor::pipeline_layout::EmptyPipelineDesc;
     use crate::descriptor::pipeline_layout::PipelineLayoutAbstract;
     use crate::descriptor::pipeline_layout::PipelineLayoutDesc;
     use crate::descriptor::pipeline_layout::PipelineLayoutDescPcRange;
+    use crate::pipeline::shader::ComputeEntryPoint;
     use crate::pipeline::shader::ShaderModule;
     use crate::pipeline::shader::SpecializationConstants;
     use crate::pipeline::shader::SpecializationMapEntry;
     use crate::pipeline::ComputePipeline;
+    use crate::pipeline::ComputePipelineBuilder;
+    use crate::pipeline::ComputePipelineCreationError;
     use crate::sync::now;
     use crate::sync::GpuFuture;
     use std::ffi::CStr;
@@ -429,6 +853,110 @@ mod tests {
     // TODO: test for basic creation
     // TODO: test for pipeline layout error
 
+    /*
+     * OpCapability Shader
+     * OpMemoryModel Logical GLSL450
+     * OpEntryPoint GLCompute %main "main"
+     * OpExecutionMode %main LocalSize 1 1 1
+     * void main() {}
+     */
+    const EMPTY_MODULE: [u8; 140] = [
+        3, 2, 35, 7, 0, 0, 1, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 17, 0, 2, 0, 1, 0, 0, 0, 14,
+        0, 3, 0, 0, 0, 0, 0, 1, 0, 0, 0, 15, 0, 5, 0, 5, 0, 0, 0, 4, 0, 0, 0, 109, 97, 105, 110, 0,
+        0, 0, 0, 16, 0, 6, 0, 4, 0, 0, 0, 17, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 19, 0,
+        2, 0, 2, 0, 0, 0, 33, 0, 3, 0, 3, 0, 0, 0, 2, 0, 0, 0, 54, 0, 5, 0, 2, 0, 0, 0, 4, 0, 0, 0,
+        0, 0, 0, 0, 3, 0, 0, 0, 248, 0, 2, 0, 5, 0, 0, 0, 253, 0, 1, 0, 56, 0, 1, 0,
+    ];
+
+    fn empty_entry_point(module: &ShaderModule) -> ComputeEntryPoint<'_, (), EmptyPipelineDesc> {
+        static NAME: [u8; 5] = [109, 97, 105, 110, 0]; // "main"
+        unsafe {
+            module.compute_entry_point(CStr::from_ptr(NAME.as_ptr() as *const _), EmptyPipelineDesc)
+        }
+    }
+
+    #[test]
+    fn derive_from_pipeline() {
+        let (device, _) = gfx_dev_and_queue!();
+        let module = unsafe { ShaderModule::new(device.clone(), &EMPTY_MODULE).unwrap() };
+        let shader = empty_entry_point(&module);
+
+        let base = Arc::new(
+            ComputePipeline::start(&shader, &())
+                .allow_derivatives()
+                .build(device.clone())
+                .unwrap(),
+        );
+        assert!(base.allows_derivatives());
+
+        let derived = ComputePipeline::start(&shader, &())
+            .derive_from(base)
+            .build(device.clone())
+            .unwrap();
+        assert!(!derived.allows_derivatives());
+    }
+
+    #[test]
+    fn derive_from_pipeline_without_derivatives() {
+        let (device, _) = gfx_dev_and_queue!();
+        let module = unsafe { ShaderModule::new(device.clone(), &EMPTY_MODULE).unwrap() };
+        let shader = empty_entry_point(&module);
+
+        let base = Arc::new(ComputePipeline::new(device.clone(), &shader, &(), None).unwrap());
+
+        match ComputePipeline::start(&shader, &())
+            .derive_from(base)
+            .build(device.clone())
+        {
+            Err(ComputePipelineCreationError::BasePipelineDoesntAllowDerivatives) => (),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn build_many_derive_from_index() {
+        let (device, _) = gfx_dev_and_queue!();
+        let module = unsafe { ShaderModule::new(device.clone(), &EMPTY_MODULE).unwrap() };
+        let shader = empty_entry_point(&module);
+
+        let pipelines = ComputePipelineBuilder::build_many(
+            device.clone(),
+            vec![
+                ComputePipeline::start(&shader, &()).allow_derivatives(),
+                ComputePipeline::start(&shader, &()).derive_from_index(0),
+                ComputePipeline::start(&shader, &()).derive_from_index(0),
+            ],
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(pipelines.len(), 3);
+    }
+
+    #[test]
+    fn build_many_derive_from_later_index() {
+        let (device, _) = gfx_dev_and_queue!();
+        let module = unsafe { ShaderModule::new(device.clone(), &EMPTY_MODULE).unwrap() };
+        let shader = empty_entry_point(&module);
+
+        let result = ComputePipelineBuilder::build_many(
+            device.clone(),
+            vec![
+                ComputePipeline::start(&shader, &()).derive_from_index(1),
+                ComputePipeline::start(&shader, &()).allow_derivatives(),
+            ],
+            None,
+        );
+
+        match result {
+            Err(ComputePipelineCreationError::InvalidBasePipelineIndex {
+                index: 0,
+                base_index: 1,
+            }) => (),
+            _ => panic!(),
+        }
+    }
+
     #[test]
     fn spec_constants() {
         // This test checks whether specialization constants work.
@@ -12,6 +12,7 @@
 #![allow(deprecated)]
 
 use crate::check_errors;
+use crate::descriptor::pipeline_layout::EmptyPipelineDesc;
 use crate::descriptor::pipeline_layout::PipelineLayoutAbstract;
 use crate::descriptor::pipeline_layout::PipelineLayoutDesc;
 use crate::descriptor::pipeline_layout::PipelineLayoutDescTweaks;
@@ -45,19 +46,38 @@ use crate::pipeline::vertex::VertexDefinition;
 use crate::pipeline::viewport::Scissor;
 use crate::pipeline::viewport::Viewport;
 use crate::pipeline::viewport::ViewportsState;
+use crate::pipeline::BasePipeline;
 use crate::render_pass::Subpass;
 use crate::vk;
 use crate::VulkanObject;
 use smallvec::SmallVec;
 use std::mem;
-use std::mem::MaybeUninit;
 use std::ptr;
+use std::slice;
 use std::sync::Arc;
 use std::u32;
 
+// Graphics pipeline whose layout was inferred from its shaders.
+type AutoLayoutGraphicsPipeline<Vdef> =
+    GraphicsPipeline<Vdef, Box<dyn PipelineLayoutAbstract + Send + Sync>>;
+
 /// Prototype for a `GraphicsPipeline`.
 // TODO: we can optimize this by filling directly the raw vk structs
-pub struct GraphicsPipelineBuilder<Vdef, Vs, Vss, Tcs, Tcss, Tes, Tess, Gs, Gss, Fs, Fss> {
+pub struct GraphicsPipelineBuilder<
+    Vdef,
+    Vs,
+    Vss,
+    Tcs,
+    Tcss,
+    Tes,
+    Tess,
+    Gs,
+    Gss,
+    Fs,
+    Fss,
+    Ts = EmptyEntryPointDummy,
+    Tss = (),
+> {
     vertex_input: Vdef,
     vertex_shader: Option<(Vs, Vss)>,
     input_assembly: vk::PipelineInputAssemblyStateCreateInfo,
@@ -70,10 +90,13 @@ pub struct GraphicsPipelineBuilder<Vdef, Vs, Vss, Tcs, Tcss, Tes, Tess, Gs, Gss,
     raster: Rasterization,
     multisample: vk::PipelineMultisampleStateCreateInfo,
     fragment_shader: Option<(Fs, Fss)>,
+    task_shader: Option<(Ts, Tss)>,
     depth_stencil: DepthStencil,
     blend: Blend,
     subpass: Option<Subpass>,
     cache: Option<Arc<PipelineCache>>,
+    allow_derivatives: bool,
+    base_pipeline: Option<BasePipeline>,
 }
 
 // Additional parameters if tessellation is used.
@@ -83,6 +106,159 @@ struct TessInfo<Tcs, Tcss, Tes, Tess> {
     tessellation_evaluation_shader: (Tes, Tess),
 }
 
+// Vulkan structures describing a graphics pipeline, as produced by
+// `GraphicsPipelineBuilder::create_infos`.
+//
+// The structures point to each other, so the pointers are only filled by `create_info` once the
+// object is boxed.
+struct GraphicsPipelineCreateInfos {
+    flags: vk::PipelineCreateFlags,
+    mesh_shading: bool,
+    specializations: SmallVec<[vk::SpecializationInfo; 5]>,
+    stages: SmallVec<[vk::PipelineShaderStageCreateInfo; 5]>,
+    binding_descriptions: SmallVec<[vk::VertexInputBindingDescription; 8]>,
+    attribute_descriptions: SmallVec<[vk::VertexInputAttributeDescription; 8]>,
+    vertex_input_state: vk::PipelineVertexInputStateCreateInfo,
+    input_assembly: vk::PipelineInputAssemblyStateCreateInfo,
+    tessellation: Option<vk::PipelineTessellationStateCreateInfo>,
+    viewports: SmallVec<[vk::Viewport; 4]>,
+    scissors: SmallVec<[vk::Rect2D; 4]>,
+    viewport_info: vk::PipelineViewportStateCreateInfo,
+    rasterization: vk::PipelineRasterizationStateCreateInfo,
+    multisample: vk::PipelineMultisampleStateCreateInfo,
+    depth_stencil: vk::PipelineDepthStencilStateCreateInfo,
+    blend_attachments: SmallVec<[vk::PipelineColorBlendAttachmentState; 8]>,
+    blend: vk::PipelineColorBlendStateCreateInfo,
+    dynamic_states: SmallVec<[vk::DynamicState; 8]>,
+    dynamic_state_info: Option<vk::PipelineDynamicStateCreateInfo>,
+    layout: vk::PipelineLayout,
+    render_pass: vk::RenderPass,
+    subpass: u32,
+    base_pipeline_handle: vk::Pipeline,
+    base_pipeline_index: i32,
+}
+
+impl GraphicsPipelineCreateInfos {
+    // Fills the pointers between the structures and returns the create info of the pipeline.
+    //
+    // `self` must not be moved or modified while the returned value is in use.
+    fn create_info(&mut self) -> vk::GraphicsPipelineCreateInfo {
+        for (stage, specialization) in self.stages.iter_mut().zip(self.specializations.iter()) {
+            stage.pSpecializationInfo = specialization;
+        }
+
+        self.vertex_input_state.pVertexBindingDescriptions = self.binding_descriptions.as_ptr();
+        self.vertex_input_state.pVertexAttributeDescriptions = self.attribute_descriptions.as_ptr();
+
+        // validation layer crashes if you just pass the pointer
+        self.viewport_info.pViewports = if self.viewports.is_empty() {
+            ptr::null()
+        } else {
+            self.viewports.as_ptr()
+        };
+        self.viewport_info.pScissors = if self.scissors.is_empty() {
+            ptr::null()
+        } else {
+            self.scissors.as_ptr()
+        };
+
+        self.blend.pAttachments = self.blend_attachments.as_ptr();
+
+        if let Some(ref mut dynamic_state_info) = self.dynamic_state_info {
+            dynamic_state_info.pDynamicStates = self.dynamic_states.as_ptr();
+        }
+
+        vk::GraphicsPipelineCreateInfo {
+            sType: vk::STRUCTURE_TYPE_GRAPHICS_PIPELINE_CREATE_INFO,
+            pNext: ptr::null(),
+            flags: self.flags,
+            stageCount: self.stages.len() as u32,
+            pStages: self.stages.as_ptr(),
+            pVertexInputState: if self.mesh_shading {
+                ptr::null()
+            } else {
+                &self.vertex_input_state
+            },
+            pInputAssemblyState: if self.mesh_shading {
+                ptr::null()
+            } else {
+                &self.input_assembly
+            },
+            pTessellationState: self
+                .tessellation
+                .as_ref()
+                .map(|t| t as *const _)
+                .unwrap_or(ptr::null()),
+            pViewportState: &self.viewport_info,
+            pRasterizationState: &self.rasterization,
+            pMultisampleState: &self.multisample,
+            pDepthStencilState: &self.depth_stencil,
+            pColorBlendState: &self.blend,
+            pDynamicState: self
+                .dynamic_state_info
+                .as_ref()
+                .map(|s| s as *const _)
+                .unwrap_or(ptr::null()),
+            layout: self.layout,
+            renderPass: self.render_pass,
+            subpass: self.subpass,
+            basePipelineHandle: self.base_pipeline_handle,
+            basePipelineIndex: self.base_pipeline_index,
+        }
+    }
+}
+
+// Creates all the pipelines described by `infos` with a single `vkCreateGraphicsPipelines` call.
+//
+// The objects referenced by `infos` must still be alive.
+unsafe fn create_graphics_pipelines(
+    device: &Device,
+    cache: Option<&Arc<PipelineCache>>,
+    infos: &mut [Box<GraphicsPipelineCreateInfos>],
+) -> Result<Vec<vk::Pipeline>, GraphicsPipelineCreationError> {
+    let vk = device.pointers();
+
+    let create_infos = infos
+        .iter_mut()
+        .map(|infos| infos.create_info())
+        .collect::<SmallVec<[_; 8]>>();
+
+    let cache_handle = match cache {
+        Some(cache) => cache.internal_object(),
+        None => vk::NULL_HANDLE,
+    };
+
+    let mut output = vec![vk::NULL_HANDLE; create_infos.len()];
+    let result = check_errors(vk.CreateGraphicsPipelines(
+        device.internal_object(),
+        cache_handle,
+        create_infos.len() as u32,
+        create_infos.as_ptr(),
+        ptr::null(),
+        output.as_mut_ptr(),
+    ));
+
+    if let Err(err) = result {
+        // The pipelines of the batch that could be created must still be destroyed.
+        for &pipeline in output.iter() {
+            if pipeline != vk::NULL_HANDLE {
+                vk.DestroyPipeline(device.internal_object(), pipeline, ptr::null());
+            }
+        }
+
+        return Err(err.into());
+    }
+
+    // Some drivers return `VK_SUCCESS` but provide a null handle if they
+    // fail to create the pipeline (due to invalid shaders, etc)
+    // This check ensures that we don't create an invalid `GraphicsPipeline` instance
+    if output.contains(&vk::NULL_HANDLE) {
+        panic!("vkCreateGraphicsPipelines provided a NULL handle");
+    }
+
+    Ok(output)
+}
+
 impl
     GraphicsPipelineBuilder<
         BufferlessDefinition,
@@ -96,6 +272,8 @@ impl
         (),
         EmptyEntryPointDummy,
         (),
+        EmptyEntryPointDummy,
+        (),
     >
 {
     /// Builds a new empty builder.
@@ -119,17 +297,20 @@ impl
                     ..mem::zeroed()
                 },
                 fragment_shader: None,
+                task_shader: None,
                 depth_stencil: DepthStencil::disabled(),
                 blend: Blend::pass_through(),
                 subpass: None,
                 cache: None,
+                allow_derivatives: false,
+                base_pipeline: None,
             }
         }
     }
 }
 
-impl<Vdef, Vs, Vss, Tcs, Tcss, Tes, Tess, Gs, Gss, Fs, Fss>
-    GraphicsPipelineBuilder<Vdef, Vs, Vss, Tcs, Tcss, Tes, Tess, Gs, Gss, Fs, Fss>
+impl<Vdef, Vs, Vss, Tcs, Tcss, Tes, Tess, Gs, Gss, Fs, Fss, Ts, Tss>
+    GraphicsPipelineBuilder<Vdef, Vs, Vss, Tcs, Tcss, Tes, Tess, Gs, Gss, Fs, Fss, Ts, Tss>
 where
     Vdef: VertexDefinition<Vs::InputDefinition>,
     Vs: GraphicsEntryPointAbstract,
@@ -137,16 +318,19 @@ where
     Gs: GraphicsEntryPointAbstract,
     Tcs: GraphicsEntryPointAbstract,
     Tes: GraphicsEntryPointAbstract,
+    Ts: GraphicsEntryPointAbstract,
     Vss: SpecializationConstants,
     Tcss: SpecializationConstants,
     Tess: SpecializationConstants,
     Gss: SpecializationConstants,
     Fss: SpecializationConstants,
+    Tss: SpecializationConstants,
     Vs::PipelineLayout: Clone + 'static + Send + Sync, // TODO: shouldn't be required
     Fs::PipelineLayout: Clone + 'static + Send + Sync, // TODO: shouldn't be required
     Tcs::PipelineLayout: Clone + 'static + Send + Sync, // TODO: shouldn't be required
     Tes::PipelineLayout: Clone + 'static + Send + Sync, // TODO: shouldn't be required
     Gs::PipelineLayout: Clone + 'static + Send + Sync, // TODO: shouldn't be required
+    Ts::PipelineLayout: Clone + 'static + Send + Sync, // TODO: shouldn't be required
     Tcs::InputDefinition: ShaderInterfaceDefMatch<Vs::OutputDefinition>,
     Tes::InputDefinition: ShaderInterfaceDefMatch<Tcs::OutputDefinition>,
     Gs::InputDefinition: ShaderInterfaceDefMatch<Tes::OutputDefinition>
@@ -179,6 +363,16 @@ where
         GraphicsPipeline<Vdef, Box<dyn PipelineLayoutAbstract + Send + Sync>>,
         GraphicsPipelineCreationError,
     > {
+        let pipeline_layout = self.auto_layout(&device, dynamic_buffers)?;
+        self.with_pipeline_layout(device, pipeline_layout)
+    }
+
+    // Builds the pipeline layout corresponding to the union of the layouts of the shaders.
+    fn auto_layout(
+        &self,
+        device: &Arc<Device>,
+        dynamic_buffers: &[(usize, usize)],
+    ) -> Result<Box<dyn PipelineLayoutAbstract + Send + Sync>, GraphicsPipelineCreationError> {
         let pipeline_layout;
 
         if let Some(ref tess) = self.tessellation {
@@ -369,6 +563,13 @@ where
                     ));
                 }
 
+                // A mesh pipeline can also have a task shader.
+                let task_layout: Box<dyn PipelineLayoutDesc + Send + Sync> = match self.task_shader
+                {
+                    Some(ref task_shader) => Box::new(task_shader.0.layout().clone()),
+                    None => Box::new(EmptyPipelineDesc),
+                };
+
                 pipeline_layout = Box::new(
                     PipelineLayoutDescTweaks::new(
                         self.vertex_shader
@@ -377,7 +578,8 @@ where
                             .0
                             .layout()
                             .clone()
-                            .union(self.fragment_shader.as_ref().unwrap().0.layout().clone()),
+                            .union(self.fragment_shader.as_ref().unwrap().0.layout().clone())
+                            .union(task_layout),
                         dynamic_buffers.into_iter().cloned(),
                     )
                     .build(device.clone())
@@ -386,7 +588,7 @@ where
             }
         }
 
-        self.with_pipeline_layout(device, pipeline_layout)
+        Ok(pipeline_layout)
     }
 
     /// Builds the graphics pipeline.
@@ -395,43 +597,147 @@ where
     /// object corresponding to the union of your shaders while this function allows you to specify
     /// the pipeline layout.
     pub fn with_pipeline_layout<Pl>(
-        mut self,
+        self,
         device: Arc<Device>,
         pipeline_layout: Pl,
     ) -> Result<GraphicsPipeline<Vdef, Pl>, GraphicsPipelineCreationError>
+    where
+        Pl: PipelineLayoutAbstract,
+    {
+        let mut infos = self.create_infos(&device, &pipeline_layout, &[])?;
+        let pipelines = unsafe {
+            create_graphics_pipelines(&device, self.cache.as_ref(), slice::from_mut(&mut infos))?
+        };
+
+        Ok(self.into_pipeline(device, pipeline_layout, pipelines[0]))
+    }
+
+    /// Builds several graphics pipelines at once, using inferred pipeline layouts.
+    ///
+    /// All the pipelines are created with a single `vkCreateGraphicsPipelines` call, which is
+    /// usually faster than building them one by one. Since the call takes a single pipeline
+    /// cache, `cache` is used for the whole batch if it is `Some`. Otherwise, the cache passed to
+    /// `build_with_cache` of the first builder that has one is used. In both cases, the caches of
+    /// the other builders are ignored.
+    ///
+    /// A builder of the batch can use `derive_from_index` to derive from a builder that comes
+    /// before it in the batch.
+    pub fn build_many<I>(
+        device: Arc<Device>,
+        builders: I,
+        cache: Option<Arc<PipelineCache>>,
+    ) -> Result<Vec<AutoLayoutGraphicsPipeline<Vdef>>, GraphicsPipelineCreationError>
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        let builders: Vec<Self> = builders.into_iter().collect();
+        let layouts = builders
+            .iter()
+            .map(|builder| builder.auto_layout(&device, &[]))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut infos = Vec::with_capacity(builders.len());
+        let mut batch_allow_derivatives = Vec::with_capacity(builders.len());
+        for (builder, layout) in builders.iter().zip(layouts.iter()) {
+            infos.push(builder.create_infos(&device, layout, &batch_allow_derivatives)?);
+            batch_allow_derivatives.push(builder.allow_derivatives);
+        }
+
+        let cache = cache.or_else(|| builders.iter().find_map(|builder| builder.cache.clone()));
+        let pipelines = unsafe { create_graphics_pipelines(&device, cache.as_ref(), &mut infos)? };
+        drop(infos);
+
+        Ok(builders
+            .into_iter()
+            .zip(layouts)
+            .zip(pipelines)
+            .map(|((builder, layout), pipeline)| {
+                builder.into_pipeline(device.clone(), layout, pipeline)
+            })
+            .collect())
+    }
+
+    // Checks the parameters of the builder and turns them into the Vulkan structures describing
+    // the pipeline.
+    //
+    // `batch_allow_derivatives` contains the `allow_derivatives` value of each pipeline that
+    // comes before this one in the same `vkCreateGraphicsPipelines` call.
+    //
+    // The returned structures point into `self`, which must therefore not be moved or modified
+    // until the pipeline has been created.
+    fn create_infos<Pl>(
+        &self,
+        device: &Arc<Device>,
+        pipeline_layout: &Pl,
+        batch_allow_derivatives: &[bool],
+    ) -> Result<Box<GraphicsPipelineCreateInfos>, GraphicsPipelineCreationError>
     where
         Pl: PipelineLayoutAbstract,
     {
         // TODO: return errors instead of panicking if missing param
 
-        let vk = device.pointers();
+        // With a mesh shader, the mesh shader takes the place of the vertex shader and the
+        // vertex input and input assembly states are ignored.
+        let mesh_shading = match self.vertex_shader.as_ref().unwrap().0.ty() {
+            GraphicsShaderType::Vertex => false,
+            GraphicsShaderType::Mesh => true,
+            _ => return Err(GraphicsPipelineCreationError::WrongShaderType),
+        };
+
+        if mesh_shading {
+            if !device.enabled_features().mesh_shader && !device.enabled_features().mesh_shader_nv {
+                return Err(GraphicsPipelineCreationError::MeshShaderFeatureNotEnabled);
+            }
+
+            if self.tessellation.is_some() || self.geometry_shader.is_some() {
+                return Err(GraphicsPipelineCreationError::MeshShaderIncompatibleStages);
+            }
+        }
+
+        if let Some(ref task_shader) = self.task_shader {
+            if !mesh_shading {
+                return Err(GraphicsPipelineCreationError::TaskShaderWithoutMeshShader);
+            }
+
+            if !device.enabled_features().task_shader && !device.enabled_features().task_shader_nv {
+                return Err(GraphicsPipelineCreationError::TaskShaderFeatureNotEnabled);
+            }
+
+            match task_shader.0.ty() {
+                GraphicsShaderType::Task => {}
+                _ => return Err(GraphicsPipelineCreationError::WrongShaderType),
+            };
+        }
 
         // Checking that the pipeline layout matches the shader stages.
         // TODO: more details in the errors
         PipelineLayoutSuperset::ensure_superset_of(
-            &pipeline_layout,
+            pipeline_layout,
             self.vertex_shader.as_ref().unwrap().0.layout(),
         )?;
         PipelineLayoutSuperset::ensure_superset_of(
-            &pipeline_layout,
+            pipeline_layout,
             self.fragment_shader.as_ref().unwrap().0.layout(),
         )?;
         if let Some(ref geometry_shader) = self.geometry_shader {
             PipelineLayoutSuperset::ensure_superset_of(
-                &pipeline_layout,
+                pipeline_layout,
                 geometry_shader.0.layout(),
             )?;
         }
         if let Some(ref tess) = self.tessellation {
             PipelineLayoutSuperset::ensure_superset_of(
-                &pipeline_layout,
+                pipeline_layout,
                 tess.tessellation_control_shader.0.layout(),
             )?;
             PipelineLayoutSuperset::ensure_superset_of(
-                &pipeline_layout,
+                pipeline_layout,
                 tess.tessellation_evaluation_shader.0.layout(),
             )?;
         }
+        if let Some(ref task_shader) = self.task_shader {
+            PipelineLayoutSuperset::ensure_superset_of(pipeline_layout, task_shader.0.layout())?;
+        }
 
         // Check that the subpass can accept the output of the fragment shader.
         if !self
@@ -494,6 +800,18 @@ where
         } else {
             None
         };
+        let task_shader_specialization = if let Some(ref ts) = self.task_shader {
+            let spec_descriptors = Tss::descriptors();
+            let constants = &ts.1;
+            Some(vk::SpecializationInfo {
+                mapEntryCount: spec_descriptors.len() as u32,
+                pMapEntries: spec_descriptors.as_ptr() as *const _,
+                dataSize: mem::size_of_val(constants),
+                pData: constants as *const Tss as *const _,
+            })
+        } else {
+            None
+        };
         let fragment_shader_specialization = {
             let spec_descriptors = Fss::descriptors();
             let constants = &self.fragment_shader.as_ref().unwrap().1;
@@ -509,16 +827,15 @@ where
         let stages = {
             let mut stages = SmallVec::<[_; 5]>::new();
 
-            match self.vertex_shader.as_ref().unwrap().0.ty() {
-                GraphicsShaderType::Vertex => {}
-                _ => return Err(GraphicsPipelineCreationError::WrongShaderType),
-            };
-
             stages.push(vk::PipelineShaderStageCreateInfo {
                 sType: vk::STRUCTURE_TYPE_PIPELINE_SHADER_STAGE_CREATE_INFO,
                 pNext: ptr::null(),
                 flags: 0, // reserved
-                stage: vk::SHADER_STAGE_VERTEX_BIT,
+                stage: if mesh_shading {
+                    vk::SHADER_STAGE_MESH_BIT_NV
+                } else {
+                    vk::SHADER_STAGE_VERTEX_BIT
+                },
                 module: self
                     .vertex_shader
                     .as_ref()
@@ -527,7 +844,7 @@ where
                     .module()
                     .internal_object(),
                 pName: self.vertex_shader.as_ref().unwrap().0.name().as_ptr(),
-                pSpecializationInfo: &vertex_shader_specialization as *const _,
+                pSpecializationInfo: ptr::null(),
             });
 
             match self.fragment_shader.as_ref().unwrap().0.ty() {
@@ -548,9 +865,21 @@ where
                     .module()
                     .internal_object(),
                 pName: self.fragment_shader.as_ref().unwrap().0.name().as_ptr(),
-                pSpecializationInfo: &fragment_shader_specialization as *const _,
+                pSpecializationInfo: ptr::null(),
             });
 
+            if let Some(ref ts) = self.task_shader {
+                stages.push(vk::PipelineShaderStageCreateInfo {
+                    sType: vk::STRUCTURE_TYPE_PIPELINE_SHADER_STAGE_CREATE_INFO,
+                    pNext: ptr::null(),
+                    flags: 0, // reserved
+                    stage: vk::SHADER_STAGE_TASK_BIT_NV,
+                    module: ts.0.module().internal_object(),
+                    pName: ts.0.name().as_ptr(),
+                    pSpecializationInfo: ptr::null(),
+                });
+            }
+
             if let Some(ref gs) = self.geometry_shader {
                 if !device.enabled_features().geometry_shader {
                     return Err(GraphicsPipelineCreationError::GeometryShaderFeatureNotEnabled);
@@ -563,8 +892,7 @@ where
                     stage: vk::SHADER_STAGE_GEOMETRY_BIT,
                     module: gs.0.module().internal_object(),
                     pName: gs.0.name().as_ptr(),
-                    pSpecializationInfo: geometry_shader_specialization.as_ref().unwrap()
-                        as *const _,
+                    pSpecializationInfo: ptr::null(),
                 });
             }
 
@@ -596,8 +924,7 @@ where
                         .module()
                         .internal_object(),
                     pName: tess.tessellation_control_shader.0.name().as_ptr(),
-                    pSpecializationInfo: &tess_shader_specialization.as_ref().unwrap().0
-                        as *const _,
+                    pSpecializationInfo: ptr::null(),
                 });
 
                 stages.push(vk::PipelineShaderStageCreateInfo {
@@ -611,14 +938,27 @@ where
                         .module()
                         .internal_object(),
                     pName: tess.tessellation_evaluation_shader.0.name().as_ptr(),
-                    pSpecializationInfo: &tess_shader_specialization.as_ref().unwrap().1
-                        as *const _,
+                    pSpecializationInfo: ptr::null(),
                 });
             }
 
             stages
         };
 
+        // Specialization infos, in the same order as `stages`.
+        let specializations = {
+            let mut specializations = SmallVec::<[_; 5]>::new();
+            specializations.push(vertex_shader_specialization);
+            specializations.push(fragment_shader_specialization);
+            specializations.extend(task_shader_specialization);
+            specializations.extend(geometry_shader_specialization);
+            if let Some((tcs_spec, tes_spec)) = tess_shader_specialization {
+                specializations.push(tcs_spec);
+                specializations.push(tes_spec);
+            }
+            specializations
+        };
+
         // Vertex bindings.
         let (binding_descriptions, attribute_descriptions) = {
             let (buffers_iter, attribs_iter) = self
@@ -730,9 +1070,9 @@ where
             pNext: ptr::null(),
             flags: 0, // reserved
             vertexBindingDescriptionCount: binding_descriptions.len() as u32,
-            pVertexBindingDescriptions: binding_descriptions.as_ptr(),
+            pVertexBindingDescriptions: ptr::null(),
             vertexAttributeDescriptionCount: attribute_descriptions.len() as u32,
-            pVertexAttributeDescriptions: attribute_descriptions.as_ptr(),
+            pVertexAttributeDescriptions: ptr::null(),
         };
 
         if self.input_assembly.primitiveRestartEnable != vk::FALSE
@@ -856,17 +1196,9 @@ where
             pNext: ptr::null(),
             flags: 0, // reserved
             viewportCount: vp_num,
-            pViewports: if vp_vp.is_empty() {
-                ptr::null()
-            } else {
-                vp_vp.as_ptr()
-            }, // validation layer crashes if you just pass the pointer
+            pViewports: ptr::null(),
             scissorCount: vp_num,
-            pScissors: if vp_sc.is_empty() {
-                ptr::null()
-            } else {
-                vp_sc.as_ptr()
-            }, // validation layer crashes if you just pass the pointer
+            pScissors: ptr::null(),
         };
 
         if let Some(line_width) = self.raster.line_width {
@@ -931,8 +1263,17 @@ where
             lineWidth: self.raster.line_width.unwrap_or(1.0),
         };
 
-        self.multisample.rasterizationSamples =
-            self.subpass.as_ref().unwrap().num_samples().unwrap_or(1);
+        let multisample = vk::PipelineMultisampleStateCreateInfo {
+            sType: self.multisample.sType,
+            pNext: self.multisample.pNext,
+            flags: self.multisample.flags,
+            rasterizationSamples: self.subpass.as_ref().unwrap().num_samples().unwrap_or(1),
+            sampleShadingEnable: self.multisample.sampleShadingEnable,
+            minSampleShading: self.multisample.minSampleShading,
+            pSampleMask: self.multisample.pSampleMask,
+            alphaToCoverageEnable: self.multisample.alphaToCoverageEnable,
+            alphaToOneEnable: self.multisample.alphaToOneEnable,
+        };
         if self.multisample.sampleShadingEnable != vk::FALSE {
             debug_assert!(
                 self.multisample.minSampleShading >= 0.0
@@ -1091,10 +1432,10 @@ where
             let num_atch = self.subpass.as_ref().unwrap().num_color_attachments();
 
             match self.blend.attachments {
-                AttachmentsBlend::Collective(blend) => (0..num_atch)
+                AttachmentsBlend::Collective(ref blend) => (0..num_atch)
                     .map(|_| blend.clone().into_vulkan_state())
                     .collect(),
-                AttachmentsBlend::Individual(blend) => {
+                AttachmentsBlend::Individual(ref blend) => {
                     if blend.len() != num_atch as usize {
                         return Err(
                             GraphicsPipelineCreationError::MismatchBlendingAttachmentsCount,
@@ -1129,7 +1470,7 @@ where
             },
             logicOp: self.blend.logic_op.unwrap_or(Default::default()) as u32,
             attachmentCount: blend_atch.len() as u32,
-            pAttachments: blend_atch.as_ptr(),
+            pAttachments: ptr::null(),
             blendConstants: if let Some(c) = self.blend.blend_constants {
                 c
             } else {
@@ -1138,82 +1479,117 @@ where
             },
         };
 
-        let dynamic_states = if !dynamic_states.is_empty() {
+        let dynamic_state_info = if !dynamic_states.is_empty() {
             Some(vk::PipelineDynamicStateCreateInfo {
                 sType: vk::STRUCTURE_TYPE_PIPELINE_DYNAMIC_STATE_CREATE_INFO,
                 pNext: ptr::null(),
                 flags: 0, // reserved
                 dynamicStateCount: dynamic_states.len() as u32,
-                pDynamicStates: dynamic_states.as_ptr(),
+                pDynamicStates: ptr::null(),
             })
         } else {
             None
         };
 
-        let pipeline = unsafe {
-            let infos = vk::GraphicsPipelineCreateInfo {
-                sType: vk::STRUCTURE_TYPE_GRAPHICS_PIPELINE_CREATE_INFO,
-                pNext: ptr::null(),
-                flags: 0, // TODO: some flags are available but none are critical
-                stageCount: stages.len() as u32,
-                pStages: stages.as_ptr(),
-                pVertexInputState: &vertex_input_state,
-                pInputAssemblyState: &self.input_assembly,
-                pTessellationState: tessellation
-                    .as_ref()
-                    .map(|t| t as *const _)
-                    .unwrap_or(ptr::null()),
-                pViewportState: &viewport_info,
-                pRasterizationState: &rasterization,
-                pMultisampleState: &self.multisample,
-                pDepthStencilState: &depth_stencil,
-                pColorBlendState: &blend,
-                pDynamicState: dynamic_states
-                    .as_ref()
-                    .map(|s| s as *const _)
-                    .unwrap_or(ptr::null()),
-                layout: PipelineLayoutAbstract::sys(&pipeline_layout).internal_object(),
-                renderPass: self
-                    .subpass
-                    .as_ref()
-                    .unwrap()
-                    .render_pass()
-                    .inner()
-                    .internal_object(),
-                subpass: self.subpass.as_ref().unwrap().index(),
-                basePipelineHandle: 0, // TODO:
-                basePipelineIndex: -1, // TODO:
-            };
+        let (base_pipeline_handle, base_pipeline_index) = match self.base_pipeline {
+            None => (0, -1),
+            Some(BasePipeline::Pipeline {
+                handle,
+                device: base_device,
+                allows_derivatives,
+                ..
+            }) => {
+                if base_device != device.internal_object() {
+                    return Err(GraphicsPipelineCreationError::BasePipelineDeviceMismatch);
+                }
 
-            let cache_handle = match self.cache {
-                Some(cache) => cache.internal_object(),
-                None => vk::NULL_HANDLE,
-            };
+                if !allows_derivatives {
+                    return Err(GraphicsPipelineCreationError::BasePipelineDoesntAllowDerivatives);
+                }
 
-            let mut output = MaybeUninit::uninit();
-            check_errors(vk.CreateGraphicsPipelines(
-                device.internal_object(),
-                cache_handle,
-                1,
-                &infos,
-                ptr::null(),
-                output.as_mut_ptr(),
-            ))?;
-            output.assume_init()
+                (handle, -1)
+            }
+            Some(BasePipeline::Index(base_index)) => {
+                match batch_allow_derivatives.get(base_index) {
+                    Some(true) => (0, base_index as i32),
+                    Some(false) => {
+                        return Err(
+                            GraphicsPipelineCreationError::BasePipelineDoesntAllowDerivatives,
+                        )
+                    }
+                    None => {
+                        return Err(GraphicsPipelineCreationError::InvalidBasePipelineIndex {
+                            index: batch_allow_derivatives.len(),
+                            base_index,
+                        })
+                    }
+                }
+            }
         };
 
-        // Some drivers return `VK_SUCCESS` but provide a null handle if they
-        // fail to create the pipeline (due to invalid shaders, etc)
-        // This check ensures that we don't create an invalid `GraphicsPipeline` instance
-        if pipeline == vk::NULL_HANDLE {
-            panic!("vkCreateGraphicsPipelines provided a NULL handle");
+        let mut flags = 0;
+        if self.allow_derivatives {
+            flags |= vk::PIPELINE_CREATE_ALLOW_DERIVATIVES_BIT;
+        }
+        if self.base_pipeline.is_some() {
+            flags |= vk::PIPELINE_CREATE_DERIVATIVE_BIT;
         }
 
-        Ok(GraphicsPipeline {
-            inner: GraphicsPipelineInner {
-                device: device.clone(),
-                pipeline,
+        Ok(Box::new(GraphicsPipelineCreateInfos {
+            flags,
+            mesh_shading,
+            specializations,
+            stages,
+            binding_descriptions,
+            attribute_descriptions,
+            vertex_input_state,
+            input_assembly: vk::PipelineInputAssemblyStateCreateInfo {
+                sType: vk::STRUCTURE_TYPE_PIPELINE_INPUT_ASSEMBLY_STATE_CREATE_INFO,
+                pNext: ptr::null(),
+                flags: self.input_assembly.flags,
+                topology: self.input_assembly.topology,
+                primitiveRestartEnable: self.input_assembly.primitiveRestartEnable,
             },
+            tessellation,
+            viewports: vp_vp,
+            scissors: vp_sc,
+            viewport_info,
+            rasterization,
+            multisample,
+            depth_stencil,
+            blend_attachments: blend_atch,
+            blend,
+            dynamic_states,
+            dynamic_state_info,
+            layout: PipelineLayoutAbstract::sys(pipeline_layout).internal_object(),
+            render_pass: self
+                .subpass
+                .as_ref()
+                .unwrap()
+                .render_pass()
+                .inner()
+                .internal_object(),
+            subpass: self.subpass.as_ref().unwrap().index(),
+            base_pipeline_handle,
+            base_pipeline_index,
+        }))
+    }
+
+    // Turns the builder into the `GraphicsPipeline` object, once `pipeline` has been created from
+    // the result of `create_infos`.
+    fn into_pipeline<Pl>(
+        mut self,
+        device: Arc<Device>,
+        pipeline_layout: Pl,
+        pipeline: vk::Pipeline,
+    ) -> GraphicsPipeline<Vdef, Pl> {
+        let mesh_shading = matches!(
+            self.vertex_shader.as_ref().unwrap().0.ty(),
+            GraphicsShaderType::Mesh
+        );
+
+        GraphicsPipeline {
+            inner: GraphicsPipelineInner { device, pipeline },
             layout: pipeline_layout,
 
             vertex_definition: self.vertex_input,
@@ -1231,23 +1607,65 @@ where
             dynamic_blend_constants: self.blend.blend_constants.is_none(),
 
             num_viewports: self.viewport.as_ref().unwrap().num_viewports(),
-        })
+
+            mesh_shading,
+            task_shading: self.task_shader.is_some(),
+            allows_derivatives: self.allow_derivatives,
+        }
     }
 
     // TODO: add build_with_cache method
 }
 
-impl<Vdef, Vs, Vss, Tcs, Tcss, Tes, Tess, Gs, Gss, Fs, Fss>
-    GraphicsPipelineBuilder<Vdef, Vs, Vss, Tcs, Tcss, Tes, Tess, Gs, Gss, Fs, Fss>
+impl<Vdef, Vs, Vss, Tcs, Tcss, Tes, Tess, Gs, Gss, Fs, Fss, Ts, Tss>
+    GraphicsPipelineBuilder<Vdef, Vs, Vss, Tcs, Tcss, Tes, Tess, Gs, Gss, Fs, Fss, Ts, Tss>
 {
-    // TODO: add pipeline derivate system
+    /// Allows other pipelines to be derived from the pipeline being built.
+    ///
+    /// Creating a pipeline that derives from another one can be faster, as the implementation
+    /// can reuse what it compiled for the base pipeline.
+    #[inline]
+    pub fn allow_derivatives(mut self) -> Self {
+        self.allow_derivatives = true;
+        self
+    }
+
+    /// Makes the pipeline being built a derivative of `base`.
+    ///
+    /// `base` must have been built with `allow_derivatives`, otherwise building the pipeline
+    /// will return an error.
+    #[inline]
+    pub fn derive_from<Mv, L>(mut self, base: Arc<GraphicsPipeline<Mv, L>>) -> Self
+    where
+        Mv: Send + Sync + 'static,
+        L: Send + Sync + 'static,
+    {
+        self.base_pipeline = Some(BasePipeline::Pipeline {
+            handle: base.inner.pipeline,
+            device: base.inner.device.internal_object(),
+            allows_derivatives: base.allows_derivatives,
+            _keep_alive: base,
+        });
+        self
+    }
+
+    /// Makes the pipeline being built a derivative of the pipeline at index `base_index` of the
+    /// same `build_many` batch.
+    ///
+    /// The base pipeline must come before this one in the batch and must have been built with
+    /// `allow_derivatives`.
+    #[inline]
+    pub fn derive_from_index(mut self, base_index: usize) -> Self {
+        self.base_pipeline = Some(BasePipeline::Index(base_index));
+        self
+    }
 
     /// Sets the vertex input.
     #[inline]
     pub fn vertex_input<T>(
         self,
         vertex_input: T,
-    ) -> GraphicsPipelineBuilder<T, Vs, Vss, Tcs, Tcss, Tes, Tess, Gs, Gss, Fs, Fss> {
+    ) -> GraphicsPipelineBuilder<T, Vs, Vss, Tcs, Tcss, Tes, Tess, Gs, Gss, Fs, Fss, Ts, Tss> {
         GraphicsPipelineBuilder {
             vertex_input,
             vertex_shader: self.vertex_shader,
@@ -1259,10 +1677,13 @@ impl<Vdef, Vs, Vss, Tcs, Tcss, Tes, Tess, Gs, Gss, Fs, Fss>
             raster: self.raster,
             multisample: self.multisample,
             fragment_shader: self.fragment_shader,
+            task_shader: self.task_shader,
             depth_stencil: self.depth_stencil,
             blend: self.blend,
             subpass: self.subpass,
             cache: self.cache,
+            allow_derivatives: self.allow_derivatives,
+            base_pipeline: self.base_pipeline,
         }
     }
 
@@ -1285,6 +1706,8 @@ impl<Vdef, Vs, Vss, Tcs, Tcss, Tes, Tess, Gs, Gss, Fs, Fss>
         Gss,
         Fs,
         Fss,
+        Ts,
+        Tss,
     > {
         self.vertex_input(SingleBufferDefinition::<V>::new())
     }
@@ -1296,7 +1719,7 @@ impl<Vdef, Vs, Vss, Tcs, Tcss, Tes, Tess, Gs, Gss, Fs, Fss>
         self,
         shader: Vs2,
         specialization_constants: Vss2,
-    ) -> GraphicsPipelineBuilder<Vdef, Vs2, Vss2, Tcs, Tcss, Tes, Tess, Gs, Gss, Fs, Fss>
+    ) -> GraphicsPipelineBuilder<Vdef, Vs2, Vss2, Tcs, Tcss, Tes, Tess, Gs, Gss, Fs, Fss, Ts, Tss>
     where
         Vs2: GraphicsEntryPointAbstract<SpecializationConstants = Vss2>,
         Vss2: SpecializationConstants,
@@ -1312,10 +1735,84 @@ impl<Vdef, Vs, Vss, Tcs, Tcss, Tes, Tess, Gs, Gss, Fs, Fss>
             raster: self.raster,
             multisample: self.multisample,
             fragment_shader: self.fragment_shader,
+            task_shader: self.task_shader,
             depth_stencil: self.depth_stencil,
             blend: self.blend,
             subpass: self.subpass,
             cache: self.cache,
+            allow_derivatives: self.allow_derivatives,
+            base_pipeline: self.base_pipeline,
+        }
+    }
+
+    /// Sets the mesh shader to use, in place of the vertex input and the vertex shader.
+    ///
+    /// A pipeline with a mesh shader doesn't read any vertex buffer, and is drawn with the
+    /// `draw_mesh_tasks` commands instead of the `draw` commands. Tessellation and geometry
+    /// shaders can't be used together with a mesh shader.
+    ///
+    /// The `mesh_shader` or `mesh_shader_nv` feature must be enabled on the device.
+    // TODO: correct specialization constants
+    #[inline]
+    pub fn mesh_shader<Ms, Mss>(
+        self,
+        shader: Ms,
+        specialization_constants: Mss,
+    ) -> GraphicsPipelineBuilder<
+        BufferlessDefinition,
+        Ms,
+        Mss,
+        Tcs,
+        Tcss,
+        Tes,
+        Tess,
+        Gs,
+        Gss,
+        Fs,
+        Fss,
+        Ts,
+        Tss,
+    >
+    where
+        Ms: GraphicsEntryPointAbstract<SpecializationConstants = Mss>,
+        Mss: SpecializationConstants,
+    {
+        self.vertex_input(BufferlessDefinition)
+            .vertex_shader(shader, specialization_constants)
+    }
+
+    /// Sets the task shader to use. The pipeline must also have a mesh shader.
+    ///
+    /// The `task_shader` or `task_shader_nv` feature must be enabled on the device.
+    // TODO: correct specialization constants
+    #[inline]
+    pub fn task_shader<Ts2, Tss2>(
+        self,
+        shader: Ts2,
+        specialization_constants: Tss2,
+    ) -> GraphicsPipelineBuilder<Vdef, Vs, Vss, Tcs, Tcss, Tes, Tess, Gs, Gss, Fs, Fss, Ts2, Tss2>
+    where
+        Ts2: GraphicsEntryPointAbstract<SpecializationConstants = Tss2>,
+        Tss2: SpecializationConstants,
+    {
+        GraphicsPipelineBuilder {
+            vertex_input: self.vertex_input,
+            vertex_shader: self.vertex_shader,
+            input_assembly: self.input_assembly,
+            input_assembly_topology: self.input_assembly_topology,
+            tessellation: self.tessellation,
+            geometry_shader: self.geometry_shader,
+            viewport: self.viewport,
+            raster: self.raster,
+            multisample: self.multisample,
+            fragment_shader: self.fragment_shader,
+            task_shader: Some((shader, specialization_constants)),
+            depth_stencil: self.depth_stencil,
+            blend: self.blend,
+            subpass: self.subpass,
+            cache: self.cache,
+            allow_derivatives: self.allow_derivatives,
+            base_pipeline: self.base_pipeline,
         }
     }
 
@@ -1444,7 +1941,7 @@ impl<Vdef, Vs, Vss, Tcs, Tcss, Tes, Tess, Gs, Gss, Fs, Fss>
         tessellation_control_shader_spec_constants: Tcss2,
         tessellation_evaluation_shader: Tes2,
         tessellation_evaluation_shader_spec_constants: Tess2,
-    ) -> GraphicsPipelineBuilder<Vdef, Vs, Vss, Tcs2, Tcss2, Tes2, Tess2, Gs, Gss, Fs, Fss>
+    ) -> GraphicsPipelineBuilder<Vdef, Vs, Vss, Tcs2, Tcss2, Tes2, Tess2, Gs, Gss, Fs, Fss, Ts, Tss>
     where
         Tcs2: GraphicsEntryPointAbstract<SpecializationConstants = Tcss2>,
         Tes2: GraphicsEntryPointAbstract<SpecializationConstants = Tess2>,
@@ -1471,10 +1968,13 @@ impl<Vdef, Vs, Vss, Tcs, Tcss, Tes, Tess, Gs, Gss, Fs, Fss>
             raster: self.raster,
             multisample: self.multisample,
             fragment_shader: self.fragment_shader,
+            task_shader: self.task_shader,
             depth_stencil: self.depth_stencil,
             blend: self.blend,
             subpass: self.subpass,
             cache: self.cache,
+            allow_derivatives: self.allow_derivatives,
+            base_pipeline: self.base_pipeline,
         }
     }
 
@@ -1492,7 +1992,7 @@ impl<Vdef, Vs, Vss, Tcs, Tcss, Tes, Tess, Gs, Gss, Fs, Fss>
         self,
         shader: Gs2,
         specialization_constants: Gss2,
-    ) -> GraphicsPipelineBuilder<Vdef, Vs, Vss, Tcs, Tcss, Tes, Tess, Gs2, Gss2, Fs, Fss>
+    ) -> GraphicsPipelineBuilder<Vdef, Vs, Vss, Tcs, Tcss, Tes, Tess, Gs2, Gss2, Fs, Fss, Ts, Tss>
     where
         Gs2: GraphicsEntryPointAbstract<SpecializationConstants = Gss2>,
         Gss2: SpecializationConstants,
@@ -1508,10 +2008,13 @@ impl<Vdef, Vs, Vss, Tcs, Tcss, Tes, Tess, Gs, Gss, Fs, Fss>
             raster: self.raster,
             multisample: self.multisample,
             fragment_shader: self.fragment_shader,
+            task_shader: self.task_shader,
             depth_stencil: self.depth_stencil,
             blend: self.blend,
             subpass: self.subpass,
             cache: self.cache,
+            allow_derivatives: self.allow_derivatives,
+            base_pipeline: self.base_pipeline,
         }
     }
 
@@ -1775,7 +2278,7 @@ impl<Vdef, Vs, Vss, Tcs, Tcss, Tes, Tess, Gs, Gss, Fs, Fss>
         self,
         shader: Fs2,
         specialization_constants: Fss2,
-    ) -> GraphicsPipelineBuilder<Vdef, Vs, Vss, Tcs, Tcss, Tes, Tess, Gs, Gss, Fs2, Fss2>
+    ) -> GraphicsPipelineBuilder<Vdef, Vs, Vss, Tcs, Tcss, Tes, Tess, Gs, Gss, Fs2, Fss2, Ts, Tss>
     where
         Fs2: GraphicsEntryPointAbstract<SpecializationConstants = Fss2>,
         Fss2: SpecializationConstants,
@@ -1791,10 +2294,13 @@ impl<Vdef, Vs, Vss, Tcs, Tcss, Tes, Tess, Gs, Gss, Fs, Fss>
             raster: self.raster,
             multisample: self.multisample,
             fragment_shader: Some((shader, specialization_constants)),
+            task_shader: self.task_shader,
             depth_stencil: self.depth_stencil,
             blend: self.blend,
             subpass: self.subpass,
             cache: self.cache,
+            allow_derivatives: self.allow_derivatives,
+            base_pipeline: self.base_pipeline,
         }
     }
 
@@ -1897,7 +2403,8 @@ impl<Vdef, Vs, Vss, Tcs, Tcss, Tes, Tess, Gs, Gss, Fs, Fss>
     pub fn render_pass(
         self,
         subpass: Subpass,
-    ) -> GraphicsPipelineBuilder<Vdef, Vs, Vss, Tcs, Tcss, Tes, Tess, Gs, Gss, Fs, Fss> {
+    ) -> GraphicsPipelineBuilder<Vdef, Vs, Vss, Tcs, Tcss, Tes, Tess, Gs, Gss, Fs, Fss, Ts, Tss>
+    {
         GraphicsPipelineBuilder {
             vertex_input: self.vertex_input,
             vertex_shader: self.vertex_shader,
@@ -1909,10 +2416,13 @@ impl<Vdef, Vs, Vss, Tcs, Tcss, Tes, Tess, Gs, Gss, Fs, Fss>
             raster: self.raster,
             multisample: self.multisample,
             fragment_shader: self.fragment_shader,
+            task_shader: self.task_shader,
             depth_stencil: self.depth_stencil,
             blend: self.blend,
             subpass: Some(subpass),
             cache: self.cache,
+            allow_derivatives: self.allow_derivatives,
+            base_pipeline: self.base_pipeline,
         }
     }
 
@@ -1928,8 +2438,8 @@ impl<Vdef, Vs, Vss, Tcs, Tcss, Tes, Tess, Gs, Gss, Fs, Fss>
     }
 }
 
-impl<Vdef, Vs, Vss, Tcs, Tcss, Tes, Tess, Gs, Gss, Fs, Fss> Clone
-    for GraphicsPipelineBuilder<Vdef, Vs, Vss, Tcs, Tcss, Tes, Tess, Gs, Gss, Fs, Fss>
+impl<Vdef, Vs, Vss, Tcs, Tcss, Tes, Tess, Gs, Gss, Fs, Fss, Ts, Tss> Clone
+    for GraphicsPipelineBuilder<Vdef, Vs, Vss, Tcs, Tcss, Tes, Tess, Gs, Gss, Fs, Fss, Ts, Tss>
 where
     Vdef: Clone,
     Vs: Clone,
@@ -1942,12 +2452,20 @@ where
     Gss: Clone,
     Fs: Clone,
     Fss: Clone,
+    Ts: Clone,
+    Tss: Clone,
 {
     fn clone(&self) -> Self {
         GraphicsPipelineBuilder {
             vertex_input: self.vertex_input.clone(),
             vertex_shader: self.vertex_shader.clone(),
-            input_assembly: unsafe { ptr::read(&self.input_assembly) },
+            input_assembly: vk::PipelineInputAssemblyStateCreateInfo {
+                sType: vk::STRUCTURE_TYPE_PIPELINE_INPUT_ASSEMBLY_STATE_CREATE_INFO,
+                pNext: ptr::null(),
+                flags: self.input_assembly.flags,
+                topology: self.input_assembly.topology,
+                primitiveRestartEnable: self.input_assembly.primitiveRestartEnable,
+            },
             input_assembly_topology: self.input_assembly_topology,
             tessellation: self.tessellation.clone(),
             geometry_shader: self.geometry_shader.clone(),
@@ -1965,10 +2483,253 @@ where
                 alphaToOneEnable: self.multisample.alphaToOneEnable,
             },
             fragment_shader: self.fragment_shader.clone(),
+            task_shader: self.task_shader.clone(),
             depth_stencil: self.depth_stencil.clone(),
             blend: self.blend.clone(),
             subpass: self.subpass.clone(),
             cache: self.cache.clone(),
+            allow_derivatives: self.allow_derivatives,
+            base_pipeline: self.base_pipeline.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::descriptor::pipeline_layout::EmptyPipelineDesc;
+    use crate::format::Format;
+    use crate::pipeline::graphics_pipeline::GraphicsPipeline;
+    use crate::pipeline::graphics_pipeline::GraphicsPipelineBuilder;
+    use crate::pipeline::graphics_pipeline::GraphicsPipelineCreationError;
+    use crate::pipeline::shader::EmptyEntryPointDummy;
+    use crate::pipeline::shader::EmptyShaderInterfaceDef;
+    use crate::pipeline::shader::GraphicsEntryPoint;
+    use crate::pipeline::shader::GraphicsShaderType;
+    use crate::pipeline::shader::ShaderModule;
+    use crate::pipeline::vertex::BufferlessDefinition;
+    use crate::render_pass::Subpass;
+    use std::ffi::CStr;
+    use std::sync::Arc;
+
+    type EntryPoint<'a> = GraphicsEntryPoint<
+        'a,
+        (),
+        EmptyShaderInterfaceDef,
+        EmptyShaderInterfaceDef,
+        EmptyPipelineDesc,
+    >;
+
+    type Builder<'a> = GraphicsPipelineBuilder<
+        BufferlessDefinition,
+        EntryPoint<'a>,
+        (),
+        EmptyEntryPointDummy,
+        (),
+        EmptyEntryPointDummy,
+        (),
+        EmptyEntryPointDummy,
+        (),
+        EntryPoint<'a>,
+        (),
+    >;
+
+    /*
+     * OpCapability Shader
+     * OpMemoryModel Logical GLSL450
+     * OpEntryPoint Vertex %main "main"
+     * void main() {}
+     */
+    const VS_MODULE: [u8; 116] = [
+        3, 2, 35, 7, 0, 0, 1, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 17, 0, 2, 0, 1, 0, 0, 0, 14,
+        0, 3, 0, 0, 0, 0, 0, 1, 0, 0, 0, 15, 0, 5, 0, 0, 0, 0, 0, 4, 0, 0, 0, 109, 97, 105, 110, 0,
+        0, 0, 0, 19, 0, 2, 0, 2, 0, 0, 0, 33, 0, 3, 0, 3, 0, 0, 0, 2, 0, 0, 0, 54, 0, 5, 0, 2, 0,
+        0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 248, 0, 2, 0, 5, 0, 0, 0, 253, 0, 1, 0, 56, 0, 1,
+        0,
+    ];
+
+    /*
+     * OpCapability Shader
+     * OpMemoryModel Logical GLSL450
+     * OpEntryPoint Fragment %main "main"
+     * OpExecutionMode %main OriginUpperLeft
+     * void main() {}
+     */
+    const FS_MODULE: [u8; 128] = [
+        3, 2, 35, 7, 0, 0, 1, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 17, 0, 2, 0, 1, 0, 0, 0, 14,
+        0, 3, 0, 0, 0, 0, 0, 1, 0, 0, 0, 15, 0, 5, 0, 4, 0, 0, 0, 4, 0, 0, 0, 109, 97, 105, 110, 0,
+        0, 0, 0, 16, 0, 3, 0, 4, 0, 0, 0, 7, 0, 0, 0, 19, 0, 2, 0, 2, 0, 0, 0, 33, 0, 3, 0, 3, 0,
+        0, 0, 2, 0, 0, 0, 54, 0, 5, 0, 2, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 248, 0, 2,
+        0, 5, 0, 0, 0, 253, 0, 1, 0, 56, 0, 1, 0,
+    ];
+
+    static NAME: [u8; 5] = [109, 97, 105, 110, 0]; // "main"
+
+    fn builder<'a>(vs: &'a ShaderModule, fs: &'a ShaderModule, subpass: Subpass) -> Builder<'a> {
+        let name = unsafe { CStr::from_ptr(NAME.as_ptr() as *const _) };
+        let (vs, fs) = unsafe {
+            (
+                vs.graphics_entry_point(
+                    name,
+                    EmptyShaderInterfaceDef,
+                    EmptyShaderInterfaceDef,
+                    EmptyPipelineDesc,
+                    GraphicsShaderType::Vertex,
+                ),
+                fs.graphics_entry_point(
+                    name,
+                    EmptyShaderInterfaceDef,
+                    EmptyShaderInterfaceDef,
+                    EmptyPipelineDesc,
+                    GraphicsShaderType::Fragment,
+                ),
+            )
+        };
+
+        GraphicsPipeline::start()
+            .vertex_shader(vs, ())
+            .viewports_dynamic_scissors_irrelevant(1)
+            .fragment_shader(fs, ())
+            .render_pass(subpass)
+    }
+
+    macro_rules! setup {
+        ($device:ident, $vs:ident, $fs:ident, $subpass:ident) => {
+            let ($device, _) = gfx_dev_and_queue!();
+            let $vs = unsafe { ShaderModule::new($device.clone(), &VS_MODULE).unwrap() };
+            let $fs = unsafe { ShaderModule::new($device.clone(), &FS_MODULE).unwrap() };
+            let render_pass = Arc::new(
+                single_pass_renderpass!($device.clone(),
+                    attachments: {
+                        color: {
+                            load: Clear,
+                            store: Store,
+                            format: Format::R8G8B8A8Unorm,
+                            samples: 1,
+                        }
+                    },
+                    pass: {
+                        color: [color],
+                        depth_stencil: {}
+                    }
+                )
+                .unwrap(),
+            );
+            let $subpass = Subpass::from(render_pass, 0).unwrap();
+        };
+    }
+
+    #[test]
+    fn derive_from_pipeline() {
+        setup!(device, vs, fs, subpass);
+
+        let base = Arc::new(
+            builder(&vs, &fs, subpass.clone())
+                .allow_derivatives()
+                .build(device.clone())
+                .unwrap(),
+        );
+
+        builder(&vs, &fs, subpass)
+            .derive_from(base)
+            .build(device.clone())
+            .unwrap();
+    }
+
+    #[test]
+    fn derive_from_pipeline_without_derivatives() {
+        setup!(device, vs, fs, subpass);
+
+        let base = Arc::new(
+            builder(&vs, &fs, subpass.clone())
+                .build(device.clone())
+                .unwrap(),
+        );
+
+        match builder(&vs, &fs, subpass)
+            .derive_from(base)
+            .build(device.clone())
+        {
+            Err(GraphicsPipelineCreationError::BasePipelineDoesntAllowDerivatives) => (),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn build_many_derive_from_index() {
+        setup!(device, vs, fs, subpass);
+
+        let pipelines = GraphicsPipelineBuilder::build_many(
+            device.clone(),
+            vec![
+                builder(&vs, &fs, subpass.clone()).allow_derivatives(),
+                builder(&vs, &fs, subpass).derive_from_index(0),
+            ],
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(pipelines.len(), 2);
+    }
+
+    #[test]
+    fn build_many_derive_from_later_index() {
+        setup!(device, vs, fs, subpass);
+
+        let result = GraphicsPipelineBuilder::build_many(
+            device.clone(),
+            vec![
+                builder(&vs, &fs, subpass.clone()).derive_from_index(1),
+                builder(&vs, &fs, subpass).allow_derivatives(),
+            ],
+            None,
+        );
+
+        match result {
+            Err(GraphicsPipelineCreationError::InvalidBasePipelineIndex {
+                index: 0,
+                base_index: 1,
+            }) => (),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn build_many_derive_from_self() {
+        setup!(device, vs, fs, subpass);
+
+        let result = GraphicsPipelineBuilder::build_many(
+            device.clone(),
+            vec![builder(&vs, &fs, subpass)
+                .allow_derivatives()
+                .derive_from_index(0)],
+            None,
+        );
+
+        match result {
+            Err(GraphicsPipelineCreationError::InvalidBasePipelineIndex {
+                index: 0,
+                base_index: 0,
+            }) => (),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn build_many_derive_from_index_without_derivatives() {
+        setup!(device, vs, fs, subpass);
+
+        let result = GraphicsPipelineBuilder::build_many(
+            device.clone(),
+            vec![
+                builder(&vs, &fs, subpass.clone()),
+                builder(&vs, &fs, subpass).derive_from_index(0),
+            ],
+            None,
+        );
+
+        match result {
+            Err(GraphicsPipelineCreationError::BasePipelineDoesntAllowDerivatives) => (),
+            _ => panic!(),
         }
     }
 }
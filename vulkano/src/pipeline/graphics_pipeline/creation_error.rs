@@ -182,6 +182,34 @@ pub enum GraphicsPipelineCreationError {
 
     /// The `alpha_to_one` feature must be enabled in order to use alpha-to-one.
     AlphaToOneFeatureNotEnabled,
+
+    /// The `mesh_shader` or `mesh_shader_nv` feature must be enabled in order to use mesh
+    /// shaders.
+    MeshShaderFeatureNotEnabled,
+
+    /// The `task_shader` or `task_shader_nv` feature must be enabled in order to use task
+    /// shaders.
+    TaskShaderFeatureNotEnabled,
+
+    /// Tessellation and geometry shaders can't be used together with a mesh shader.
+    MeshShaderIncompatibleStages,
+
+    /// A task shader can only be used together with a mesh shader.
+    TaskShaderWithoutMeshShader,
+
+    /// The base pipeline was not created with `allow_derivatives`.
+    BasePipelineDoesntAllowDerivatives,
+
+    /// The base pipeline was created with a different device.
+    BasePipelineDeviceMismatch,
+
+    /// The base pipeline index doesn't refer to an earlier pipeline of the same batch.
+    InvalidBasePipelineIndex {
+        /// Index of the pipeline in the batch.
+        index: usize,
+        /// Index of the base pipeline that was requested.
+        base_index: usize,
+    },
 }
 
 impl error::Error for GraphicsPipelineCreationError {
@@ -343,6 +371,29 @@ impl fmt::Display for GraphicsPipelineCreationError {
                 GraphicsPipelineCreationError::AlphaToOneFeatureNotEnabled => {
                     "the `alpha_to_one` feature must be enabled in order to use alpha-to-one"
                 }
+                GraphicsPipelineCreationError::MeshShaderFeatureNotEnabled => {
+                    "the `mesh_shader` or `mesh_shader_nv` feature must be enabled in order to use \
+                 mesh shaders"
+                }
+                GraphicsPipelineCreationError::TaskShaderFeatureNotEnabled => {
+                    "the `task_shader` or `task_shader_nv` feature must be enabled in order to use \
+                 task shaders"
+                }
+                GraphicsPipelineCreationError::MeshShaderIncompatibleStages => {
+                    "tessellation and geometry shaders can't be used together with a mesh shader"
+                }
+                GraphicsPipelineCreationError::TaskShaderWithoutMeshShader => {
+                    "a task shader can only be used together with a mesh shader"
+                }
+                GraphicsPipelineCreationError::BasePipelineDoesntAllowDerivatives => {
+                    "the base pipeline was not created with `allow_derivatives`"
+                }
+                GraphicsPipelineCreationError::BasePipelineDeviceMismatch => {
+                    "the base pipeline was created with a different device"
+                }
+                GraphicsPipelineCreationError::InvalidBasePipelineIndex { .. } => {
+                    "the base pipeline index doesn't refer to an earlier pipeline of the same batch"
+                }
             }
         )
     }
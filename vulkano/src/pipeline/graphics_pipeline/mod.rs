@@ -64,6 +64,10 @@ pub struct GraphicsPipeline<VertexDefinition, Layout> {
     dynamic_blend_constants: bool,
 
     num_viewports: u32,
+
+    mesh_shading: bool,
+    task_shading: bool,
+    allows_derivatives: bool,
 }
 
 #[derive(PartialEq, Eq, Hash)]
@@ -87,6 +91,8 @@ impl GraphicsPipeline<(), ()> {
         (),
         EmptyEntryPointDummy,
         (),
+        EmptyEntryPointDummy,
+        (),
     > {
         GraphicsPipelineBuilder::new()
     }
@@ -179,6 +185,26 @@ impl<Mv, L> GraphicsPipeline<Mv, L> {
     pub fn has_dynamic_stencil_reference(&self) -> bool {
         self.dynamic_stencil_reference
     }
+
+    /// Returns true if this pipeline uses a mesh shader instead of a vertex shader.
+    #[inline]
+    pub fn has_mesh_shader(&self) -> bool {
+        self.mesh_shading
+    }
+
+    /// Returns true if this pipeline has a task shader.
+    #[inline]
+    pub fn has_task_shader(&self) -> bool {
+        self.task_shading
+    }
+
+    /// Returns true if other pipelines can be derived from this one.
+    ///
+    /// See `GraphicsPipelineBuilder::allow_derivatives`.
+    #[inline]
+    pub fn allows_derivatives(&self) -> bool {
+        self.allows_derivatives
+    }
 }
 
 unsafe impl<Mv, L> PipelineLayoutAbstract for GraphicsPipeline<Mv, L>
@@ -297,6 +323,12 @@ pub unsafe trait GraphicsPipelineAbstract:
 
     /// Returns true if the stencil references used by this pipeline are dynamic.
     fn has_dynamic_stencil_reference(&self) -> bool;
+
+    /// Returns true if this pipeline uses a mesh shader instead of a vertex shader.
+    fn has_mesh_shader(&self) -> bool;
+
+    /// Returns true if this pipeline has a task shader.
+    fn has_task_shader(&self) -> bool;
 }
 
 unsafe impl<Mv, L> GraphicsPipelineAbstract for GraphicsPipeline<Mv, L>
@@ -353,6 +385,16 @@ where
     fn has_dynamic_stencil_reference(&self) -> bool {
         self.dynamic_stencil_reference
     }
+
+    #[inline]
+    fn has_mesh_shader(&self) -> bool {
+        self.mesh_shading
+    }
+
+    #[inline]
+    fn has_task_shader(&self) -> bool {
+        self.task_shading
+    }
 }
 
 unsafe impl<T> GraphicsPipelineAbstract for T
@@ -409,6 +451,16 @@ where
     fn has_dynamic_stencil_reference(&self) -> bool {
         (**self).has_dynamic_stencil_reference()
     }
+
+    #[inline]
+    fn has_mesh_shader(&self) -> bool {
+        (**self).has_mesh_shader()
+    }
+
+    #[inline]
+    fn has_task_shader(&self) -> bool {
+        (**self).has_task_shader()
+    }
 }
 
 impl<Mv, L> PartialEq for GraphicsPipeline<Mv, L>
@@ -53,8 +53,8 @@
 //! - The output of the fragment shader is written to the framebuffer attachments, possibly by
 //!   mixing it with the existing values.
 //!
-//! All the sub-modules of this module (with the exception of `cache`) correspond to the various
-//! stages of graphical pipelines.
+//! All the sub-modules of this module (with the exception of `cache` and `compiler`) correspond to
+//! the various stages of graphical pipelines.
 //!
 //! > **Note**: With the exception of the addition of the tessellation shaders and the geometry
 //! > shader, these steps haven't changed in the past decade. If you are familiar with shaders in
@@ -77,6 +77,7 @@
 
 pub use self::compute_pipeline::ComputePipeline;
 pub use self::compute_pipeline::ComputePipelineAbstract;
+pub use self::compute_pipeline::ComputePipelineBuilder;
 pub use self::compute_pipeline::ComputePipelineCreationError;
 pub use self::compute_pipeline::ComputePipelineSys;
 pub use self::graphics_pipeline::GraphicsPipeline;
@@ -85,11 +86,16 @@ pub use self::graphics_pipeline::GraphicsPipelineBuilder;
 pub use self::graphics_pipeline::GraphicsPipelineCreationError;
 pub use self::graphics_pipeline::GraphicsPipelineSys;
 
+use crate::vk;
+use std::any::Any;
+use std::sync::Arc;
+
 mod compute_pipeline;
 mod graphics_pipeline;
 
 pub mod blend;
 pub mod cache;
+pub mod compiler;
 pub mod depth_stencil;
 pub mod input_assembly;
 pub mod multisample;
@@ -97,3 +103,17 @@ pub mod raster;
 pub mod shader;
 pub mod vertex;
 pub mod viewport;
+
+// Pipeline that a pipeline being built derives from.
+#[derive(Clone)]
+pub(crate) enum BasePipeline {
+    // An existing pipeline, kept alive until the derivative has been created.
+    Pipeline {
+        handle: vk::Pipeline,
+        device: vk::Device,
+        allows_derivatives: bool,
+        _keep_alive: Arc<dyn Any + Send + Sync>,
+    },
+    // A pipeline that comes earlier in the same `build_many` batch.
+    Index(usize),
+}
@@ -286,6 +286,10 @@ pub enum GraphicsShaderType {
     TessellationEvaluation,
     Geometry(GeometryShaderExecutionMode),
     Fragment,
+    /// Task shader of the mesh shading pipeline.
+    Task,
+    /// Mesh shader of the mesh shading pipeline.
+    Mesh,
 }
 
 /// Declares which type of primitives are expected by the geometry shader.
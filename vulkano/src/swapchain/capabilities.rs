@@ -57,6 +57,17 @@ pub struct Capabilities {
     pub present_modes: SupportedPresentModes,
 }
 
+impl Capabilities {
+    /// Returns true if the given format and color space can be used together for the images of
+    /// the swapchain.
+    #[inline]
+    pub fn supports_format(&self, format: Format, color_space: ColorSpace) -> bool {
+        self.supported_formats
+            .iter()
+            .any(|&(f, c)| f == format && c == color_space)
+    }
+}
+
 /// The way presenting a swapchain is accomplished.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 #[repr(u32)]
@@ -572,6 +583,18 @@ impl Default for SurfaceTransform {
 /// Additionally you can try detect whether the implementation supports any additional color space
 /// and perform a manual conversion to that color space from inside your shader.
 ///
+/// # HDR and wide gamut
+///
+/// All the color spaces other than `SrgbNonLinear` require the `ext_swapchain_colorspace`
+/// instance extension, and are only reported in `Capabilities::supported_formats` when it is
+/// enabled. The most common HDR configurations are:
+///
+/// - HDR10: the `Hdr10St2084` color space with the `A2B10G10R10UnormPack32` format.
+/// - scRGB: the `ExtendedSrgbLinear` color space with the `R16G16B16A16Sfloat` format.
+///
+/// The mastering display information of the content can then be given to the implementation
+/// with `Swapchain::set_hdr_metadata`, which requires the `ext_hdr_metadata` device extension.
+///
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 #[repr(u32)]
 pub enum ColorSpace {
@@ -591,6 +614,28 @@ pub enum ColorSpace {
     PassThrough = vk::COLOR_SPACE_PASS_THROUGH_EXT,
 }
 
+impl ColorSpace {
+    /// Returns true if this color space can represent colors brighter than the reference white of
+    /// standard dynamic range displays.
+    #[inline]
+    pub fn is_hdr(&self) -> bool {
+        matches!(
+            *self,
+            ColorSpace::ExtendedSrgbLinear
+                | ColorSpace::Hdr10St2084
+                | ColorSpace::DolbyVision
+                | ColorSpace::Hdr10Hlg
+        )
+    }
+
+    /// Returns true if this color space requires the `ext_swapchain_colorspace` instance
+    /// extension.
+    #[inline]
+    pub fn requires_swapchain_colorspace_extension(&self) -> bool {
+        *self != ColorSpace::SrgbNonLinear
+    }
+}
+
 #[inline]
 pub fn color_space_from_num(val: u32) -> ColorSpace {
     match val {
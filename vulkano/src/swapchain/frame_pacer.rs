@@ -0,0 +1,243 @@
+// Copyright (c) 2021 The vulkano developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>,
+// at your option. All files in the project carrying such
+// notice may not be copied, modified, or distributed except
+// according to those terms.
+
+use crate::swapchain::PastPresentationTiming;
+use crate::swapchain::PresentTime;
+use crate::swapchain::PresentTimingError;
+use crate::swapchain::Swapchain;
+use std::time::Duration;
+
+/// Chooses the time at which each frame should be presented, so that frames are displayed at
+/// a steady rate with as little latency as possible.
+///
+/// The pacer presents one frame every `swap_interval` refresh cycles of the display. When a
+/// frame is displayed later than requested, the interval is increased so that the following
+/// frames are displayed at a regular pace instead of stuttering. When frames are consistently
+/// finished with more than a whole refresh cycle of margin, the interval is decreased again.
+///
+/// This requires the `google_display_timing` extension to be enabled on the device.
+///
+/// # Example
+///
+/// ```no_run
+/// # use std::sync::Arc;
+/// # use vulkano::swapchain::{self, FramePacer, Swapchain};
+/// # use vulkano::sync::GpuFuture;
+/// # let swapchain: Arc<Swapchain<()>> = return;
+/// # let queue: Arc<vulkano::device::Queue> = return;
+/// let mut pacer = FramePacer::from_swapchain(&swapchain).unwrap();
+///
+/// loop {
+///     pacer.update_from_swapchain(&swapchain).unwrap();
+///
+///     let (image_num, _, acquire_future) =
+///         swapchain::acquire_next_image(swapchain.clone(), None).unwrap();
+///     // ... draw ...
+///     let future = acquire_future
+///         .then_swapchain_present(queue.clone(), swapchain.clone(), image_num)
+///         .with_present_time(pacer.next_present_time());
+///     # break;
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct FramePacer {
+    refresh_duration: Duration,
+    // Number of refresh cycles between two frames.
+    swap_interval: u32,
+    next_present_id: u32,
+    // Present ID and actual present time of the latest frame that has been displayed.
+    last_presented: Option<(u32, Duration)>,
+    // Number of consecutive frames that were displayed on time with more than a refresh cycle of
+    // margin.
+    frames_with_margin: u32,
+}
+
+impl FramePacer {
+    /// Number of consecutive frames that must have enough margin before the swap interval is
+    /// decreased.
+    pub const FRAMES_BEFORE_SPEEDUP: u32 = 60;
+
+    /// Maximum swap interval that the pacer falls back to.
+    pub const MAX_SWAP_INTERVAL: u32 = 4;
+
+    /// Builds a new `FramePacer` for a display with the given refresh cycle duration.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if `refresh_duration` is zero.
+    ///
+    #[inline]
+    pub fn new(refresh_duration: Duration) -> FramePacer {
+        assert!(refresh_duration > Duration::from_secs(0));
+
+        FramePacer {
+            refresh_duration,
+            swap_interval: 1,
+            next_present_id: 1,
+            last_presented: None,
+            frames_with_margin: 0,
+        }
+    }
+
+    /// Builds a new `FramePacer` with the refresh cycle duration of the display that the
+    /// swapchain is presented on.
+    #[inline]
+    pub fn from_swapchain<W>(swapchain: &Swapchain<W>) -> Result<FramePacer, PresentTimingError> {
+        Ok(FramePacer::new(swapchain.refresh_cycle_duration()?))
+    }
+
+    /// Returns the duration of a refresh cycle of the display.
+    #[inline]
+    pub fn refresh_duration(&self) -> Duration {
+        self.refresh_duration
+    }
+
+    /// Returns the current number of refresh cycles between two frames.
+    #[inline]
+    pub fn swap_interval(&self) -> u32 {
+        self.swap_interval
+    }
+
+    /// Returns the current duration between two frames.
+    #[inline]
+    pub fn frame_duration(&self) -> Duration {
+        self.refresh_duration * self.swap_interval
+    }
+
+    /// Updates the pacer with the timing of the frames that have been displayed.
+    ///
+    /// The timings must be passed in the order in which they were returned by
+    /// `Swapchain::past_presentation_timing`.
+    pub fn update(&mut self, timings: &[PastPresentationTiming]) {
+        for timing in timings {
+            let late = timing.desired_present_time != Duration::from_secs(0)
+                && timing.actual_present_time
+                    > timing.desired_present_time + self.refresh_duration / 2;
+
+            if late {
+                self.swap_interval = (self.swap_interval + 1).min(Self::MAX_SWAP_INTERVAL);
+                self.frames_with_margin = 0;
+            } else if timing.present_margin > self.refresh_duration {
+                self.frames_with_margin += 1;
+
+                if self.frames_with_margin >= Self::FRAMES_BEFORE_SPEEDUP {
+                    self.swap_interval = (self.swap_interval - 1).max(1);
+                    self.frames_with_margin = 0;
+                }
+            } else {
+                self.frames_with_margin = 0;
+            }
+
+            self.last_presented = Some((timing.present_id, timing.actual_present_time));
+        }
+    }
+
+    /// Same as `update`, but queries the timings from the swapchain.
+    #[inline]
+    pub fn update_from_swapchain<W>(
+        &mut self,
+        swapchain: &Swapchain<W>,
+    ) -> Result<(), PresentTimingError> {
+        let timings = swapchain.past_presentation_timing()?;
+        self.update(&timings);
+        Ok(())
+    }
+
+    /// Returns the `PresentTime` to use for the next frame.
+    ///
+    /// Until the timing of a first frame is known, the returned time lets the frame be
+    /// displayed as soon as possible.
+    pub fn next_present_time(&mut self) -> PresentTime {
+        let present_id = self.next_present_id;
+        self.next_present_id = self.next_present_id.wrapping_add(1);
+
+        let desired_present_time = match self.last_presented {
+            Some((last_id, last_time)) => {
+                let frames_since = present_id.wrapping_sub(last_id);
+                last_time + self.frame_duration() * frames_since
+            }
+            None => Duration::from_secs(0),
+        };
+
+        PresentTime {
+            present_id,
+            desired_present_time,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::swapchain::FramePacer;
+    use crate::swapchain::PastPresentationTiming;
+    use std::time::Duration;
+
+    const REFRESH: Duration = Duration::from_millis(16);
+
+    fn timing(present_id: u32, desired: u64, actual: u64, margin: u64) -> PastPresentationTiming {
+        PastPresentationTiming {
+            present_id,
+            desired_present_time: Duration::from_millis(desired),
+            actual_present_time: Duration::from_millis(actual),
+            earliest_present_time: Duration::from_millis(actual),
+            present_margin: Duration::from_millis(margin),
+        }
+    }
+
+    #[test]
+    fn paces_from_last_presented_frame() {
+        let mut pacer = FramePacer::new(REFRESH);
+
+        let first = pacer.next_present_time();
+        assert_eq!(first.present_id, 1);
+        assert_eq!(first.desired_present_time, Duration::from_secs(0));
+        pacer.next_present_time();
+        let third = pacer.next_present_time();
+        assert_eq!(third.present_id, 3);
+
+        pacer.update(&[timing(first.present_id, 0, 1000, 2)]);
+        let fourth = pacer.next_present_time();
+        assert_eq!(
+            fourth.desired_present_time,
+            Duration::from_millis(1000) + REFRESH * 3
+        );
+    }
+
+    #[test]
+    fn late_frames_increase_swap_interval() {
+        let mut pacer = FramePacer::new(REFRESH);
+
+        pacer.update(&[timing(1, 1000, 1005, 0)]);
+        assert_eq!(pacer.swap_interval(), 1);
+
+        pacer.update(&[timing(2, 1016, 1032, 0)]);
+        assert_eq!(pacer.swap_interval(), 2);
+
+        for id in 3..10 {
+            pacer.update(&[timing(id, 1000, 2000, 0)]);
+        }
+        assert_eq!(pacer.swap_interval(), FramePacer::MAX_SWAP_INTERVAL);
+    }
+
+    #[test]
+    fn margin_decreases_swap_interval() {
+        let mut pacer = FramePacer::new(REFRESH);
+        pacer.update(&[timing(1, 1000, 1016, 0)]);
+        assert_eq!(pacer.swap_interval(), 2);
+
+        let timings: Vec<_> = (0..FramePacer::FRAMES_BEFORE_SPEEDUP)
+            .map(|id| timing(id + 2, 1000, 1000, 20))
+            .collect();
+        let (first, last) = timings.split_at(timings.len() - 1);
+        pacer.update(first);
+        assert_eq!(pacer.swap_interval(), 2);
+        pacer.update(last);
+        assert_eq!(pacer.swap_interval(), 1);
+    }
+}
@@ -0,0 +1,706 @@
+// Copyright (c) 2021 The vulkano developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>,
+// at your option. All files in the project carrying such
+// notice may not be copied, modified, or distributed except
+// according to those terms.
+
+use crate::command_buffer::AutoCommandBufferBuilder;
+use crate::command_buffer::BuildError;
+use crate::command_buffer::CommandBufferExecError;
+use crate::command_buffer::CommandBufferExecFuture;
+use crate::command_buffer::ImageData;
+use crate::command_buffer::ImageReadback;
+use crate::command_buffer::PrimaryAutoCommandBuffer;
+use crate::command_buffer::ReadbackCreationError;
+use crate::command_buffer::ReadbackError;
+use crate::device::Device;
+use crate::device::DeviceOwned;
+use crate::device::Queue;
+use crate::format::Format;
+use crate::image::AttachmentImage;
+use crate::image::ImageCreationError;
+use crate::image::ImageUsage;
+use crate::sync::now;
+use crate::sync::FenceSignalFuture;
+use crate::sync::FlushError;
+use crate::sync::GpuFuture;
+use crate::sync::NowFuture;
+use crate::OomError;
+use std::collections::VecDeque;
+use std::error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::mpsc::Sender;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Future returned by `HeadlessSwapchain::present`.
+pub type HeadlessPresentFuture = Arc<
+    FenceSignalFuture<
+        CommandBufferExecFuture<Box<dyn GpuFuture + Send + Sync>, PrimaryAutoCommandBuffer>,
+    >,
+>;
+
+/// Offscreen replacement for a `Swapchain`, for rendering where no display is available.
+///
+/// The images of a `HeadlessSwapchain` are regular `AttachmentImage`s, and its
+/// `acquire_next_image` and `present` methods work like the functions of the same name that
+/// drive a `Swapchain`. This makes it possible to run the same rendering code in regression
+/// tests or to generate thumbnails. Whenever an image is presented, its content is copied to
+/// CPU-accessible memory and delivered to the `HeadlessOutput` of the swapchain, in the order in
+/// which the images were presented.
+///
+/// A frame is delivered once the image it was presented from is acquired again, when
+/// `flush_frames` is called, or when the swapchain is destroyed.
+///
+/// > **Note**: If the `ext_headless_surface` instance extension is available, an actual
+/// > `Swapchain` can be created from `Surface::headless` instead. The images presented to such a
+/// > swapchain are discarded, though.
+///
+/// # Example
+///
+/// ```no_run
+/// # use std::sync::Arc;
+/// # use std::sync::mpsc;
+/// # use vulkano::format::Format;
+/// # use vulkano::image::ImageUsage;
+/// # use vulkano::swapchain::{HeadlessOutput, HeadlessSwapchain};
+/// # use vulkano::sync::GpuFuture;
+/// # let device: Arc<vulkano::device::Device> = return;
+/// # let queue: Arc<vulkano::device::Queue> = return;
+/// let (sender, receiver) = mpsc::channel();
+/// let (swapchain, images) = HeadlessSwapchain::new(
+///     device.clone(),
+///     2,
+///     Format::B8G8R8A8Srgb,
+///     [640, 480],
+///     ImageUsage::color_attachment(),
+///     HeadlessOutput::Channel(sender),
+/// )
+/// .unwrap();
+///
+/// let (image_num, _, acquire_future) = swapchain.acquire_next_image(None).unwrap();
+/// // ... draw to `images[image_num]` ...
+/// swapchain
+///     .present(acquire_future, queue.clone(), image_num)
+///     .unwrap();
+/// swapchain.flush_frames().unwrap();
+///
+/// let frame = receiver.recv().unwrap();
+/// assert_eq!(frame.dimensions(), [640, 480]);
+/// ```
+pub struct HeadlessSwapchain {
+    device: Arc<Device>,
+    images: Vec<Arc<AttachmentImage>>,
+    format: Format,
+    dimensions: [u32; 2],
+    state: Mutex<State>,
+}
+
+struct State {
+    output: HeadlessOutput,
+    next_image: usize,
+    next_frame: u64,
+    // For each image, true if it has been acquired and not presented yet.
+    acquired: Vec<bool>,
+    // Frames that have been presented and not delivered yet, in presentation order.
+    pending: VecDeque<PendingFrame>,
+}
+
+struct PendingFrame {
+    number: u64,
+    image_num: usize,
+    readback: ImageReadback<u8>,
+    future: HeadlessPresentFuture,
+}
+
+impl HeadlessSwapchain {
+    /// Builds a new headless swapchain with `num_images` images.
+    ///
+    /// The `transfer_source` usage is automatically added to `usage`, so that the images can be
+    /// read back.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if `num_images` is zero.
+    ///
+    pub fn new(
+        device: Arc<Device>,
+        num_images: u32,
+        format: Format,
+        dimensions: [u32; 2],
+        usage: ImageUsage,
+        output: HeadlessOutput,
+    ) -> Result<(Arc<HeadlessSwapchain>, Vec<Arc<AttachmentImage>>), HeadlessSwapchainCreationError>
+    {
+        assert!(num_images >= 1);
+
+        if format.size().is_none() {
+            return Err(HeadlessSwapchainCreationError::UnsupportedFormat);
+        }
+
+        #[cfg(feature = "png")]
+        {
+            if let HeadlessOutput::PngFiles(_) = output {
+                if !is_rgba8(format) {
+                    return Err(HeadlessSwapchainCreationError::UnsupportedFormat);
+                }
+            }
+        }
+
+        let usage = ImageUsage {
+            transfer_source: true,
+            ..usage
+        };
+
+        let images = (0..num_images)
+            .map(|_| AttachmentImage::with_usage(device.clone(), dimensions, format, usage))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let swapchain = Arc::new(HeadlessSwapchain {
+            device,
+            images: images.clone(),
+            format,
+            dimensions,
+            state: Mutex::new(State {
+                output,
+                next_image: 0,
+                next_frame: 0,
+                acquired: vec![false; num_images as usize],
+                pending: VecDeque::new(),
+            }),
+        });
+
+        Ok((swapchain, images))
+    }
+
+    /// Returns the images of the swapchain.
+    #[inline]
+    pub fn images(&self) -> &[Arc<AttachmentImage>] {
+        &self.images
+    }
+
+    /// Returns the number of images of the swapchain.
+    #[inline]
+    pub fn num_images(&self) -> u32 {
+        self.images.len() as u32
+    }
+
+    /// Returns the format of the images of the swapchain.
+    #[inline]
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    /// Returns the dimensions of the images of the swapchain.
+    #[inline]
+    pub fn dimensions(&self) -> [u32; 2] {
+        self.dimensions
+    }
+
+    /// Acquires the next image of the swapchain.
+    ///
+    /// Returns the index of the image, a boolean that is always false and only exists to match
+    /// the return value of `swapchain::acquire_next_image`, and a future that can be used to draw
+    /// to the image.
+    ///
+    /// If the image was presented before, this blocks until the frame that was presented from it
+    /// has been delivered to the output.
+    ///
+    /// Returns `Timeout` if all the images are acquired and not presented yet, or if the timeout
+    /// elapses before the image is available.
+    pub fn acquire_next_image(
+        &self,
+        timeout: Option<Duration>,
+    ) -> Result<(usize, bool, NowFuture), HeadlessSwapchainError> {
+        let mut state = self.state.lock().unwrap();
+        let image_num = state.next_image;
+
+        if state.acquired[image_num] {
+            return Err(HeadlessSwapchainError::Timeout);
+        }
+
+        while state
+            .pending
+            .iter()
+            .any(|frame| frame.image_num == image_num)
+        {
+            state.deliver_next(self.format, timeout)?;
+        }
+
+        state.acquired[image_num] = true;
+        state.next_image = (image_num + 1) % self.images.len();
+
+        Ok((image_num, false, now(self.device.clone())))
+    }
+
+    /// Presents an image once `before` has completed, by copying its content to
+    /// CPU-accessible memory. The copy is submitted to `queue`.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if the image hasn't been acquired.
+    ///
+    pub fn present<F>(
+        &self,
+        before: F,
+        queue: Arc<Queue>,
+        image_num: usize,
+    ) -> Result<HeadlessPresentFuture, HeadlessSwapchainError>
+    where
+        F: GpuFuture + Send + Sync + 'static,
+    {
+        let mut state = self.state.lock().unwrap();
+        assert!(state.acquired[image_num]);
+
+        let mut builder =
+            AutoCommandBufferBuilder::primary_one_time_submit(self.device.clone(), queue.family())?;
+        let readback = ImageReadback::from_image(&mut builder, self.images[image_num].clone())?;
+        let command_buffer = builder.build()?;
+
+        let before: Box<dyn GpuFuture + Send + Sync> = Box::new(before);
+        let future = Arc::new(
+            before
+                .then_execute(queue, command_buffer)?
+                .then_signal_fence_and_flush()?,
+        );
+
+        let number = state.next_frame;
+        state.next_frame += 1;
+        state.acquired[image_num] = false;
+        state.pending.push_back(PendingFrame {
+            number,
+            image_num,
+            readback,
+            future: future.clone(),
+        });
+
+        Ok(future)
+    }
+
+    /// Blocks until all the frames that have been presented are delivered to the output.
+    pub fn flush_frames(&self) -> Result<(), HeadlessSwapchainError> {
+        let mut state = self.state.lock().unwrap();
+
+        while !state.pending.is_empty() {
+            state.deliver_next(self.format, None)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl State {
+    // Waits for the oldest pending frame and delivers it to the output.
+    fn deliver_next(
+        &mut self,
+        format: Format,
+        timeout: Option<Duration>,
+    ) -> Result<(), HeadlessSwapchainError> {
+        match self.pending.front().unwrap().future.wait(timeout) {
+            Ok(()) => (),
+            Err(FlushError::Timeout) => return Err(HeadlessSwapchainError::Timeout),
+            Err(err) => return Err(err.into()),
+        }
+
+        let frame = self.pending.pop_front().unwrap();
+        let data = frame.readback.wait(None)?;
+        self.output.deliver(HeadlessFrame {
+            number: frame.number,
+            image_num: frame.image_num,
+            format,
+            data,
+        })
+    }
+}
+
+impl Drop for HeadlessSwapchain {
+    #[inline]
+    fn drop(&mut self) {
+        let _ = self.flush_frames();
+    }
+}
+
+unsafe impl DeviceOwned for HeadlessSwapchain {
+    #[inline]
+    fn device(&self) -> &Arc<Device> {
+        &self.device
+    }
+}
+
+impl fmt::Debug for HeadlessSwapchain {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("HeadlessSwapchain")
+            .field("num_images", &self.images.len())
+            .field("format", &self.format)
+            .field("dimensions", &self.dimensions)
+            .field("output", &self.state.lock().unwrap().output)
+            .finish()
+    }
+}
+
+/// Where the frames presented to a `HeadlessSwapchain` are delivered.
+pub enum HeadlessOutput {
+    /// Each frame is passed to a callback.
+    ///
+    /// The callback is called while the swapchain is locked, and therefore must not use the
+    /// swapchain.
+    Callback(Box<dyn FnMut(HeadlessFrame) + Send>),
+
+    /// Each frame is sent through a channel.
+    Channel(Sender<HeadlessFrame>),
+
+    /// Each frame is written to a file named `frame_NNNNNN.raw` in the given directory, where
+    /// `NNNNNN` is the number of the frame. The file contains the texels of the image, tightly
+    /// packed, without any header.
+    RawFiles(PathBuf),
+
+    /// Each frame is written to a file named `frame_NNNNNN.png` in the given directory, where
+    /// `NNNNNN` is the number of the frame.
+    ///
+    /// Only the `R8G8B8A8` and `B8G8R8A8` formats are supported. Requires the `png` feature of
+    /// vulkano.
+    #[cfg(feature = "png")]
+    PngFiles(PathBuf),
+}
+
+impl HeadlessOutput {
+    fn deliver(&mut self, frame: HeadlessFrame) -> Result<(), HeadlessSwapchainError> {
+        match *self {
+            HeadlessOutput::Callback(ref mut callback) => callback(frame),
+            HeadlessOutput::Channel(ref sender) => sender
+                .send(frame)
+                .map_err(|_| HeadlessSwapchainError::ChannelDisconnected)?,
+            HeadlessOutput::RawFiles(ref dir) => fs::write(
+                dir.join(frame_file_name(frame.number, "raw")),
+                frame.as_bytes(),
+            )?,
+            #[cfg(feature = "png")]
+            HeadlessOutput::PngFiles(ref dir) => {
+                let [width, height] = frame.dimensions();
+                let file = fs::File::create(dir.join(frame_file_name(frame.number, "png")))?;
+                let mut encoder = png::Encoder::new(io::BufWriter::new(file), width, height);
+                encoder.set_color(png::ColorType::RGBA);
+                encoder.set_depth(png::BitDepth::Eight);
+                encoder
+                    .write_header()?
+                    .write_image_data(&frame.to_rgba8().unwrap())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Debug for HeadlessOutput {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            HeadlessOutput::Callback(_) => write!(fmt, "Callback"),
+            HeadlessOutput::Channel(_) => write!(fmt, "Channel"),
+            HeadlessOutput::RawFiles(ref dir) => fmt.debug_tuple("RawFiles").field(dir).finish(),
+            #[cfg(feature = "png")]
+            HeadlessOutput::PngFiles(ref dir) => fmt.debug_tuple("PngFiles").field(dir).finish(),
+        }
+    }
+}
+
+/// A frame that has been presented to a `HeadlessSwapchain`.
+#[derive(Debug, Clone)]
+pub struct HeadlessFrame {
+    number: u64,
+    image_num: usize,
+    format: Format,
+    data: ImageData<u8>,
+}
+
+impl HeadlessFrame {
+    /// Returns the number of the frame. Frames are numbered from zero in the order in which they
+    /// were presented.
+    #[inline]
+    pub fn number(&self) -> u64 {
+        self.number
+    }
+
+    /// Returns the index of the image of the swapchain that the frame was presented from.
+    #[inline]
+    pub fn image_num(&self) -> usize {
+        self.image_num
+    }
+
+    /// Returns the format of the texels.
+    #[inline]
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    /// Returns the width and height of the frame.
+    #[inline]
+    pub fn dimensions(&self) -> [u32; 2] {
+        let [width, height, _] = self.data.dimensions();
+        [width, height]
+    }
+
+    /// Returns the texels of the frame, tightly packed.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        self.data.as_slice()
+    }
+
+    /// Returns the texels of the frame along with their layout.
+    #[inline]
+    pub fn data(&self) -> &ImageData<u8> {
+        &self.data
+    }
+
+    /// Returns the texels of the frame converted to 8 bits RGBA, or `None` if the format of the
+    /// frame isn't a `R8G8B8A8` or `B8G8R8A8` format.
+    pub fn to_rgba8(&self) -> Option<Vec<u8>> {
+        to_rgba8(self.format, self.as_bytes())
+    }
+}
+
+#[cfg(feature = "png")]
+fn is_rgba8(format: Format) -> bool {
+    match format {
+        Format::R8G8B8A8Unorm
+        | Format::R8G8B8A8Srgb
+        | Format::B8G8R8A8Unorm
+        | Format::B8G8R8A8Srgb => true,
+        _ => false,
+    }
+}
+
+fn to_rgba8(format: Format, data: &[u8]) -> Option<Vec<u8>> {
+    match format {
+        Format::R8G8B8A8Unorm | Format::R8G8B8A8Srgb => Some(data.to_vec()),
+        Format::B8G8R8A8Unorm | Format::B8G8R8A8Srgb => Some(
+            data.chunks_exact(4)
+                .flat_map(|texel| vec![texel[2], texel[1], texel[0], texel[3]])
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
+fn frame_file_name(number: u64, extension: &str) -> String {
+    format!("frame_{:06}.{}", number, extension)
+}
+
+/// Error that can happen when creating a `HeadlessSwapchain`.
+#[derive(Debug, Clone)]
+pub enum HeadlessSwapchainCreationError {
+    /// Creating the images failed.
+    ImageCreationError(ImageCreationError),
+
+    /// The format can't be read back, or isn't supported by the output.
+    UnsupportedFormat,
+}
+
+impl error::Error for HeadlessSwapchainCreationError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            HeadlessSwapchainCreationError::ImageCreationError(ref err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for HeadlessSwapchainCreationError {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(
+            fmt,
+            "{}",
+            match *self {
+                HeadlessSwapchainCreationError::ImageCreationError(_) => {
+                    "creating the images failed"
+                }
+                HeadlessSwapchainCreationError::UnsupportedFormat => {
+                    "the format can't be read back or isn't supported by the output"
+                }
+            }
+        )
+    }
+}
+
+impl From<ImageCreationError> for HeadlessSwapchainCreationError {
+    #[inline]
+    fn from(err: ImageCreationError) -> HeadlessSwapchainCreationError {
+        HeadlessSwapchainCreationError::ImageCreationError(err)
+    }
+}
+
+/// Error that can happen when acquiring or presenting an image of a `HeadlessSwapchain`, or
+/// when delivering a frame.
+#[derive(Debug)]
+pub enum HeadlessSwapchainError {
+    /// Not enough memory.
+    OomError(OomError),
+
+    /// The timeout has elapsed before an image was available.
+    Timeout,
+
+    /// Recording the copy of the image failed.
+    ReadbackCreationError(ReadbackCreationError),
+
+    /// Reading the content of the image failed.
+    ReadbackError(ReadbackError),
+
+    /// Building the command buffer failed.
+    BuildError(BuildError),
+
+    /// Executing the command buffer failed.
+    CommandBufferExecError(CommandBufferExecError),
+
+    /// Submitting the command buffer failed.
+    FlushError(FlushError),
+
+    /// The receiving end of the output channel has been dropped.
+    ChannelDisconnected,
+
+    /// Writing a frame to a file failed.
+    IoError(io::Error),
+
+    /// Encoding a frame to PNG failed.
+    #[cfg(feature = "png")]
+    PngError(png::EncodingError),
+}
+
+impl error::Error for HeadlessSwapchainError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            HeadlessSwapchainError::OomError(ref err) => Some(err),
+            HeadlessSwapchainError::ReadbackCreationError(ref err) => Some(err),
+            HeadlessSwapchainError::ReadbackError(ref err) => Some(err),
+            HeadlessSwapchainError::BuildError(ref err) => Some(err),
+            HeadlessSwapchainError::CommandBufferExecError(ref err) => Some(err),
+            HeadlessSwapchainError::FlushError(ref err) => Some(err),
+            HeadlessSwapchainError::IoError(ref err) => Some(err),
+            #[cfg(feature = "png")]
+            HeadlessSwapchainError::PngError(ref err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for HeadlessSwapchainError {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(
+            fmt,
+            "{}",
+            match *self {
+                HeadlessSwapchainError::OomError(_) => "not enough memory",
+                HeadlessSwapchainError::Timeout => {
+                    "the timeout has elapsed before an image was available"
+                }
+                HeadlessSwapchainError::ReadbackCreationError(_) => {
+                    "recording the copy of the image failed"
+                }
+                HeadlessSwapchainError::ReadbackError(_) => {
+                    "reading the content of the image failed"
+                }
+                HeadlessSwapchainError::BuildError(_) => "building the command buffer failed",
+                HeadlessSwapchainError::CommandBufferExecError(_) => {
+                    "executing the command buffer failed"
+                }
+                HeadlessSwapchainError::FlushError(_) => "submitting the command buffer failed",
+                HeadlessSwapchainError::ChannelDisconnected => {
+                    "the receiving end of the output channel has been dropped"
+                }
+                HeadlessSwapchainError::IoError(_) => "writing a frame to a file failed",
+                #[cfg(feature = "png")]
+                HeadlessSwapchainError::PngError(_) => "encoding a frame to PNG failed",
+            }
+        )
+    }
+}
+
+impl From<OomError> for HeadlessSwapchainError {
+    #[inline]
+    fn from(err: OomError) -> HeadlessSwapchainError {
+        HeadlessSwapchainError::OomError(err)
+    }
+}
+
+impl From<ReadbackCreationError> for HeadlessSwapchainError {
+    #[inline]
+    fn from(err: ReadbackCreationError) -> HeadlessSwapchainError {
+        HeadlessSwapchainError::ReadbackCreationError(err)
+    }
+}
+
+impl From<ReadbackError> for HeadlessSwapchainError {
+    #[inline]
+    fn from(err: ReadbackError) -> HeadlessSwapchainError {
+        HeadlessSwapchainError::ReadbackError(err)
+    }
+}
+
+impl From<BuildError> for HeadlessSwapchainError {
+    #[inline]
+    fn from(err: BuildError) -> HeadlessSwapchainError {
+        HeadlessSwapchainError::BuildError(err)
+    }
+}
+
+impl From<CommandBufferExecError> for HeadlessSwapchainError {
+    #[inline]
+    fn from(err: CommandBufferExecError) -> HeadlessSwapchainError {
+        HeadlessSwapchainError::CommandBufferExecError(err)
+    }
+}
+
+impl From<FlushError> for HeadlessSwapchainError {
+    #[inline]
+    fn from(err: FlushError) -> HeadlessSwapchainError {
+        HeadlessSwapchainError::FlushError(err)
+    }
+}
+
+impl From<io::Error> for HeadlessSwapchainError {
+    #[inline]
+    fn from(err: io::Error) -> HeadlessSwapchainError {
+        HeadlessSwapchainError::IoError(err)
+    }
+}
+
+#[cfg(feature = "png")]
+impl From<png::EncodingError> for HeadlessSwapchainError {
+    #[inline]
+    fn from(err: png::EncodingError) -> HeadlessSwapchainError {
+        HeadlessSwapchainError::PngError(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::frame_file_name;
+    use super::to_rgba8;
+    use crate::format::Format;
+
+    #[test]
+    fn frame_file_names() {
+        assert_eq!(frame_file_name(0, "raw"), "frame_000000.raw");
+        assert_eq!(frame_file_name(1234567, "png"), "frame_1234567.png");
+    }
+
+    #[test]
+    fn converts_to_rgba8() {
+        let data = [1, 2, 3, 4, 5, 6, 7, 8];
+        assert_eq!(to_rgba8(Format::R8G8B8A8Srgb, &data), Some(data.to_vec()));
+        assert_eq!(
+            to_rgba8(Format::B8G8R8A8Unorm, &data),
+            Some(vec![3, 2, 1, 4, 7, 6, 5, 8])
+        );
+        assert_eq!(to_rgba8(Format::R16G16B16A16Sfloat, &data), None);
+    }
+}
@@ -317,27 +317,42 @@ pub use self::capabilities::SupportedPresentModesIter;
 pub use self::capabilities::SupportedSurfaceTransforms;
 pub use self::capabilities::SupportedSurfaceTransformsIter;
 pub use self::capabilities::SurfaceTransform;
+pub use self::frame_pacer::FramePacer;
+pub use self::headless::HeadlessFrame;
+pub use self::headless::HeadlessOutput;
+pub use self::headless::HeadlessPresentFuture;
+pub use self::headless::HeadlessSwapchain;
+pub use self::headless::HeadlessSwapchainCreationError;
+pub use self::headless::HeadlessSwapchainError;
 pub use self::present_region::PresentRegion;
 pub use self::present_region::RectangleLayer;
+pub use self::present_timing::PastPresentationTiming;
+pub use self::present_timing::PresentTime;
 pub use self::surface::CapabilitiesError;
 pub use self::surface::Surface;
 pub use self::surface::SurfaceCreationError;
 pub use self::swapchain::acquire_next_image;
 pub use self::swapchain::acquire_next_image_raw;
+pub use self::swapchain::acquire_next_image_with_device_mask;
 pub use self::swapchain::present;
 pub use self::swapchain::present_incremental;
 pub use self::swapchain::AcquireError;
 pub use self::swapchain::AcquiredImage;
 pub use self::swapchain::FullscreenExclusive;
 pub use self::swapchain::FullscreenExclusiveError;
+pub use self::swapchain::HdrMetadata;
 pub use self::swapchain::PresentFuture;
+pub use self::swapchain::PresentTimingError;
 pub use self::swapchain::Swapchain;
 pub use self::swapchain::SwapchainAcquireFuture;
 pub use self::swapchain::SwapchainCreationError;
 
 mod capabilities;
 pub mod display;
+mod frame_pacer;
+mod headless;
 mod present_region;
+mod present_timing;
 mod surface;
 mod swapchain;
 
@@ -0,0 +1,72 @@
+// Copyright (c) 2021 The vulkano developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>,
+// at your option. All files in the project carrying such
+// notice may not be copied, modified, or distributed except
+// according to those terms.
+
+use crate::vk;
+use std::time::Duration;
+
+/// The time at which an image should be presented, with the `google_display_timing` extension.
+///
+/// All the times are measured on the same clock as the one that is used by the presentation
+/// engine, which is `CLOCK_MONOTONIC` on most platforms.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct PresentTime {
+    /// Identifier chosen by the application, that is later reported back in the
+    /// `PastPresentationTiming` of the image.
+    pub present_id: u32,
+
+    /// The image must not be displayed before this time. A value of zero means that the image
+    /// can be displayed at any time.
+    pub desired_present_time: Duration,
+}
+
+impl PresentTime {
+    #[inline]
+    pub(crate) fn to_vk(self) -> vk::PresentTimeGOOGLE {
+        vk::PresentTimeGOOGLE {
+            presentID: self.present_id,
+            desiredPresentTime: self.desired_present_time.as_nanos() as u64,
+        }
+    }
+}
+
+/// Timing information about an image that has been presented, returned by
+/// `Swapchain::past_presentation_timing`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct PastPresentationTiming {
+    /// The `present_id` that was passed in the `PresentTime` of the image.
+    pub present_id: u32,
+
+    /// The `desired_present_time` that was passed in the `PresentTime` of the image.
+    pub desired_present_time: Duration,
+
+    /// The time at which the image was actually displayed.
+    pub actual_present_time: Duration,
+
+    /// The earliest time at which the image could have been displayed. This can differ from
+    /// `actual_present_time` if the image was presented late on purpose because of its
+    /// `desired_present_time`.
+    pub earliest_present_time: Duration,
+
+    /// How early the rendering of the image was finished compared to the latest time at which
+    /// it could have been finished while still being displayed at `earliest_present_time`.
+    pub present_margin: Duration,
+}
+
+impl PastPresentationTiming {
+    #[inline]
+    pub(crate) fn from_vk(timing: &vk::PastPresentationTimingGOOGLE) -> PastPresentationTiming {
+        PastPresentationTiming {
+            present_id: timing.presentID,
+            desired_present_time: Duration::from_nanos(timing.desiredPresentTime),
+            actual_present_time: Duration::from_nanos(timing.actualPresentTime),
+            earliest_present_time: Duration::from_nanos(timing.earliestPresentTime),
+            present_margin: Duration::from_nanos(timing.presentMargin),
+        }
+    }
+}
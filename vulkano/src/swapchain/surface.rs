@@ -509,6 +509,52 @@ impl<W> Surface<W> {
         }))
     }
 
+    /// Creates a headless `Surface`, which isn't tied to any window or display.
+    ///
+    /// Images presented to a swapchain created from a headless surface are discarded. This can
+    /// be used to run code that drives a `Swapchain` where no display is available, for example
+    /// on a CI machine. If the `ext_headless_surface` extension isn't available, a
+    /// `HeadlessSwapchain` can be used instead.
+    ///
+    /// The `win` parameter can be used to attach arbitrary data to the surface, or `()` can be
+    /// passed.
+    pub fn headless(
+        instance: Arc<Instance>,
+        win: W,
+    ) -> Result<Arc<Surface<W>>, SurfaceCreationError> {
+        let vk = instance.pointers();
+
+        if !instance.loaded_extensions().ext_headless_surface {
+            return Err(SurfaceCreationError::MissingExtension {
+                name: "VK_EXT_headless_surface",
+            });
+        }
+
+        let surface = unsafe {
+            let infos = vk::HeadlessSurfaceCreateInfoEXT {
+                sType: vk::STRUCTURE_TYPE_HEADLESS_SURFACE_CREATE_INFO_EXT,
+                pNext: ptr::null(),
+                flags: 0, // reserved
+            };
+
+            let mut output = MaybeUninit::uninit();
+            check_errors(vk.CreateHeadlessSurfaceEXT(
+                instance.internal_object(),
+                &infos,
+                ptr::null(),
+                output.as_mut_ptr(),
+            ))?;
+            output.assume_init()
+        };
+
+        Ok(Arc::new(Surface {
+            window: win,
+            instance: instance.clone(),
+            surface,
+            has_swapchain: AtomicBool::new(false),
+        }))
+    }
+
     /// Returns true if the given queue family can draw on this surface.
     // FIXME: vulkano doesn't check this for the moment!
     pub fn is_supported(&self, queue: QueueFamily) -> Result<bool, CapabilitiesError> {
@@ -29,8 +29,10 @@ use crate::image::ImageUsage;
 use crate::swapchain::CapabilitiesError;
 use crate::swapchain::ColorSpace;
 use crate::swapchain::CompositeAlpha;
+use crate::swapchain::PastPresentationTiming;
 use crate::swapchain::PresentMode;
 use crate::swapchain::PresentRegion;
+use crate::swapchain::PresentTime;
 use crate::swapchain::Surface;
 use crate::swapchain::SurfaceSwapchainLock;
 use crate::swapchain::SurfaceTransform;
@@ -84,7 +86,7 @@ pub enum FullscreenExclusive {
 impl FullscreenExclusive {
     fn vk_sys_enum(&self) -> u32 {
         match self {
-            &Self::Default => vk::FULL_SCREEN_EXCLUSIVE_DEFAUlT_EXT,
+            &Self::Default => vk::FULL_SCREEN_EXCLUSIVE_DEFAULT_EXT,
             &Self::Allowed => vk::FULL_SCREEN_EXCLUSIVE_ALLOWED_EXT,
             &Self::Disallowed => vk::FULL_SCREEN_EXCLUSIVE_DISALLOWED_EXT,
             &Self::AppControlled => vk::FULL_SCREEN_EXCLUSIVE_APPLICATION_CONTROLLED_EXT,
@@ -92,6 +94,72 @@ impl FullscreenExclusive {
     }
 }
 
+/// Mastering display information of HDR content, given to the implementation with
+/// `Swapchain::set_hdr_metadata`.
+///
+/// The chromaticity coordinates are in the CIE 1931 color space, and the luminances are in nits
+/// (candelas per square meter).
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct HdrMetadata {
+    /// Chromaticity of the red primary of the mastering display.
+    pub display_primary_red: [f32; 2],
+    /// Chromaticity of the green primary of the mastering display.
+    pub display_primary_green: [f32; 2],
+    /// Chromaticity of the blue primary of the mastering display.
+    pub display_primary_blue: [f32; 2],
+    /// Chromaticity of the white point of the mastering display.
+    pub white_point: [f32; 2],
+    /// Maximum luminance of the mastering display.
+    pub max_luminance: f32,
+    /// Minimum luminance of the mastering display.
+    pub min_luminance: f32,
+    /// Maximum luminance of any pixel of the content (MaxCLL).
+    pub max_content_light_level: f32,
+    /// Maximum average luminance of any frame of the content (MaxFALL).
+    pub max_frame_average_light_level: f32,
+}
+
+impl HdrMetadata {
+    /// Builds an `HdrMetadata` for a mastering display with the BT.2020 primaries and the D65
+    /// white point, as commonly used for HDR10 content.
+    #[inline]
+    pub fn bt2020(
+        max_luminance: f32,
+        min_luminance: f32,
+        max_content_light_level: f32,
+        max_frame_average_light_level: f32,
+    ) -> HdrMetadata {
+        HdrMetadata {
+            display_primary_red: [0.708, 0.292],
+            display_primary_green: [0.170, 0.797],
+            display_primary_blue: [0.131, 0.046],
+            white_point: [0.3127, 0.3290],
+            max_luminance,
+            min_luminance,
+            max_content_light_level,
+            max_frame_average_light_level,
+        }
+    }
+
+    #[inline]
+    fn to_vk(&self) -> vk::HdrMetadataEXT {
+        let xy = |c: [f32; 2]| vk::XYColorEXT { x: c[0], y: c[1] };
+
+        vk::HdrMetadataEXT {
+            sType: vk::STRUCTURE_TYPE_HDR_METADATA_EXT,
+            pNext: ptr::null(),
+            displayPrimaryRed: xy(self.display_primary_red),
+            displayPrimaryGreen: xy(self.display_primary_green),
+            displayPrimaryBlue: xy(self.display_primary_blue),
+            whitePoint: xy(self.white_point),
+            maxLuminance: self.max_luminance,
+            minLuminance: self.min_luminance,
+            maxContentLightLevel: self.max_content_light_level,
+            maxFrameAverageLightLevel: self.max_frame_average_light_level,
+        }
+    }
+}
+
 /// Tries to take ownership of an image in order to draw on it.
 ///
 /// The function returns the index of the image in the array of images that was returned
@@ -107,6 +175,34 @@ impl FullscreenExclusive {
 pub fn acquire_next_image<W>(
     swapchain: Arc<Swapchain<W>>,
     timeout: Option<Duration>,
+) -> Result<(usize, bool, SwapchainAcquireFuture<W>), AcquireError> {
+    acquire_next_image_inner(swapchain, timeout, None)
+}
+
+/// Same as `acquire_next_image`, but for a device that spans a device group. The image is
+/// acquired for the physical devices in `device_mask`.
+///
+/// # Panic
+///
+/// - Panics if the `khr_device_group` extension is not enabled on the device.
+/// - Panics if `device_mask` is 0 or contains physical devices that are not part of the device.
+///
+pub fn acquire_next_image_with_device_mask<W>(
+    swapchain: Arc<Swapchain<W>>,
+    timeout: Option<Duration>,
+    device_mask: u32,
+) -> Result<(usize, bool, SwapchainAcquireFuture<W>), AcquireError> {
+    assert!(swapchain.device.loaded_extensions().khr_device_group);
+    assert_ne!(device_mask, 0);
+    assert_eq!(device_mask & !swapchain.device.device_mask(), 0);
+
+    acquire_next_image_inner(swapchain, timeout, Some(device_mask))
+}
+
+fn acquire_next_image_inner<W>(
+    swapchain: Arc<Swapchain<W>>,
+    timeout: Option<Duration>,
+    device_mask: Option<u32>,
 ) -> Result<(usize, bool, SwapchainAcquireFuture<W>), AcquireError> {
     let semaphore = Semaphore::from_pool(swapchain.device.clone())?;
     let fence = Fence::from_pool(swapchain.device.clone())?;
@@ -120,8 +216,15 @@ pub fn acquire_next_image<W>(
             return Err(AcquireError::OutOfDate);
         }
 
-        let acquire_result =
-            unsafe { acquire_next_image_raw(&swapchain, timeout, Some(&semaphore), Some(&fence)) };
+        let acquire_result = unsafe {
+            acquire_next_image_raw_inner(
+                &swapchain,
+                timeout,
+                Some(&semaphore),
+                Some(&fence),
+                device_mask,
+            )
+        };
 
         if let &Err(AcquireError::FullscreenExclusiveLost) = &acquire_result {
             swapchain
@@ -176,6 +279,8 @@ where
         swapchain,
         image_id: index,
         present_region: None,
+        present_time: None,
+        present_id: None,
         flushed: AtomicBool::new(false),
         finished: AtomicBool::new(false),
     }
@@ -212,6 +317,8 @@ where
         swapchain,
         image_id: index,
         present_region: Some(present_region),
+        present_time: None,
+        present_id: None,
         flushed: AtomicBool::new(false),
         finished: AtomicBool::new(false),
     }
@@ -347,7 +454,7 @@ impl<W> Swapchain<W> {
             surface,
             num_images,
             format,
-            ColorSpace::SrgbNonLinear,
+            color_space,
             Some(dimensions),
             layers,
             usage,
@@ -440,11 +547,15 @@ impl<W> Swapchain<W> {
                 return Err(SwapchainCreationError::UnsupportedMaxImagesCount);
             }
         }
-        if !capabilities
-            .supported_formats
-            .iter()
-            .any(|&(f, c)| f == format && c == color_space)
+        if color_space.requires_swapchain_colorspace_extension()
+            && !surface
+                .instance()
+                .loaded_extensions()
+                .ext_swapchain_colorspace
         {
+            return Err(SwapchainCreationError::MissingExtensionExtSwapchainColorspace);
+        }
+        if !capabilities.supports_format(format, color_space) {
             return Err(SwapchainCreationError::UnsupportedFormat);
         }
         let dimensions = if let Some(dimensions) = dimensions {
@@ -728,6 +839,14 @@ impl<W> Swapchain<W> {
         self.format
     }
 
+    /// Returns the color space of the images of the swapchain.
+    ///
+    /// See the documentation of `Swapchain::new`.
+    #[inline]
+    pub fn color_space(&self) -> ColorSpace {
+        self.color_space
+    }
+
     /// Returns the dimensions of the images of the swapchain.
     ///
     /// See the documentation of `Swapchain::new`.
@@ -844,6 +963,131 @@ impl<W> Swapchain<W> {
         }
     }
 
+    /// Gives the mastering display information of the content to the implementation. It
+    /// applies to the images presented after this call.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if the `ext_hdr_metadata` extension is not enabled on the device.
+    ///
+    pub fn set_hdr_metadata(&self, metadata: &HdrMetadata) {
+        assert!(self.device.loaded_extensions().ext_hdr_metadata);
+
+        unsafe {
+            let vk = self.device.pointers();
+            let metadata = metadata.to_vk();
+            vk.SetHdrMetadataEXT(self.device.internal_object(), 1, &self.swapchain, &metadata);
+        }
+    }
+
+    /// Returns the duration of a refresh cycle of the display that the swapchain is presented on.
+    ///
+    /// The `google_display_timing` extension must be enabled on the device.
+    pub fn refresh_cycle_duration(&self) -> Result<Duration, PresentTimingError> {
+        if !self.device.loaded_extensions().google_display_timing {
+            return Err(PresentTimingError::MissingExtension(
+                "google_display_timing",
+            ));
+        }
+
+        unsafe {
+            let vk = self.device.pointers();
+            let mut output = MaybeUninit::uninit();
+            check_errors(vk.GetRefreshCycleDurationGOOGLE(
+                self.device.internal_object(),
+                self.swapchain,
+                output.as_mut_ptr(),
+            ))?;
+
+            Ok(Duration::from_nanos(output.assume_init().refreshDuration))
+        }
+    }
+
+    /// Returns the timing information of the images that have been displayed since the last
+    /// call to this function. The images presented without a `PresentTime` are not reported.
+    ///
+    /// The `google_display_timing` extension must be enabled on the device.
+    pub fn past_presentation_timing(
+        &self,
+    ) -> Result<Vec<PastPresentationTiming>, PresentTimingError> {
+        if !self.device.loaded_extensions().google_display_timing {
+            return Err(PresentTimingError::MissingExtension(
+                "google_display_timing",
+            ));
+        }
+
+        unsafe {
+            let vk = self.device.pointers();
+
+            let mut num = 0;
+            check_errors(vk.GetPastPresentationTimingGOOGLE(
+                self.device.internal_object(),
+                self.swapchain,
+                &mut num,
+                ptr::null_mut(),
+            ))?;
+
+            let mut timings = Vec::with_capacity(num as usize);
+            check_errors(vk.GetPastPresentationTimingGOOGLE(
+                self.device.internal_object(),
+                self.swapchain,
+                &mut num,
+                timings.as_mut_ptr(),
+            ))?;
+            timings.set_len(num as usize);
+
+            Ok(timings
+                .iter()
+                .map(PastPresentationTiming::from_vk)
+                .collect())
+        }
+    }
+
+    /// Blocks until the present operation with the given identifier, or a later one, has been
+    /// displayed. The identifier is the one passed to `PresentFuture::with_present_id`.
+    ///
+    /// Returns `PresentTimingError::Timeout` if the timeout is reached first.
+    ///
+    /// The `present_wait` feature must be enabled on the device.
+    pub fn wait_for_present(
+        &self,
+        present_id: u64,
+        timeout: Option<Duration>,
+    ) -> Result<(), PresentTimingError> {
+        if !self.device.enabled_features().present_wait {
+            return Err(PresentTimingError::MissingFeature("present_wait"));
+        }
+
+        let timeout_ns = if let Some(timeout) = timeout {
+            timeout
+                .as_secs()
+                .saturating_mul(1_000_000_000)
+                .saturating_add(timeout.subsec_nanos() as u64)
+        } else {
+            u64::max_value()
+        };
+
+        let result = unsafe {
+            let vk = self.device.pointers();
+            check_errors(vk.WaitForPresentKHR(
+                self.device.internal_object(),
+                self.swapchain,
+                present_id,
+                timeout_ns,
+            ))
+        };
+
+        if let Err(Error::FullscreenExclusiveLost) = result {
+            self.fullscreen_exclusive_held
+                .store(false, Ordering::SeqCst);
+        }
+
+        match result? {
+            Success::Timeout => Err(PresentTimingError::Timeout),
+            _ => Ok(()),
+        }
+    }
+
     // This method is necessary to allow `SwapchainImage`s to signal when they have been
     // transitioned out of their initial `undefined` image layout.
     //
@@ -917,6 +1161,9 @@ pub enum SwapchainCreationError {
     MissingExtensionKHRSwapchain,
     /// The `VK_EXT_full_screen_exclusive` extension was not enabled.
     MissingExtensionExtFullScreenExclusive,
+    /// The `VK_EXT_swapchain_colorspace` extension was not enabled, but is required by the
+    /// requested color space.
+    MissingExtensionExtSwapchainColorspace,
     /// Surface mismatch between old and new swapchain.
     OldSwapchainSurfaceMismatch,
     /// The old swapchain has already been used to recreate another one.
@@ -975,6 +1222,9 @@ impl fmt::Display for SwapchainCreationError {
                 SwapchainCreationError::MissingExtensionExtFullScreenExclusive => {
                     "the `VK_EXT_full_screen_exclusive` extension was not enabled"
                 }
+                SwapchainCreationError::MissingExtensionExtSwapchainColorspace => {
+                    "the `VK_EXT_swapchain_colorspace` extension was not enabled"
+                }
                 SwapchainCreationError::OldSwapchainSurfaceMismatch => {
                     "surface mismatch between old and new swapchain"
                 }
@@ -1255,6 +1505,97 @@ impl fmt::Display for FullscreenExclusiveError {
     }
 }
 
+/// Error that can happen when querying or waiting for the presentation timing of a swapchain.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PresentTimingError {
+    /// Not enough memory.
+    OomError(OomError),
+
+    /// The connection to the device has been lost.
+    DeviceLost,
+
+    /// The surface is no longer accessible and must be recreated.
+    SurfaceLost,
+
+    /// The swapchain has lost or doesn't have fullscreen exclusivity possibly for
+    /// implementation-specific reasons outside of the application’s control.
+    FullscreenExclusiveLost,
+
+    /// The surface has changed in a way that makes the swapchain unusable. You must query the
+    /// surface's new properties and recreate a new swapchain if you want to continue drawing.
+    OutOfDate,
+
+    /// The timeout has been reached before the present operation was displayed.
+    Timeout,
+
+    /// An extension is missing.
+    MissingExtension(&'static str),
+
+    /// A feature is missing.
+    MissingFeature(&'static str),
+}
+
+impl error::Error for PresentTimingError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            PresentTimingError::OomError(ref err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for PresentTimingError {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            PresentTimingError::OomError(_) => write!(fmt, "not enough memory"),
+            PresentTimingError::DeviceLost => {
+                write!(fmt, "the connection to the device has been lost")
+            }
+            PresentTimingError::SurfaceLost => {
+                write!(fmt, "the surface of this swapchain is no longer valid")
+            }
+            PresentTimingError::FullscreenExclusiveLost => {
+                write!(fmt, "the swapchain no longer has fullscreen exclusivity")
+            }
+            PresentTimingError::OutOfDate => write!(fmt, "the swapchain needs to be recreated"),
+            PresentTimingError::Timeout => write!(
+                fmt,
+                "the present operation was not displayed before the timeout"
+            ),
+            PresentTimingError::MissingExtension(ext) => {
+                write!(fmt, "the extension `{}` must be enabled", ext)
+            }
+            PresentTimingError::MissingFeature(feature) => {
+                write!(fmt, "the feature `{}` must be enabled", feature)
+            }
+        }
+    }
+}
+
+impl From<OomError> for PresentTimingError {
+    #[inline]
+    fn from(err: OomError) -> PresentTimingError {
+        PresentTimingError::OomError(err)
+    }
+}
+
+impl From<Error> for PresentTimingError {
+    #[inline]
+    fn from(err: Error) -> PresentTimingError {
+        match err {
+            err @ Error::OutOfHostMemory => PresentTimingError::OomError(OomError::from(err)),
+            err @ Error::OutOfDeviceMemory => PresentTimingError::OomError(OomError::from(err)),
+            Error::DeviceLost => PresentTimingError::DeviceLost,
+            Error::SurfaceLost => PresentTimingError::SurfaceLost,
+            Error::OutOfDate => PresentTimingError::OutOfDate,
+            Error::FullscreenExclusiveLost => PresentTimingError::FullscreenExclusiveLost,
+            _ => panic!("unexpected error: {:?}", err),
+        }
+    }
+}
+
 /// Error that can happen when calling `acquire_next_image`.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 #[repr(u32)]
@@ -1343,6 +1684,8 @@ where
     swapchain: Arc<Swapchain<W>>,
     image_id: usize,
     present_region: Option<PresentRegion>,
+    present_time: Option<PresentTime>,
+    present_id: Option<u64>,
     // True if `flush()` has been called on the future, which means that the present command has
     // been submitted.
     flushed: AtomicBool,
@@ -1366,6 +1709,46 @@ where
     pub fn swapchain(&self) -> &Arc<Swapchain<W>> {
         &self.swapchain
     }
+
+    /// Requests the image to be displayed no earlier than the given time. The `present_id` is
+    /// reported back by `Swapchain::past_presentation_timing` once the image has been displayed.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if the `google_display_timing` extension is not enabled on the device.
+    /// - Panics if the future has already been flushed.
+    ///
+    #[inline]
+    pub fn with_present_time(mut self, present_time: PresentTime) -> Self {
+        assert!(
+            self.swapchain
+                .device
+                .loaded_extensions()
+                .google_display_timing
+        );
+        assert!(!self.flushed.load(Ordering::SeqCst));
+        self.present_time = Some(present_time);
+        self
+    }
+
+    /// Assigns an identifier to the present operation, that can then be waited upon with
+    /// `Swapchain::wait_for_present`. Identifiers must be increasing for each present operation
+    /// on the same swapchain.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if the `present_id` feature is not enabled on the device.
+    /// - Panics if `present_id` is 0.
+    /// - Panics if the future has already been flushed.
+    ///
+    #[inline]
+    pub fn with_present_id(mut self, present_id: u64) -> Self {
+        assert!(self.swapchain.device.enabled_features().present_id);
+        assert_ne!(present_id, 0);
+        assert!(!self.flushed.load(Ordering::SeqCst));
+        self.present_id = Some(present_id);
+        self
+    }
 }
 
 unsafe impl<P, W> GpuFuture for PresentFuture<P, W>
@@ -1391,19 +1774,23 @@ where
         Ok(match self.previous.build_submission()? {
             SubmitAnyBuilder::Empty => {
                 let mut builder = SubmitPresentBuilder::new();
-                builder.add_swapchain(
+                builder.add_swapchain_with_timing(
                     &self.swapchain,
                     self.image_id as u32,
                     self.present_region.as_ref(),
+                    self.present_time,
+                    self.present_id,
                 );
                 SubmitAnyBuilder::QueuePresent(builder)
             }
             SubmitAnyBuilder::SemaphoresWait(sem) => {
                 let mut builder: SubmitPresentBuilder = sem.into();
-                builder.add_swapchain(
+                builder.add_swapchain_with_timing(
                     &self.swapchain,
                     self.image_id as u32,
                     self.present_region.as_ref(),
+                    self.present_time,
+                    self.present_id,
                 );
                 SubmitAnyBuilder::QueuePresent(builder)
             }
@@ -1413,10 +1800,12 @@ where
                 self.previous.flush()?;
 
                 let mut builder = SubmitPresentBuilder::new();
-                builder.add_swapchain(
+                builder.add_swapchain_with_timing(
                     &self.swapchain,
                     self.image_id as u32,
                     self.present_region.as_ref(),
+                    self.present_time,
+                    self.present_id,
                 );
                 SubmitAnyBuilder::QueuePresent(builder)
             }
@@ -1426,10 +1815,12 @@ where
                 self.previous.flush()?;
 
                 let mut builder = SubmitPresentBuilder::new();
-                builder.add_swapchain(
+                builder.add_swapchain_with_timing(
                     &self.swapchain,
                     self.image_id as u32,
                     self.present_region.as_ref(),
+                    self.present_time,
+                    self.present_id,
                 );
                 SubmitAnyBuilder::QueuePresent(builder)
             }
@@ -1581,6 +1972,17 @@ pub unsafe fn acquire_next_image_raw<W>(
     timeout: Option<Duration>,
     semaphore: Option<&Semaphore>,
     fence: Option<&Fence>,
+) -> Result<AcquiredImage, AcquireError> {
+    acquire_next_image_raw_inner(swapchain, timeout, semaphore, fence, None)
+}
+
+// If `device_mask` is `Some`, `vkAcquireNextImage2KHR` is used to pass it.
+unsafe fn acquire_next_image_raw_inner<W>(
+    swapchain: &Swapchain<W>,
+    timeout: Option<Duration>,
+    semaphore: Option<&Semaphore>,
+    fence: Option<&Fence>,
+    device_mask: Option<u32>,
 ) -> Result<AcquiredImage, AcquireError> {
     let vk = swapchain.device.pointers();
 
@@ -1593,15 +1995,36 @@ pub unsafe fn acquire_next_image_raw<W>(
         u64::max_value()
     };
 
+    let semaphore = semaphore.map(|s| s.internal_object()).unwrap_or(0);
+    let fence = fence.map(|f| f.internal_object()).unwrap_or(0);
+
     let mut out = MaybeUninit::uninit();
-    let r = check_errors(vk.AcquireNextImageKHR(
-        swapchain.device.internal_object(),
-        swapchain.swapchain,
-        timeout_ns,
-        semaphore.map(|s| s.internal_object()).unwrap_or(0),
-        fence.map(|f| f.internal_object()).unwrap_or(0),
-        out.as_mut_ptr(),
-    ))?;
+    let r = if let Some(device_mask) = device_mask {
+        let infos = vk::AcquireNextImageInfoKHR {
+            sType: vk::STRUCTURE_TYPE_ACQUIRE_NEXT_IMAGE_INFO_KHR,
+            pNext: ptr::null(),
+            swapchain: swapchain.swapchain,
+            timeout: timeout_ns,
+            semaphore,
+            fence,
+            deviceMask: device_mask,
+        };
+
+        check_errors(vk.AcquireNextImage2KHR(
+            swapchain.device.internal_object(),
+            &infos,
+            out.as_mut_ptr(),
+        ))?
+    } else {
+        check_errors(vk.AcquireNextImageKHR(
+            swapchain.device.internal_object(),
+            swapchain.swapchain,
+            timeout_ns,
+            semaphore,
+            fence,
+            out.as_mut_ptr(),
+        ))?
+    };
 
     let out = out.assume_init();
     let (id, suboptimal) = match r {
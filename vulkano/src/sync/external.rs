@@ -0,0 +1,322 @@
+// Copyright (c) 2021 The vulkano developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>,
+// at your option. All files in the project carrying such
+// notice may not be copied, modified, or distributed except
+// according to those terms.
+
+//! Sharing semaphores and fences with other APIs or processes.
+
+use crate::sync::FlushError;
+use crate::vk;
+use crate::Error;
+use crate::OomError;
+use std::error;
+use std::fmt;
+use std::ops::BitOr;
+
+/// Describes the handle types that can be used to export or import the payload of a semaphore.
+/// Check out `VkExternalSemaphoreHandleTypeFlagBits` in the Vulkan spec.
+///
+/// Only the file descriptor handle types are currently supported by vulkano for exporting and
+/// importing.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ExternalSemaphoreHandleType {
+    pub opaque_fd: bool,
+    pub opaque_win32: bool,
+    pub opaque_win32_kmt: bool,
+    pub d3d12_fence: bool,
+    pub sync_fd: bool,
+}
+
+impl ExternalSemaphoreHandleType {
+    /// Builds an `ExternalSemaphoreHandleType` with all values set to false. Useful as a default
+    /// value.
+    #[inline]
+    pub fn none() -> ExternalSemaphoreHandleType {
+        ExternalSemaphoreHandleType {
+            opaque_fd: false,
+            opaque_win32: false,
+            opaque_win32_kmt: false,
+            d3d12_fence: false,
+            sync_fd: false,
+        }
+    }
+
+    /// Builds an `ExternalSemaphoreHandleType` for an opaque posix file descriptor.
+    #[inline]
+    pub fn posix() -> ExternalSemaphoreHandleType {
+        ExternalSemaphoreHandleType {
+            opaque_fd: true,
+            ..ExternalSemaphoreHandleType::none()
+        }
+    }
+
+    /// Builds an `ExternalSemaphoreHandleType` for a Linux sync file.
+    #[inline]
+    pub fn sync_fd() -> ExternalSemaphoreHandleType {
+        ExternalSemaphoreHandleType {
+            sync_fd: true,
+            ..ExternalSemaphoreHandleType::none()
+        }
+    }
+
+    #[inline]
+    pub(crate) fn to_bits(self) -> vk::ExternalSemaphoreHandleTypeFlagBits {
+        let mut result = 0;
+        if self.opaque_fd {
+            result |= vk::EXTERNAL_SEMAPHORE_HANDLE_TYPE_OPAQUE_FD_BIT;
+        }
+        if self.opaque_win32 {
+            result |= vk::EXTERNAL_SEMAPHORE_HANDLE_TYPE_OPAQUE_WIN32_BIT;
+        }
+        if self.opaque_win32_kmt {
+            result |= vk::EXTERNAL_SEMAPHORE_HANDLE_TYPE_OPAQUE_WIN32_KMT_BIT;
+        }
+        if self.d3d12_fence {
+            result |= vk::EXTERNAL_SEMAPHORE_HANDLE_TYPE_D3D12_FENCE_BIT;
+        }
+        if self.sync_fd {
+            result |= vk::EXTERNAL_SEMAPHORE_HANDLE_TYPE_SYNC_FD_BIT;
+        }
+        result
+    }
+
+    // Returns the bit of the handle type, if it is a single file descriptor handle type.
+    pub(crate) fn fd_bit(
+        &self,
+    ) -> Result<vk::ExternalSemaphoreHandleTypeFlagBits, ExternalSyncError> {
+        let bits = self.to_bits();
+        if bits != vk::EXTERNAL_SEMAPHORE_HANDLE_TYPE_OPAQUE_FD_BIT
+            && bits != vk::EXTERNAL_SEMAPHORE_HANDLE_TYPE_SYNC_FD_BIT
+        {
+            return Err(ExternalSyncError::InvalidHandleType);
+        }
+
+        Ok(bits)
+    }
+}
+
+impl BitOr for ExternalSemaphoreHandleType {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        ExternalSemaphoreHandleType {
+            opaque_fd: self.opaque_fd || rhs.opaque_fd,
+            opaque_win32: self.opaque_win32 || rhs.opaque_win32,
+            opaque_win32_kmt: self.opaque_win32_kmt || rhs.opaque_win32_kmt,
+            d3d12_fence: self.d3d12_fence || rhs.d3d12_fence,
+            sync_fd: self.sync_fd || rhs.sync_fd,
+        }
+    }
+}
+
+/// Describes the handle types that can be used to export or import the payload of a fence.
+/// Check out `VkExternalFenceHandleTypeFlagBits` in the Vulkan spec.
+///
+/// Only the file descriptor handle types are currently supported by vulkano for exporting and
+/// importing.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ExternalFenceHandleType {
+    pub opaque_fd: bool,
+    pub opaque_win32: bool,
+    pub opaque_win32_kmt: bool,
+    pub sync_fd: bool,
+}
+
+impl ExternalFenceHandleType {
+    /// Builds an `ExternalFenceHandleType` with all values set to false. Useful as a default
+    /// value.
+    #[inline]
+    pub fn none() -> ExternalFenceHandleType {
+        ExternalFenceHandleType {
+            opaque_fd: false,
+            opaque_win32: false,
+            opaque_win32_kmt: false,
+            sync_fd: false,
+        }
+    }
+
+    /// Builds an `ExternalFenceHandleType` for an opaque posix file descriptor.
+    #[inline]
+    pub fn posix() -> ExternalFenceHandleType {
+        ExternalFenceHandleType {
+            opaque_fd: true,
+            ..ExternalFenceHandleType::none()
+        }
+    }
+
+    /// Builds an `ExternalFenceHandleType` for a Linux sync file.
+    #[inline]
+    pub fn sync_fd() -> ExternalFenceHandleType {
+        ExternalFenceHandleType {
+            sync_fd: true,
+            ..ExternalFenceHandleType::none()
+        }
+    }
+
+    #[inline]
+    pub(crate) fn to_bits(self) -> vk::ExternalFenceHandleTypeFlagBits {
+        let mut result = 0;
+        if self.opaque_fd {
+            result |= vk::EXTERNAL_FENCE_HANDLE_TYPE_OPAQUE_FD_BIT;
+        }
+        if self.opaque_win32 {
+            result |= vk::EXTERNAL_FENCE_HANDLE_TYPE_OPAQUE_WIN32_BIT;
+        }
+        if self.opaque_win32_kmt {
+            result |= vk::EXTERNAL_FENCE_HANDLE_TYPE_OPAQUE_WIN32_KMT_BIT;
+        }
+        if self.sync_fd {
+            result |= vk::EXTERNAL_FENCE_HANDLE_TYPE_SYNC_FD_BIT;
+        }
+        result
+    }
+
+    // Returns the bit of the handle type, if it is a single file descriptor handle type.
+    pub(crate) fn fd_bit(&self) -> Result<vk::ExternalFenceHandleTypeFlagBits, ExternalSyncError> {
+        let bits = self.to_bits();
+        if bits != vk::EXTERNAL_FENCE_HANDLE_TYPE_OPAQUE_FD_BIT
+            && bits != vk::EXTERNAL_FENCE_HANDLE_TYPE_SYNC_FD_BIT
+        {
+            return Err(ExternalSyncError::InvalidHandleType);
+        }
+
+        Ok(bits)
+    }
+}
+
+impl BitOr for ExternalFenceHandleType {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        ExternalFenceHandleType {
+            opaque_fd: self.opaque_fd || rhs.opaque_fd,
+            opaque_win32: self.opaque_win32 || rhs.opaque_win32,
+            opaque_win32_kmt: self.opaque_win32_kmt || rhs.opaque_win32_kmt,
+            sync_fd: self.sync_fd || rhs.sync_fd,
+        }
+    }
+}
+
+/// Error that can happen when creating, exporting or importing an external semaphore or fence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExternalSyncError {
+    /// Not enough memory.
+    OomError(OomError),
+    /// Too many file descriptors are open.
+    TooManyObjects,
+    /// An extension is missing.
+    MissingExtension(&'static str),
+    /// The handle type must be exactly one of the file descriptor handle types.
+    InvalidHandleType,
+    /// The handle type was not requested when the semaphore or fence was created.
+    HandleTypeNotExportable,
+    /// The file descriptor is not a valid handle of the given handle type.
+    InvalidExternalHandle,
+    /// Flushing the future before exporting its payload failed.
+    FlushError(FlushError),
+    /// The payload of the future can no longer be exported, because its semaphore has already
+    /// been waited upon or its fence has already been cleaned up.
+    PayloadUnavailable,
+}
+
+impl error::Error for ExternalSyncError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            Self::OomError(ref err) => Some(err),
+            Self::FlushError(ref err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ExternalSyncError {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            Self::OomError(_) => write!(fmt, "not enough memory available"),
+            Self::TooManyObjects => write!(fmt, "too many file descriptors are open"),
+            Self::MissingExtension(ext) => {
+                write!(fmt, "the extension `{}` must be enabled", ext)
+            }
+            Self::InvalidHandleType => write!(
+                fmt,
+                "the handle type must be exactly one of the file descriptor handle types"
+            ),
+            Self::HandleTypeNotExportable => write!(
+                fmt,
+                "the handle type was not requested when the object was created"
+            ),
+            Self::InvalidExternalHandle => write!(
+                fmt,
+                "the file descriptor is not a valid handle of the given handle type"
+            ),
+            Self::FlushError(_) => write!(fmt, "flushing the future failed"),
+            Self::PayloadUnavailable => {
+                write!(fmt, "the payload of the future can no longer be exported")
+            }
+        }
+    }
+}
+
+impl From<OomError> for ExternalSyncError {
+    #[inline]
+    fn from(err: OomError) -> Self {
+        Self::OomError(err)
+    }
+}
+
+impl From<FlushError> for ExternalSyncError {
+    #[inline]
+    fn from(err: FlushError) -> Self {
+        Self::FlushError(err)
+    }
+}
+
+impl From<Error> for ExternalSyncError {
+    #[inline]
+    fn from(err: Error) -> Self {
+        match err {
+            err @ Error::OutOfHostMemory => Self::OomError(OomError::from(err)),
+            err @ Error::OutOfDeviceMemory => Self::OomError(OomError::from(err)),
+            Error::TooManyObjects => Self::TooManyObjects,
+            Error::InvalidExternalHandle => Self::InvalidExternalHandle,
+            _ => panic!("unexpected error: {:?}", err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sync::ExternalFenceHandleType;
+    use crate::sync::ExternalSemaphoreHandleType;
+    use crate::sync::ExternalSyncError;
+
+    #[test]
+    fn fd_bit_requires_single_fd_type() {
+        assert!(ExternalSemaphoreHandleType::posix().fd_bit().is_ok());
+        assert!(ExternalSemaphoreHandleType::sync_fd().fd_bit().is_ok());
+        assert_eq!(
+            (ExternalSemaphoreHandleType::posix() | ExternalSemaphoreHandleType::sync_fd())
+                .fd_bit(),
+            Err(ExternalSyncError::InvalidHandleType)
+        );
+
+        let win32 = ExternalFenceHandleType {
+            opaque_win32: true,
+            ..ExternalFenceHandleType::none()
+        };
+        assert!(ExternalFenceHandleType::sync_fd().fd_bit().is_ok());
+        assert_eq!(win32.fd_bit(), Err(ExternalSyncError::InvalidHandleType));
+        assert_eq!(
+            ExternalFenceHandleType::none().fd_bit(),
+            Err(ExternalSyncError::InvalidHandleType)
+        );
+    }
+}
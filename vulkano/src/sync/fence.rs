@@ -10,16 +10,28 @@
 use smallvec::SmallVec;
 use std::error;
 use std::fmt;
+#[cfg(unix)]
+use std::fs::File;
+use std::future::Future;
 use std::mem::MaybeUninit;
+#[cfg(unix)]
+use std::os::unix::io::{FromRawFd, IntoRawFd};
+use std::pin::Pin;
 use std::ptr;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+use std::task::Waker;
 use std::time::Duration;
 
 use crate::check_errors;
 use crate::device::Device;
 use crate::device::DeviceOwned;
+use crate::sync::fence_waiter;
+use crate::sync::ExternalFenceHandleType;
+use crate::sync::ExternalSyncError;
 use crate::vk;
 use crate::Error;
 use crate::OomError;
@@ -32,6 +44,10 @@ use crate::VulkanObject;
 /// When a command buffer accesses a resource, you have to ensure that the CPU doesn't access
 /// the same resource simultaneously (except for concurrent reads). Therefore in order to know
 /// when the CPU can access a resource again, a fence has to be used.
+///
+/// `Fence` implements `std::future::Future`, which resolves once the fence is signaled. While a
+/// fence is being awaited, it is waited upon by a background thread shared by all fences, which
+/// wakes up the task once the fence is signaled.
 #[derive(Debug)]
 pub struct Fence<D = Arc<Device>>
 where
@@ -49,6 +65,13 @@ where
     // Indicates whether this fence was taken from the fence pool.
     // If true, will be put back into fence pool on drop.
     must_put_in_pool: bool,
+
+    // True if the fence has been registered with the background fence waiter, in which case it
+    // must be unregistered before being destroyed.
+    registered: AtomicBool,
+
+    // Handle types the fence can be exported to.
+    export_handle_types: ExternalFenceHandleType,
 }
 
 impl<D> Fence<D>
@@ -75,11 +98,13 @@ where
                     device: device,
                     signaled: AtomicBool::new(false),
                     must_put_in_pool: true,
+                    registered: AtomicBool::new(false),
+                    export_handle_types: ExternalFenceHandleType::none(),
                 })
             }
             None => {
                 // Pool is empty, alloc new fence
-                Fence::alloc_impl(device, false, true)
+                Fence::alloc_impl(device, false, true, ExternalFenceHandleType::none())
             }
         }
     }
@@ -87,20 +112,165 @@ where
     /// Builds a new fence.
     #[inline]
     pub fn alloc(device: D) -> Result<Fence<D>, OomError> {
-        Fence::alloc_impl(device, false, false)
+        Fence::alloc_impl(device, false, false, ExternalFenceHandleType::none())
     }
 
     /// Builds a new fence in signaled state.
     #[inline]
     pub fn alloc_signaled(device: D) -> Result<Fence<D>, OomError> {
-        Fence::alloc_impl(device, true, false)
+        Fence::alloc_impl(device, true, false, ExternalFenceHandleType::none())
     }
 
-    fn alloc_impl(device: D, signaled: bool, must_put_in_pool: bool) -> Result<Fence<D>, OomError> {
+    /// Builds a new fence whose payload can be exported to the given handle types with
+    /// `export_fd`.
+    ///
+    /// The `khr_external_fence` extension must be enabled on the device.
+    pub fn alloc_exportable(
+        device: D,
+        handle_types: ExternalFenceHandleType,
+    ) -> Result<Fence<D>, ExternalSyncError> {
+        if !device.loaded_extensions().khr_external_fence {
+            return Err(ExternalSyncError::MissingExtension("khr_external_fence"));
+        }
+
+        Ok(Fence::alloc_impl(device, false, false, handle_types)?)
+    }
+
+    /// Builds a new fence and imports a payload into it from a file descriptor.
+    ///
+    /// Payloads of type `sync_fd` are imported temporarily, as required by the Vulkan
+    /// specification: the fence goes back to its original payload once it is reset. Other
+    /// payloads are imported permanently. The file descriptor is consumed on success, and closed
+    /// on failure.
+    ///
+    /// The `khr_external_fence_fd` extension must be enabled on the device.
+    ///
+    /// # Safety
+    ///
+    /// - `file` must be a payload of type `handle_type`, exported from a fence created on a
+    ///   device with the same device and driver UUIDs as `device`.
+    ///
+    #[cfg(unix)]
+    pub unsafe fn from_fd(
+        device: D,
+        handle_type: ExternalFenceHandleType,
+        file: File,
+    ) -> Result<Fence<D>, ExternalSyncError> {
+        if !device.loaded_extensions().khr_external_fence_fd {
+            return Err(ExternalSyncError::MissingExtension("khr_external_fence_fd"));
+        }
+
+        let bits = handle_type.fd_bit()?;
+        let fence = Fence::alloc_impl(device, false, false, ExternalFenceHandleType::none())?;
+        fence.import_fd(bits, file)?;
+        Ok(fence)
+    }
+
+    /// Exports the payload of the fence to a file descriptor.
+    ///
+    /// `handle_type` must be one of the handle types passed to `alloc_exportable`. Exporting a
+    /// `sync_fd` payload has the same effect on the fence as resetting it.
+    ///
+    /// The `khr_external_fence_fd` extension must be enabled on the device.
+    ///
+    /// # Safety
+    ///
+    /// - If `handle_type` is `sync_fd`, the fence must be signaled or have a pending signal
+    ///   operation.
+    ///
+    #[cfg(unix)]
+    pub unsafe fn export_fd(
+        &self,
+        handle_type: ExternalFenceHandleType,
+    ) -> Result<File, ExternalSyncError> {
+        if !self.device.loaded_extensions().khr_external_fence_fd {
+            return Err(ExternalSyncError::MissingExtension("khr_external_fence_fd"));
+        }
+
+        let bits = handle_type.fd_bit()?;
+        if bits & self.export_handle_types.to_bits() == 0 {
+            return Err(ExternalSyncError::HandleTypeNotExportable);
+        }
+
+        let info = vk::FenceGetFdInfoKHR {
+            sType: vk::STRUCTURE_TYPE_FENCE_GET_FD_INFO_KHR,
+            pNext: ptr::null(),
+            fence: self.fence,
+            handleType: bits,
+        };
+
+        let vk = self.device.pointers();
+        let mut output = MaybeUninit::uninit();
+        check_errors(vk.GetFenceFdKHR(self.device.internal_object(), &info, output.as_mut_ptr()))?;
+
+        if bits == vk::EXTERNAL_FENCE_HANDLE_TYPE_SYNC_FD_BIT {
+            self.signaled.store(false, Ordering::Relaxed);
+        }
+
+        Ok(File::from_raw_fd(output.assume_init()))
+    }
+
+    /// Returns the handle types the fence can be exported to.
+    #[inline]
+    pub fn export_handle_types(&self) -> ExternalFenceHandleType {
+        self.export_handle_types
+    }
+
+    // Imports a payload into the fence. `sync_fd` payloads are imported temporarily.
+    #[cfg(unix)]
+    pub(crate) unsafe fn import_fd(
+        &self,
+        bits: vk::ExternalFenceHandleTypeFlagBits,
+        file: File,
+    ) -> Result<(), ExternalSyncError> {
+        let fd = file.into_raw_fd();
+        let info = vk::ImportFenceFdInfoKHR {
+            sType: vk::STRUCTURE_TYPE_IMPORT_FENCE_FD_INFO_KHR,
+            pNext: ptr::null(),
+            fence: self.fence,
+            flags: if bits == vk::EXTERNAL_FENCE_HANDLE_TYPE_SYNC_FD_BIT {
+                vk::FENCE_IMPORT_TEMPORARY_BIT
+            } else {
+                0
+            },
+            handleType: bits,
+            fd,
+        };
+
+        let vk = self.device.pointers();
+        match check_errors(vk.ImportFenceFdKHR(self.device.internal_object(), &info)) {
+            Ok(_) => {
+                self.signaled.store(false, Ordering::Relaxed);
+                Ok(())
+            }
+            Err(err) => {
+                // The implementation only takes ownership of the file descriptor on success.
+                drop(File::from_raw_fd(fd));
+                Err(err.into())
+            }
+        }
+    }
+
+    fn alloc_impl(
+        device: D,
+        signaled: bool,
+        must_put_in_pool: bool,
+        export_handle_types: ExternalFenceHandleType,
+    ) -> Result<Fence<D>, OomError> {
         let fence = unsafe {
+            let export_info = vk::ExportFenceCreateInfo {
+                sType: vk::STRUCTURE_TYPE_EXPORT_FENCE_CREATE_INFO,
+                pNext: ptr::null(),
+                handleTypes: export_handle_types.to_bits(),
+            };
+
             let infos = vk::FenceCreateInfo {
                 sType: vk::STRUCTURE_TYPE_FENCE_CREATE_INFO,
-                pNext: ptr::null(),
+                pNext: if export_handle_types.to_bits() != 0 {
+                    &export_info as *const _ as *const _
+                } else {
+                    ptr::null()
+                },
                 flags: if signaled {
                     vk::FENCE_CREATE_SIGNALED_BIT
                 } else {
@@ -124,6 +294,8 @@ where
             device: device,
             signaled: AtomicBool::new(signaled),
             must_put_in_pool: must_put_in_pool,
+            registered: AtomicBool::new(false),
+            export_handle_types,
         })
     }
 
@@ -259,6 +431,11 @@ where
     #[inline]
     pub fn reset(&mut self) -> Result<(), OomError> {
         unsafe {
+            // The background fence waiter must not be waiting on the fence while it is reset.
+            if self.registered.swap(false, Ordering::Relaxed) {
+                fence_waiter::unregister(self.fence);
+            }
+
             let vk = self.device.pointers();
             check_errors(vk.ResetFences(self.device.internal_object(), 1, &self.fence))?;
             self.signaled.store(false, Ordering::Relaxed);
@@ -292,6 +469,10 @@ where
                     ),
                 };
 
+                if fence.registered.swap(false, Ordering::Relaxed) {
+                    fence_waiter::unregister(fence.fence);
+                }
+
                 fence.signaled.store(false, Ordering::Relaxed);
                 fence.fence
             })
@@ -318,6 +499,30 @@ unsafe impl DeviceOwned for Fence {
     }
 }
 
+impl Fence {
+    // Registers `waker` with the background fence waiter, to be woken up once the fence is
+    // signaled.
+    pub(crate) fn register_waker(&self, waker: &Waker) {
+        self.registered.store(true, Ordering::Relaxed);
+        fence_waiter::register(&self.device, self.fence, waker);
+    }
+}
+
+impl Future for Fence {
+    type Output = Result<(), FenceWaitError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        match self.ready() {
+            Ok(true) => Poll::Ready(Ok(())),
+            Ok(false) => {
+                self.register_waker(cx.waker());
+                Poll::Pending
+            }
+            Err(err) => Poll::Ready(Err(FenceWaitError::OomError(err))),
+        }
+    }
+}
+
 unsafe impl<D> VulkanObject for Fence<D>
 where
     D: SafeDeref<Target = Device>,
@@ -339,6 +544,10 @@ where
     #[inline]
     fn drop(&mut self) {
         unsafe {
+            if self.registered.load(Ordering::Relaxed) {
+                fence_waiter::unregister(self.fence);
+            }
+
             if self.must_put_in_pool {
                 let raw_fence = self.fence;
                 self.device.fence_pool().lock().unwrap().push(raw_fence);
@@ -402,10 +611,26 @@ impl From<Error> for FenceWaitError {
 
 #[cfg(test)]
 mod tests {
+    use crate::sync::ExternalFenceHandleType;
+    use crate::sync::ExternalSyncError;
     use crate::sync::Fence;
     use crate::VulkanObject;
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::atomic::Ordering;
+    use std::sync::Arc;
+    use std::task::Context;
+    use std::task::Poll;
+    use std::task::Wake;
+    use std::task::Waker;
     use std::time::Duration;
 
+    struct NoopWaker;
+
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
     #[test]
     fn fence_create() {
         let (device, _) = gfx_dev_and_queue!();
@@ -430,6 +655,45 @@ mod tests {
         fence.wait(Some(Duration::new(0, 10))).unwrap();
     }
 
+    #[test]
+    fn fence_future_signaled() {
+        let (device, _) = gfx_dev_and_queue!();
+
+        let mut fence = Fence::alloc_signaled(device.clone()).unwrap();
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(&waker);
+        assert!(matches!(
+            Pin::new(&mut fence).poll(&mut cx),
+            Poll::Ready(Ok(()))
+        ));
+    }
+
+    #[test]
+    fn fence_future_pending() {
+        let (device, _) = gfx_dev_and_queue!();
+
+        let mut fence = Fence::from_pool(device.clone()).unwrap();
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(&waker);
+        assert!(Pin::new(&mut fence).poll(&mut cx).is_pending());
+        assert!(Pin::new(&mut fence).poll(&mut cx).is_pending());
+
+        // Dropping the fence while it is registered must return it to the pool safely.
+        drop(fence);
+        let fence = Fence::from_pool(device.clone()).unwrap();
+        assert!(!fence.ready().unwrap());
+    }
+
+    #[test]
+    fn exportable_missing_extension() {
+        let (device, _) = gfx_dev_and_queue!();
+
+        match Fence::alloc_exportable(device, ExternalFenceHandleType::sync_fd()) {
+            Err(ExternalSyncError::MissingExtension("khr_external_fence")) => (),
+            _ => panic!(),
+        }
+    }
+
     #[test]
     fn fence_reset() {
         let (device, _) = gfx_dev_and_queue!();
@@ -439,6 +703,25 @@ mod tests {
         assert!(!fence.ready().unwrap());
     }
 
+    #[test]
+    fn fence_reset_while_pending() {
+        let (device, _) = gfx_dev_and_queue!();
+
+        let mut fence = Fence::from_pool(device.clone()).unwrap();
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(&waker);
+        assert!(Pin::new(&mut fence).poll(&mut cx).is_pending());
+
+        // Resetting the fence must first remove it from the background fence waiter.
+        fence.reset().unwrap();
+        assert!(!fence.registered.load(Ordering::Relaxed));
+        assert!(!fence.ready().unwrap());
+
+        // The fence can be waited upon again after being reset.
+        assert!(Pin::new(&mut fence).poll(&mut cx).is_pending());
+        assert!(fence.registered.load(Ordering::Relaxed));
+    }
+
     #[test]
     fn multiwait_different_devices() {
         let (device1, _) = gfx_dev_and_queue!();
@@ -0,0 +1,249 @@
+// Copyright (c) 2021 The vulkano developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>,
+// at your option. All files in the project carrying such
+// notice may not be copied, modified, or distributed except
+// according to those terms.
+
+//! Background thread that waits on fences on behalf of `std::future::Future` implementations.
+//!
+//! The thread is started the first time a fence is registered, and stays blocked on a condition
+//! variable while no fence is pending. When fences are pending, it calls `vkWaitForFences` on all
+//! of them with a short timeout, so that fences registered in the meantime are picked up.
+//!
+//! The raw fences are only used by the waiter thread while it holds `wait_lock`. A fence that
+//! is reset or destroyed while registered must therefore call `unregister` first, which blocks
+//! until the current wait is over.
+
+use crate::check_errors;
+use crate::device::Device;
+use crate::vk;
+use crate::Success;
+use crate::VulkanObject;
+use lazy_static::lazy_static;
+use smallvec::SmallVec;
+use std::any::Any;
+use std::mem;
+use std::panic;
+use std::panic::AssertUnwindSafe;
+use std::sync::Arc;
+use std::sync::Condvar;
+use std::sync::Mutex;
+use std::task::Waker;
+use std::thread;
+use std::time::Duration;
+
+// Maximum duration of a single wait. This is the maximum delay before a newly-registered fence
+// is taken into account.
+const WAIT_INTERVAL: Duration = Duration::from_millis(5);
+
+lazy_static! {
+    static ref WAITER: FenceWaiter = FenceWaiter {
+        entries: Mutex::new(Entries {
+            list: Vec::new(),
+            thread_started: false,
+        }),
+        condvar: Condvar::new(),
+        wait_lock: Mutex::new(()),
+    };
+}
+
+struct FenceWaiter {
+    entries: Mutex<Entries>,
+    condvar: Condvar,
+    // Held by the waiter thread while it uses the raw fences of a snapshot of `entries`.
+    wait_lock: Mutex<()>,
+}
+
+struct Entries {
+    list: Vec<Entry>,
+    thread_started: bool,
+}
+
+struct Entry {
+    device: Arc<Device>,
+    fence: vk::Fence,
+    action: Action,
+}
+
+// Fences of a single device, which can be waited upon with a single `vkWaitForFences` call.
+type FenceGroup = (Arc<Device>, SmallVec<[vk::Fence; 8]>);
+
+enum Action {
+    // Wake up a task that is waiting for the fence.
+    Wake(Waker),
+    // Drop an object that owns the fence, once it is signaled.
+    Release(Box<dyn Any + Send>),
+}
+
+/// Registers `waker` to be woken up once `fence` is signaled.
+///
+/// If the fence is already registered with a waker, the previous waker is replaced.
+pub(crate) fn register(device: &Arc<Device>, fence: vk::Fence, waker: &Waker) {
+    let mut entries = WAITER.entries.lock().unwrap();
+
+    for entry in entries.list.iter_mut() {
+        if entry.fence == fence {
+            if let Action::Wake(ref mut w) = entry.action {
+                if !w.will_wake(waker) {
+                    *w = waker.clone();
+                }
+                return;
+            }
+        }
+    }
+
+    push(
+        &mut entries,
+        Entry {
+            device: device.clone(),
+            fence,
+            action: Action::Wake(waker.clone()),
+        },
+    );
+}
+
+/// Keeps `owner` alive until `fence` is signaled, then drops it from the waiter thread.
+///
+/// `owner` must keep the fence alive until it is dropped.
+pub(crate) fn release_when_signaled(
+    device: &Arc<Device>,
+    fence: vk::Fence,
+    owner: Box<dyn Any + Send>,
+) {
+    let mut entries = WAITER.entries.lock().unwrap();
+    push(
+        &mut entries,
+        Entry {
+            device: device.clone(),
+            fence,
+            action: Action::Release(owner),
+        },
+    );
+}
+
+/// Removes the wakers registered for `fence`. Must be called before the fence is reset,
+/// destroyed or returned to the fence pool.
+///
+/// Blocks until the waiter thread has stopped using the fence.
+pub(crate) fn unregister(fence: vk::Fence) {
+    let removed = {
+        let mut entries = WAITER.entries.lock().unwrap();
+        let len = entries.list.len();
+        entries.list.retain(|entry| entry.fence != fence);
+        entries.list.len() != len
+    };
+
+    // If no entry was removed, then the waiter thread has already finished using the fence.
+    if removed {
+        drop(WAITER.wait_lock.lock().unwrap());
+    }
+}
+
+fn push(entries: &mut Entries, entry: Entry) {
+    entries.list.push(entry);
+
+    if !entries.thread_started {
+        thread::Builder::new()
+            .name("vulkano fence waiter".to_owned())
+            .spawn(run)
+            .expect("failed to spawn the fence waiter thread");
+        entries.thread_started = true;
+    }
+
+    WAITER.condvar.notify_one();
+}
+
+fn run() {
+    loop {
+        let (wait_guard, groups) = {
+            let mut entries = WAITER.entries.lock().unwrap();
+            while entries.list.is_empty() {
+                entries = WAITER.condvar.wait(entries).unwrap();
+            }
+
+            // Group the fences by device, since `vkWaitForFences` only accepts fences from a
+            // single device.
+            let mut groups: SmallVec<[FenceGroup; 1]> = SmallVec::new();
+            for entry in entries.list.iter() {
+                match groups
+                    .iter_mut()
+                    .find(|(device, _)| Arc::ptr_eq(device, &entry.device))
+                {
+                    Some((_, fences)) => fences.push(entry.fence),
+                    None => {
+                        groups.push((entry.device.clone(), SmallVec::from_elem(entry.fence, 1)))
+                    }
+                }
+            }
+
+            (WAITER.wait_lock.lock().unwrap(), groups)
+        };
+
+        let timeout = WAIT_INTERVAL / groups.len() as u32;
+        let mut finished: SmallVec<[vk::Fence; 8]> = SmallVec::new();
+
+        for (device, fences) in groups.iter() {
+            unsafe {
+                if wait_any(device, fences, timeout) {
+                    finished.extend(fences.iter().cloned().filter(|&f| is_signaled(device, f)));
+                }
+            }
+        }
+
+        drop(wait_guard);
+
+        if finished.is_empty() {
+            continue;
+        }
+
+        let done: Vec<Entry> = {
+            let mut entries = WAITER.entries.lock().unwrap();
+            let (done, pending) = mem::take(&mut entries.list)
+                .into_iter()
+                .partition(|entry| finished.contains(&entry.fence));
+            entries.list = pending;
+            done
+        };
+
+        // Wakers and owners are processed without holding any lock, as they can run arbitrary
+        // code, including registering or unregistering other fences.
+        for entry in done {
+            match entry.action {
+                Action::Wake(waker) => waker.wake(),
+                Action::Release(owner) => {
+                    // A panic while releasing resources must not stop the thread, as other
+                    // fences depend on it.
+                    let _ = panic::catch_unwind(AssertUnwindSafe(move || drop(owner)));
+                }
+            }
+        }
+    }
+}
+
+// Waits until at least one of the fences is signaled. Returns false if the timeout has elapsed
+// first.
+unsafe fn wait_any(device: &Device, fences: &[vk::Fence], timeout: Duration) -> bool {
+    let vk = device.pointers();
+    let result = check_errors(vk.WaitForFences(
+        device.internal_object(),
+        fences.len() as u32,
+        fences.as_ptr(),
+        vk::FALSE,
+        timeout.as_nanos() as u64,
+    ));
+
+    !matches!(result, Ok(Success::Timeout))
+}
+
+// Returns true if the fence is signaled. Errors, such as a device loss, also count as signaled,
+// and are reported to the owner of the fence when it checks the fence itself.
+unsafe fn is_signaled(device: &Device, fence: vk::Fence) -> bool {
+    let vk = device.pointers();
+    !matches!(
+        check_errors(vk.GetFenceStatus(device.internal_object(), fence)),
+        Ok(Success::NotReady)
+    )
+}
@@ -7,10 +7,16 @@
 // notice may not be copied, modified, or distributed except
 // according to those terms.
 
+#[cfg(unix)]
+use std::fs::File;
+use std::future::Future;
 use std::mem;
+use std::pin::Pin;
 use std::sync::Arc;
 use std::sync::Mutex;
 use std::sync::MutexGuard;
+use std::task::Context;
+use std::task::Poll;
 use std::time::Duration;
 
 use crate::buffer::BufferAccess;
@@ -21,24 +27,44 @@ use crate::device::DeviceOwned;
 use crate::device::Queue;
 use crate::image::ImageAccess;
 use crate::image::ImageLayout;
+use crate::sync::fence_waiter;
 use crate::sync::AccessCheckError;
 use crate::sync::AccessFlagBits;
+#[cfg(unix)]
+use crate::sync::ExternalFenceHandleType;
+#[cfg(unix)]
+use crate::sync::ExternalSyncError;
 use crate::sync::Fence;
 use crate::sync::FlushError;
 use crate::sync::GpuFuture;
 use crate::sync::PipelineStages;
+use crate::VulkanObject;
 
 /// Builds a new fence signal future.
 #[inline]
 pub fn then_signal_fence<F>(future: F, behavior: FenceSignalFutureBehavior) -> FenceSignalFuture<F>
+where
+    F: GpuFuture,
+{
+    let fence = Fence::from_pool(future.device().clone()).unwrap();
+    then_signal_fence_with(future, fence, behavior)
+}
+
+/// Builds a new fence signal future that signals `fence`.
+#[inline]
+pub fn then_signal_fence_with<F>(
+    future: F,
+    fence: Fence,
+    behavior: FenceSignalFutureBehavior,
+) -> FenceSignalFuture<F>
 where
     F: GpuFuture,
 {
     let device = future.device().clone();
 
     assert!(future.queue().is_some()); // TODO: document
+    assert_eq!(fence.device().internal_object(), device.internal_object());
 
-    let fence = Fence::from_pool(device.clone()).unwrap();
     FenceSignalFuture {
         device: device,
         state: Mutex::new(FenceSignalFutureState::Pending(future, fence)),
@@ -88,6 +114,15 @@ pub enum FenceSignalFutureBehavior {
 /// // Later you can wait until you reach the point of `fence_signal`:
 /// fence_signal.wait(None).unwrap();
 /// ```
+///
+/// `FenceSignalFuture` also implements `std::future::Future`, which flushes the submission if
+/// necessary and resolves once the fence is signaled, after having released the resources of the
+/// previous submissions. This allows asynchronous code to wait for the GPU without blocking a
+/// thread:
+///
+/// ```ignore
+/// future.then_signal_fence_and_flush()?.await?;
+/// ```
 #[must_use = "Dropping this object will immediately block the thread until the GPU has finished \
               processing the submission"]
 pub struct FenceSignalFuture<F>
@@ -156,6 +191,74 @@ where
     }
 }
 
+impl<F> FenceSignalFuture<F>
+where
+    F: GpuFuture + Send + 'static,
+{
+    /// Flushes the submission if necessary, then hands the future over to a background thread
+    /// which drops it once the fence is signaled.
+    ///
+    /// This releases the resources locked by the submission as soon as the GPU has finished,
+    /// without having to keep the future around and call `cleanup_finished` on it.
+    pub fn detach(self) -> Result<(), FlushError> {
+        let fence = {
+            let mut state = self.state.lock().unwrap();
+            self.flush_impl(&mut state)?;
+
+            match *state {
+                FenceSignalFutureState::Flushed(_, ref fence) => fence.internal_object(),
+                FenceSignalFutureState::Cleaned => return Ok(()),
+                _ => unreachable!(),
+            }
+        };
+
+        let device = self.device.clone();
+        fence_waiter::release_when_signaled(&device, fence, Box::new(self));
+        Ok(())
+    }
+}
+
+impl<F> FenceSignalFuture<F>
+where
+    F: GpuFuture,
+{
+    /// Flushes the submission if necessary, then exports the payload of the fence to a file
+    /// descriptor, so that another API or process can wait for this future.
+    ///
+    /// The fence must have been created with `handle_type` as an export handle type, which is the
+    /// case when the future was created with `then_signal_exportable_fence`. Exporting a
+    /// `sync_fd` payload does not prevent this future from being waited upon.
+    ///
+    /// Returns an error if the future has already been cleaned up.
+    #[cfg(unix)]
+    pub fn export_fd(
+        &self,
+        handle_type: ExternalFenceHandleType,
+    ) -> Result<File, ExternalSyncError> {
+        let mut state = self.state.lock().unwrap();
+        self.flush_impl(&mut state)?;
+
+        match *state {
+            FenceSignalFutureState::Flushed(_, ref fence) => unsafe {
+                let file = fence.export_fd(handle_type)?;
+
+                // Exporting a sync file resets the fence. Importing a copy of it back restores
+                // the payload, so that the fence can still be waited upon.
+                if handle_type.sync_fd {
+                    let copy = file
+                        .try_clone()
+                        .map_err(|_| ExternalSyncError::TooManyObjects)?;
+                    fence.import_fd(handle_type.fd_bit()?, copy)?;
+                }
+
+                Ok(file)
+            },
+            FenceSignalFutureState::Cleaned => Err(ExternalSyncError::PayloadUnavailable),
+            _ => unreachable!(),
+        }
+    }
+}
+
 impl<F> FenceSignalFuture<F>
 where
     F: GpuFuture,
@@ -192,6 +295,13 @@ where
         *state = FenceSignalFutureState::Cleaned;
     }
 
+    // Calls `cleanup_finished`, then returns true if the fence has been signaled and the
+    // resources of the previous submissions have been released. Never blocks.
+    pub(crate) fn cleanup_and_check_finished(&self) -> bool {
+        self.cleanup_finished_impl();
+        matches!(*self.state.lock().unwrap(), FenceSignalFutureState::Cleaned)
+    }
+
     // Implementation of `flush`. You must lock the state and pass the mutex guard here.
     fn flush_impl(
         &self,
@@ -296,6 +406,43 @@ where
     }
 }
 
+impl<F> Future for FenceSignalFuture<F>
+where
+    F: GpuFuture,
+{
+    type Output = Result<(), FlushError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let mut state = self.state.lock().unwrap();
+
+        if let Err(err) = self.flush_impl(&mut state) {
+            return Poll::Ready(Err(err));
+        }
+
+        match *state {
+            FenceSignalFutureState::Flushed(_, ref fence) => match fence.ready() {
+                Ok(true) => (),
+                Ok(false) => {
+                    fence.register_waker(cx.waker());
+                    return Poll::Pending;
+                }
+                Err(err) => return Poll::Ready(Err(FlushError::OomError(err))),
+            },
+            FenceSignalFutureState::Cleaned => return Poll::Ready(Ok(())),
+            _ => unreachable!(),
+        }
+
+        match mem::replace(&mut *state, FenceSignalFutureState::Cleaned) {
+            FenceSignalFutureState::Flushed(previous, _) => unsafe {
+                previous.signal_finished();
+            },
+            _ => unreachable!(),
+        }
+
+        Poll::Ready(Ok(()))
+    }
+}
+
 impl<F> FenceSignalFutureState<F> {
     #[inline]
     fn get_prev(&self) -> Option<&F> {
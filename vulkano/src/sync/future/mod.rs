@@ -11,6 +11,7 @@ pub use self::fence_signal::{FenceSignalFuture, FenceSignalFutureBehavior};
 pub use self::join::JoinFuture;
 pub use self::now::{now, NowFuture};
 pub use self::semaphore_signal::SemaphoreSignalFuture;
+pub use self::semaphore_wait::{semaphore_wait, SemaphoreWaitFuture};
 use crate::buffer::BufferAccess;
 use crate::command_buffer::submit::SubmitAnyBuilder;
 use crate::command_buffer::submit::SubmitBindSparseError;
@@ -23,13 +24,21 @@ use crate::device::DeviceOwned;
 use crate::device::Queue;
 use crate::image::ImageAccess;
 use crate::image::ImageLayout;
+use crate::memory;
+use crate::memory::SparseBindFuture;
+use crate::memory::SparseBinds;
 use crate::swapchain;
 use crate::swapchain::PresentFuture;
 use crate::swapchain::PresentRegion;
 use crate::swapchain::Swapchain;
 use crate::sync::AccessFlagBits;
+use crate::sync::ExternalFenceHandleType;
+use crate::sync::ExternalSemaphoreHandleType;
+use crate::sync::ExternalSyncError;
+use crate::sync::Fence;
 use crate::sync::FenceWaitError;
 use crate::sync::PipelineStages;
+use crate::sync::Semaphore;
 use crate::OomError;
 use std::error;
 use std::fmt;
@@ -39,6 +48,7 @@ mod fence_signal;
 mod join;
 mod now;
 mod semaphore_signal;
+mod semaphore_wait;
 
 /// Represents an event that will happen on the GPU in the future.
 ///
@@ -198,6 +208,26 @@ pub unsafe trait GpuFuture: DeviceOwned {
         semaphore_signal::then_signal_semaphore(self)
     }
 
+    /// Signals a semaphore whose payload can be exported to other APIs or processes after this
+    /// future. Returns another future that represents the signal.
+    ///
+    /// Call `export_fd` on the returned future to obtain a file descriptor that the other API or
+    /// process can wait upon. The `khr_external_semaphore` extension must be enabled on the
+    /// device.
+    #[inline]
+    fn then_signal_exportable_semaphore(
+        self,
+        handle_types: ExternalSemaphoreHandleType,
+    ) -> Result<SemaphoreSignalFuture<Self>, ExternalSyncError>
+    where
+        Self: Sized,
+    {
+        let semaphore = Semaphore::alloc_exportable(self.device().clone(), handle_types)?;
+        Ok(semaphore_signal::then_signal_semaphore_with(
+            self, semaphore,
+        ))
+    }
+
     /// Signals a semaphore after this future and flushes it. Returns another future that
     /// represents the moment when the semaphore is signalled.
     ///
@@ -246,6 +276,48 @@ pub unsafe trait GpuFuture: DeviceOwned {
         Ok(f)
     }
 
+    /// Signals a fence whose payload can be exported to other APIs or processes after this
+    /// future. Returns another future that represents the signal.
+    ///
+    /// Call `export_fd` on the returned future to obtain a file descriptor that the other API or
+    /// process can wait upon. The `khr_external_fence` extension must be enabled on the device.
+    #[inline]
+    fn then_signal_exportable_fence(
+        self,
+        handle_types: ExternalFenceHandleType,
+    ) -> Result<FenceSignalFuture<Self>, ExternalSyncError>
+    where
+        Self: Sized,
+    {
+        let fence = Fence::alloc_exportable(self.device().clone(), handle_types)?;
+        Ok(fence_signal::then_signal_fence_with(
+            self,
+            fence,
+            FenceSignalFutureBehavior::Continue,
+        ))
+    }
+
+    /// Binds or unbinds sparse memory after this future, on the given queue. Returns another
+    /// future that represents the operations.
+    ///
+    /// The operations are not ordered with the submissions that come after them. Call
+    /// `then_signal_semaphore()` or `then_signal_fence()` on the returned future before using
+    /// the affected resources.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if the queue doesn't support sparse binding.
+    /// - Panics if the future, the queue and `binds` don't belong to the same device.
+    /// - Panics if this future doesn't allow changing queues and `queue` is a different queue.
+    ///
+    #[inline]
+    fn then_bind_sparse(self, queue: Arc<Queue>, binds: SparseBinds) -> SparseBindFuture<Self>
+    where
+        Self: Sized,
+    {
+        memory::then_bind_sparse(self, queue, binds)
+    }
+
     /// Presents a swapchain image after this future.
     ///
     /// You should only ever do this indirectly after a `SwapchainAcquireFuture` of the same image,
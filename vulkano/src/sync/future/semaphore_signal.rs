@@ -7,6 +7,8 @@
 // notice may not be copied, modified, or distributed except
 // according to those terms.
 
+#[cfg(unix)]
+use std::fs::File;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
@@ -23,6 +25,10 @@ use crate::image::ImageAccess;
 use crate::image::ImageLayout;
 use crate::sync::AccessCheckError;
 use crate::sync::AccessFlagBits;
+#[cfg(unix)]
+use crate::sync::ExternalSemaphoreHandleType;
+#[cfg(unix)]
+use crate::sync::ExternalSyncError;
 use crate::sync::FlushError;
 use crate::sync::GpuFuture;
 use crate::sync::PipelineStages;
@@ -35,13 +41,22 @@ where
     F: GpuFuture,
 {
     let device = future.device().clone();
+    then_signal_semaphore_with(future, Semaphore::from_pool(device).unwrap())
+}
 
+/// Builds a new semaphore signal future that signals `semaphore`.
+#[inline]
+pub fn then_signal_semaphore_with<F>(future: F, semaphore: Semaphore) -> SemaphoreSignalFuture<F>
+where
+    F: GpuFuture,
+{
     assert!(future.queue().is_some()); // TODO: document
 
     SemaphoreSignalFuture {
         previous: future,
-        semaphore: Semaphore::from_pool(device).unwrap(),
+        semaphore,
         wait_submitted: Mutex::new(false),
+        wait_built: AtomicBool::new(false),
         finished: AtomicBool::new(false),
     }
 }
@@ -59,9 +74,59 @@ where
     // If flush is called multiple times, we want to block so that only one flushing is executed.
     // Therefore we use a `Mutex<bool>` and not an `AtomicBool`.
     wait_submitted: Mutex<bool>,
+    // True if a submission waiting on the semaphore has been built.
+    wait_built: AtomicBool,
     finished: AtomicBool,
 }
 
+impl<F> SemaphoreSignalFuture<F>
+where
+    F: GpuFuture,
+{
+    /// Returns the semaphore that is signaled.
+    #[inline]
+    pub fn semaphore(&self) -> &Semaphore {
+        &self.semaphore
+    }
+
+    /// Flushes the signal operation if necessary, then exports the payload of the semaphore to a
+    /// file descriptor, so that another API or process can wait for this future.
+    ///
+    /// The semaphore must have been created with `handle_type` as an export handle type, which
+    /// is the case when the future was created with `then_signal_exportable_semaphore`. Exporting
+    /// a `sync_fd` payload does not prevent operations chained after this future from waiting
+    /// on the semaphore.
+    ///
+    /// Returns an error if a submission that waits on the semaphore has already been built.
+    #[cfg(unix)]
+    pub fn export_fd(
+        &self,
+        handle_type: ExternalSemaphoreHandleType,
+    ) -> Result<File, ExternalSyncError> {
+        if self.wait_built.load(Ordering::SeqCst) {
+            return Err(ExternalSyncError::PayloadUnavailable);
+        }
+
+        self.flush()?;
+
+        unsafe {
+            let file = self.semaphore.export_fd(handle_type)?;
+
+            // Exporting a sync file unsignals the semaphore, as if it had been waited upon.
+            // Importing a copy of it back restores the payload for the operations that come after
+            // this future.
+            if handle_type.sync_fd {
+                let copy = file
+                    .try_clone()
+                    .map_err(|_| ExternalSyncError::TooManyObjects)?;
+                self.semaphore.import_fd(handle_type.fd_bit()?, copy)?;
+            }
+
+            Ok(file)
+        }
+    }
+}
+
 unsafe impl<F> GpuFuture for SemaphoreSignalFuture<F>
 where
     F: GpuFuture,
@@ -72,10 +137,11 @@ where
     }
 
     #[inline]
-    unsafe fn build_submission(&self) -> Result<SubmitAnyBuilder, FlushError> {
+    unsafe fn build_submission(&self) -> Result<SubmitAnyBuilder<'_>, FlushError> {
         // Flushing the signaling part, since it must always be submitted before the waiting part.
         self.flush()?;
 
+        self.wait_built.store(true, Ordering::SeqCst);
         let mut sem = SubmitSemaphoresWaitBuilder::new();
         sem.add_wait_semaphore(&self.semaphore);
         Ok(SubmitAnyBuilder::SemaphoresWait(sem))
@@ -107,11 +173,9 @@ where
                     builder.add_signal_semaphore(&self.semaphore);
                     builder.submit(&queue)?;
                 }
-                SubmitAnyBuilder::BindSparse(_) => {
-                    unimplemented!() // TODO: how to do that?
-                                     /*debug_assert_eq!(builder.num_signal_semaphores(), 0);
-                                     builder.add_signal_semaphore(&self.semaphore);
-                                     builder.submit(&queue)?;*/
+                SubmitAnyBuilder::BindSparse(mut builder) => {
+                    builder.add_signal_semaphore(&self.semaphore);
+                    builder.submit(&queue)?;
                 }
                 SubmitAnyBuilder::QueuePresent(present) => {
                     present.submit(&queue)?;
@@ -0,0 +1,116 @@
+// Copyright (c) 2021 The vulkano developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>,
+// at your option. All files in the project carrying such
+// notice may not be copied, modified, or distributed except
+// according to those terms.
+
+use std::sync::Arc;
+
+use crate::buffer::BufferAccess;
+use crate::command_buffer::submit::SubmitAnyBuilder;
+use crate::command_buffer::submit::SubmitSemaphoresWaitBuilder;
+use crate::device::Device;
+use crate::device::DeviceOwned;
+use crate::device::Queue;
+use crate::image::ImageAccess;
+use crate::image::ImageLayout;
+use crate::sync::AccessCheckError;
+use crate::sync::AccessFlagBits;
+use crate::sync::FlushError;
+use crate::sync::GpuFuture;
+use crate::sync::PipelineStages;
+use crate::sync::Semaphore;
+
+/// Builds a future that represents the moment when `semaphore` is signaled.
+///
+/// This is typically used with a semaphore imported with `Semaphore::from_fd`, in order to wait
+/// for work done by another API or process before executing operations on the GPU:
+///
+/// ```ignore
+/// let semaphore = unsafe {
+///     Semaphore::from_fd(device.clone(), ExternalSemaphoreHandleType::sync_fd(), file)?
+/// };
+/// let future = sync::semaphore_wait(semaphore)
+///     .then_execute(queue.clone(), command_buffer)?;
+/// ```
+///
+/// The semaphore is waited upon by the first submission that comes after this future. The
+/// semaphore is not waited upon if the returned future is flushed on its own.
+#[inline]
+pub fn semaphore_wait(semaphore: Semaphore) -> SemaphoreWaitFuture {
+    SemaphoreWaitFuture { semaphore }
+}
+
+/// Represents a semaphore being signaled by something outside of the control of vulkano.
+pub struct SemaphoreWaitFuture {
+    semaphore: Semaphore,
+}
+
+impl SemaphoreWaitFuture {
+    /// Returns the semaphore that is waited upon.
+    #[inline]
+    pub fn semaphore(&self) -> &Semaphore {
+        &self.semaphore
+    }
+}
+
+unsafe impl GpuFuture for SemaphoreWaitFuture {
+    #[inline]
+    fn cleanup_finished(&mut self) {}
+
+    #[inline]
+    unsafe fn build_submission(&self) -> Result<SubmitAnyBuilder<'_>, FlushError> {
+        let mut sem = SubmitSemaphoresWaitBuilder::new();
+        sem.add_wait_semaphore(&self.semaphore);
+        Ok(SubmitAnyBuilder::SemaphoresWait(sem))
+    }
+
+    #[inline]
+    fn flush(&self) -> Result<(), FlushError> {
+        Ok(())
+    }
+
+    #[inline]
+    unsafe fn signal_finished(&self) {}
+
+    #[inline]
+    fn queue_change_allowed(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn queue(&self) -> Option<Arc<Queue>> {
+        None
+    }
+
+    #[inline]
+    fn check_buffer_access(
+        &self,
+        _: &dyn BufferAccess,
+        _: bool,
+        _: &Queue,
+    ) -> Result<Option<(PipelineStages, AccessFlagBits)>, AccessCheckError> {
+        Err(AccessCheckError::Unknown)
+    }
+
+    #[inline]
+    fn check_image_access(
+        &self,
+        _: &dyn ImageAccess,
+        _: ImageLayout,
+        _: bool,
+        _: &Queue,
+    ) -> Result<Option<(PipelineStages, AccessFlagBits)>, AccessCheckError> {
+        Err(AccessCheckError::Unknown)
+    }
+}
+
+unsafe impl DeviceOwned for SemaphoreWaitFuture {
+    #[inline]
+    fn device(&self) -> &Arc<Device> {
+        self.semaphore.device()
+    }
+}
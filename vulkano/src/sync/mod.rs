@@ -99,6 +99,11 @@
 //!
 //! Signalling a fence is kind of a "terminator" to a chain of futures.
 //!
+//! `FenceSignalFuture` and `Fence` both implement `std::future::Future`, so that asynchronous code
+//! can `.await` the end of an operation. A background thread waits on the fences being awaited
+//! and wakes up the corresponding tasks. A `FenceSignalFuture` can also be handed over to that
+//! thread with `detach`, which releases the resources of the submission once the GPU is done.
+//!
 //! TODO: lots of problems with how to use fences
 //! TODO: talk about fence + semaphore simultaneously
 //! TODO: talk about using fences to clean up
@@ -107,9 +112,13 @@ use crate::device::Queue;
 use std::sync::Arc;
 
 pub use self::event::Event;
+pub use self::external::ExternalFenceHandleType;
+pub use self::external::ExternalSemaphoreHandleType;
+pub use self::external::ExternalSyncError;
 pub use self::fence::Fence;
 pub use self::fence::FenceWaitError;
 pub use self::future::now;
+pub use self::future::semaphore_wait;
 pub use self::future::AccessCheckError;
 pub use self::future::AccessError;
 pub use self::future::FenceSignalFuture;
@@ -118,6 +127,7 @@ pub use self::future::GpuFuture;
 pub use self::future::JoinFuture;
 pub use self::future::NowFuture;
 pub use self::future::SemaphoreSignalFuture;
+pub use self::future::SemaphoreWaitFuture;
 pub use self::pipeline::AccessFlagBits;
 pub use self::pipeline::PipelineMemoryAccess;
 pub use self::pipeline::PipelineStage;
@@ -125,7 +135,9 @@ pub use self::pipeline::PipelineStages;
 pub use self::semaphore::Semaphore;
 
 mod event;
+mod external;
 mod fence;
+mod fence_waiter;
 mod future;
 mod pipeline;
 mod semaphore;
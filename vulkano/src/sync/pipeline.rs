@@ -11,12 +11,19 @@ use crate::vk;
 use std::ops;
 
 macro_rules! pipeline_stages {
-    ($($elem:ident, $var:ident => $val:expr, $queue:expr;)+) => (
+    (
+        $($elem:ident, $var:ident => $val:expr, $queue:expr;)+
+        ,
+        $($elem2:ident, $var2:ident => $val2:expr, $fallback:expr, $queue2:expr;)+
+    ) => (
         #[derive(Debug, Copy, Clone, PartialEq, Eq)]
         pub struct PipelineStages {
             $(
                 pub $elem: bool,
             )+
+            $(
+                pub $elem2: bool,
+            )+
         }
 
         impl PipelineStages {
@@ -26,15 +33,36 @@ macro_rules! pipeline_stages {
                     $(
                         $elem: false,
                     )+
+                    $(
+                        $elem2: false,
+                    )+
                 }
             }
 
+            /// Returns the legacy 32-bit stage mask. Stages that only exist with
+            /// `VK_KHR_synchronization2` are replaced with the legacy stages that contain them.
             #[inline]
             pub(crate) fn into_vulkan_bits(self) -> vk::PipelineStageFlagBits {
                 let mut result = 0;
                 $(
                     if self.$elem { result |= $val }
                 )+
+                $(
+                    if self.$elem2 { result |= $fallback }
+                )+
+                result
+            }
+
+            /// Returns the 64-bit stage mask used by `VK_KHR_synchronization2`.
+            #[inline]
+            pub(crate) fn into_vulkan_bits2(self) -> vk::PipelineStageFlags2KHR {
+                let mut result = 0;
+                $(
+                    if self.$elem { result |= $val as vk::PipelineStageFlags2KHR }
+                )+
+                $(
+                    if self.$elem2 { result |= $val2 }
+                )+
                 result
             }
         }
@@ -48,6 +76,9 @@ macro_rules! pipeline_stages {
                     $(
                         $elem: self.$elem || rhs.$elem,
                     )+
+                    $(
+                        $elem2: self.$elem2 || rhs.$elem2,
+                    )+
                 }
             }
         }
@@ -58,14 +89,20 @@ macro_rules! pipeline_stages {
                 $(
                     self.$elem = self.$elem || rhs.$elem;
                 )+
+                $(
+                    self.$elem2 = self.$elem2 || rhs.$elem2;
+                )+
             }
         }
 
         #[derive(Debug, Copy, Clone, PartialEq, Eq)]
-        #[repr(u32)]
+        #[repr(u64)]
         pub enum PipelineStage {
             $(
-                $var = $val,
+                $var = $val as u64,
+            )+
+            $(
+                $var2 = $val2,
             )+
         }
 
@@ -76,8 +113,44 @@ macro_rules! pipeline_stages {
                     $(
                         Self::$var => $queue,
                     )+
+                    $(
+                        Self::$var2 => $queue2,
+                    )+
                 }
             }
+
+            /// Returns true if the stage only exists with `VK_KHR_synchronization2`.
+            #[inline]
+            pub fn requires_synchronization2(&self) -> bool {
+                match self {
+                    $(
+                        Self::$var => false,
+                    )+
+                    $(
+                        Self::$var2 => true,
+                    )+
+                }
+            }
+
+            /// Returns the legacy 32-bit stage mask. Stages that only exist with
+            /// `VK_KHR_synchronization2` are replaced with the legacy stages that contain them.
+            #[inline]
+            pub(crate) fn into_vulkan_bits(self) -> vk::PipelineStageFlagBits {
+                match self {
+                    $(
+                        Self::$var => $val,
+                    )+
+                    $(
+                        Self::$var2 => $fallback,
+                    )+
+                }
+            }
+
+            /// Returns the 64-bit stage mask used by `VK_KHR_synchronization2`.
+            #[inline]
+            pub(crate) fn into_vulkan_bits2(self) -> vk::PipelineStageFlags2KHR {
+                self as vk::PipelineStageFlags2KHR
+            }
         }
     );
 }
@@ -100,16 +173,42 @@ pipeline_stages! {
     host, Host => vk::PIPELINE_STAGE_HOST_BIT, 0;
     all_graphics, AllGraphics => vk::PIPELINE_STAGE_ALL_GRAPHICS_BIT, vk::QUEUE_GRAPHICS_BIT;
     all_commands, AllCommands => vk::PIPELINE_STAGE_ALL_COMMANDS_BIT, 0;
+    task_shader, TaskShader => vk::PIPELINE_STAGE_TASK_SHADER_BIT_NV, vk::QUEUE_GRAPHICS_BIT;
+    mesh_shader, MeshShader => vk::PIPELINE_STAGE_MESH_SHADER_BIT_NV, vk::QUEUE_GRAPHICS_BIT;
+    ,
+    // The following stages only exist with `VK_KHR_synchronization2`. Without it, they are
+    // replaced with the legacy stages given after their value.
+    copy, Copy => vk::PIPELINE_STAGE_2_COPY_BIT_KHR, vk::PIPELINE_STAGE_TRANSFER_BIT, vk::QUEUE_GRAPHICS_BIT | vk::QUEUE_COMPUTE_BIT | vk::QUEUE_TRANSFER_BIT;
+    resolve, Resolve => vk::PIPELINE_STAGE_2_RESOLVE_BIT_KHR, vk::PIPELINE_STAGE_TRANSFER_BIT, vk::QUEUE_GRAPHICS_BIT;
+    blit, Blit => vk::PIPELINE_STAGE_2_BLIT_BIT_KHR, vk::PIPELINE_STAGE_TRANSFER_BIT, vk::QUEUE_GRAPHICS_BIT;
+    clear, Clear => vk::PIPELINE_STAGE_2_CLEAR_BIT_KHR, vk::PIPELINE_STAGE_TRANSFER_BIT, vk::QUEUE_GRAPHICS_BIT | vk::QUEUE_COMPUTE_BIT;
+    index_input, IndexInput => vk::PIPELINE_STAGE_2_INDEX_INPUT_BIT_KHR, vk::PIPELINE_STAGE_VERTEX_INPUT_BIT, vk::QUEUE_GRAPHICS_BIT;
+    vertex_attribute_input, VertexAttributeInput => vk::PIPELINE_STAGE_2_VERTEX_ATTRIBUTE_INPUT_BIT_KHR, vk::PIPELINE_STAGE_VERTEX_INPUT_BIT, vk::QUEUE_GRAPHICS_BIT;
+    pre_rasterization_shaders, PreRasterizationShaders => vk::PIPELINE_STAGE_2_PRE_RASTERIZATION_SHADERS_BIT_KHR,
+        vk::PIPELINE_STAGE_VERTEX_SHADER_BIT
+            | vk::PIPELINE_STAGE_TESSELLATION_CONTROL_SHADER_BIT
+            | vk::PIPELINE_STAGE_TESSELLATION_EVALUATION_SHADER_BIT
+            | vk::PIPELINE_STAGE_GEOMETRY_SHADER_BIT
+            | vk::PIPELINE_STAGE_TASK_SHADER_BIT_NV
+            | vk::PIPELINE_STAGE_MESH_SHADER_BIT_NV,
+        vk::QUEUE_GRAPHICS_BIT;
 }
 
 macro_rules! access_flags {
-    ($($elem:ident => $val:expr,)+) => (
+    (
+        $($elem:ident => $val:expr,)+
+        ;
+        $($elem2:ident => $val2:expr, $fallback:expr,)+
+    ) => (
         #[derive(Debug, Copy, Clone)]
         #[allow(missing_docs)]
         pub struct AccessFlagBits {
             $(
                 pub $elem: bool,
             )+
+            $(
+                pub $elem2: bool,
+            )+
         }
 
         impl AccessFlagBits {
@@ -119,6 +218,9 @@ macro_rules! access_flags {
                     $(
                         $elem: true,
                     )+
+                    $(
+                        $elem2: true,
+                    )+
                 }
             }
 
@@ -128,15 +230,36 @@ macro_rules! access_flags {
                     $(
                         $elem: false,
                     )+
+                    $(
+                        $elem2: false,
+                    )+
                 }
             }
 
+            /// Returns the legacy 32-bit access mask. Accesses that only exist with
+            /// `VK_KHR_synchronization2` are replaced with the legacy accesses that contain them.
             #[inline]
             pub(crate) fn into_vulkan_bits(self) -> vk::AccessFlagBits {
                 let mut result = 0;
                 $(
                     if self.$elem { result |= $val }
                 )+
+                $(
+                    if self.$elem2 { result |= $fallback }
+                )+
+                result
+            }
+
+            /// Returns the 64-bit access mask used by `VK_KHR_synchronization2`.
+            #[inline]
+            pub(crate) fn into_vulkan_bits2(self) -> vk::AccessFlags2KHR {
+                let mut result = 0;
+                $(
+                    if self.$elem { result |= $val as vk::AccessFlags2KHR }
+                )+
+                $(
+                    if self.$elem2 { result |= $val2 }
+                )+
                 result
             }
         }
@@ -150,6 +273,9 @@ macro_rules! access_flags {
                     $(
                         $elem: self.$elem || rhs.$elem,
                     )+
+                    $(
+                        $elem2: self.$elem2 || rhs.$elem2,
+                    )+
                 }
             }
         }
@@ -160,6 +286,9 @@ macro_rules! access_flags {
                 $(
                     self.$elem = self.$elem || rhs.$elem;
                 )+
+                $(
+                    self.$elem2 = self.$elem2 || rhs.$elem2;
+                )+
             }
         }
     );
@@ -183,6 +312,12 @@ access_flags! {
     host_write => vk::ACCESS_HOST_WRITE_BIT,
     memory_read => vk::ACCESS_MEMORY_READ_BIT,
     memory_write => vk::ACCESS_MEMORY_WRITE_BIT,
+    ;
+    // The following accesses only exist with `VK_KHR_synchronization2`. Without it, they are
+    // replaced with the legacy accesses given after their value.
+    shader_sampled_read => vk::ACCESS_2_SHADER_SAMPLED_READ_BIT_KHR, vk::ACCESS_SHADER_READ_BIT,
+    shader_storage_read => vk::ACCESS_2_SHADER_STORAGE_READ_BIT_KHR, vk::ACCESS_SHADER_READ_BIT,
+    shader_storage_write => vk::ACCESS_2_SHADER_STORAGE_WRITE_BIT_KHR, vk::ACCESS_SHADER_WRITE_BIT,
 }
 
 impl AccessFlagBits {
@@ -199,14 +334,27 @@ impl AccessFlagBits {
             return false;
         }
 
-        if (self.index_read || self.vertex_attribute_read)
+        if self.index_read && !stages.vertex_input && !stages.index_input && !stages.all_graphics {
+            return false;
+        }
+
+        if self.vertex_attribute_read
             && !stages.vertex_input
+            && !stages.vertex_attribute_input
             && !stages.all_graphics
         {
             return false;
         }
 
-        if (self.uniform_read || self.shader_read || self.shader_write)
+        if (self.uniform_read
+            || self.shader_read
+            || self.shader_write
+            || self.shader_sampled_read
+            || self.shader_storage_read
+            || self.shader_storage_write)
+            && !stages.pre_rasterization_shaders
+            && !stages.task_shader
+            && !stages.mesh_shader
             && !stages.vertex_shader
             && !stages.tessellation_control_shader
             && !stages.tessellation_evaluation_shader
@@ -237,7 +385,13 @@ impl AccessFlagBits {
             return false;
         }
 
-        if (self.transfer_read || self.transfer_write) && !stages.transfer {
+        if (self.transfer_read || self.transfer_write)
+            && !stages.transfer
+            && !stages.copy
+            && !stages.resolve
+            && !stages.blit
+            && !stages.clear
+        {
             return false;
         }
 
@@ -259,3 +413,92 @@ pub struct PipelineMemoryAccess {
     /// Whether the resource needs exclusive (mutable) access or can be shared.
     pub exclusive: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn synchronization2_stages_legacy_fallback() {
+        let stages = PipelineStages {
+            copy: true,
+            blit: true,
+            ..PipelineStages::none()
+        };
+
+        assert_eq!(stages.into_vulkan_bits(), vk::PIPELINE_STAGE_TRANSFER_BIT);
+        assert_eq!(
+            stages.into_vulkan_bits2(),
+            vk::PIPELINE_STAGE_2_COPY_BIT_KHR | vk::PIPELINE_STAGE_2_BLIT_BIT_KHR
+        );
+    }
+
+    #[test]
+    fn synchronization2_stage_legacy_fallback() {
+        let stage = PipelineStage::PreRasterizationShaders;
+
+        assert!(stage.requires_synchronization2());
+        assert_eq!(
+            stage.into_vulkan_bits2(),
+            vk::PIPELINE_STAGE_2_PRE_RASTERIZATION_SHADERS_BIT_KHR
+        );
+        assert_eq!(
+            stage.into_vulkan_bits(),
+            vk::PIPELINE_STAGE_VERTEX_SHADER_BIT
+                | vk::PIPELINE_STAGE_TESSELLATION_CONTROL_SHADER_BIT
+                | vk::PIPELINE_STAGE_TESSELLATION_EVALUATION_SHADER_BIT
+                | vk::PIPELINE_STAGE_GEOMETRY_SHADER_BIT
+                | vk::PIPELINE_STAGE_TASK_SHADER_BIT_NV
+                | vk::PIPELINE_STAGE_MESH_SHADER_BIT_NV
+        );
+        assert_eq!(stage.required_queue_flags(), vk::QUEUE_GRAPHICS_BIT);
+
+        assert!(!PipelineStage::Transfer.requires_synchronization2());
+        assert_eq!(
+            PipelineStage::Transfer.into_vulkan_bits(),
+            vk::PIPELINE_STAGE_TRANSFER_BIT
+        );
+        assert_eq!(
+            PipelineStage::Copy.required_queue_flags(),
+            PipelineStage::Transfer.required_queue_flags()
+        );
+    }
+
+    #[test]
+    fn synchronization2_accesses_legacy_fallback() {
+        let access = AccessFlagBits {
+            shader_sampled_read: true,
+            shader_storage_write: true,
+            ..AccessFlagBits::none()
+        };
+
+        assert_eq!(
+            access.into_vulkan_bits(),
+            vk::ACCESS_SHADER_READ_BIT | vk::ACCESS_SHADER_WRITE_BIT
+        );
+        assert_eq!(
+            access.into_vulkan_bits2(),
+            vk::ACCESS_2_SHADER_SAMPLED_READ_BIT_KHR | vk::ACCESS_2_SHADER_STORAGE_WRITE_BIT_KHR
+        );
+    }
+
+    #[test]
+    fn transfer_access_compatible_with_copy_stage() {
+        let access = AccessFlagBits {
+            transfer_write: true,
+            ..AccessFlagBits::none()
+        };
+
+        let copy = PipelineStages {
+            copy: true,
+            ..PipelineStages::none()
+        };
+        let compute = PipelineStages {
+            compute_shader: true,
+            ..PipelineStages::none()
+        };
+
+        assert!(access.is_compatible_with(&copy));
+        assert!(!access.is_compatible_with(&compute));
+    }
+}
@@ -7,13 +7,19 @@
 // notice may not be copied, modified, or distributed except
 // according to those terms.
 
+#[cfg(unix)]
+use std::fs::File;
 use std::mem::MaybeUninit;
+#[cfg(unix)]
+use std::os::unix::io::{FromRawFd, IntoRawFd};
 use std::ptr;
 use std::sync::Arc;
 
 use crate::check_errors;
 use crate::device::Device;
 use crate::device::DeviceOwned;
+use crate::sync::ExternalSemaphoreHandleType;
+use crate::sync::ExternalSyncError;
 use crate::vk;
 use crate::OomError;
 use crate::SafeDeref;
@@ -31,6 +37,8 @@ where
     semaphore: vk::Semaphore,
     device: D,
     must_put_in_pool: bool,
+    // Handle types the semaphore can be exported to.
+    export_handle_types: ExternalSemaphoreHandleType,
 }
 
 impl<D> Semaphore<D>
@@ -47,13 +55,14 @@ where
         let maybe_raw_sem = device.semaphore_pool().lock().unwrap().pop();
         match maybe_raw_sem {
             Some(raw_sem) => Ok(Semaphore {
-                device: device,
+                device,
                 semaphore: raw_sem,
                 must_put_in_pool: true,
+                export_handle_types: ExternalSemaphoreHandleType::none(),
             }),
             None => {
                 // Pool is empty, alloc new semaphore
-                Semaphore::alloc_impl(device, true)
+                Semaphore::alloc_impl(device, true, ExternalSemaphoreHandleType::none())
             }
         }
     }
@@ -61,23 +70,168 @@ where
     /// Builds a new semaphore.
     #[inline]
     pub fn alloc(device: D) -> Result<Semaphore<D>, OomError> {
-        Semaphore::alloc_impl(device, false)
+        Semaphore::alloc_impl(device, false, ExternalSemaphoreHandleType::none())
     }
 
-    fn alloc_impl(device: D, must_put_in_pool: bool) -> Result<Semaphore<D>, OomError> {
+    /// Builds a new semaphore whose payload can be exported to the given handle types with
+    /// `export_fd`.
+    ///
+    /// The `khr_external_semaphore` extension must be enabled on the device.
+    pub fn alloc_exportable(
+        device: D,
+        handle_types: ExternalSemaphoreHandleType,
+    ) -> Result<Semaphore<D>, ExternalSyncError> {
+        if !device.loaded_extensions().khr_external_semaphore {
+            return Err(ExternalSyncError::MissingExtension(
+                "khr_external_semaphore",
+            ));
+        }
+
+        Ok(Semaphore::alloc_impl(device, false, handle_types)?)
+    }
+
+    /// Builds a new semaphore and imports a payload into it from a file descriptor.
+    ///
+    /// Payloads of type `sync_fd` are imported temporarily, as required by the Vulkan
+    /// specification: the semaphore goes back to its original payload once it has been waited
+    /// upon. Other payloads are imported permanently. The file descriptor is consumed on success,
+    /// and closed on failure.
+    ///
+    /// The `khr_external_semaphore_fd` extension must be enabled on the device.
+    ///
+    /// # Safety
+    ///
+    /// - `file` must be a payload of type `handle_type`, exported from a semaphore created on a
+    ///   device with the same device and driver UUIDs as `device`.
+    ///
+    #[cfg(unix)]
+    pub unsafe fn from_fd(
+        device: D,
+        handle_type: ExternalSemaphoreHandleType,
+        file: File,
+    ) -> Result<Semaphore<D>, ExternalSyncError> {
+        if !device.loaded_extensions().khr_external_semaphore_fd {
+            return Err(ExternalSyncError::MissingExtension(
+                "khr_external_semaphore_fd",
+            ));
+        }
+
+        let bits = handle_type.fd_bit()?;
+        let semaphore = Semaphore::alloc_impl(device, false, ExternalSemaphoreHandleType::none())?;
+        semaphore.import_fd(bits, file)?;
+        Ok(semaphore)
+    }
+
+    /// Exports the payload of the semaphore to a file descriptor.
+    ///
+    /// `handle_type` must be one of the handle types passed to `alloc_exportable`. Exporting a
+    /// `sync_fd` payload has the same effect on the semaphore as waiting on it.
+    ///
+    /// The `khr_external_semaphore_fd` extension must be enabled on the device.
+    ///
+    /// # Safety
+    ///
+    /// - If `handle_type` is `sync_fd`, the semaphore must have a pending signal operation and
+    ///   no pending wait operation.
+    ///
+    #[cfg(unix)]
+    pub unsafe fn export_fd(
+        &self,
+        handle_type: ExternalSemaphoreHandleType,
+    ) -> Result<File, ExternalSyncError> {
+        if !self.device.loaded_extensions().khr_external_semaphore_fd {
+            return Err(ExternalSyncError::MissingExtension(
+                "khr_external_semaphore_fd",
+            ));
+        }
+
+        let bits = handle_type.fd_bit()?;
+        if bits & self.export_handle_types.to_bits() == 0 {
+            return Err(ExternalSyncError::HandleTypeNotExportable);
+        }
+
+        let info = vk::SemaphoreGetFdInfoKHR {
+            sType: vk::STRUCTURE_TYPE_SEMAPHORE_GET_FD_INFO_KHR,
+            pNext: ptr::null(),
+            semaphore: self.semaphore,
+            handleType: bits,
+        };
+
+        let vk = self.device.pointers();
+        let mut output = MaybeUninit::uninit();
+        check_errors(vk.GetSemaphoreFdKHR(
+            self.device.internal_object(),
+            &info,
+            output.as_mut_ptr(),
+        ))?;
+        Ok(File::from_raw_fd(output.assume_init()))
+    }
+
+    /// Returns the handle types the semaphore can be exported to.
+    #[inline]
+    pub fn export_handle_types(&self) -> ExternalSemaphoreHandleType {
+        self.export_handle_types
+    }
+
+    // Imports a payload into the semaphore. `sync_fd` payloads are imported temporarily.
+    #[cfg(unix)]
+    pub(crate) unsafe fn import_fd(
+        &self,
+        bits: vk::ExternalSemaphoreHandleTypeFlagBits,
+        file: File,
+    ) -> Result<(), ExternalSyncError> {
+        let fd = file.into_raw_fd();
+        let info = vk::ImportSemaphoreFdInfoKHR {
+            sType: vk::STRUCTURE_TYPE_IMPORT_SEMAPHORE_FD_INFO_KHR,
+            pNext: ptr::null(),
+            semaphore: self.semaphore,
+            flags: if bits == vk::EXTERNAL_SEMAPHORE_HANDLE_TYPE_SYNC_FD_BIT {
+                vk::SEMAPHORE_IMPORT_TEMPORARY_BIT
+            } else {
+                0
+            },
+            handleType: bits,
+            fd,
+        };
+
+        let vk = self.device.pointers();
+        match check_errors(vk.ImportSemaphoreFdKHR(self.device.internal_object(), &info)) {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                // The implementation only takes ownership of the file descriptor on success.
+                drop(File::from_raw_fd(fd));
+                Err(err.into())
+            }
+        }
+    }
+
+    fn alloc_impl(
+        device: D,
+        must_put_in_pool: bool,
+        export_handle_types: ExternalSemaphoreHandleType,
+    ) -> Result<Semaphore<D>, OomError> {
         let semaphore = unsafe {
-            // since the creation is constant, we use a `static` instead of a struct on the stack
-            static mut INFOS: vk::SemaphoreCreateInfo = vk::SemaphoreCreateInfo {
+            let export_info = vk::ExportSemaphoreCreateInfo {
+                sType: vk::STRUCTURE_TYPE_EXPORT_SEMAPHORE_CREATE_INFO,
+                pNext: ptr::null(),
+                handleTypes: export_handle_types.to_bits(),
+            };
+
+            let infos = vk::SemaphoreCreateInfo {
                 sType: vk::STRUCTURE_TYPE_SEMAPHORE_CREATE_INFO,
-                pNext: 0 as *const _, // ptr::null()
-                flags: 0,             // reserved
+                pNext: if export_handle_types.to_bits() != 0 {
+                    &export_info as *const _ as *const _
+                } else {
+                    ptr::null()
+                },
+                flags: 0, // reserved
             };
 
             let vk = device.pointers();
             let mut output = MaybeUninit::uninit();
             check_errors(vk.CreateSemaphore(
                 device.internal_object(),
-                &INFOS,
+                &infos,
                 ptr::null(),
                 output.as_mut_ptr(),
             ))?;
@@ -85,9 +239,10 @@ where
         };
 
         Ok(Semaphore {
-            device: device,
-            semaphore: semaphore,
-            must_put_in_pool: must_put_in_pool,
+            device,
+            semaphore,
+            must_put_in_pool,
+            export_handle_types,
         })
     }
 }
@@ -133,6 +288,8 @@ where
 
 #[cfg(test)]
 mod tests {
+    use crate::sync::ExternalSemaphoreHandleType;
+    use crate::sync::ExternalSyncError;
     use crate::sync::Semaphore;
     use crate::VulkanObject;
 
@@ -158,4 +315,14 @@ mod tests {
         assert_eq!(device.semaphore_pool().lock().unwrap().len(), 0);
         assert_eq!(sem2.internal_object(), sem1_internal_obj);
     }
+
+    #[test]
+    fn exportable_missing_extension() {
+        let (device, _) = gfx_dev_and_queue!();
+
+        match Semaphore::alloc_exportable(device, ExternalSemaphoreHandleType::posix()) {
+            Err(ExternalSyncError::MissingExtension("khr_external_semaphore")) => (),
+            _ => panic!(),
+        }
+    }
 }
@@ -59,6 +59,25 @@ impl Ord for Version {
 }
 
 impl Version {
+    /// Vulkan 1.0.
+    pub const V1_0: Version = Version::major_minor(1, 0);
+    /// Vulkan 1.1.
+    pub const V1_1: Version = Version::major_minor(1, 1);
+    /// Vulkan 1.2.
+    pub const V1_2: Version = Version::major_minor(1, 2);
+    /// Vulkan 1.3.
+    pub const V1_3: Version = Version::major_minor(1, 3);
+
+    /// Builds a `Version` with the given major and minor numbers, and a patch number of 0.
+    #[inline]
+    pub const fn major_minor(major: u16, minor: u16) -> Version {
+        Version {
+            major,
+            minor,
+            patch: 0,
+        }
+    }
+
     /// Turns a version number given by Vulkan into a `Version` struct.
     #[inline]
     pub fn from_vulkan_version(value: u32) -> Version {